@@ -0,0 +1,482 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::cmp::Ordering;
+use std::collections::LinkedList;
+use std::collections::hash_map::HashMap;
+
+use env::named_lambda::RLNamedLambda;
+use env::result::RLResult;
+use env::symb::RLEnvSymbol;
+
+use err::err::{RLError,
+               SimpleTypeError};
+
+use expr::atom::RLAtom;
+use expr::bool::RLBool;
+use expr::char::RLChar;
+use expr::nil::RLNil;
+use expr::num::RLNum;
+use expr::sexpr::SExpr;
+use expr::t::RLT;
+
+use hash::hash::RLHash;
+
+pub struct CharFuncs {
+}
+
+impl CharFuncs {
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    pub fn init(&mut self,
+                cl_pack_hash: &mut HashMap<String, RLEnvSymbol, RLHash>) {
+
+        cl_pack_hash.insert("char=".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR=".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if all of the arguments are the same character."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_MONOTONIC(&sexpr, Ordering::is_eq)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char/=".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR/=".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR/=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if no two of the arguments are the same character."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_NE(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char<".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR<".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR<".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if the arguments are in strictly increasing alphabetic order."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_MONOTONIC(&sexpr, Ordering::is_lt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char>".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR>".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR>".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if the arguments are in strictly decreasing alphabetic order."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_MONOTONIC(&sexpr, Ordering::is_gt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char<=".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR<=".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR<=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if the arguments are in increasing alphabetic order."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_MONOTONIC(&sexpr, Ordering::is_le)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char>=".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR>=".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR>=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if the arguments are in decreasing alphabetic order."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_MONOTONIC(&sexpr, Ordering::is_ge)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char-code".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR-CODE".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR-CODE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the code of CHAR, its Unicode code point."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_CODE(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("code-char".to_string(),
+            RLEnvSymbol::new_named_lambda("CODE-CHAR".to_string(),
+                RLNamedLambda::new_func(
+                    "CODE-CHAR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the character with the code CODE, or NIL if there is none."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CODE_CHAR(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char-upcase".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR-UPCASE".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR-UPCASE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return CHAR converted to upper case if it has a single upper case character, otherwise CHAR."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_CASE(&sexpr, true)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char-downcase".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR-DOWNCASE".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR-DOWNCASE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return CHAR converted to lower case if it has a single lower case character, otherwise CHAR."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR_CASE(&sexpr, false)?) }),
+                    None)));
+
+        cl_pack_hash.insert("alpha-char-p".to_string(),
+            RLEnvSymbol::new_named_lambda("ALPHA-CHAR-P".to_string(),
+                RLNamedLambda::new_func(
+                    "ALPHA-CHAR-P".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if CHAR is an alphabetic character, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            ALPHA_CHAR_P(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("digit-char-p".to_string(),
+            RLEnvSymbol::new_named_lambda("DIGIT-CHAR-P".to_string(),
+                RLNamedLambda::new_func(
+                    "DIGIT-CHAR-P".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("If CHAR is a digit in RADIX, 10 by default, return its weight, otherwise NIL."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DIGIT_CHAR_P(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("characterp".to_string(),
+            RLEnvSymbol::new_named_lambda("CHARACTERP".to_string(),
+                RLNamedLambda::new_func(
+                    "CHARACTERP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if OBJECT is a character, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHARACTERP(&sexpr)?) }),
+                    None)));
+    }
+}
+
+// char=, char<, char>, char<= and char>= hold if accept is true for
+// every pair of neighbouring arguments. Characters are ordered by
+// their codes.
+#[allow(non_snake_case)]
+pub fn CHAR_MONOTONIC(sexpr: &SExpr, accept: fn(Ordering) -> bool) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    char= &rest characters+ => generalized-boolean
+    char< &rest characters+ => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let chars = char_args(ll, 1, None)?;
+
+            let ordered = chars.iter()
+                               .zip(chars.iter().skip(1))
+                               .all(|(a, b)| accept(a.cmp(b)));
+
+            Ok(bool_res(ordered))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CHAR_NE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    char/= &rest characters+ => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let chars = char_args(ll, 1, None)?;
+
+            for (idx, ch) in chars.iter().enumerate() {
+                if chars.iter().skip(idx + 1).any(|other| other == ch) {
+                    return Ok(bool_res(false))
+                }
+            }
+
+            Ok(bool_res(true))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CHAR_CODE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    char-code character => code
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let chars = char_args(ll, 1, Some(1))?;
+
+            let code = *chars.front().unwrap() as i64;
+
+            Ok(RLResult::NumRes(RLNum::Fixnum(code)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CODE_CHAR(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    code-char code => char-p
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let code = ll.front().unwrap();
+
+            let ch = match code {
+                SExpr::Num(RLNum::Fixnum(code)) if *code >= 0 => {
+                    Some(u32::try_from(*code).ok().and_then(char::from_u32))
+                }
+
+                _ => None,
+            };
+
+            match ch {
+                Some(Some(ch)) => {
+                    Ok(RLResult::SExprRes(SExpr::Char(RLChar::new(ch))))
+                }
+
+                // surrogates and codes beyond the Unicode range
+                Some(None) => Ok(RLResult::NilRes(RLNil::new())),
+
+                None => {
+                    let err = SimpleTypeError::new(&code.to_string(),
+                                                   "(INTEGER 0 1114111)");
+
+                    Err(RLError::SimpleTypeError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CHAR_CASE(sexpr: &SExpr, upcase: bool) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    char-upcase character => corresponding-character
+    char-downcase character => corresponding-character
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let chars = char_args(ll, 1, Some(1))?;
+
+            let ch = RLChar::new(*chars.front().unwrap());
+
+            let result = if upcase { ch.upcase() } else { ch.downcase() };
+
+            Ok(RLResult::SExprRes(SExpr::Char(result)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn ALPHA_CHAR_P(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    alpha-char-p character => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let chars = char_args(ll, 1, Some(1))?;
+
+            Ok(bool_res(chars.front().unwrap().is_alphabetic()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn DIGIT_CHAR_P(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    digit-char-p char &optional radix => weight
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.is_empty() || ll.len() > 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let ch = char_arg(ll.front().unwrap())?;
+
+            let radix = match ll.iter().nth(1) {
+                Some(SExpr::Num(RLNum::Fixnum(radix)))
+                    if (2..=36).contains(radix) => *radix as u32,
+
+                Some(other) => return Err(radix_error(other)),
+
+                None => 10,
+            };
+
+            match ch.to_digit(radix) {
+                Some(weight) => {
+                    Ok(RLResult::NumRes(RLNum::Fixnum(weight as i64)))
+                }
+
+                None => Ok(RLResult::NilRes(RLNil::new())),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CHARACTERP(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    characterp object => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            Ok(bool_res(matches!(ll.front().unwrap(), SExpr::Char(_))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+pub fn char_arg(sexpr: &SExpr) -> Result<char, RLError> {
+    match sexpr {
+        SExpr::Char(ch) => Ok(ch.get()),
+
+        _ => {
+            let err = SimpleTypeError::new(&sexpr.to_string(), "CHARACTER");
+
+            Err(RLError::SimpleTypeError(err))
+        }
+    }
+}
+
+// The arguments as characters, there must be at least min and at most
+// max of them.
+fn char_args(ll: &LinkedList<SExpr>,
+             min: usize,
+             max: Option<usize>) -> Result<LinkedList<char>, RLError> {
+
+    if ll.len() < min || max.is_some_and(|max| ll.len() > max) {
+        return Err(RLError::SimpleProgramError)
+    }
+
+    ll.iter().map(char_arg).collect()
+}
+
+fn radix_error(sexpr: &SExpr) -> RLError {
+    let err = SimpleTypeError::new(&sexpr.to_string(), "(INTEGER 2 36)");
+
+    RLError::SimpleTypeError(err)
+}
+
+fn bool_res(value: bool) -> RLResult {
+    if value {
+        RLResult::BoolRes(RLBool::T(RLT::new()))
+    } else {
+        RLResult::BoolRes(RLBool::Nil(RLNil::new()))
+    }
+}
+
+/*
+Characters
+
+System Class CHARACTER
+
+Type BASE-CHAR
+
+Type STANDARD-CHAR
+
+Type EXTENDED-CHAR
+
+// Function CHAR=, CHAR/=, CHAR<, CHAR>, CHAR<=, CHAR>=
+
+Function CHAR-EQUAL, CHAR-NOT-EQUAL, CHAR-LESSP, CHAR-GREATERP,
+CHAR-NOT-GREATERP, CHAR-NOT-LESSP
+
+Function CHARACTER
+
+// Function CHARACTERP
+
+// Function ALPHA-CHAR-P
+
+Function ALPHANUMERICP
+
+Function DIGIT-CHAR
+
+// Function DIGIT-CHAR-P
+
+Function GRAPHIC-CHAR-P
+
+Function STANDARD-CHAR-P
+
+// Function CHAR-UPCASE, CHAR-DOWNCASE
+
+Function UPPER-CASE-P, LOWER-CASE-P, BOTH-CASE-P
+
+// Function CHAR-CODE
+
+Function CHAR-INT
+
+// Function CODE-CHAR
+
+Constant Variable CHAR-CODE-LIMIT
+
+Function CHAR-NAME
+
+Function NAME-CHAR
+*/
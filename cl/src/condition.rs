@@ -0,0 +1,1444 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::collections::LinkedList;
+use std::collections::hash_map::HashMap;
+
+use env::block::RLBlock;
+use env::condition::{RLConditionType, RLSlot};
+use env::env::RLEnvironment;
+use env::env_trait::EnvTrait;
+use env::named_lambda::RLNamedLambda;
+use env::result::{RLRestartClause, RLResult};
+use env::symb::RLEnvSymbol;
+
+use err::err::{ConditionError,
+               RLError,
+               SimpleError,
+               TypeError};
+
+use expr::atom::RLAtom;
+use expr::condition::RLCondition;
+use expr::nil::RLNil;
+use expr::num::RLNum;
+use expr::sexpr::SExpr;
+use expr::symb::RLSymbol;
+
+use hash::hash::RLHash;
+
+use crate::data_control::{list_items, make_form, skip_declarations,
+                          variable_name};
+use crate::list::make_list;
+
+pub struct ConditionFuncs {
+}
+
+impl ConditionFuncs {
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    pub fn init(&mut self,
+                cl_pack_hash: &mut HashMap<String, RLEnvSymbol, RLHash>) {
+
+        cl_pack_hash.insert("define-condition".to_string(),
+            RLEnvSymbol::new_named_lambda("DEFINE-CONDITION".to_string(),
+                RLNamedLambda::new_func(
+                    "DEFINE-CONDITION".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("DEFINE-CONDITION name (parent-type*) (slot-spec*) option*
+
+Define NAME as a condition type which inherits the slots of the
+PARENT-TYPES. The :report option is a string which is printed for the
+conditions of the type."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            DEFINE_CONDITION(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("make-condition".to_string(),
+            RLEnvSymbol::new_named_lambda("MAKE-CONDITION".to_string(),
+                RLNamedLambda::new_func(
+                    "MAKE-CONDITION".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("MAKE-CONDITION type &rest initargs
+
+Make an instance of a condition object using the specified initargs."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGNAL(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("signal".to_string(),
+            RLEnvSymbol::new_named_lambda("SIGNAL".to_string(),
+                RLNamedLambda::new_func(
+                    "SIGNAL".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("SIGNAL datum &rest arguments
+
+Invoke the signal facility on a condition formed from DATUM and ARGUMENTS.
+If the condition is not handled, NIL is returned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGNAL(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("error".to_string(),
+            RLEnvSymbol::new_named_lambda("ERROR".to_string(),
+                RLNamedLambda::new_func(
+                    "ERROR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("ERROR datum &rest arguments
+
+Invoke the signal facility on a condition formed from DATUM and ARGUMENTS.
+If the condition is not handled, the evaluation is aborted."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGNAL(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("cerror".to_string(),
+            RLEnvSymbol::new_named_lambda("CERROR".to_string(),
+                RLNamedLambda::new_func(
+                    "CERROR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("CERROR continue-format-control datum &rest arguments
+
+Like ERROR, CONTINUE-FORMAT-CONTROL describes what continuing does."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGNAL(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("warn".to_string(),
+            RLEnvSymbol::new_named_lambda("WARN".to_string(),
+                RLNamedLambda::new_func(
+                    "WARN".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("WARN datum &rest arguments
+
+Warn about a situation by signalling a condition formed by DATUM and
+ARGUMENTS. If the condition is not handled, it is printed to the error
+output and NIL is returned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGNAL(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("handler-case".to_string(),
+            RLEnvSymbol::new_named_lambda("HANDLER-CASE".to_string(),
+                RLNamedLambda::new_func(
+                    "HANDLER-CASE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("HANDLER-CASE form {(type ([var]) body)}* [(:no-error lambda-list body)]
+
+Execute FORM in a context with handlers established for the condition types.
+A peculiar property allows type to be :NO-ERROR. If such a clause occurs, and
+form returns normally, all its values are passed to this clause as if by
+MULTIPLE-VALUE-CALL. The :NO-ERROR clause accepts more than one var
+specification."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            HANDLER_CASE(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("handler-bind".to_string(),
+            RLEnvSymbol::new_named_lambda("HANDLER-BIND".to_string(),
+                RLNamedLambda::new_func(
+                    "HANDLER-BIND".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("HANDLER-BIND ({(type handler)}*) form*
+
+Execute FORMS in a dynamic context where the given handler bindings are in
+effect. Each handler must take the condition being signalled as an argument.
+The bindings are searched first to last in the event of a signalled
+condition."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            HANDLER_BIND(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("ignore-errors".to_string(),
+            RLEnvSymbol::new_named_lambda("IGNORE-ERRORS".to_string(),
+                RLNamedLambda::new_func(
+                    "IGNORE-ERRORS".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("IGNORE-ERRORS form*
+
+Execute FORMS handling ERROR conditions, returning the condition as the
+secondary value if an error occurs."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            IGNORE_ERRORS(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("restart-case".to_string(),
+            RLEnvSymbol::new_named_lambda("RESTART-CASE".to_string(),
+                RLNamedLambda::new_func(
+                    "RESTART-CASE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("RESTART-CASE restartable-form {(case-name lambda-list [[:report string]] form*)}*
+
+Evaluate RESTARTABLE-FORM in a dynamic environment where the clauses have
+special meanings as points to which control may be transferred. If
+RESTARTABLE-FORM finishes executing and returns, all values are returned. If
+control is transferred to a clause, the clause is called with the arguments
+of INVOKE-RESTART and its values are returned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RESTART_CASE(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("with-simple-restart".to_string(),
+            RLEnvSymbol::new_named_lambda("WITH-SIMPLE-RESTART".to_string(),
+                RLNamedLambda::new_func(
+                    "WITH-SIMPLE-RESTART".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("WITH-SIMPLE-RESTART (name format-control format-argument*) form*
+
+Evaluate the FORMS with a restart named NAME described by FORMAT-CONTROL.
+If the restart is invoked, NIL and T are returned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RESTART_CASE(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("invoke-restart".to_string(),
+            RLEnvSymbol::new_named_lambda("INVOKE-RESTART".to_string(),
+                RLNamedLambda::new_func(
+                    "INVOKE-RESTART".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("INVOKE-RESTART restart &rest values
+
+Call the function associated with RESTART, passing VALUES to it. RESTART is
+a restart or the name of an active restart."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RESTART_FUNCTION(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("find-restart".to_string(),
+            RLEnvSymbol::new_named_lambda("FIND-RESTART".to_string(),
+                RLNamedLambda::new_func(
+                    "FIND-RESTART".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("FIND-RESTART identifier &optional condition
+
+Return the first active restart named IDENTIFIER, NIL if there is none."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RESTART_FUNCTION(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("compute-restarts".to_string(),
+            RLEnvSymbol::new_named_lambda("COMPUTE-RESTARTS".to_string(),
+                RLNamedLambda::new_func(
+                    "COMPUTE-RESTARTS".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("COMPUTE-RESTARTS &optional condition
+
+Return a list of all the currently active restarts, most recently
+established first."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RESTART_FUNCTION(&sexpr)?) }),
+                    None)));
+
+        for (name, doc) in [
+            ("abort", "Transfer control to the innermost ABORT restart, the outermost one returns to the top level."),
+            ("continue", "Transfer control to the innermost CONTINUE restart, if there is one. Otherwise return NIL."),
+            ("muffle-warning", "Transfer control to the innermost MUFFLE-WARNING restart, which stops WARN from printing the warning."),
+            ("store-value", "Transfer control to the innermost STORE-VALUE restart with VALUE, if there is one. Otherwise return NIL."),
+            ("use-value", "Transfer control to the innermost USE-VALUE restart with VALUE, if there is one. Otherwise return NIL.")] {
+
+            cl_pack_hash.insert(name.to_string(),
+                RLEnvSymbol::new_named_lambda(name.to_uppercase(),
+                    RLNamedLambda::new_func(
+                        name.to_uppercase(),
+                        Some(SExpr::Atom(RLAtom::new(doc))),
+                        "COMMON-LISP".to_string(),
+                        Some(|sexpr| {
+                            Ok::<RLResult, RLError>(
+                                RESTART_FUNCTION(&sexpr)?) }),
+                        None)));
+        }
+
+        cl_pack_hash.insert("restart-name".to_string(),
+            RLEnvSymbol::new_named_lambda("RESTART-NAME".to_string(),
+                RLNamedLambda::new_func(
+                    "RESTART-NAME".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the name of the given restart object."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RESTART_NAME(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("slot-value".to_string(),
+            RLEnvSymbol::new_named_lambda("SLOT-VALUE".to_string(),
+                RLNamedLambda::new_func(
+                    "SLOT-VALUE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("SLOT-VALUE object slot-name
+
+Return the value of the slot SLOT-NAME of the condition OBJECT."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SLOT_VALUE(&sexpr)?) }),
+                    None)));
+
+        for (name, _, _) in READERS {
+            cl_pack_hash.insert(name.to_string(),
+                RLEnvSymbol::new_named_lambda(name.to_uppercase(),
+                    RLNamedLambda::new_func(
+                        name.to_uppercase(),
+                        None,
+                        "COMMON-LISP".to_string(),
+                        Some(|sexpr| {
+                            Ok::<RLResult, RLError>(
+                                CONDITION_READER(&sexpr)?) }),
+                        None)));
+        }
+    }
+}
+
+// (reader, slot, condition type) of the standard condition types
+const READERS: [(&str, &str, &str); 7] = [
+    ("simple-condition-format-control", "FORMAT-CONTROL", "SIMPLE-CONDITION"),
+    ("simple-condition-format-arguments", "FORMAT-ARGUMENTS",
+        "SIMPLE-CONDITION"),
+    ("type-error-datum", "DATUM", "TYPE-ERROR"),
+    ("type-error-expected-type", "EXPECTED-TYPE", "TYPE-ERROR"),
+    ("cell-error-name", "NAME", "CELL-ERROR"),
+    ("arithmetic-error-operation", "OPERATION", "ARITHMETIC-ERROR"),
+    ("arithmetic-error-operands", "OPERANDS", "ARITHMETIC-ERROR"),
+];
+
+// Macro DEFINE-CONDITION
+// The condition type is registered in the environment, the readers of
+// the slots become (defun reader (condition) (slot-value condition 'slot))
+// which the evaluator evaluates.
+#[allow(non_snake_case)]
+pub fn DEFINE_CONDITION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    define-condition name (parent-type*) ({slot-spec}*) option* => name
+
+    slot-spec::= slot-name | (slot-name slot-option)
+    slot-option::= [[ {:reader symbol}* | {:accessor symbol}* |
+                      {:initarg symbol}* | {:initform form} |
+                      {:type type-specifier} | {:documentation string} ]]
+    option::= [[ (:report string) | (:documentation string) ]]
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let name = variable_name(&linked_list.pop_front().unwrap())?;
+
+            let mut parents = Vec::<String>::new();
+
+            for parent in list_items(linked_list.pop_front().unwrap())? {
+                let parent = variable_name(&parent)?;
+
+                if env_ref.get_condition_type(&parent).is_none() {
+                    return Err(not_a_condition_type(&parent))
+                }
+
+                parents.push(parent);
+            }
+
+            if parents.is_empty() {
+                parents.push("condition".to_string());
+            }
+
+            let slot_specs = match linked_list.pop_front() {
+                Some(slot_specs) => list_items(slot_specs)?,
+
+                None => LinkedList::<SExpr>::new(),
+            };
+
+            let mut slots = Vec::<RLSlot>::new();
+
+            for slot_spec in slot_specs {
+                slots.push(parse_slot_spec(slot_spec)?);
+            }
+
+            let mut report = None;
+
+            for option in linked_list {
+                let mut items = list_items(option.clone())?;
+
+                match (items.pop_front(), items.pop_front()) {
+                    (Some(SExpr::Symb(key)), Some(SExpr::Atom(string)))
+                        if string.is_rlstring_atom() &&
+                           items.is_empty() => {
+
+                        match &*key.get_symbol_name() {
+                            ":report" => {
+                                report = Some(
+                                    string.get_atom_rlstring().get());
+                            }
+
+                            ":documentation" => {}
+
+                            _ => return Err(bad_option(&option)),
+                        }
+                    }
+
+                    _ => return Err(bad_option(&option)),
+                }
+            }
+
+            let mut forms = LinkedList::<SExpr>::new();
+
+            for slot in &slots {
+                for reader in &slot.readers {
+                    forms.push_back(slot_reader(reader, &slot.name));
+                }
+            }
+
+            forms.push_back(make_form("quote", LinkedList::from([
+                SExpr::Symb(RLSymbol::new_with_str(&name))])));
+
+            env_ref.add_condition_type(
+                RLConditionType::new(&name, parents, slots, report));
+
+            Ok(RLResult::ExpansionRes(make_form("progn", forms)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// slot-name or (slot-name slot-option*)
+fn parse_slot_spec(slot_spec: SExpr) -> Result<RLSlot, RLError> {
+    let mut items = match slot_spec {
+        SExpr::SList(slist) => slist.get_items(),
+
+        slot_name => LinkedList::from([slot_name]),
+    };
+
+    let mut slot = RLSlot::new(&variable_name(&items.pop_front().unwrap())?);
+
+    slot.initarg = None;
+
+    if !items.len().is_multiple_of(2) {
+        let err = SimpleError::new(&format!(
+            "Odd number of slot options for the slot {}.",
+            slot.name));
+
+        return Err(RLError::SimpleError(err))
+    }
+
+    while let (Some(key), Some(value)) = (items.pop_front(), items.pop_front()) {
+        let key = match key {
+            SExpr::Symb(symb) => symb.get_symbol_name(),
+
+            other => {
+                let err = TypeError::new(&other.to_string(), "KEYWORD");
+
+                return Err(RLError::TypeError(err))
+            }
+        };
+
+        match &*key {
+            ":initarg" => {
+                slot.initarg = Some(variable_symbol_name(&value)?);
+            }
+
+            ":initform" => slot.initform = Some(value),
+
+            ":reader" | ":accessor" => {
+                slot.readers.push(variable_symbol_name(&value)?);
+            }
+
+            ":type" | ":documentation" => {}
+
+            _ => {
+                let err = SimpleError::new(&format!(
+                    "Unknown slot option {} for the slot {}.",
+                    key.to_uppercase(),
+                    slot.name));
+
+                return Err(RLError::SimpleError(err))
+            }
+        }
+    }
+
+    Ok(slot)
+}
+
+// keywords are symbols here, unlike in variable_name
+fn variable_symbol_name(sexpr: &SExpr) -> Result<String, RLError> {
+    match sexpr {
+        SExpr::Symb(symb) => Ok(symb.get_symbol_name().to_uppercase()),
+
+        other => {
+            let err = TypeError::new(&other.to_string(), "SYMBOL");
+
+            Err(RLError::TypeError(err))
+        }
+    }
+}
+
+// (defun reader (condition) (slot-value condition 'slot))
+fn slot_reader(reader: &str, slot: &str) -> SExpr {
+    let condition = SExpr::Symb(RLSymbol::new_with_str("condition"));
+
+    let slot_value = make_form("slot-value", LinkedList::from([
+        condition.clone(),
+        make_form("quote", LinkedList::from([
+            SExpr::Symb(RLSymbol::new_with_str(&slot.to_lowercase()))]))]));
+
+    make_form("defun", LinkedList::from([
+        SExpr::Symb(RLSymbol::new_with_str(&reader.to_lowercase())),
+        make_list(&LinkedList::from([condition])),
+        slot_value]))
+}
+
+fn bad_option(option: &SExpr) -> RLError {
+    let err = SimpleError::new(&format!(
+        "Unsupported DEFINE-CONDITION option {}, only (:REPORT string) and \
+         (:DOCUMENTATION string) are supported.",
+        option));
+
+    RLError::SimpleError(err)
+}
+
+pub fn not_a_condition_type(name: &str) -> RLError {
+    let err = SimpleError::new(&format!(
+        "{} does not designate a condition class.",
+        name.to_uppercase()));
+
+    RLError::SimpleError(err)
+}
+
+// Function MAKE-CONDITION, SIGNAL, ERROR, CERROR, WARN
+// The arguments have already been evaluated. The evaluator makes the
+// condition, the initforms of its slots are evaluated, and signals it.
+#[allow(non_snake_case)]
+pub fn SIGNAL(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    make-condition type &rest slot-initializations => condition
+    signal datum &rest arguments => nil
+    error datum &rest arguments =>|
+    cerror continue-format-control datum &rest arguments => nil
+    warn datum &rest arguments => nil
+    */
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let min_args = if symb.name.eq("cerror") { 2 } else { 1 };
+
+            if ll.len() < min_args {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            Ok(RLResult::ConditionRes(symb.name.clone(), ll.clone()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro HANDLER-CASE
+// The evaluator evaluates the expression with a handler for every
+// clause, a handled condition is bound to the variable of the clause
+// in the block of its body.
+#[allow(non_snake_case)]
+pub fn HANDLER_CASE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    handler-case expression [[{error-clause}* | no-error-clause]]
+        => result*
+
+    clause::= error-clause | no-error-clause
+    error-clause::= (typespec ([var]) declaration* form*)
+    no-error-clause::= (:no-error lambda-list declaration* form*)
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let expression = match linked_list.pop_front() {
+                Some(expression) => expression,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let mut clauses = Vec::<(SExpr, Option<String>, RLBlock)>::new();
+
+            let mut no_error = None;
+
+            for clause in linked_list {
+                let mut items = list_items(clause.clone())?;
+
+                if items.len() < 2 {
+                    return Err(malformed_clause("HANDLER-CASE", &clause))
+                }
+
+                let typespec = items.pop_front().unwrap();
+
+                if let SExpr::Symb(ref symb) = typespec {
+                    if symb.get_symbol_name().eq(":no-error") {
+                        // (lambda lambda-list declaration* form*)
+                        no_error = Some(make_form("lambda", items));
+
+                        continue;
+                    }
+                }
+
+                let mut vars = list_items(items.pop_front().unwrap())?;
+
+                let var = match vars.len() {
+                    0 => None,
+
+                    1 => Some(variable_name(&vars.pop_front().unwrap())?),
+
+                    _ => return Err(malformed_clause("HANDLER-CASE", &clause)),
+                };
+
+                let specials = skip_declarations(&mut items);
+
+                let mut block = RLBlock::new(
+                    "(HANDLER-CASE)-START".to_string());
+
+                block.set_specials(&specials);
+                block.set_progn_ll(&items);
+
+                clauses.push((typespec, var, block));
+            }
+
+            Ok(RLResult::HandlerCaseRes(expression, clauses, no_error))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro HANDLER-BIND
+// The evaluator evaluates the handler forms to functions and calls
+// them when a condition of their type is signalled by the forms.
+#[allow(non_snake_case)]
+pub fn HANDLER_BIND(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    handler-bind ({(type handler)}*) form* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let binding_forms = match linked_list.pop_front() {
+                Some(binding_forms) => list_items(binding_forms)?,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let mut bindings = Vec::<(SExpr, SExpr)>::new();
+
+            for binding_form in binding_forms {
+                let mut items = list_items(binding_form.clone())?;
+
+                if items.len() != 2 {
+                    return Err(malformed_clause("HANDLER-BIND",
+                                                &binding_form))
+                }
+
+                let typespec = items.pop_front().unwrap();
+
+                bindings.push((typespec, items.pop_front().unwrap()));
+            }
+
+            Ok(RLResult::HandlerBindRes(bindings, linked_list))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn malformed_clause(operator: &str, clause: &SExpr) -> RLError {
+    let err = SimpleError::new(&format!(
+        "Malformed {} clause: {}",
+        operator, clause));
+
+    RLError::SimpleError(err)
+}
+
+// Macro IGNORE-ERRORS
+// (ignore-errors forms) =>
+// (handler-case (progn forms) (error (condition) (values nil condition)))
+#[allow(non_snake_case)]
+pub fn IGNORE_ERRORS(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    ignore-errors form* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let condition = SExpr::Symb(RLSymbol::new_with_str("condition"));
+
+            let clause = make_list(&LinkedList::from([
+                SExpr::Symb(RLSymbol::new_with_str("error")),
+                make_list(&LinkedList::from([condition.clone()])),
+                make_form("values", LinkedList::from([
+                    SExpr::Nil(RLNil::new()),
+                    condition]))]));
+
+            Ok(RLResult::ExpansionRes(make_form("handler-case",
+                LinkedList::from([make_form("progn", ll.clone()), clause]))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro RESTART-CASE, WITH-SIMPLE-RESTART
+// The evaluator establishes the restarts while it evaluates the
+// restartable form. The clause of an invoked restart becomes a lambda
+// which is called with the arguments of INVOKE-RESTART.
+// (with-simple-restart (name control args) forms) =>
+// (restart-case (progn forms) (name () :report control (values nil t)))
+#[allow(non_snake_case)]
+pub fn RESTART_CASE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    restart-case restartable-form {clause}* => result*
+    with-simple-restart (name format-control format-argument*) form*
+        => result*
+
+    clause::= (case-name lambda-list
+               [[:interactive interactive-expression |
+                 :report report-expression |
+                 :test test-expression]]
+               declaration* form*)
+    */
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if symb.name.eq("with-simple-restart") {
+                let mut restart_spec = match linked_list.pop_front() {
+                    Some(restart_spec) => list_items(restart_spec)?,
+
+                    None => return Err(RLError::SimpleProgramError),
+                };
+
+                if restart_spec.len() < 2 {
+                    return Err(RLError::SimpleProgramError)
+                }
+
+                let name = restart_name(&restart_spec.pop_front().unwrap())?;
+
+                let function = make_form("lambda", LinkedList::from([
+                    SExpr::Nil(RLNil::new()),
+                    make_form("values", LinkedList::from([
+                        SExpr::Nil(RLNil::new()),
+                        SExpr::Symb(RLSymbol::new_with_str("t"))]))]));
+
+                let clause = RLRestartClause {
+                    name,
+                    report: restart_spec,
+                    function,
+                    arity: 0,
+                };
+
+                return Ok(RLResult::RestartCaseRes(
+                    make_form("progn", linked_list), vec![clause]))
+            }
+
+            let form = match linked_list.pop_front() {
+                Some(form) => form,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let mut clauses = Vec::<RLRestartClause>::new();
+
+            for clause in linked_list {
+                let mut items = list_items(clause.clone())?;
+
+                if items.len() < 2 {
+                    return Err(malformed_clause("RESTART-CASE", &clause))
+                }
+
+                let name = restart_name(&items.pop_front().unwrap())?;
+
+                let lambda_list = items.pop_front().unwrap();
+
+                // required parameters come before the first &-keyword
+                let arity = list_items(lambda_list.clone())?
+                                .iter()
+                                .take_while(|param| {
+                                    !matches!(param, SExpr::Symb(symb)
+                                        if symb.get_symbol_name()
+                                               .starts_with('&'))
+                                })
+                                .count();
+
+                let mut report = LinkedList::<SExpr>::new();
+
+                while let Some(SExpr::Symb(key)) = items.front().cloned() {
+                    let key = key.get_symbol_name();
+
+                    if !matches!(&*key, ":report" | ":interactive" | ":test") {
+                        break;
+                    }
+
+                    items.pop_front();
+
+                    let value = match items.pop_front() {
+                        Some(value) => value,
+
+                        None => {
+                            return Err(malformed_clause("RESTART-CASE",
+                                                        &clause))
+                        }
+                    };
+
+                    match (&*key, value) {
+                        (":report", SExpr::Atom(atom))
+                            if atom.is_rlstring_atom() => {
+
+                            report.push_back(SExpr::Atom(atom));
+                        }
+
+                        (":report", other) => {
+                            let err = SimpleError::new(&format!(
+                                "Unsupported :REPORT {} of the restart {}, \
+                                 only strings are supported.",
+                                other, name.to_uppercase()));
+
+                            return Err(RLError::SimpleError(err))
+                        }
+
+                        // the debugger asks for the required arguments,
+                        // all restarts are visible
+                        _ => {}
+                    }
+                }
+
+                items.push_front(lambda_list);
+
+                clauses.push(RLRestartClause {
+                    name,
+                    report,
+                    function: make_form("lambda", items),
+                    arity,
+                });
+            }
+
+            Ok(RLResult::RestartCaseRes(form, clauses))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// name of a restart, NIL for anonymous restarts
+fn restart_name(sexpr: &SExpr) -> Result<String, RLError> {
+    match sexpr {
+        SExpr::Symb(symb) => Ok(symb.get_symbol_name()),
+
+        SExpr::Nil(_) => Ok("nil".to_string()),
+
+        other => {
+            let err = TypeError::new(&other.to_string(), "SYMBOL");
+
+            Err(RLError::TypeError(err))
+        }
+    }
+}
+
+// Function INVOKE-RESTART, FIND-RESTART, COMPUTE-RESTARTS
+// Function ABORT, CONTINUE, MUFFLE-WARNING, STORE-VALUE, USE-VALUE
+// The arguments have already been evaluated, the evaluator finds the
+// active restarts. The optional condition arguments are accepted and
+// ignored, all restarts are visible to every condition.
+#[allow(non_snake_case)]
+pub fn RESTART_FUNCTION(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    invoke-restart restart &rest arguments => result*
+    find-restart identifier &optional condition => restart
+    compute-restarts &optional condition => restarts
+    abort &optional condition =>|
+    continue &optional condition => nil
+    muffle-warning &optional condition =>|
+    store-value value &optional condition => nil
+    use-value value &optional condition => nil
+    */
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let (min_args, max_args) = match &*symb.name {
+                "invoke-restart" => (1, usize::MAX),
+
+                "find-restart" | "store-value" | "use-value" => (1, 2),
+
+                _ => (0, 1),
+            };
+
+            if ll.len() < min_args || ll.len() > max_args {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut args = ll.clone();
+
+            // the condition
+            if max_args == 2 && args.len() == 2 {
+                args.pop_back();
+            } else if max_args == 1 {
+                args.clear();
+            }
+
+            Ok(RLResult::ConditionRes(symb.name.clone(), args))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Function RESTART-NAME
+#[allow(non_snake_case)]
+pub fn RESTART_NAME(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    restart-name restart => name
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            match ll.front().unwrap() {
+                SExpr::Restart(restart) if restart.get_name().eq("NIL") => {
+                    Ok(RLResult::NilRes(RLNil::new()))
+                }
+
+                SExpr::Restart(restart) => {
+                    Ok(RLResult::SExprRes(SExpr::Symb(RLSymbol::new_with_str(
+                        &restart.get_name().to_lowercase()))))
+                }
+
+                other => {
+                    let err = TypeError::new(&other.to_string(), "RESTART");
+
+                    Err(RLError::TypeError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Function SLOT-VALUE
+// Only conditions have slots in rlisp.
+#[allow(non_snake_case)]
+pub fn SLOT_VALUE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    slot-value object slot-name => value
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut linked_list = ll.clone();
+
+            let object = linked_list.pop_front().unwrap();
+
+            let slot_name = variable_symbol_name(
+                &linked_list.pop_front().unwrap())?;
+
+            match object {
+                SExpr::Condition(ref condition)
+                    if condition.has_slot(&slot_name) => {
+
+                    Ok(RLResult::SExprRes(
+                        condition.get_slot_value(&slot_name)))
+                }
+
+                SExpr::Condition(_) => {
+                    let err = SimpleError::new(&format!(
+                        "When attempting to read the slot's value \
+                         (slot-value), the slot {} is missing from the \
+                         object {}.",
+                        slot_name, object));
+
+                    Err(RLError::SimpleError(err))
+                }
+
+                other => {
+                    let err = TypeError::new(&other.to_string(),
+                                             "CONDITION");
+
+                    Err(RLError::TypeError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// The readers of the slots of the standard condition types, e.g.
+// TYPE-ERROR-DATUM
+#[allow(non_snake_case)]
+pub fn CONDITION_READER(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    type-error-datum condition => datum
+    */
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let (_, slot, condition_type) = READERS.iter()
+                                                   .find(|(name, _, _)| {
+                                                       name.eq(&symb.name)
+                                                   })
+                                                   .unwrap();
+
+            match ll.front().unwrap() {
+                SExpr::Condition(condition) if condition.has_slot(slot) => {
+                    Ok(RLResult::SExprRes(condition.get_slot_value(slot)))
+                }
+
+                other => {
+                    let err = TypeError::new(&other.to_string(),
+                                             condition_type);
+
+                    Err(RLError::TypeError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+///////////////////////////////////////////////////////////
+/*
+Reports and errors
+*/
+///////////////////////////////////////////////////////////
+
+// The text of a condition of the type name with the given slots: the
+// :report string of the type or of its nearest supertype which has
+// one, the formatted control string of simple conditions, or the
+// report of the standard condition type.
+pub fn report_condition(env: &dyn EnvTrait,
+                        name: &str,
+                        slots: &[(String, SExpr)]) ->
+    Result<String, RLError> {
+
+    if let Some(report) = find_report(env, name) {
+        return Ok(report)
+    }
+
+    let slot = |slot_name: &str| {
+        slots.iter()
+             .find(|(name, _)| name.eq(slot_name))
+             .map(|(_, value)| value.clone())
+             .unwrap_or(SExpr::Nil(RLNil::new()))
+    };
+
+    if env.condition_subtypep(name, "simple-condition") &&
+       slots.iter().any(|(name, _)| name.eq("FORMAT-CONTROL")) {
+
+        let control = princ_to_string(&slot("FORMAT-CONTROL"));
+
+        let arguments = list_items(slot("FORMAT-ARGUMENTS"))?;
+
+        return format_control(&control, &arguments)
+    }
+
+    let report = if env.condition_subtypep(name, "type-error") {
+        format!("The value {} is not of type {}",
+                slot("DATUM"), slot("EXPECTED-TYPE"))
+
+    } else if env.condition_subtypep(name, "unbound-variable") {
+        format!("The variable {} is unbound.", slot("NAME"))
+
+    } else if env.condition_subtypep(name, "undefined-function") {
+        format!("The function {} is undefined.", slot("NAME"))
+
+    } else if env.condition_subtypep(name, "arithmetic-error") {
+        format!("arithmetic error {} signalled", name.to_uppercase())
+
+    } else {
+        format!("Condition of type {} was signalled.", name.to_uppercase())
+    };
+
+    Ok(report)
+}
+
+// the :report string of the type or of a supertype, depth first
+fn find_report(env: &dyn EnvTrait, name: &str) -> Option<String> {
+    let condition_type = env.get_condition_type(name)?;
+
+    if let Some(report) = condition_type.get_report() {
+        return Some(report)
+    }
+
+    condition_type.get_parents()
+                  .iter()
+                  .find_map(|parent| find_report(env, parent))
+}
+
+// A FORMAT control string with the directives ~A, ~S, ~D, ~% and ~~.
+pub fn format_control(control: &str, arguments: &LinkedList<SExpr>) ->
+    Result<String, RLError> {
+
+    let mut out = String::new();
+
+    let mut arguments = arguments.iter();
+
+    let mut chars = control.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            out.push(c);
+
+            continue;
+        }
+
+        let directive = chars.next().map(|d| d.to_ascii_lowercase());
+
+        match directive {
+            Some('a') | Some('d') | Some('s') => {
+                let argument = match arguments.next() {
+                    Some(argument) => argument,
+
+                    None => {
+                        let err = SimpleError::new(&format!(
+                            "There are not enough arguments for the format \
+                             control \"{}\".",
+                            control));
+
+                        return Err(RLError::SimpleError(err))
+                    }
+                };
+
+                if directive == Some('s') {
+                    out.push_str(&argument.to_string());
+                } else {
+                    out.push_str(&princ_to_string(argument));
+                }
+            }
+
+            Some('%') => out.push('\n'),
+
+            Some('&') => {
+                if !out.is_empty() && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+            }
+
+            Some('~') => out.push('~'),
+
+            _ => {
+                let err = SimpleError::new(&format!(
+                    "Unknown directive ~{} in the format control \"{}\".",
+                    directive.map(String::from).unwrap_or_default(),
+                    control));
+
+                return Err(RLError::SimpleError(err))
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+// The printed representation of sexpr for people: strings without
+// quotes, conditions and restarts by their report.
+pub fn princ_to_string(sexpr: &SExpr) -> String {
+    match sexpr {
+        SExpr::Atom(atom) if atom.is_rlstring_atom() => {
+            atom.get_atom_rlstring().get()
+        }
+
+        SExpr::Condition(condition) => condition.get_message(),
+
+        SExpr::Restart(restart) => restart.get_report(),
+
+        other => other.to_string(),
+    }
+}
+
+// The condition of the standard type which stands for err, e.g.
+// UNBOUND-VARIABLE for UnboundVariableError.
+pub fn error_to_condition(err: &RLError) -> RLCondition {
+    let symbol = |name: &str| {
+        if name.contains([' ', '(', ')', '"']) {
+            SExpr::Atom(RLAtom::new(name))
+        } else {
+            SExpr::Symb(RLSymbol::new_with_str(&name.to_lowercase()))
+        }
+    };
+
+    let nil = || SExpr::Nil(RLNil::new());
+
+    // the datum is kept printed, numbers are read back
+    let datum = |text: &str| match RLNum::read(text) {
+        Ok(Some(num)) => SExpr::Num(num),
+
+        _ => SExpr::Atom(RLAtom::new(text)),
+    };
+
+    let (type_name, slots, message) = match err {
+        RLError::TypeError(type_error) => {
+            ("type-error",
+             vec![("DATUM".to_string(),
+                   datum(&type_error.get_datum())),
+                  ("EXPECTED-TYPE".to_string(),
+                   symbol(&type_error.get_expected_type()))],
+             format!("The value {} is not of type {}",
+                     type_error.get_datum(),
+                     type_error.get_expected_type()))
+        }
+
+        RLError::SimpleTypeError(simple_type_error) => {
+            ("simple-type-error",
+             vec![("DATUM".to_string(),
+                   datum(&simple_type_error.get_datum())),
+                  ("EXPECTED-TYPE".to_string(),
+                   symbol(&simple_type_error.get_expected_type())),
+                  ("FORMAT-ARGUMENTS".to_string(), nil())],
+             format!("The value {} is not of type {}",
+                     simple_type_error.get_datum(),
+                     simple_type_error.get_expected_type()))
+        }
+
+        RLError::UnboundVariableError(unbound_var_error) => {
+            let name = unbound_var_error.to_string();
+
+            ("unbound-variable",
+             vec![("NAME".to_string(), symbol(&name))],
+             format!("The variable {} is unbound.", name))
+        }
+
+        RLError::UndefinedFuncError(undefined_func_error) => {
+            let details = undefined_func_error.to_string();
+
+            // "The function COMMON-LISP-USER::F is undefined." or "F"
+            let name = details.strip_prefix("The function ")
+                              .and_then(|rest| rest.split(' ').next())
+                              .map(|name| name.rsplit(':').next().unwrap())
+                              .unwrap_or(&details)
+                              .to_string();
+
+            let message = if details.contains(' ') {
+                details.clone()
+            } else {
+                format!("The function {} is undefined.", details)
+            };
+
+            ("undefined-function",
+             vec![("NAME".to_string(), symbol(&name))],
+             message)
+        }
+
+        RLError::DivisionByZero => {
+            ("division-by-zero",
+             vec![("OPERATION".to_string(), symbol("/")),
+                  ("OPERANDS".to_string(), nil())],
+             "arithmetic error DIVISION-BY-ZERO signalled".to_string())
+        }
+
+        RLError::FloatingPointOverflow => {
+            ("floating-point-overflow",
+             vec![("OPERATION".to_string(), nil()),
+                  ("OPERANDS".to_string(), nil())],
+             "arithmetic error FLOATING-POINT-OVERFLOW signalled".to_string())
+        }
+
+        RLError::SimpleError(simple_error) => {
+            let details = simple_error.to_string();
+
+            ("simple-error",
+             vec![("FORMAT-CONTROL".to_string(),
+                   SExpr::Atom(RLAtom::new(&format!("\"{}\"", details)))),
+                  ("FORMAT-ARGUMENTS".to_string(), nil())],
+             details)
+        }
+
+        RLError::ProgramError(program_error) => {
+            ("program-error", vec![], program_error.to_string())
+        }
+
+        RLError::SimpleProgramError => {
+            ("program-error", vec![],
+             "invalid number of arguments".to_string())
+        }
+
+        RLError::BlockError(_) => {
+            ("program-error", vec![], err.to_string())
+        }
+
+        RLError::ControlError(control_error) => {
+            ("control-error", vec![], control_error.to_string())
+        }
+
+        RLError::ReturnFromError(_) => {
+            ("control-error", vec![], err.to_string())
+        }
+
+        RLError::ParseError(_) => {
+            ("parse-error", vec![], err.to_string())
+        }
+
+        RLError::ParseFloatError(_) | RLError::ParseIntError(_) => {
+            ("type-error", vec![], err.to_string())
+        }
+
+        RLError::ConditionError(condition_error) => {
+            ("error", vec![], condition_error.to_string())
+        }
+
+        RLError::WithBacktrace(err, _frames) => {
+            return error_to_condition(err)
+        }
+    };
+
+    RLCondition::new_from_error(type_name, slots, &message, err.clone())
+}
+
+// The error which aborts the evaluation if no handler takes the
+// condition, the error of rlisp a condition was made for.
+pub fn condition_to_error(condition: &RLCondition) -> RLError {
+    if let Some(err) = condition.get_error() {
+        return err
+    }
+
+    let type_name = condition.get_type_name();
+
+    if type_name.eq("SIMPLE-ERROR") {
+        let err = SimpleError::new(&condition.get_message());
+
+        return RLError::SimpleError(err)
+    }
+
+    let err = ConditionError::new(&type_name, &condition.get_message());
+
+    RLError::ConditionError(err)
+}
+
+/*
+// Condition Type CONDITION
+
+// Condition Type WARNING
+
+// Condition Type STYLE-WARNING
+
+// Condition Type SERIOUS-CONDITION
+
+// Condition Type ERROR
+
+// Condition Type CELL-ERROR
+
+// Function CELL-ERROR-NAME
+
+// Condition Type PARSE-ERROR
+
+Condition Type STORAGE-CONDITION
+
+Macro ASSERT
+
+// Function ERROR
+
+// Function CERROR
+
+Macro CHECK-TYPE
+
+// Condition Type SIMPLE-ERROR
+
+Function INVALID-METHOD-ERROR
+
+Function METHOD-COMBINATION-ERROR
+
+// Function SIGNAL
+
+// Condition Type SIMPLE-CONDITION
+
+// Function SIMPLE-CONDITION-FORMAT-CONTROL, SIMPLE-CONDITION-FORMAT-ARGUMENTS
+
+// Function WARN
+
+// Condition Type SIMPLE-WARNING
+
+Function INVOKE-DEBUGGER
+
+Function BREAK
+
+Variable *DEBUGGER-HOOK*
+
+Variable *BREAK-ON-SIGNALS*
+
+// Macro HANDLER-BIND
+
+// Macro HANDLER-CASE
+
+// Macro IGNORE-ERRORS
+
+// Macro DEFINE-CONDITION
+
+// Function MAKE-CONDITION
+
+// System Class RESTART
+
+// Function COMPUTE-RESTARTS
+
+// Function FIND-RESTART
+
+// Function INVOKE-RESTART
+
+Function INVOKE-RESTART-INTERACTIVELY
+
+Macro RESTART-BIND
+
+// Macro RESTART-CASE
+
+// Function RESTART-NAME
+
+Macro WITH-CONDITION-RESTARTS
+
+// Macro WITH-SIMPLE-RESTART
+
+// Restart ABORT
+
+// Restart CONTINUE
+
+// Restart MUFFLE-WARNING
+
+// Restart STORE-VALUE
+
+// Restart USE-VALUE
+
+// Function ABORT, CONTINUE, MUFFLE-WARNING, STORE-VALUE, USE-VALUE
+*/
@@ -3,66 +3,100 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use crate::condition::ConditionFuncs;
 use crate::data_control::DataControlFuncs;
 use crate::eval_comp::EvalCompilationFuncs;
+use crate::iteration::IterationFuncs;
 
 use crate::pack::PackageFuncs;
 use crate::symb::SymbolFuncs;
 
+use crate::character::CharFuncs;
 use crate::list::ListFuncs;
 use crate::math::MathFuncs;
 use crate::string::StrFuncs;
+use crate::system::SystemFuncs;
+
+use crate::rlisp::RLispFuncs;
 
 use env::pack::RLPackage;
 
 pub struct CLCreator {
+    rconditionf: ConditionFuncs,
     rdata_controlf: DataControlFuncs,
     reval_compf: EvalCompilationFuncs,
+    riterationf: IterationFuncs,
 
     rpackf: PackageFuncs,
     rsymbf: SymbolFuncs,
 
+    rcharf: CharFuncs,
     rlf:   ListFuncs,
     rmf:   MathFuncs,
     rstrf: StrFuncs,
+    rsysf: SystemFuncs,
+
+    rrlispf: RLispFuncs,
 }
 
 impl CLCreator {
     pub fn new() -> CLCreator {
+        let rconditionf = ConditionFuncs::new();
         let rdata_controlf = DataControlFuncs::new();
         let reval_compf = EvalCompilationFuncs::new();
+        let riterationf = IterationFuncs::new();
 
         let rpackf = PackageFuncs::new();
         let rsymbf = SymbolFuncs::new();
 
+        let rcharf = CharFuncs::new();
         let rlf   = ListFuncs::new();
         let rmf   = MathFuncs::new();
         let rstrf = StrFuncs::new();
+        let rsysf = SystemFuncs::new();
+
+        let rrlispf = RLispFuncs::new();
 
         CLCreator {
+            rconditionf,
             rdata_controlf,
             reval_compf,
+            riterationf,
 
             rpackf,
             rsymbf,
 
+            rcharf,
             rlf,
             rmf,
-            rstrf
+            rstrf,
+            rsysf,
+
+            rrlispf,
         }
     }
 
     pub fn init(&mut self, cl_pack: &mut RLPackage) {
         let cl_pack_hash = cl_pack.get_symbols_hash_map();
 
+        self.rconditionf.init(cl_pack_hash);
         self.rdata_controlf.init(cl_pack_hash);
         self.reval_compf.init(cl_pack_hash);
+        self.riterationf.init(cl_pack_hash);
 
         self.rpackf.init(cl_pack_hash);
         self.rsymbf.init(cl_pack_hash);
 
+        self.rcharf.init(cl_pack_hash);
         self.rlf.init(cl_pack_hash);
         self.rmf.init(cl_pack_hash);
         self.rstrf.init(cl_pack_hash);
+        self.rsysf.init(cl_pack_hash);
+    }
+
+    pub fn init_rlisp(&mut self, rlisp_pack: &mut RLPackage) {
+        let rlisp_pack_hash = rlisp_pack.get_symbols_hash_map();
+
+        self.rrlispf.init(rlisp_pack_hash);
     }
 }
@@ -5,13 +5,14 @@
 
 use std::collections::LinkedList;
 use std::collections::hash_map::HashMap;
-use std::ops::Not;
 
 use env::block::RLBlock;
 use env::dyn_var::RLDynVar;
 use env::env::RLEnvironment;
 use env::env_trait::EnvTrait;
+use env::lambda::RLLambda;
 use env::named_lambda::RLNamedLambda;
+use env::ordinary_lambda_list::RLOrdinaryLambdaList;
 use env::pack::RLPackage;
 use env::result::RLResult;
 use env::symb::RLEnvSymbol;
@@ -19,18 +20,28 @@ use env::var::RLVar;
 
 use err::err::RLError;
 use err::err::{BlockError,
+               ControlError,
                ReturnFromError,
+               SimpleError,
+               TypeError,
                UndefinedFuncError};
 
 use expr::atom::RLAtom;
+use expr::bool::RLBool;
+use expr::list::RLList;
 use expr::nil::RLNil;
 use expr::sexpr::SExpr;
 use expr::string::RLString;
+use expr::symb::RLSymbol;
+use expr::t::RLT;
 
 use hash::hash::RLHash;
 
 use pars_symb::symbol::Symbol;
 
+use crate::eval_comp::{LAMBDA, make_macro, parse_definition};
+use crate::list::make_list;
+
 pub struct DataControlFuncs {
 }
 
@@ -118,6 +129,80 @@ be a lambda expression."))),
                         Ok::<RLResult, RLError>(
                             PROGV(&sexpr, env_ref)?) }))));
 
+        cl_pack_hash.insert("let".to_string(),
+            RLEnvSymbol::new_named_lambda("LET".to_string(),
+                RLNamedLambda::new_func(
+                    "LET".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("LET ({var-binding}*) declaration* form*
+
+During evaluation of the FORMS, bind the VARS to the result of evaluating the
+VALUE forms. The variables are bound in parallel after all of the VALUES forms
+have been evaluated."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            LET(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("let*".to_string(),
+            RLEnvSymbol::new_named_lambda("LET*".to_string(),
+                RLNamedLambda::new_func(
+                    "LET*".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("LET* ({var-binding}*) declaration* form*
+
+Similar to LET, but the variables are bound sequentially, allowing each VALUE
+form to reference any of the previous VARS."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            LET(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("flet".to_string(),
+            RLEnvSymbol::new_named_lambda("FLET".to_string(),
+                RLNamedLambda::new_func(
+                    "FLET".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("FLET ({(name lambda-list declaration* form*)}*) declaration* body-form*
+
+Evaluate the BODY-FORMS with local function definitions. The bindings do
+not enclose the definitions; any use of NAME in the FORMS will refer to the
+lexically apparent function definition in the enclosing environment."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            FLET(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("labels".to_string(),
+            RLEnvSymbol::new_named_lambda("LABELS".to_string(),
+                RLNamedLambda::new_func(
+                    "LABELS".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("LABELS ({(name lambda-list declaration* form*)}*) declaration* body-form*
+
+Evaluate the BODY-FORMS with local function definitions. The bindings enclose
+the new definitions, so the defined functions can call themselves or each
+other."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            FLET(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("macrolet".to_string(),
+            RLEnvSymbol::new_named_lambda("MACROLET".to_string(),
+                RLNamedLambda::new_func(
+                    "MACROLET".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("MACROLET ({(name lambda-list declaration* form*)}*) declaration* body-form*
+
+Evaluate the BODY-FORMS in an environment with the specified local macros
+defined. NAME is the local macro name, LAMBDA-LIST is a DEFMACRO style
+destructuring lambda list, and the FORMS evaluate to the expansion."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            MACROLET(&sexpr, env_ref)?) }))));
+
         cl_pack_hash.insert("setq".to_string(),
             RLEnvSymbol::new_named_lambda("SETQ".to_string(),
                 RLNamedLambda::new_func(
@@ -129,6 +214,105 @@ be a lambda expression."))),
                         Ok::<RLResult, RLError>(
                             SETQ(&sexpr, env_ref)?) }))));
 
+        cl_pack_hash.insert("psetq".to_string(),
+            RLEnvSymbol::new_named_lambda("PSETQ".to_string(),
+                RLNamedLambda::new_func(
+                    "PSETQ".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("PSETQ {var value}*
+
+Assign the VALUES to the VARS in parallel, all VALUES are evaluated
+before the first variable is assigned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            PSETQ(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("if".to_string(),
+            RLEnvSymbol::new_named_lambda("IF".to_string(),
+                RLNamedLambda::new_func(
+                    "IF".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("IF predicate then [else]
+
+If PREDICATE evaluates to true, evaluate THEN and return its values,
+otherwise evaluate ELSE and return its values. ELSE defaults to NIL."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            IF(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("cond".to_string(),
+            RLEnvSymbol::new_named_lambda("COND".to_string(),
+                RLNamedLambda::new_func(
+                    "COND".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("COND {(test form*)}*
+
+Evaluate the TEST of every clause in turn, the FORMS of the first clause
+whose TEST is true are evaluated as a PROGN. Without forms the value of the
+TEST is returned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            COND(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("when".to_string(),
+            RLEnvSymbol::new_named_lambda("WHEN".to_string(),
+                RLNamedLambda::new_func(
+                    "WHEN".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("WHEN test form*
+
+If the first argument is true, the rest of the forms are
+evaluated as a PROGN."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            WHEN(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("unless".to_string(),
+            RLEnvSymbol::new_named_lambda("UNLESS".to_string(),
+                RLNamedLambda::new_func(
+                    "UNLESS".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("UNLESS test form*
+
+If the first argument is not true, the rest of the forms are
+evaluated as a PROGN."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            WHEN(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("and".to_string(),
+            RLEnvSymbol::new_named_lambda("AND".to_string(),
+                RLNamedLambda::new_func(
+                    "AND".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("AND form*
+
+Evaluate the FORMS in order. If any form returns NIL, quit and return NIL.
+Otherwise return the values of the last form, T without forms."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            AND(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("or".to_string(),
+            RLEnvSymbol::new_named_lambda("OR".to_string(),
+                RLNamedLambda::new_func(
+                    "OR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("OR form*
+
+Evaluate the FORMS in order, returning the value of the first one which
+is not NIL. NIL is returned if every form is NIL."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            OR(&sexpr)?) }),
+                    None)));
+
         cl_pack_hash.insert("block".to_string(),
             RLEnvSymbol::new_named_lambda("BLOCK".to_string(),
                 RLNamedLambda::new_func(
@@ -158,6 +342,95 @@ extent of the block."))),
                         Ok::<RLResult, RLError>(
                             RETURN_FROM(&sexpr, env_ref)?) }))));
 
+        cl_pack_hash.insert("return".to_string(),
+            RLEnvSymbol::new_named_lambda("RETURN".to_string(),
+                RLNamedLambda::new_func(
+                    "RETURN".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("RETURN [result]
+
+Return the values of RESULT from the lexically enclosing block named
+NIL, like the ones of DO, DOLIST, DOTIMES and LOOP."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            RETURN(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("catch".to_string(),
+            RLEnvSymbol::new_named_lambda("CATCH".to_string(),
+                RLNamedLambda::new_func(
+                    "CATCH".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("CATCH tag form*
+
+Evaluate TAG and instantiate it as a catcher while the rest of the forms are
+evaluated as a PROGN. If a THROW is done to TAG within the dynamic scope of
+the CATCH, then control will be transferred to the end of the CATCH, making
+the result of the THROW the result of the CATCH."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CATCH(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("throw".to_string(),
+            RLEnvSymbol::new_named_lambda("THROW".to_string(),
+                RLNamedLambda::new_func(
+                    "THROW".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("THROW tag form
+
+Do a non-local exit, return the values of FORM from the CATCH whose tag is
+EQ to TAG."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            THROW(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("unwind-protect".to_string(),
+            RLEnvSymbol::new_named_lambda("UNWIND-PROTECT".to_string(),
+                RLNamedLambda::new_func(
+                    "UNWIND-PROTECT".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("UNWIND-PROTECT protected cleanup*
+
+Evaluate the form PROTECTED, returning its values. The CLEANUP forms are
+evaluated whenever the dynamic extent of the PROTECTED form is exited (either
+due to normal completion or a non-local exit such as THROW)."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            UNWIND_PROTECT(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("tagbody".to_string(),
+            RLEnvSymbol::new_named_lambda("TAGBODY".to_string(),
+                RLNamedLambda::new_func(
+                    "TAGBODY".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("TAGBODY {tag | statement}*
+
+Define tags for use with GO. The STATEMENTS are evaluated in order, skipping
+TAGS, and NIL is returned. If a statement contains a GO to a defined TAG
+within the lexical scope of the form, then control is transferred to the
+next statement following that tag."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            TAGBODY(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("go".to_string(),
+            RLEnvSymbol::new_named_lambda("GO".to_string(),
+                RLNamedLambda::new_func(
+                    "GO".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("GO tag
+
+Transfer control to the named TAG in the lexically enclosing TAGBODY. This
+is constrained to be used only within the dynamic extent of the TAGBODY."))),
+                    "COMMON-LISP".to_string(),
+                    None,
+                    Some(|sexpr, env_ref| {
+                        Ok::<RLResult, RLError>(
+                            GO(&sexpr, env_ref)?) }))));
+
         cl_pack_hash.insert("progn".to_string(),
             RLEnvSymbol::new_named_lambda("PROGN".to_string(),
                 RLNamedLambda::new_func(
@@ -171,187 +444,409 @@ forms, returns NIL."))),
                     Some(|sexpr, env_ref| {
                         Ok::<RLResult, RLError>(
                             PROGN(&sexpr, env_ref)?) }))));
-    }
-}
-
-/*
-Function APPLY
-*/
 
-#[allow(non_snake_case)]
-pub fn FUNCALL(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
-    Result<RLResult, RLError> {
+        cl_pack_hash.insert("destructuring-bind".to_string(),
+            RLEnvSymbol::new_named_lambda("DESTRUCTURING-BIND".to_string(),
+                RLNamedLambda::new_func(
+                    "DESTRUCTURING-BIND".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("DESTRUCTURING-BIND lambda-list expression declaration* form*
 
-    println!("Hello from FUNCALL");
-    println!("");
+Bind the variables in LAMBDA-LIST to the corresponding values in the tree
+structure resulting from the evaluation of EXPRESSION."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DESTRUCTURING_BIND(&sexpr)?) }),
+                    None)));
 
-    let curr_pack = env_ref.get_mut_current_package();
+        cl_pack_hash.insert("multiple-value-bind".to_string(),
+            RLEnvSymbol::new_named_lambda("MULTIPLE-VALUE-BIND".to_string(),
+                RLNamedLambda::new_func(
+                    "MULTIPLE-VALUE-BIND".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("MULTIPLE-VALUE-BIND (var*) values-form declaration* form*
 
-    let curr_pack_name = curr_pack.get_name();
+Evaluate VALUES-FORM and bind the VARS to its values, extra VARS are bound
+to NIL. Then evaluate the FORMS as an implicit PROGN."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MULTIPLE_VALUE_BIND(&sexpr)?) }),
+                    None)));
 
-    match sexpr {
-        SExpr::Cons(symb, ll) => {
-            let mut linked_list = ll.clone();
+        cl_pack_hash.insert("multiple-value-call".to_string(),
+            RLEnvSymbol::new_named_lambda("MULTIPLE-VALUE-CALL".to_string(),
+                RLNamedLambda::new_func(
+                    "MULTIPLE-VALUE-CALL".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("MULTIPLE-VALUE-CALL function values-form*
 
-            println!("linked_list: {:?}", linked_list);
-            println!("");
+Call FUNCTION, passing all the values of each VALUES-FORM as arguments,
+values from the first VALUES-FORM making up the first argument, etc."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MULTIPLE_VALUE_CALL(&sexpr)?) }),
+                    None)));
 
-            match &*symb.name {
-                "funcall" => {
+        cl_pack_hash.insert("multiple-value-list".to_string(),
+            RLEnvSymbol::new_named_lambda("MULTIPLE-VALUE-LIST".to_string(),
+                RLNamedLambda::new_func(
+                    "MULTIPLE-VALUE-LIST".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("MULTIPLE-VALUE-LIST form
 
-                    /*
-                    for _n in 0..linked_list.len() {
-                        let item = linked_list.pop_front().expect("REASON");
+Return a list of the values returned by FORM."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MULTIPLE_VALUE_LIST(&sexpr)?) }),
+                    None)));
 
-                        println!("funcall - item: {:?}", item);
-                        println!("");
-                    }
-                    */
+        cl_pack_hash.insert("multiple-value-prog1".to_string(),
+            RLEnvSymbol::new_named_lambda("MULTIPLE-VALUE-PROG1".to_string(),
+                RLNamedLambda::new_func(
+                    "MULTIPLE-VALUE-PROG1".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("MULTIPLE-VALUE-PROG1 values-form form*
 
+Evaluate VALUES-FORM and then the FORMS, but return all the values of
+VALUES-FORM."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MULTIPLE_VALUE_PROG1(&sexpr)?) }),
+                    None)));
 
-                    let first_param = linked_list.pop_front();
+        cl_pack_hash.insert("nth-value".to_string(),
+            RLEnvSymbol::new_named_lambda("NTH-VALUE".to_string(),
+                RLNamedLambda::new_func(
+                    "NTH-VALUE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("NTH-VALUE n form
 
-                    if let Some(SExpr::Atom(ref atom)) = first_param {
-                        // let symbol: Option<RLSymbol>;
+Evaluate N and then FORM, returning as its only value the Nth value returned
+by FORM. If N is at least the number of values returned, NIL is returned."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            NTH_VALUE(&sexpr)?) }),
+                    None)));
 
-                        let atom_string = atom.get_atom_string();
+        cl_pack_hash.insert("values".to_string(),
+            RLEnvSymbol::new_named_lambda("VALUES".to_string(),
+                RLNamedLambda::new_func(
+                    "VALUES".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return all arguments, in order, as values."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            VALUES(&sexpr)?) }),
+                    None)));
 
-                        match &*atom_string.as_str() {
-                            "ADD" => {
-                                let symb = Symbol::new("+");
+        cl_pack_hash.insert("values-list".to_string(),
+            RLEnvSymbol::new_named_lambda("VALUES-LIST".to_string(),
+                RLNamedLambda::new_func(
+                    "VALUES-LIST".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return all of the elements of LIST, in order, as values."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            VALUES_LIST(&sexpr)?) }),
+                    None)));
+    }
+}
 
-                                return Ok(RLResult::SExprRes(
-                                    SExpr::Cons(symb, linked_list)));
-                            }
+// Special Operator IF
+// The evaluator evaluates the test and then only the selected branch.
+#[allow(non_snake_case)]
+pub fn IF(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                            "MINUS" => {
-                                let symb = Symbol::new("-");
+    /*
+    Syntax:
+    if test-form then-form [else-form] => result*
+    */
 
-                                return Ok(RLResult::SExprRes(
-                                    SExpr::Cons(symb, linked_list)));
-                            }
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                            "MUL" => {
-                                let symb = Symbol::new("*");
+            if linked_list.len() < 2 || linked_list.len() > 3 {
+                return Err(RLError::SimpleProgramError)
+            }
 
-                                return Ok(RLResult::SExprRes(
-                                    SExpr::Cons(symb, linked_list)));
-                            }
+            let test_form = linked_list.pop_front().unwrap();
+            let then_form = linked_list.pop_front().unwrap();
+            let else_form = linked_list.pop_front()
+                                       .unwrap_or(SExpr::Nil(RLNil::new()));
 
-                            "DIV" => {
-                                let symb = Symbol::new("/");
+            Ok(RLResult::IfRes(test_form, then_form, else_form))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                                return Ok(RLResult::SExprRes(
-                                    SExpr::Cons(symb, linked_list)));
-                            }
+// Macro COND
+// (cond (a b) (c)) => (if a (progn b) (or c (cond)))
+#[allow(non_snake_case)]
+pub fn COND(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                            _ => {
-                                let err_description = format!(
-                                    "The function {}::{} is undefined.",
-                                        curr_pack_name,
-                                        atom_string.to_uppercase());
+    /*
+    Syntax:
+    cond {clause}* => result*
+    clause::= (test-form form*)
+    */
 
-                                let err = UndefinedFuncError::new(
-                                    &err_description);
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                                return Err(RLError::UndefinedFuncError(err))
-                            }
-                        }
-                    }
+            let mut clause = match linked_list.pop_front() {
+                Some(SExpr::SList(slist)) => slist.get_items(),
 
-                    if let Some(SExpr::Lambda(atom)) = first_param {
-                        let atom_string = atom.get_atom_string();
+                Some(other) => {
+                    let err = SimpleError::new(&format!(
+                        "COND clause is not a CONS: {}",
+                        other));
 
-                        println!("atom_string: {:?}", atom_string);
-                        println!("");
+                    return Err(RLError::SimpleError(err))
+                }
 
-                        if let Some(lambda) =
-                            curr_pack.get_lambda(&atom_string) {
+                None => return Ok(RLResult::NilRes(RLNil::new())),
+            };
 
-                            println!("lambda: {:?}", lambda);
-                            println!("");
+            let test_form = match clause.pop_front() {
+                Some(test_form) => test_form,
 
-                            if let Some(_name) =
-                                lambda.get_named_lambda_name() {
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                                let mut l_params =
-                                    lambda.get_cleaned_req_params();
+            let rest = make_form(&symb.name, linked_list);
 
-                                println!("l_params: {:?}", l_params);
-                                println!("");
+            let expansion = if clause.is_empty() {
+                make_form("or", LinkedList::from([test_form, rest]))
+            } else {
+                make_form("if", LinkedList::from([
+                    test_form,
+                    make_form("progn", clause),
+                    rest]))
+            };
 
-                                for _n in 0..l_params.len() {
+            Ok(RLResult::ExpansionRes(expansion))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                                    let item =
-                                        l_params.pop_front().unwrap();
+// Macro WHEN, UNLESS
+// (when a b c) => (if a (progn b c) nil)
+// (unless a b c) => (if a nil (progn b c))
+#[allow(non_snake_case)]
+pub fn WHEN(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                                    if let Some(value) =
-                                        linked_list.pop_front() {
+    /*
+    Syntax:
+    when test-form form* => result*
+    unless test-form form* => result*
+    */
 
-                                        match item {
-                                            SExpr::Atom(param) => {
-                                                let param_string =
-                                                    param.get_atom_string();
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                                                let var =
-                                                    RLVar::SAtomVar(value);
+            let test_form = match linked_list.pop_front() {
+                Some(test_form) => test_form,
 
-                                                lambda.add_dyn_env_var(
-                                                    param_string, var);
-                                            }
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                                            _ => todo!(),
-                                        }
-                                    } else {
-                                        return Err(
-                                            RLError::SimpleProgramError);
-                                    }
-                                }
-                            } else {
-                                let params = lambda.get_parameters();
+            let body = make_form("progn", linked_list);
 
-                                if let SExpr::SList(slist) =
-                                    params.get_required_params() {
+            let nil = SExpr::Nil(RLNil::new());
 
-                                    let list = slist.get_linked_list();
+            let (then_form, else_form) = if symb.name.eq("unless") {
+                (nil, body)
+            } else {
+                (body, nil)
+            };
 
-                                    println!("parameter list: {:?}", list);
-                                    println!("");
+            Ok(RLResult::IfRes(test_form, then_form, else_form))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                                    for item in list {
-                                        if let SExpr::Atom(param) = item {
+// Macro AND
+// (and a b c) => (if a (and b c) nil)
+#[allow(non_snake_case)]
+pub fn AND(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                                            let var = RLVar::SAtomVar(
-                                                linked_list.pop_front()
-                                                           .expect("REASON"));
+    /*
+    Syntax:
+    and form* => result*
+    */
 
-                                            lambda.add_dyn_env_var(
-                                                param.to_string(), var);
-                                        }
-                                    }
-                                }
-                            }
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                            let l_hash_map = lambda.get_ref_dyn_env_lambda();
+            match linked_list.len() {
+                0 => Ok(RLResult::BoolRes(RLBool::T(RLT::new()))),
 
-                            println!("l_hash_map: {:?}", l_hash_map);
-                            println!("");
+                1 => Ok(RLResult::ExpansionRes(
+                    linked_list.pop_front().unwrap())),
 
-                            return Ok(RLResult::LambdaRes(lambda.clone()));
-                        } else {
-                            return Err(RLError::SimpleProgramError);
-                        }
-                    }
+                _ => {
+                    let test_form = linked_list.pop_front().unwrap();
 
-                    if let Some(SExpr::Symb(symb)) = first_param {
+                    Ok(RLResult::IfRes(test_form,
+                                       make_form(&symb.name, linked_list),
+                                       SExpr::Nil(RLNil::new())))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
 
-                        let symbol_string = symb.get_symbol_name();
+// Macro OR
+// (or a b c) => (let ((#:or-value a)) (if #:or-value #:or-value (or b c)))
+// The value of a is bound to a name which can't be read, so the forms
+// b and c can't refer to it.
+#[allow(non_snake_case)]
+pub fn OR(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                        if let Some(pack_symbol) =
-                            env_ref.get_symbol(&symbol_string) {
+    /*
+    Syntax:
+    or form* => results*
+    */
 
-                            println!("pack_symbol: {:?}", pack_symbol);
-                            println!("");
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            match linked_list.len() {
+                0 => Ok(RLResult::BoolRes(RLBool::Nil(RLNil::new()))),
+
+                1 => Ok(RLResult::ExpansionRes(
+                    linked_list.pop_front().unwrap())),
+
+                _ => {
+                    let value = SExpr::Symb(
+                        RLSymbol::new_with_str("#:or-value"));
+
+                    let binding = make_form("#:or-value", LinkedList::from([
+                        linked_list.pop_front().unwrap()]));
+
+                    let bindings = SExpr::SList(
+                        RLList::<SExpr>::new_with_items(
+                            &LinkedList::from([binding])));
+
+                    let test = make_form("if", LinkedList::from([
+                        value.clone(),
+                        value,
+                        make_form(&symb.name, linked_list)]));
+
+                    Ok(RLResult::ExpansionRes(
+                        make_form("let", LinkedList::from([bindings, test]))))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// (name items...), (progn) and (or) are valid forms too
+pub fn make_form(name: &str, items: LinkedList<SExpr>) -> SExpr {
+    let mut form = items;
+
+    form.push_front(SExpr::Symb(RLSymbol::new_with_str(name)));
+
+    SExpr::SList(RLList::<SExpr>::new_with_items(&form))
+}
+
+/*
+Function APPLY
+*/
+
+#[allow(non_snake_case)]
+pub fn FUNCALL(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
+
+    let curr_pack = env_ref.get_mut_current_package();
+
+    let curr_pack_name = curr_pack.get_name();
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            match &*symb.name {
+                "funcall" => {
+
+                    /*
+                    for _n in 0..linked_list.len() {
+                        let item = linked_list.pop_front().expect("REASON");
+
+                    }
+                    */
+
+
+                    let first_param = linked_list.pop_front();
+
+                    if let Some(SExpr::Atom(ref atom)) = first_param {
+                        // let symbol: Option<RLSymbol>;
+
+                        let atom_string = atom.get_atom_string();
+
+                        match &*atom_string.as_str() {
+                            "ADD" => {
+                                let symb = Symbol::new("+");
+
+                                return Ok(RLResult::SExprRes(
+                                    SExpr::Cons(symb, linked_list)));
+                            }
+
+                            "MINUS" => {
+                                let symb = Symbol::new("-");
+
+                                return Ok(RLResult::SExprRes(
+                                    SExpr::Cons(symb, linked_list)));
+                            }
+
+                            "MUL" => {
+                                let symb = Symbol::new("*");
+
+                                return Ok(RLResult::SExprRes(
+                                    SExpr::Cons(symb, linked_list)));
+                            }
+
+                            "DIV" => {
+                                let symb = Symbol::new("/");
+
+                                return Ok(RLResult::SExprRes(
+                                    SExpr::Cons(symb, linked_list)));
+                            }
+
+                            _ => {
+                                let err_description = format!(
+                                    "The function {}::{} is undefined.",
+                                        curr_pack_name,
+                                        atom_string.to_uppercase());
+
+                                let err = UndefinedFuncError::new(
+                                    &err_description);
+
+                                return Err(RLError::UndefinedFuncError(err))
+                            }
+                        }
+                    }
+
+                    if let Some(SExpr::Lambda(function)) = first_param {
+                        return Ok(RLResult::SExprRes(SExpr::Lambda(function)));
+                    }
+
+                    if let Some(SExpr::Symb(symb)) = first_param {
+
+                        let symbol_string = symb.get_symbol_name();
+
+                        if let Some(pack_symbol) =
+                            env_ref.get_symbol(&symbol_string) {
 
                             if pack_symbol.get_is_macro() == true {
                                 let err_description = format!(
@@ -367,9 +862,6 @@ pub fn FUNCALL(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
 
                                 let pars_symbol = symb.get_symbol();
 
-                                println!("SYMB - FIRSTPARAM");
-                                println!("");
-
                                 return Ok(RLResult::SExprRes(
                                     SExpr::Cons(pars_symbol, linked_list)))
                             }
@@ -398,108 +890,77 @@ pub fn FUNCTION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
   ==  #'(lambda lambda-list [[declaration* | documentation]] form*)
     */
 
-    println!("Hello from FUNCTION");
-    println!("");
-
-    let curr_pack = env_ref.get_mut_current_package();
+    let curr_pack_name = env_ref.get_mut_current_package().get_name();
 
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            let mut linked_list = ll.clone();
-
-            match &*symb.name {
-                "function" => {
-                    if linked_list.is_empty() {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-
-                        let item = linked_list.pop_front();
-
-                        println!("function - next item: {:?}", item);
-                        println!("");
-
-                        match item {
-                            Some(SExpr::Lambda(atom)) => {
-                                let atom_string = atom.get_atom_string();
-
-                                if let Some(lambda) =
-                                    curr_pack.get_lambda(&atom_string) {
-
-                                    println!("lambda: {:?}", lambda);
-                                    println!("");
-
-                                    return Ok(RLResult::LambdaRes(
-                                        lambda.clone()));
-                                } else {
-                                    return Err(RLError::SimpleProgramError);
-                                }
-                            }
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError);
+            }
 
-                            /*
-                            Some(SExpr::Atom(atom)) => {
-                                let atom_string = atom.get_atom_string();
+            match ll.front() {
+                Some(SExpr::Symb(symb)) => {
+                    let symb_name = symb.get_symbol_name();
 
-                                if let Some(&mut ref lambda) =
-                                    curr_pack.get_lambda(&atom_string) {
+                    let named_lambda = env_ref
+                        .get_symbol(&symb_name)
+                        .and_then(|symbol| symbol.get_named_lambda());
 
-                                    println!("lambda: {:?}", lambda);
-                                    println!("");
+                    match named_lambda {
+                        // the function object keeps the name it is
+                        // registered with, e.g. + instead of ADD
+                        Some(named_lambda) if !named_lambda.get_is_macro() => {
+                            Ok(RLResult::SExprRes(
+                                SExpr::Func(RLAtom::new(&symb_name))))
+                        }
 
-                                    return Ok(RLResult::LambdaRes(
-                                        lambda.clone()));
-                                }
+                        _ => {
+                            let err_description = format!(
+                                "The function {}::{} is undefined.",
+                                    curr_pack_name,
+                                    symb_name.to_uppercase());
 
-                                if let Some(&mut ref mut symbol) =
-                                    env_ref.get_symbol(&atom_string) {
+                            let err = UndefinedFuncError::new(
+                                &err_description);
 
-                                    if let Some(named_lambda) =
-                                        symbol.get_named_lambda() {
+                            Err(RLError::UndefinedFuncError(err))
+                        }
+                    }
+                }
 
-                                        return Ok(RLResult::FuncRes(
-                                            named_lambda.clone()));
-                                    } else {
-                                        return Err(
-                                            RLError::SimpleProgramError);
-                                    }
-                                } else {
-                                    return Err(RLError::SimpleProgramError);
-                                }
-                            }
-                            */
+                Some(SExpr::SList(slist)) => {
+                    let mut items = slist.get_items();
 
-                            Some(SExpr::Symb(symb)) => {
-                                let symb_name = symb.get_symbol_name();
+                    match items.pop_front() {
+                        Some(SExpr::Symb(symb))
+                            if symb.get_symbol_name().eq("lambda") => {
 
-                                if let Some(&mut ref mut symbol) =
-                                    env_ref.get_symbol(&symb_name) {
+                            LAMBDA(&SExpr::Cons(symb.get_symbol(), items),
+                                   env_ref)
+                        }
 
-                                    if let Some(named_lambda) =
-                                        symbol.get_named_lambda() {
+                        _ => {
+                            let err = TypeError::new(
+                                &SExpr::SList(slist.clone()).to_string(),
+                                "FUNCTION-NAME");
 
-                                        println!("named_lambda: {:?}",
-                                            named_lambda);
-                                        println!("");
+                            Err(RLError::TypeError(err))
+                        }
+                    }
+                }
 
-                                        return Ok(RLResult::FuncRes(
-                                            named_lambda.clone()));
-                                    } else {
-                                        return Err(
-                                            RLError::SimpleProgramError);
-                                    }
-                                } else {
-                                    return Err(RLError::SimpleProgramError);
-                                }
-                            }
+                Some(SExpr::Lambda(function)) => {
+                    Ok(RLResult::SExprRes(SExpr::Lambda(function.clone())))
+                }
 
-                            None => {
-                                return Err(RLError::SimpleProgramError);
-                            }
+                Some(other) => {
+                    let err = TypeError::new(&other.to_string(),
+                                             "FUNCTION-NAME");
 
-                            _ => unreachable!(),
-                        }
-                    }
+                    Err(RLError::TypeError(err))
                 }
-                &_ => unreachable!(),
+
+                None => Err(RLError::SimpleProgramError),
             }
         }
         _ => unreachable!()
@@ -507,7 +968,7 @@ pub fn FUNCTION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
 }
 
 // Macro DEFUN
-#[allow(non_snake_case, unused_assignments)]
+#[allow(non_snake_case)]
 pub fn DEFUN(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
@@ -516,632 +977,931 @@ pub fn DEFUN(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     defun function-name lambda-list [[declaration* | documentation]] form*
     */
 
-    println!("Hello from DEFUN");
-    println!("");
+    // a DEFUN inside a binding form closes over its variables
+    let captured = env_ref.capture_curr_eval_dyn_env();
 
-    let curr_pack = &mut env_ref.get_mut_current_package();
+    let curr_pack = env_ref.get_mut_current_package();
 
     let curr_pack_name = curr_pack.get_name();
 
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            let mut linked_list = ll.clone();
+        SExpr::Cons(_symb, ll) => {
+            let (name, parameters, opt_docstring, mut body) =
+                parse_definition(ll)?;
 
-            println!("linked_list: {:?}", linked_list);
+            let specials = skip_declarations(&mut body);
 
-            match &*symb.name {
-                "defun" => {
-                    if linked_list.is_empty() {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-                        // let mut name = RLString::new("dummy");
-                        let mut name = "dummy".to_string();
+            let mut named_lambda = RLNamedLambda::new_func(
+                name.to_uppercase(),
+                opt_docstring,
+                curr_pack_name,
+                None,
+                None);
 
-                        if let Some(SExpr::Symb(symb)) =
-                            linked_list.pop_front() {
+            named_lambda.init_parameters(parameters)?;
 
-                            let symb_name = symb.get_symbol_name();
+            // the body is evaluated in an implicit block named
+            // like the function
+            if let Some(mut block) = named_lambda.get_block() {
+                block.set_progn_ll(&body);
+                block.set_specials(&specials);
 
-                                // name.set(&atom);
-                                name = symb_name;
+                for (var_name, var) in captured {
+                    block.add_dyn_env_var(var_name, var);
+                }
 
-                                println!("{}", name);
-                        } else {
-                            // Which RLError?!
-                            return Err(RLError::SimpleProgramError);
-                        }
+                named_lambda.set_block(&block);
+            }
 
-                        let mut parameters = SExpr::Nil(RLNil::new());
+            let symbol = RLEnvSymbol::new_named_lambda(
+                name.to_uppercase(),
+                named_lambda);
 
-                        if let Some(SExpr::SList(mut list)) =
-                            linked_list.pop_front() {
-                                list.pop_front();
-                                list.pop_back();
+            curr_pack.add_symbol(name.clone(), symbol);
 
-                                parameters = SExpr::SList(list);
+            Ok(RLResult::SymbRes(Symbol::new(&name)))
+        }
+        _ => unreachable!()
+    }
+}
 
-                                println!("parameters: {:?}", parameters);
-                                println!("");
-                        } else {
-                            // which RLError?!
-                            return Err(RLError::SimpleProgramError);
-                        }
+// The value form has already been evaluated.
+#[allow(non_snake_case)]
+pub fn DEFPARAMETER(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
 
-                        let opt_docstring: Option<SExpr>;
+    /*
+    Syntax:
+    defparameter name initial-value [documentation] => name
+    */
 
-                        let mut lambda_string = "".to_string();
+    let curr_pack: &mut RLPackage = env_ref.get_mut_current_package();
+    let pack_name = curr_pack.get_name();
 
-                        let next_param = linked_list.pop_front();
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                        match next_param {
-                            Some(SExpr::Nil(_nil)) => {
-                                // opt_docstring = Some(SExpr::Nil(nil));
-                                opt_docstring = None;
-                            }
+            if linked_list.len() < 2 || linked_list.len() > 3 {
+                return Err(RLError::SimpleProgramError);
+            }
 
-                            Some(SExpr::Atom(atom)) => {
-                                opt_docstring = Some(SExpr::Atom(atom));
-                            }
+            let var_name = match linked_list.pop_front() {
+                Some(SExpr::Symb(symb)) => {
+                    symb.get_symbol_name().to_uppercase()
+                }
 
-                            Some(SExpr::Lambda(atom)) => {
-                                opt_docstring = None;
+                Some(other) => {
+                    let err = TypeError::new(&other.to_string(), "SYMBOL");
 
-                                    lambda_string =
-                                        atom.get_atom_string();
+                    return Err(RLError::TypeError(err))
+                }
 
-                                    println!("lambda_string: {:?}",
-                                        lambda_string);
-                                    println!("")
-                            }
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                            Some(SExpr::SForm(form)) => {
-                                opt_docstring = None;
+            let value = linked_list.pop_front().unwrap();
 
-                                linked_list.push_front(SExpr::SForm(form));
-                            }
+            let mut var_value = RLDynVar::new(None,
+                                              pack_name,
+                                              RLVar::from_sexpr(value));
 
-                            Some(SExpr::SToken(token)) => {
-                                opt_docstring = None;
+            var_value.set_special(true);
 
-                                linked_list.push_front(
-                                    SExpr::SToken(token));
-                            }
+            if let Some(SExpr::Atom(atom)) = linked_list.pop_front() {
+                let atom_doc_str = atom.get_atom_string();
 
-                            Some(SExpr::Cons(symb, ll)) => {
-                                opt_docstring = None;
+                var_value.set_doc_string(
+                    Some(RLString::new(&atom_doc_str)));
+            }
 
-                                linked_list.push_front(
-                                    SExpr::Cons(symb, ll));
-                            }
+            let symbol = RLEnvSymbol::new_var(var_name.clone(), var_value);
 
-                            _ => unreachable!(),
-                        }
+            curr_pack.add_symbol(var_name, symbol.clone());
+
+            Ok(RLResult::SymbolRes(symbol))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro DEFVAR
+// The arguments are passed unevaluated. An unbound variable with an
+// initial value becomes (defparameter name initial-value [doc]),
+// which lets the evaluator evaluate the form. Otherwise the variable
+// is only declared special.
+#[allow(non_snake_case)]
+pub fn DEFVAR(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
 
-                        let mut named_lambda = RLNamedLambda::new_func(
-                            name.clone().to_uppercase(),
-                            opt_docstring.clone(),
-                            curr_pack_name,
-                            None,
-                            Some(|sexpr, env_ref| {
-                                Ok::<RLResult, RLError>(
-                                    RLFUNC(&sexpr, env_ref)?) }));
+    /*
+    Syntax:
+    defvar name [initial-value [documentation]] => name
+    */
 
-                        if lambda_string.eq("").not() {
-                            println!("in lambda_string.eq.(\"\").not()");
-                            println!("");
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.is_empty() || ll.len() > 3 {
+                return Err(RLError::SimpleProgramError);
+            }
 
-                            if let Some(lambda) =
-                                curr_pack.get_lambda(&lambda_string) {
+            let var_name = match ll.front().unwrap() {
+                SExpr::Symb(symb) => symb.get_symbol_name().to_uppercase(),
 
-                                println!("lambda: {:?}", lambda);
-                                println!("");
+                other => {
+                    let err = TypeError::new(&other.to_string(), "SYMBOL");
 
-                                named_lambda.set_lambda(lambda);
+                    return Err(RLError::TypeError(err))
+                }
+            };
 
-                                curr_pack.delete_lambda(lambda_string);
-                             }
+            let is_bound = env_ref.get_symbol(&var_name)
+                                  .and_then(|symbol| symbol.get_dyn_var_value())
+                                  .is_some();
 
-                        } else {
+            if !is_bound && ll.len() > 1 {
+                return Ok(RLResult::ExpansionRes(
+                    make_form("defparameter", ll.clone())))
+            }
 
-                            let mut block = RLBlock::new(name.clone()
-                                                             .to_uppercase());
+            let doc = match ll.iter().nth(2) {
+                Some(SExpr::Atom(atom)) => {
+                    Some(RLString::new(&atom.get_atom_string()))
+                }
 
-                            block.set_progn_ll(&linked_list);
+                _ => None,
+            };
 
-                            named_lambda.set_block(&block);
-                        }
+            let symbol = match env_ref.get_symbol(&var_name) {
+                Some(symbol) => symbol,
 
-                        let _ = named_lambda.init_parameters(parameters);
+                None => {
+                    let curr_pack = env_ref.get_mut_current_package();
 
-                        let symbol = RLEnvSymbol::new_named_lambda(
-                            name.clone().to_uppercase(),
-                            named_lambda.clone());
+                    let mut dyn_var = RLDynVar::new(None,
+                                                    curr_pack.get_name(),
+                                                    RLVar::NilVar(RLNil::new()));
 
-                        curr_pack.add_symbol(name.clone(), symbol);
+                    dyn_var.restore_var(None);
 
-                        println!("last of defun");
-                        println!("named_lambda: {:?}", named_lambda);
-                        println!("");
+                    curr_pack.add_symbol(var_name.clone(),
+                        RLEnvSymbol::new_var(var_name.clone(), dyn_var));
 
-                        Ok(RLResult::StrRes(name.clone().to_uppercase()))
-                    }
+                    env_ref.get_symbol(&var_name).unwrap()
                 }
+            };
 
-                &_ => unreachable!(),
+            if symbol.dyn_var.is_none() {
+                let mut dyn_var = RLDynVar::new(None,
+                                                symbol.get_pack_name(),
+                                                RLVar::NilVar(RLNil::new()));
+
+                dyn_var.restore_var(None);
+
+                symbol.set_dyn_var(dyn_var);
             }
+
+            let dyn_var = symbol.dyn_var.as_mut().unwrap();
+
+            dyn_var.set_special(true);
+
+            if doc.is_some() {
+                dyn_var.set_doc_string(doc);
+            }
+
+            Ok(RLResult::SymbolRes(symbol.clone()))
         }
-        _ => unreachable!()
+        _ => unreachable!(),
     }
 }
 
+// Special Operator PROGV
+// The symbols and values lists have already been evaluated.
 #[allow(non_snake_case)]
-pub fn RLFUNC(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+pub fn PROGV(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from RLFUNC");
-    println!("");
-
-    let curr_pack = &mut env_ref.get_mut_current_package();
+    /*
+    Syntax:
+    progv symbols values form* => result*
+    */
 
     match sexpr {
-        SExpr::Cons(symb, ll) => {
+        SExpr::Cons(_symb, ll) => {
             let mut linked_list = ll.clone();
 
-            let symb_string = &*symb.name;
-
-            println!("{:?}", symb_string);
+            let mut param_symbols_ll = match linked_list.pop_front() {
+                Some(params) => list_items(params)?,
 
-            let mut symbol = curr_pack.get_symbol(
-                &symb_string.to_string()).unwrap()
-                                         .clone();
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-            let mut named_lambda = symbol.named_lambda.clone().unwrap();
+            let mut param_values_ll = match linked_list.pop_front() {
+                Some(values) => list_items(values)?,
 
-            println!("symbol: {:?}", symbol);
-            println!("");
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-            let mut parameters = named_lambda.get_parameters();
-
-            if let Some(SExpr::SList(list)) =
-                parameters.get_req_params_as_option() {
-
-                let ll = list.get_linked_list();
-
-                println!("required_params: {:?}", ll);
-                println!("");
-
-                for item in ll {
-                    if let SExpr::Atom(atom) = item {
-                        let var = RLVar::SAtomVar(linked_list.pop_front()
-                                                             .expect("REASON"));
-                        let param = atom.get_atom_string();
-
-                        named_lambda.add_dyn_env_var(param, var);
-                    }
-                }
+            let mut block = RLBlock::new("(PROGV)-START".to_string());
 
-                let hash_map = named_lambda.get_ref_dyn_env();
+            // PROGV always binds dynamically
+            let mut specials = Vec::new();
 
-                println!("hash_map: {:?}", hash_map);
-                println!("");
+            while let Some(symbol) = param_symbols_ll.pop_front() {
+                let var = match param_values_ll.pop_front() {
+                    Some(value) => RLVar::from_sexpr(value),
 
-                symbol.set_named_lambda(&named_lambda);
+                    None => RLVar::NilVar(RLNil::new()),
+                };
 
-                println!("symbol: {:?}", symbol);
-                println!("");
+                specials.push(symbol.to_string().to_uppercase());
 
-                if let Some(lambda) = named_lambda.get_lambda() {
-                    let lambda_id = lambda.get_id();
+                block.add_dyn_env_var(symbol.to_string(), var);
+            }
 
-                    println!("lambda_id: {:?}", lambda_id);
-                    println!("");
+            block.set_specials(&specials);
 
-                    curr_pack.add_lambda(lambda.get_id(),
-                                         lambda.clone());
+            block.set_progn_ll(&linked_list);
 
-                    Ok(RLResult::LambdaRes(lambda))
-                } else{
-                    Ok(RLResult::FuncRes(named_lambda))
-                }
-            } else {
-                // which RLError?!
-                return Err(RLError::SimpleProgramError)
-            }
-        }
+            Ok(RLResult::BlockRes(block))
+         }
         _ => unreachable!(),
     }
 }
 
+// Special Operator LET, LET*
+// The init forms are evaluated by the evaluator before the body, in
+// parallel for LET and one after another for LET*.
 #[allow(non_snake_case)]
-pub fn DEFPARAMETER(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+pub fn LET(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from DEFPARAMETER");
-    println!("");
-
-    let curr_pack: &mut RLPackage = env_ref.get_mut_current_package();
-    let pack_name = curr_pack.get_name();
+    /*
+    Syntax:
+    let ({var | (var [init-form])}*) declaration* form* => result*
+    let* ({var | (var [init-form])}*) declaration* form* => result*
+    */
 
     match sexpr {
         SExpr::Cons(symb, ll) => {
             let mut linked_list = ll.clone();
 
-            match &*symb.name {
-                "defparameter" => {
-                    println!("linked_list.len: {}", linked_list.len());
+            let sequential = symb.name.eq("let*");
 
-                    if linked_list.len() < 2 {
-                        return Err(RLError::SimpleProgramError);
-                    } else if linked_list.len() > 3  {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-                        let mut var_name = "dummy".to_string();
+            let binding_forms = match linked_list.pop_front() {
+                Some(sexpr) => list_items(sexpr)?,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                        if let Some(SExpr::Atom(atom)) =
-                            linked_list.pop_front() {
+            let mut bindings = LinkedList::<(String, SExpr)>::new();
 
-                            let atom_name = atom.get_atom_string();
+            for binding_form in binding_forms {
+                let (var, init_form) = match binding_form {
+                    SExpr::SList(slist) => {
+                        let mut items = slist.get_items();
 
-                            var_name = atom_name.to_uppercase();
+                        if items.is_empty() || items.len() > 2 {
+                            let err = SimpleError::new(&format!(
+                                "The {} binding spec {} is malformed.",
+                                symb.name.to_uppercase(),
+                                SExpr::SList(slist.clone())));
 
-                            println!("var_name: {:?}", var_name);
-                            println!("");
+                            return Err(RLError::SimpleError(err))
                         }
 
-                        let mut var_value = match linked_list.pop_front() {
-                            /*
-                            Some(SExpr::Cons(sym, list)) => {
-                                println!("cons: {:?}", SExpr::Cons(
-                                    sym.clone(), list.clone()));
-                                println!("");
+                        let var = items.pop_front().unwrap();
 
-                                RLDynVar::new(None,
-                                              pack_name,
-                                              RLVar::SConsVar(
-                                                  SExpr::Cons(sym, list)))
-                            }
-                            */
+                        (var, items.pop_front()
+                                   .unwrap_or(SExpr::Nil(RLNil::new())))
+                    }
 
-                            Some(SExpr::Atom(mut atom)) => {
+                    var => (var, SExpr::Nil(RLNil::new())),
+                };
 
-                                let atom_string = atom.get_atom_string();
+                bindings.push_back((variable_name(&var)?, init_form));
+            }
 
-                                println!("atom_string: {:?}", atom_string);
-                                atom.set_name_to_uppercase();
+            let specials = skip_declarations(&mut linked_list);
 
-                                RLDynVar::new(None,
-                                              pack_name,
-                                              RLVar::SAtomVar(
-                                                  SExpr::Atom(atom)))
-                            }
+            let mut block = RLBlock::new(
+                format!("({})-START", symb.name.to_uppercase()));
 
-                            Some(SExpr::Lambda(atom)) => {
-                                RLDynVar::new(None,
-                                              pack_name,
-                                              RLVar::SAtomVar(
-                                                  SExpr::Lambda(atom)))
-                            }
+            block.set_let_bindings(&bindings, sequential);
+            block.set_specials(&specials);
+            block.set_progn_ll(&linked_list);
 
-                            Some(SExpr::Nil(nil)) => {
-                                RLDynVar::new(None,
-                                              pack_name,
-                                              RLVar::NilVar(nil))
-                            }
+            Ok(RLResult::BlockRes(block))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                            Some(SExpr::SList(slist)) => {
-                                RLDynVar::new(None,
-                                              pack_name,
-                                              RLVar::SListVar(SExpr::SList(slist)))
-                            }
+// Special Operator FLET, LABELS
+// Every local function becomes a lambda whose body is an implicit
+// block named like the function. The evaluator makes the functions
+// visible in the body, and for LABELS in the functions themselves.
+#[allow(non_snake_case)]
+pub fn FLET(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
 
-                            _ => todo!(),
-                        };
+    /*
+    Syntax:
+    flet ((function-name lambda-list [[local-declaration* |
+                                       local-documentation]]
+                                     local-form*)*)
+         declaration* form* => result*
+    labels ((function-name lambda-list [[local-declaration* |
+                                         local-documentation]]
+                                       local-form*)*)
+           declaration* form* => result*
+    */
 
-                        println!("var_value: {:?}", var_value);
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                        if let Some(SExpr::Atom(atom)) =
-                            linked_list.pop_front() {
+            let definitions = match linked_list.pop_front() {
+                Some(sexpr) => list_items(sexpr)?,
 
-                            let atom_doc_str = atom.get_atom_string();
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                            var_value.set_doc_string(
-                                Some(RLString::new(&atom_doc_str)));
-                        }
+            let mut block = RLBlock::new(
+                format!("({})-START", symb.name.to_uppercase()));
 
-                        let symbol =
-                            RLEnvSymbol::new_var(var_name.clone(), var_value);
+            for definition in definitions {
+                let (name, parameters, opt_docstring, mut body) =
+                    parse_definition(&list_items(definition)?)?;
 
-                        if let Some(_varsymb) =
-                            curr_pack.get_symbol(&var_name) {
+                let specials = skip_declarations(&mut body);
 
-                            curr_pack.delete_symbol(var_name.clone());
+                let mut lambda = RLLambda::new(
+                    Some(name.to_uppercase()),
+                    opt_docstring.map(|doc| doc.to_string()));
 
-                            curr_pack.add_symbol(var_name,
-                                                 symbol.clone());
-                            println!("Done");
-                        } else {
-                            curr_pack.add_symbol(var_name,
-                                                 symbol.clone());
-                            println!("Done2");
-                        }
+                lambda.init_parameters(parameters)?;
+                lambda.set_specials(&specials);
 
-                        // let result_var_symbol =
-                        // curr_pack.get_symbol(&var_name.clone()).unwrap();
-                        /*
-                        let bla = &"BLA".to_string();
-                        let hash = curr_pack.get_symbols_hash_map();
-                        let clony = clone_hash_map(&hash);
-                        assert_eq!(hash.contains_key(bla), true);
-                        assert_eq!(clony.contains_key(bla), true);
-
-                        for val in hash.values() {
-                            println!("hash values: {val}");
-                        }
+                body.push_front(SExpr::Symb(RLSymbol::new_with_str(&name)));
 
-                        for val in clony.values() {
-                            println!("clony values: {val}");
-                        }
-                        */
+                lambda.set_body(make_form("block", body));
 
-                        return Ok(RLResult::SymbolRes(symbol.clone()))
-                    }
-                }
-                &_ => unreachable!()
+                lambda.set_dyn_env_lambda(env_ref.capture_curr_eval_dyn_env());
+
+                block.add_function(&name, lambda);
             }
+
+            skip_declarations(&mut linked_list);
+
+            block.set_recursive_functions(symb.name.eq("labels"));
+            block.set_progn_ll(&linked_list);
+
+            Ok(RLResult::BlockRes(block))
         }
         _ => unreachable!(),
     }
 }
 
+// Special Operator MACROLET
+// The local macros are expanded by the evaluator like the ones of
+// DEFMACRO, a local macro shadows the functions and macros of the
+// same name.
 #[allow(non_snake_case)]
-pub fn DEFVAR(_sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
+pub fn MACROLET(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from DEFVAR");
-    println!("");
+    /*
+    Syntax:
+    macrolet ((name lambda-list [[local-declaration* |
+                                  local-documentation]]
+                                local-form*)*)
+             declaration* form* => result*
+    */
+
+    let curr_pack_name = env_ref.get_mut_current_package().get_name();
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let definitions = match linked_list.pop_front() {
+                Some(sexpr) => list_items(sexpr)?,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let mut block = RLBlock::new("(MACROLET)-START".to_string());
+
+            for definition in definitions {
+                let (name, parameters, opt_docstring, mut body) =
+                    parse_definition(&list_items(definition)?)?;
+
+                skip_declarations(&mut body);
+
+                let named_lambda = make_macro(&name,
+                                              parameters,
+                                              opt_docstring,
+                                              &body,
+                                              curr_pack_name.clone())?;
 
-    Ok(RLResult::StrRes("DEFVAR".to_string()))
+                block.add_macro(&name, named_lambda);
+            }
+
+            skip_declarations(&mut linked_list);
+
+            block.set_progn_ll(&linked_list);
+
+            Ok(RLResult::BlockRes(block))
+        }
+        _ => unreachable!(),
+    }
 }
 
-// Special Operator PROGV
-#[allow(non_snake_case, unused_assignments)]
-pub fn PROGV(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
+// Declarations are accepted and ignored.
+pub fn skip_declarations(forms: &mut LinkedList<SExpr>) -> Vec<String> {
+    let mut specials = Vec::new();
+
+    while let Some(SExpr::SList(slist)) = forms.front() {
+        let mut items = slist.get_items();
+
+        match items.pop_front() {
+            Some(SExpr::Symb(head))
+                if head.get_symbol_name().eq("declare") => {
+
+                // (declare (special var*) ...)
+                for specifier in items {
+                    let mut specifier = match specifier {
+                        SExpr::SList(slist) => slist.get_items(),
+
+                        _ => continue,
+                    };
+
+                    match specifier.pop_front() {
+                        Some(SExpr::Symb(symb))
+                            if symb.get_symbol_name().eq("special") => {
+
+                            for var in specifier {
+                                if let SExpr::Symb(var) = var {
+                                    specials.push(
+                                        var.get_symbol_name().to_uppercase());
+                                }
+                            }
+                        }
+
+                        _ => {}
+                    }
+                }
+
+                forms.pop_front();
+            }
+
+            _ => break,
+        }
+    }
+
+    specials
+}
+
+// Name of a variable to be bound or assigned, constants are rejected.
+pub fn variable_name(sexpr: &SExpr) -> Result<String, RLError> {
+    match sexpr {
+        SExpr::Symb(symb) => {
+            let name = symb.get_symbol_name();
+
+            if name.eq("t") || name.starts_with(':') {
+                let err = SimpleError::new(&format!(
+                    "{} is a constant and thus can't be set.",
+                    name.to_uppercase()));
+
+                return Err(RLError::SimpleError(err))
+            }
+
+            Ok(name)
+        }
+
+        SExpr::Nil(_) => {
+            let err = SimpleError::new(
+                "NIL is a constant and thus can't be set.");
+
+            Err(RLError::SimpleError(err))
+        }
+
+        other => {
+            let err = TypeError::new(&other.to_string(), "SYMBOL");
+
+            Err(RLError::TypeError(err))
+        }
+    }
+}
+
+pub fn list_items(sexpr: SExpr) -> Result<LinkedList<SExpr>, RLError> {
+    match sexpr {
+        SExpr::SList(slist) => Ok(slist.get_items()),
+
+        SExpr::Nil(_) => Ok(LinkedList::<SExpr>::new()),
+
+        other => {
+            let err = TypeError::new(&other.to_string(), "LIST");
+
+            Err(RLError::TypeError(err))
+        }
+    }
+}
+
+// Special Form SETQ
+// The evaluator calls SETQ once for every pair, the form has already
+// been evaluated. A local binding of var is assigned if there is one,
+// the global variable otherwise.
+#[allow(non_snake_case)]
+pub fn SETQ(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from PROGV");
-    println!("");
+    /*
+    setq {pair}* => result
+    setq var1 form1 var2 form2 ...
+    */
 
-match sexpr {
+    match sexpr {
         SExpr::Cons(_symb, ll) => {
             let mut linked_list = ll.clone();
 
-            println!("linked_list: {:?}", linked_list);
-            println!("");
+            if linked_list.len() != 2 {
+                return Err(RLError::SimpleProgramError)
+            }
 
-            let mut param_symbols_ll = LinkedList::<SExpr>::new();
+            let name = variable_name(&linked_list.pop_front().unwrap())?;
 
-            if let Some(SExpr::SList(params)) =
-                linked_list.pop_front() {
+            let value = linked_list.pop_front().unwrap();
 
-                param_symbols_ll = params.get_linked_list();
+            let var = RLVar::from_sexpr(value.clone());
 
-                // get rid of "(" and ")"
-                param_symbols_ll.pop_front();
-                param_symbols_ll.pop_back();
+            // a variable captured by a closure is assigned in its cell
+            if let Some(local_var) = env_ref.get_curr_eval_dyn_env_var(&name) {
+                if !local_var.set_cell_value(var.clone()) {
+                    env_ref.add_curr_eval_dyn_env_var(name, var);
+                }
 
-                println!("param_symbols_ll: {:?}", param_symbols_ll);
-                println!("");
+                return Ok(RLResult::SExprRes(value))
+            }
 
-                // param_symbols = SExpr::SList(params);
-            } else {
-                // throw an error
+            let var_name = name.to_uppercase();
+
+            match env_ref.get_symbol(&var_name) {
+                Some(symbol) if symbol.get_dyn_var().is_some() => {
+                    symbol.set_dyn_var_value(var);
+                }
+
+                // an undefined variable becomes a global variable of
+                // the current package
+                _ => {
+                    let curr_pack = env_ref.get_mut_current_package();
+
+                    let dyn_var = RLDynVar::new(None,
+                                                curr_pack.get_name(),
+                                                var);
+
+                    curr_pack.add_symbol(var_name.clone(),
+                        RLEnvSymbol::new_var(var_name, dyn_var));
+                }
+            }
+
+            Ok(RLResult::SExprRes(value))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro PSETQ
+// (psetq a x b y) =>
+// (let ((#:psetq-1 x) (#:psetq-2 y)) (setq a #:psetq-1 b #:psetq-2) nil)
+#[allow(non_snake_case)]
+pub fn PSETQ(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    psetq {pair}* => nil
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if !ll.len().is_multiple_of(2) {
+                let err = SimpleError::new(&format!(
+                    "Odd number of args to PSETQ: {}",
+                    ll.iter()
+                      .map(|arg| arg.to_string())
+                      .collect::<Vec<String>>()
+                      .join(" ")));
+
+                return Err(RLError::SimpleError(err))
             }
 
-            let mut param_values_ll = LinkedList::<SExpr>::new();
+            let mut bindings = LinkedList::<SExpr>::new();
+            let mut assignments = LinkedList::<SExpr>::new();
 
-            if let Some(SExpr::SList(values)) =
-                linked_list.pop_front() {
+            let mut pairs = ll.iter();
 
-                param_values_ll = values.get_linked_list();
+            while let (Some(var), Some(form)) = (pairs.next(), pairs.next()) {
+                variable_name(var)?;
 
-                // get rid of "(" and ")"
-                param_values_ll.pop_front();
-                param_values_ll.pop_back();
+                let temp = format!("#:psetq-{}", bindings.len() + 1);
 
-                println!("param_values_ll: {:?}", param_values_ll);
-                println!("");
+                bindings.push_back(make_form(&temp, LinkedList::from([
+                    form.clone()])));
 
-                // param_values = SExpr::SList(values);
-            } else {
-                // throw an error
+                assignments.push_back(var.clone());
+                assignments.push_back(SExpr::Symb(
+                    RLSymbol::new_with_str(&temp)));
             }
 
-            let mut block = RLBlock::new("(PROGV)-START".to_string());
+            Ok(RLResult::ExpansionRes(make_form("let", LinkedList::from([
+                make_list(&bindings),
+                make_form("setq", assignments),
+                SExpr::Nil(RLNil::new())]))))
+        }
+        _ => unreachable!(),
+    }
+}
 
-            while let Some(symbol) = param_symbols_ll.pop_front() {
+#[allow(non_snake_case)]
+pub fn BLOCK(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
 
-                if let Some(value) = param_values_ll.pop_front() {
-                    let var = RLVar::SAtomVar(value);
-                    block.add_dyn_env_var(symbol.to_string(), var);
+    /*
+    Syntax:
+    block name form* => result*
+    */
 
-                } else {
-                    let var = RLVar::NilVar(RLNil::new());
-                    block.add_dyn_env_var(symbol.to_string(), var);
-                }
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let block_name = block_name(linked_list.pop_front())?;
+
+            if linked_list.is_empty() {
+                return Ok(RLResult::NilRes(RLNil::new()))
             }
 
+            let mut block = RLBlock::new(block_name.to_uppercase());
+
             block.set_progn_ll(&linked_list);
 
             Ok(RLResult::BlockRes(block))
-         }
+        }
         _ => unreachable!(),
     }
 }
 
-// Special Form SETQ
+// The returned block carries the unevaluated value form (if any) and
+// the id of the innermost block with the given name.
 #[allow(non_snake_case)]
-pub fn SETQ(_sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
+pub fn RETURN_FROM(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
     /*
-    setq {pair}* => result
-    setq var1 form1 var2 form2 ...
+    Syntax:
+    return-from name [result] =>|
     */
 
-    Ok(RLResult::NilRes(RLNil::new()))
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let block_name = block_name(linked_list.pop_front())?;
+
+            if linked_list.len() > 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut block_chain = env_ref.block_chain_retain(&block_name);
+
+            if let Some(mut block) = block_chain.pop() {
+                block.set_progn_ll(&linked_list);
+
+                Ok(RLResult::ReturnFromRes(block))
+            } else {
+                let err = ReturnFromError::new(&block_name);
+                Err(RLError::ReturnFromError(err))
+            }
+        }
+        _ => unreachable!(),
+    }
 }
 
-#[allow(non_snake_case, unused_assignments)]
-pub fn BLOCK(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
-    Result<RLResult, RLError> {
+// Macro RETURN
+// (return x) => (return-from nil x)
+#[allow(non_snake_case)]
+pub fn RETURN(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-    println!("Hello from BLOCK");
-    println!("");
+    /*
+    Syntax:
+    return [result] =>|
+    */
 
     match sexpr {
         SExpr::Cons(_symb, ll) => {
+            if ll.len() > 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
             let mut linked_list = ll.clone();
 
-            println!("linked_list_1: {:?}", linked_list);
-            println!("");
+            linked_list.push_front(SExpr::Nil(RLNil::new()));
 
-            // get Block name
-            let mut block_name = "".to_string();
-            let sexpr_block_name = linked_list.pop_front();
+            Ok(RLResult::ExpansionRes(make_form("return-from", linked_list)))
+        }
+        _ => unreachable!(),
+    }
+}
 
-            println!("linked_list after block_name: {:?}", linked_list);
-            println!("");
+// Special Operator CATCH
+// The tag has already been evaluated, the body is evaluated in a
+// block which THROW finds by the tag on the block chain.
+#[allow(non_snake_case)]
+pub fn CATCH(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-            if let Some(SExpr::Symb(symb)) = sexpr_block_name {
-                let symb_name = symb.get_symbol_name();
+    /*
+    Syntax:
+    catch tag form* => result*
+    */
 
-                println!("block_name: {}", symb_name);
-                println!("");
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                block_name = symb_name.clone();
-            } else {
-                let mut no_symbol_expr = SExpr::Nil(RLNil::new());
+            let tag = match linked_list.pop_front() {
+                Some(tag) => tag,
 
-                match sexpr_block_name {
-                    Some(SExpr::Atom(atom)) => {
-                        no_symbol_expr = SExpr::Atom(atom);
-                    }
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                    Some(SExpr::SList(slist)) => {
-                        no_symbol_expr = SExpr::SList(slist);
-                    }
+            let mut block = RLBlock::new("(CATCH)-START".to_string());
 
-                    _ => {}
-                }
+            block.set_catch_tag(&tag);
+            block.set_progn_ll(&linked_list);
 
-                let err_description = format!(
-                    "The block name {} is not a symbol", no_symbol_expr);
+            Ok(RLResult::BlockRes(block))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                let err = BlockError::new(&err_description);
-                return Err(RLError::BlockError(err))
+// Special Operator THROW
+// Returns from the innermost CATCH block with the evaluated tag, the
+// result form is evaluated by the evaluator.
+#[allow(non_snake_case)]
+pub fn THROW(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    throw tag result-form =>|
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() != 2 {
+                return Err(RLError::SimpleProgramError)
             }
 
-            // build Block
-            if linked_list.is_empty() {
-                return Ok(RLResult::NilRes(RLNil::new()))
+            let tag = linked_list.pop_front().unwrap();
 
-            } else {
-                println!("linked_list end: {:?}", linked_list);
-                println!("");
+            let target = env_ref.get_ref_mut_block_chain()
+                                .iter()
+                                .rev()
+                                .find(|block| block.is_catch_for(&tag))
+                                .cloned();
 
-                let mut block = RLBlock::new(block_name.to_uppercase());
+            match target {
+                Some(mut block) => {
+                    block.set_progn_ll(&linked_list);
 
-                block.set_progn_ll(&linked_list);
+                    Ok(RLResult::ReturnFromRes(block))
+                }
 
-                Ok(RLResult::BlockRes(block))
+                None => {
+                    let err = ControlError::new(&format!(
+                        "Attempt to THROW to a tag that does not exist: {}",
+                        tag));
+
+                    Err(RLError::ControlError(err))
+                }
             }
         }
         _ => unreachable!(),
     }
 }
 
-#[allow(non_snake_case, unused_assignments)]
-pub fn RETURN_FROM(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
-    Result<RLResult, RLError> {
+// Special Operator UNWIND-PROTECT
+// The evaluator evaluates the cleanup forms after the protected form
+// however it is left.
+#[allow(non_snake_case)]
+pub fn UNWIND_PROTECT(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-    println!("Hello from RETURN-FROM");
-    println!("");
+    /*
+    Syntax:
+    unwind-protect protected-form cleanup-form* => result*
+    */
 
     match sexpr {
         SExpr::Cons(_symb, ll) => {
             let mut linked_list = ll.clone();
 
-            println!("linked_list_1: {:?}", linked_list);
-            println!("");
+            let protected_form = match linked_list.pop_front() {
+                Some(form) => form,
 
-            // get Block name
-            let mut block_name = "".to_string();
-            let sexpr_block_name = linked_list.pop_front();
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-            println!("linked_list after block_name: {:?}", linked_list);
-            println!("");
+            Ok(RLResult::UnwindProtectRes(protected_form, linked_list))
+        }
+        _ => unreachable!(),
+    }
+}
 
-            if let Some(SExpr::Symb(symb)) = sexpr_block_name {
-                let symb_name = symb.get_symbol_name();
+// Special Operator TAGBODY
+// Tags are left out of the statements, every tag remembers the
+// position of the statement which follows it.
+#[allow(non_snake_case)]
+pub fn TAGBODY(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                println!("block_name: {}", symb_name);
-                println!("");
+    /*
+    Syntax:
+    tagbody {tag | statement}* => nil
+    */
 
-                block_name = symb_name.clone();
-            } else {
-                let mut no_symbol_expr = SExpr::Nil(RLNil::new());
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut block = RLBlock::new("(TAGBODY)-START".to_string());
 
-                match sexpr_block_name {
-                    Some(SExpr::Atom(atom)) => {
-                        no_symbol_expr = SExpr::Atom(atom);
-                    }
+            let mut statements = LinkedList::<SExpr>::new();
 
-                    Some(SExpr::SList(slist)) => {
-                        no_symbol_expr = SExpr::SList(slist);
-                    }
+            for item in ll {
+                if let SExpr::SList(_) = item {
+                    statements.push_back(item.clone());
 
-                    _ => {}
+                    continue
                 }
 
-                let err_description = format!(
-                    "The block name {} is not a symbol", no_symbol_expr);
+                let tag = tag_name(item)?;
 
-                let err = BlockError::new(&err_description);
-                return Err(RLError::BlockError(err))
+                if block.has_tag(&tag) {
+                    let err = SimpleError::new(&format!(
+                        "The tag {} appears more than once in a TAGBODY.",
+                        tag.to_uppercase()));
+
+                    return Err(RLError::SimpleError(err))
+                }
+
+                block.add_tag(&tag, statements.len());
             }
 
-            // build Block
-            if linked_list.is_empty() {
-                return Ok(RLResult::NilRes(RLNil::new()))
+            block.set_progn_ll(&statements);
 
-            } else {
-                println!("linked_list end: {:?}", linked_list);
-                println!("");
+            Ok(RLResult::TagbodyRes(block))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                let block_chain = env_ref.block_chain_retain(&block_name);
+// Special Operator GO
+// The innermost TAGBODY on the block chain which defines the tag is
+// the target, there is none if its extent has ended.
+#[allow(non_snake_case)]
+pub fn GO(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
+    Result<RLResult, RLError> {
 
-                println!("block_chain: {:?}", block_chain);
-                println!("");
+    /*
+    Syntax:
+    go tag =>|
+    */
 
-                if let Some(mut block) = env_ref.get_last_of_block_chain() {
-                    println!("block: {:?}", block);
-                    println!("");
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
 
-                    block.set_progn_ll(&linked_list);
+            let tag = tag_name(ll.front().unwrap())?;
 
-                    println!("block.get_progn_ll: {:?}",
-                        block.get_progn_ll());
-                    println!("");
+            let target = env_ref.get_ref_mut_block_chain()
+                                .iter()
+                                .rev()
+                                .find(|block| block.has_tag(&tag))
+                                .map(|block| block.get_id());
 
-                    // block.set_forms_tokens(&forms_tokens);
+            match target {
+                Some(id) => Ok(RLResult::GoRes(id, tag)),
 
-                    println!("block: {:?}", block);
-                    println!("");
+                None => {
+                    let err = ControlError::new(&format!(
+                        "Attempt to GO to the tag {} whose extent has \
+                         ended or which isn't defined by any TAGBODY.",
+                        tag.to_uppercase()));
 
-                    return Ok(RLResult::ReturnFromRes(block))
-                } else {
-                    let err = ReturnFromError::new(&block_name);
-                    return Err(RLError::ReturnFromError(err))
+                    Err(RLError::ControlError(err))
                 }
             }
         }
@@ -1149,14 +1909,43 @@ pub fn RETURN_FROM(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     }
 }
 
+// Tags of TAGBODY are symbols and integers.
+fn tag_name(sexpr: &SExpr) -> Result<String, RLError> {
+    match sexpr {
+        SExpr::Symb(symb) => Ok(symb.get_symbol_name()),
+
+        SExpr::Nil(_) => Ok("nil".to_string()),
+
+        SExpr::Num(num) if num.is_integer() => Ok(num.to_string()),
+
+        other => {
+            let err = TypeError::new(&other.to_string(), "(OR SYMBOL INTEGER)");
+
+            Err(RLError::TypeError(err))
+        }
+    }
+}
+
+fn block_name(sexpr_block_name: Option<SExpr>) -> Result<String, RLError> {
+    match sexpr_block_name {
+        Some(SExpr::Symb(symb)) => Ok(symb.get_symbol_name()),
+
+        Some(SExpr::Nil(_)) => Ok("nil".to_string()),
+
+        Some(other) => {
+            let err = BlockError::new(&other.to_string());
+            Err(RLError::BlockError(err))
+        }
+
+        None => Err(RLError::SimpleProgramError),
+    }
+}
+
 // Special Operator PROGN
 #[allow(non_snake_case)]
 pub fn PROGN(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from PROGN");
-    println!("");
-
     match sexpr {
         SExpr::Cons(_symb, ll) => {
             if ll.is_empty() {
@@ -1164,7 +1953,7 @@ pub fn PROGN(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
             } else {
                 let mut block = RLBlock::new("(PROGN)-START".to_string());
 
-                block.set_progn_ll(&ll);
+                block.set_progn_ll(ll);
 
                 Ok(RLResult::BlockRes(block))
             }
@@ -1173,6 +1962,237 @@ pub fn PROGN(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     }
 }
 
+// Macro DESTRUCTURING-BIND
+// The evaluator destructures the value of the expression with the
+// lambda list and evaluates the body in the returned block.
+#[allow(non_snake_case)]
+pub fn DESTRUCTURING_BIND(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    destructuring-bind lambda-list expression declaration* form*
+        => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut lambda_list = RLOrdinaryLambdaList::new();
+
+            lambda_list.parse_destructuring_parameters(
+                linked_list.pop_front().unwrap())?;
+
+            let expression = linked_list.pop_front().unwrap();
+
+            let specials = skip_declarations(&mut linked_list);
+
+            let mut block = RLBlock::new(
+                "(DESTRUCTURING-BIND)-START".to_string());
+
+            block.set_specials(&specials);
+            block.set_progn_ll(&linked_list);
+
+            Ok(RLResult::DestructuringBindRes(Box::new(lambda_list),
+                                              expression,
+                                              block))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro MULTIPLE-VALUE-BIND
+// The evaluator binds the variables to the values of the values form
+// and evaluates the body in the returned block.
+#[allow(non_snake_case)]
+pub fn MULTIPLE_VALUE_BIND(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    multiple-value-bind (var*) values-form declaration* form* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut vars = LinkedList::<String>::new();
+
+            for var in list_items(linked_list.pop_front().unwrap())? {
+                vars.push_back(variable_name(&var)?);
+            }
+
+            let values_form = linked_list.pop_front().unwrap();
+
+            let specials = skip_declarations(&mut linked_list);
+
+            let mut block = RLBlock::new(
+                "(MULTIPLE-VALUE-BIND)-START".to_string());
+
+            block.set_specials(&specials);
+            block.set_progn_ll(&linked_list);
+
+            Ok(RLResult::MultipleValueBindRes(vars, values_form, block))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Special Operator MULTIPLE-VALUE-CALL
+// The evaluator collects the values of all forms as the arguments.
+#[allow(non_snake_case)]
+pub fn MULTIPLE_VALUE_CALL(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    multiple-value-call function-form form* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            match linked_list.pop_front() {
+                Some(function_form) => {
+                    Ok(RLResult::MultipleValueCallRes(function_form,
+                                                      linked_list))
+                }
+
+                None => Err(RLError::SimpleProgramError),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro MULTIPLE-VALUE-LIST
+// (multiple-value-list form) => (multiple-value-call #'list form)
+#[allow(non_snake_case)]
+pub fn MULTIPLE_VALUE_LIST(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    multiple-value-list form => list
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let function = make_form("function", LinkedList::from([
+                SExpr::Symb(RLSymbol::new_with_str("list"))]));
+
+            Ok(RLResult::ExpansionRes(
+                make_form("multiple-value-call", LinkedList::from([
+                    function,
+                    ll.front().unwrap().clone()]))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Special Operator MULTIPLE-VALUE-PROG1
+#[allow(non_snake_case)]
+pub fn MULTIPLE_VALUE_PROG1(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    multiple-value-prog1 first-form form* => first-form-results
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            match linked_list.pop_front() {
+                Some(first_form) => {
+                    Ok(RLResult::MultipleValueProg1Res(first_form,
+                                                       linked_list))
+                }
+
+                None => Err(RLError::SimpleProgramError),
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// // Macro NTH-VALUE
+// (nth-value n form) => (nth n (multiple-value-list form))
+#[allow(non_snake_case)]
+pub fn NTH_VALUE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    nth-value n form => object
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() != 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let n = linked_list.pop_front().unwrap();
+
+            let values = make_form("multiple-value-list", linked_list);
+
+            Ok(RLResult::ExpansionRes(
+                make_form("nth", LinkedList::from([n, values]))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Accessor VALUES
+#[allow(non_snake_case)]
+pub fn VALUES(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    values &rest object => object*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => Ok(RLResult::ValuesRes(ll.clone())),
+
+        _ => unreachable!(),
+    }
+}
+
+// Function VALUES-LIST
+#[allow(non_snake_case)]
+pub fn VALUES_LIST(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    values-list list => element*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            Ok(RLResult::ValuesRes(list_items(ll.front().unwrap().clone())?))
+        }
+        _ => unreachable!(),
+    }
+}
+
 /*
 Function APPLY
 
@@ -1184,7 +2204,9 @@ Function FBOUNDP
 
 Function FMAKUNBOUND
 
-Special Operator FLET, LABELS, MACROLET
+// Special Operator FLET, LABELS
+
+// Special Operator MACROLET
 
 Function FUNCALL
 
@@ -1204,33 +2226,33 @@ Constant Variable LAMBDA-PARAMETERS-LIMIT
 
 Macro DEFCONSTANT
 
-Macro DEFPARAMETER, DEFVAR
+// Macro DEFPARAMETER, DEFVAR
 
-Macro DESTRUCTURING-BIND
+// Macro DESTRUCTURING-BIND
 
-Special Operator LET, LET*
+// Special Operator LET, LET*
 
 // Special Operator PROGV
 
 Special Form SETQ
 
-Macro PSETQ
+// Macro PSETQ
 
 // Special Operator BLOCK
 
-Special Operator CATCH
+// Special Operator CATCH
 
-Special Operator GO
+// Special Operator GO
 
 // Special Operator RETURN-FROM
 
-Macro RETURN
+// Macro RETURN
 
-Special Operator TAGBODY
+// Special Operator TAGBODY
 
-Special Operator THROW
+// Special Operator THROW
 
-Special Operator UNWIND-PROTECT
+// Special Operator UNWIND-PROTECT
 
 Constant Variable NIL
 
@@ -1254,33 +2276,33 @@ Function CONSTANTLY
 
 Function EVERY, SOME, NOTEVERY, NOTANY
 
-Macro AND
+// Macro AND
 
-Macro COND
+// Macro COND
 
-Special Operator IF
+// Special Operator IF
 
-Macro OR
+// Macro OR
 
-Macro WHEN, UNLESS
+// Macro WHEN, UNLESS
 
 Macro CASE, CCASE, ECASE
 
 Macro TYPECASE, CTYPECASE, ETYPECASE
 
-Macro MULTIPLE-VALUE-BIND
+// Macro MULTIPLE-VALUE-BIND
 
-Special Operator MULTIPLE-VALUE-CALL
+// Special Operator MULTIPLE-VALUE-CALL
 
-Macro MULTIPLE-VALUE-LIST
+// Macro MULTIPLE-VALUE-LIST
 
-Special Operator MULTIPLE-VALUE-PROG1
+// Special Operator MULTIPLE-VALUE-PROG1
 
 Macro MULTIPLE-VALUE-SETQ
 
-Accessor VALUES
+// Accessor VALUES
 
-Function VALUES-LIST
+// Function VALUES-LIST
 
 Constant Variable MULTIPLE-VALUES-LIMIT
 
@@ -1316,23 +2338,23 @@ Condition Type UNDEFINED-FUNCTION
 
 
 /*
-Special Operator FLET
-Special Operator LABELS
-Special Operator MACROLET
+// Special Operator FLET
+// Special Operator LABELS
+// Special Operator MACROLET
 // Special Operator FUNCTION
 Special Operator LET
 Special Operator LET*
 // Special Operator PROGV
 Special Form SETQ
 // Special Operator BLOCK
-Special Operator CATCH
-Special Operator GO
+// Special Operator CATCH
+// Special Operator GO
 // Special Operator RETURN-FROM
-Special Operator TAGBODY
-Special Operator THROW
-Special Operator UNWIND-PROTECT
-Special Operator IF
-Special Operator MULTIPLE-VALUE-CALL
-Special Operator MULTIPLE-VALUE-PROG1
+// Special Operator TAGBODY
+// Special Operator THROW
+// Special Operator UNWIND-PROTECT
+// Special Operator IF
+// Special Operator MULTIPLE-VALUE-CALL
+// Special Operator MULTIPLE-VALUE-PROG1
 // Special Operator PROGN
 */
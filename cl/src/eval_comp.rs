@@ -3,6 +3,7 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::collections::LinkedList;
 use std::collections::hash_map::HashMap;
 use std::ops::Not;
 
@@ -13,12 +14,12 @@ use env::lambda::RLLambda;
 use env::named_lambda::RLNamedLambda;
 use env::result::RLResult;
 use env::symb::RLEnvSymbol;
-use env::var::RLVar;
 
-use err::err::RLError;
+use err::err::{RLError, TypeError};
 
 use expr::atom::RLAtom;
 use expr::expr::Expr;
+use expr::list::RLList;
 use expr::nil::RLNil;
 // use expr::QuoteTrait;
 use expr::sexpr::SExpr;
@@ -26,6 +27,10 @@ use expr::symb::RLSymbol;
 
 use hash::hash::RLHash;
 
+use pars_symb::symbol::Symbol;
+
+use crate::data_control::{list_items, skip_declarations, variable_name};
+
 pub struct EvalCompilationFuncs {
 }
 
@@ -101,6 +106,20 @@ Return VALUE without evaluating it."))),
                     Some(|sexpr, env_ref| {
                         Ok::<RLResult, RLError>(
                             DEFMACRO(&sexpr, env_ref)?) }))));
+
+        cl_pack_hash.insert("symbol-macrolet".to_string(),
+            RLEnvSymbol::new_named_lambda("SYMBOL-MACROLET".to_string(),
+                RLNamedLambda::new_func(
+                    "SYMBOL-MACROLET".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("SYMBOL-MACROLET ({(name expansion)}*) declaration* body-form*
+
+Define the NAMES as symbol macros with the given EXPANSIONS within the
+BODY-FORMS. References to NAME and SETQ of NAME are replaced with EXPANSION."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SYMBOL_MACROLET(&sexpr)?) }),
+                    None)));
     }
 }
 
@@ -112,126 +131,67 @@ Macro LAMBDA
 Function COMPILE
 */
 
-#[allow(non_snake_case, unused_assignments)]
+#[allow(non_snake_case)]
 pub fn LAMBDA(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from LAMBDA");
-    println!("");
-
-    let curr_pack = &mut env_ref.get_mut_current_package();
-
-    let lambdas_hash_map = curr_pack.get_lambdas_hash_map();
+    /*
+    Syntax:
+    lambda lambda-list [[declaration* | documentation]] form*
+    */
 
     match sexpr {
-        SExpr::Cons(symb, ll) => {
+        SExpr::Cons(_symb, ll) => {
             let mut linked_list = ll.clone();
 
-            println!("linked_list: {:?}", linked_list);
-            println!("");
-
-            match &*symb.name {
-                "lambda" => {
-                     if linked_list.is_empty() {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-                        let mut init_parameters = SExpr::Nil(RLNil::new());
-
-                        if let Some(SExpr::SList(mut list)) =
-                            linked_list.pop_front() {
-                                list.pop_front();
-                                list.pop_back();
-
-                                init_parameters = SExpr::SList(list);
-
-                                println!("init_parameters: {:?}",
-                                    init_parameters);
-                                println!("");
-
-                        } else {
-                            // which RLError?!
-                            return Err(RLError::SimpleProgramError);
-                        }
-
-                        let opt_docstring: Option<String>;
-
-                        let mut body: SExpr = SExpr::Nil(RLNil::new());
-
-                        let next_param = linked_list.pop_front();
-
-                        if let Some(SExpr::Atom(ref atom)) = next_param {
-                            opt_docstring = Some(atom.to_string());
-
-                            println!("{}", opt_docstring.clone()
-                                                        .unwrap());
-                            println!("");
-
-                            if let Some(SExpr::SList(mut slist)) =
-                                linked_list.pop_front() {
-
-                                println!("SExpr::SList(slist): {:?}",
-                                    SExpr::SList(slist.clone()));
-                                println!("");
-
-                                body = slist.slist_to_cons();
-
-                                println!("body with doc: {:?}", body);
-                                println!("");
-
-                            } else {
-                                // which RLError?!
-                                return Err(RLError::SimpleProgramError);
-                            }
-
-                        } else {
-                            opt_docstring = None;
+            let parameters = match linked_list.pop_front() {
+                Some(params) => params,
 
-                            if let Some(SExpr::SList(mut slist)) =
-                                next_param {
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                                println!("SExpr::SList(slist): {:?}",
-                                    SExpr::SList(slist.clone()));
-                                println!("");
+            let opt_docstring = parse_docstring(&mut linked_list)
+                .map(|doc| doc.to_string());
 
-                                body = slist.slist_to_cons();
+            let specials = skip_declarations(&mut linked_list);
 
-                                println!("body without doc: {:?}", body);
-                                println!("");
-                            } else {
-                                // which RLError?!
-                                return Err(RLError::SimpleProgramError);
-                            }
-                        }
+            let mut lambda = RLLambda::new(None, opt_docstring);
 
-                        let mut lambda = RLLambda::new(
-                            None,
-                            opt_docstring,
-                            // curr_pack_name,
-                            // None,
-                            );
+            lambda.init_parameters(parameters)?;
+            lambda.set_specials(&specials);
 
-                        let _ = lambda.init_parameters(init_parameters);
+            lambda.set_body(progn_form(&linked_list));
 
-                        lambda.set_body(body);
+            // the lambda closes over the variables visible here
+            lambda.set_dyn_env_lambda(env_ref.capture_curr_eval_dyn_env());
 
-                        println!("lambda: {:?}", lambda);
-                        println!("");
+            Ok(RLResult::LambdaRes(Box::new(lambda)))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                        println!("body lambda: {:?}", lambda.get_body());
-                        println!("");
+// (progn form*)
+pub fn progn_form(forms: &LinkedList<SExpr>) -> SExpr {
+    let mut progn_ll = forms.clone();
 
-                        lambdas_hash_map.insert(lambda.get_id(),
-                                                lambda.clone());
+    progn_ll.push_front(SExpr::Symb(RLSymbol::new_with_str("progn")));
 
-                        Ok(RLResult::LambdaRes(lambda))
-                    }
+    SExpr::SList(RLList::<SExpr>::new_with_items(&progn_ll))
+}
 
-                }
+// A string followed by at least one more form is a documentation string.
+pub fn parse_docstring(forms: &mut LinkedList<SExpr>) -> Option<SExpr> {
+    if forms.len() < 2 {
+        return None
+    }
 
-                &_ => unreachable!(),
-            }
+    match forms.front() {
+        Some(SExpr::Atom(atom)) if atom.is_rlstring_atom() => {
+            forms.pop_front()
         }
-        _ => unreachable!(),
+
+        _ => None,
     }
 }
 
@@ -239,16 +199,10 @@ pub fn LAMBDA(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
 pub fn EVAL(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from EVAL");
-    println!("");
-
     match sexpr {
         SExpr::Cons(symb, ll) => {
             let mut linked_list = ll.clone();
 
-            println!("{:?}", linked_list);
-            println!("");
-
             match &*symb.name {
                 "eval" => {
                     if linked_list.is_empty() {
@@ -261,9 +215,6 @@ pub fn EVAL(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
 
                                     let form = slist.slist_to_sform();
 
-                                    println!("eval form: {:?}", form);
-                                    println!("");
-
                                     return Ok(RLResult::SExprRes(form));
                                 }
 
@@ -272,6 +223,15 @@ pub fn EVAL(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
                                         SExpr::Atom(atom)));
                                 }
 
+                                SExpr::Char(ch) => {
+                                    return Ok(RLResult::SExprRes(
+                                        SExpr::Char(ch)));
+                                }
+
+                                SExpr::Num(num) => {
+                                    Ok(RLResult::SExprRes(SExpr::Num(num)))
+                                }
+
                                 _ => todo!(),
                             }
                      } else {
@@ -303,12 +263,6 @@ pub fn QUOTE(sexpr: &SExpr) ->
         SExpr::Cons(symb, ll) => {
             match &*symb.name {
                 "quote" => {
-                    println!("Hello from QUOTE");
-                    println!("");
-
-                    println!("ll: {:?}", ll);
-                    println!("");
-
                     let mut linked_list = ll.clone();
 
                     /*
@@ -331,9 +285,6 @@ pub fn QUOTE(sexpr: &SExpr) ->
                             }
 
                             SExpr::SList(slist) => {
-                                println!("slist: {:?}", slist);
-                                println!("");
-
                                 // return Ok(Expr::SExpr(<expr::sexpr::SExpr as QuoteTrait>::quote(SExpr::SList(s_list))?));
                                 return Ok(expr::expr::Expr::SExpr(
                                     SExpr::SList(slist)));
@@ -345,6 +296,15 @@ pub fn QUOTE(sexpr: &SExpr) ->
                                     SExpr::Atom(atom)));
                             }
 
+                            SExpr::Char(ch) => {
+                                return Ok(expr::expr::Expr::SExpr(
+                                    SExpr::Char(ch)));
+                            }
+
+                            SExpr::Num(num) => {
+                                Ok(expr::expr::Expr::SExpr(SExpr::Num(num)))
+                            }
+
                             SExpr::Nil(nil) => {
                                 // return Ok(Expr::SExpr(<expr::sexpr::SExpr as QuoteTrait>::quote(SExpr::Nil)?));
                                 return Ok(expr::expr::Expr::SExpr(
@@ -358,8 +318,6 @@ pub fn QUOTE(sexpr: &SExpr) ->
                             }
 
                             SExpr::QList(qlist) => {
-                                println!("quote - QList");
-                                println!("");
                                 // return Ok(Expr::QExpr(<expr::qexpr::QExpr as QuoteTrait>::quote(SExpr::QList(qlist))?));
                                   return Ok(expr::expr::Expr::SExpr(
                                     SExpr::QList(qlist)));
@@ -383,16 +341,10 @@ pub fn QUOTE(sexpr: &SExpr) ->
 pub fn BACKQUOTE(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from BACKQUOTE");
-    println!("");
-
     match sexpr {
         SExpr::Cons(symb, ll) => {
             let mut linked_list = ll.clone();
 
-            println!("linked_list: {:?}", linked_list);
-            println!("");
-
             match &*symb.name {
                 "backquote" => {
                     if linked_list.len() == 0 {
@@ -415,7 +367,7 @@ pub fn BACKQUOTE(sexpr: &SExpr, _env_ref: &mut RLEnvironment) ->
     }
 }
 
-#[allow(non_snake_case, unused_assignments)]
+#[allow(non_snake_case)]
 pub fn DEFMACRO(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
@@ -424,233 +376,132 @@ pub fn DEFMACRO(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
         defmacro name lambda-list [[declaration* | documentation]] form*
     */
 
-    println!("Hello from DEFMACRO");
-    println!("");
-
-    let curr_pack = &mut env_ref.get_mut_current_package();
+    let curr_pack = env_ref.get_mut_current_package();
 
     let curr_pack_name = curr_pack.get_name();
 
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            let mut linked_list = ll.clone();
-
-            println!("linked_list: {:?}", linked_list);
-            println!("");
+        SExpr::Cons(_symb, ll) => {
+            let (name, parameters, opt_docstring, body) =
+                parse_definition(ll)?;
 
-            match &*symb.name {
-                "defmacro" => {
-                    if linked_list.is_empty() {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-                        let mut name = "dummy".to_string();
-
-                        if let Some(SExpr::Symb(symb)) =
-                            linked_list.pop_front() {
-                                let symb_name = symb.get_symbol_name();
-
-                                name = symb_name;
-
-                                println!("{}", name);
-                        } else {
-                            // Which RLError?!
-                            return Err(RLError::SimpleProgramError);
-                        }
-
-                        let mut parameters: SExpr = SExpr::Nil(RLNil::new());
-
-                        if let Some(SExpr::SList(mut list)) =
-                            linked_list.pop_front() {
-                                list.pop_front();
-                                list.pop_back();
+            let named_lambda = make_macro(&name,
+                                          parameters,
+                                          opt_docstring,
+                                          &body,
+                                          curr_pack_name)?;
 
-                                parameters = SExpr::SList(list);
-
-                                println!("parameters: {:?}", parameters);
-                                println!("");
-                        } else {
-                            // which RLError?!
-                            return Err(RLError::SimpleProgramError);
-                        }
-
-                        let opt_docstring: Option<SExpr>;
-
-                        // let mut lambda_string = "".to_string();
-
-                        let next_param = linked_list.pop_front();
-
-                        match next_param {
-                            Some(SExpr::Nil(nil)) => {
-                                opt_docstring = Some(SExpr::Nil(nil));
-                            }
-
-                            Some(SExpr::Atom(atom)) => {
-                                opt_docstring = Some(SExpr::Atom(atom));
-                            }
+            let symbol = RLEnvSymbol::new_named_lambda(
+                name.to_uppercase(),
+                named_lambda);
 
-                            /*
-                            Some(SExpr::Lambda(atom)) => {
-                                opt_docstring = None;
+            curr_pack.add_symbol(name.clone(), symbol);
 
-                                    lambda_string =
-                                        atom.get_atom_string();
-
-                                    println!("lambda_string: {:?}",
-                                        lambda_string);
-                                    println!("")
-                            }
-                            */
-
-                            Some(SExpr::SForm(form)) => {
-                                opt_docstring = None;
-
-                                linked_list.push_front(SExpr::SForm(form));
-                            }
-
-                            Some(SExpr::SToken(token)) => {
-                                opt_docstring = None;
-
-                                linked_list.push_front(
-                                    SExpr::SToken(token));
-                            }
-
-                            _ => unreachable!(),
-                        }
-
-                        let mut named_lambda = RLNamedLambda::new_macro(
-                            name.clone().to_uppercase(),
-                            opt_docstring,
-                            curr_pack_name,
-                            None,
-                            Some(|sexpr, env_ref| {
-                                Ok::<RLResult, RLError>(
-                                    RLMACRO(&sexpr, env_ref)?) }));
-
-                        /*
-                        if lambda_string.eq("").not() {
-                            println!("in lambda_string.eq.(\"\").not()");
-                            println!("");
-
-                            if let Some(lambda) =
-                                curr_pack.get_lambda(&lambda_string) {
-
-                                println!("lambda: {:?}", lambda);
-                                println!("");
+            Ok(RLResult::SymbRes(Symbol::new(&name)))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                                named_lambda.set_lambda(lambda);
+// The expander of DEFMACRO and MACROLET, it is called with the
+// unevaluated arguments of the macro form and returns the expansion.
+pub fn make_macro(name: &str,
+                  parameters: SExpr,
+                  opt_docstring: Option<SExpr>,
+                  body: &LinkedList<SExpr>,
+                  pack_name: String) -> Result<RLNamedLambda, RLError> {
 
-                                curr_pack.delete_lambda(lambda_string);
-                             }
+    let mut named_lambda = RLNamedLambda::new_macro(
+        name.to_uppercase(),
+        opt_docstring,
+        pack_name,
+        None,
+        None);
 
-                        } else {
-                            /*
-                            let mut block = RLBlock::new(name.clone()
-                                                             .to_uppercase());
+    named_lambda.init_parameters(parameters)?;
 
-                            block.set_progn_ll(&linked_list);
+    if let Some(mut block) = named_lambda.get_block() {
+        block.set_progn_ll(body);
 
-                            named_lambda.set_block(&block);
-                            */
+        named_lambda.set_block(&block);
+    }
 
+    Ok(named_lambda)
+}
 
-                            if let Some(mut block) =
-                                named_lambda.get_block() {
+// Special Operator SYMBOL-MACROLET
+// The evaluator expands every reference to one of the symbols in the
+// body, SETQ of such a symbol becomes SETF of its expansion.
+#[allow(non_snake_case)]
+pub fn SYMBOL_MACROLET(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                                block.set_progn_ll(&linked_list);
+    /*
+    Syntax:
+    symbol-macrolet ((symbol expansion)*) declaration* form* => result*
+    */
 
-                                named_lambda.set_block(&block);
-                            }
-                        }
-                        */
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                        let _ = named_lambda.init_parameters(parameters);
+            let definitions = match linked_list.pop_front() {
+                Some(sexpr) => list_items(sexpr)?,
 
-                        if let Some(mut block) = named_lambda.get_block() {
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                            block.set_progn_ll(&linked_list);
+            let mut block = RLBlock::new("(SYMBOL-MACROLET)-START".to_string());
 
-                            named_lambda.set_block(&block);
+            for definition in definitions {
+                let mut pair = list_items(definition)?;
 
-                            println!("named_lambda: {:?}", named_lambda);
-                            println!("");
-                        }
+                if pair.len() != 2 {
+                    return Err(RLError::SimpleProgramError)
+                }
 
-                        let symbol = RLEnvSymbol::new_named_lambda(
-                            name.clone().to_uppercase(),
-                            named_lambda.clone());
+                let name = variable_name(&pair.pop_front().unwrap())?;
 
-                        curr_pack.add_symbol(name.clone(), symbol);
+                block.add_symbol_macro(&name, pair.pop_front().unwrap());
+            }
 
-                        println!("last of defmacro");
-                        println!("named_lambda: {:?}", named_lambda);
-                        println!("");
+            skip_declarations(&mut linked_list);
 
-                        Ok(RLResult::StrRes(name.clone().to_uppercase()))
-                    }
-                }
+            block.set_progn_ll(&linked_list);
 
-                &_ => unreachable!(),
-            }
+            Ok(RLResult::BlockRes(block))
         }
         _ => unreachable!(),
     }
 }
 
+// Splits name lambda-list [[declaration* | documentation]] form*
+// as used by DEFUN and DEFMACRO.
+pub fn parse_definition(ll: &LinkedList<SExpr>) ->
+    Result<(String, SExpr, Option<SExpr>, LinkedList<SExpr>), RLError> {
 
-#[allow(non_snake_case)]
-pub fn RLMACRO(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
-    Result<RLResult, RLError> {
+    let mut linked_list = ll.clone();
 
-    println!("Hello from RLMACRO");
-    println!("");
+    let name = match linked_list.pop_front() {
+        Some(SExpr::Symb(symb)) => symb.get_symbol_name(),
 
-    let curr_pack = &mut env_ref.get_mut_current_package();
+        Some(other) => {
+            let err = TypeError::new(&other.to_string(), "SYMBOL");
 
-    match sexpr {
-        SExpr::Cons(symb, ll) => {
-            let mut linked_list = ll.clone();
-
-            let symb_string = &*symb.name;
-
-            println!("{:?}", symb_string);
-
-            let symbol = curr_pack.get_symbol(&symb_string.to_string())
-                .unwrap()
-                .clone();
-
-            println!("symbol: {:?}", symbol);
-            println!("");
-
-            let mut named_lambda = symbol.named_lambda.clone().unwrap();
-
-            let mut parameters = named_lambda.get_parameters();
-
-            if let Some(SExpr::SList(list)) =
-                parameters.get_req_params_as_option() {
-
-                let ll = list.get_linked_list();
+            return Err(RLError::TypeError(err))
+        }
 
-                for item in ll {
-                    if let SExpr::Atom(atom) = item {
-                        let param = atom.get_atom_string();
+        None => return Err(RLError::SimpleProgramError),
+    };
 
-                        let var = RLVar::SAtomVar(linked_list.pop_front().expect("REASON"));
+    let parameters = match linked_list.pop_front() {
+        Some(params) => params,
 
-                        named_lambda.add_dyn_env_var(param, var);
-                    }
-                }
+        None => return Err(RLError::SimpleProgramError),
+    };
 
-                println!("named_lambda: {:?}", named_lambda);
-                println!("");
+    let opt_docstring = parse_docstring(&mut linked_list);
 
-                Ok(RLResult::MacroRes(named_lambda))
-            } else {
-                // which RLError?!
-                return Err(RLError::SimpleProgramError)
-            }
-        }
-        _ => unreachable!(),
-    }
+    Ok((name, parameters, opt_docstring, linked_list))
 }
 
 /*
@@ -680,7 +531,7 @@ Function MACROEXPAND, MACROEXPAND-1
 
 Macro DEFINE-SYMBOL-MACRO
 
-Special Operator SYMBOL-MACROLET
+// Special Operator SYMBOL-MACROLET
 
 Variable *MACROEXPAND-HOOK*
 
@@ -719,7 +570,7 @@ Function CONSTANTP
 Special Operator EVAL-WHEN
 Special Operator LOAD-TIME-VALUE
 Special Operator QUOTE
-Special Operator SYMBOL-MACROLET
+// Special Operator SYMBOL-MACROLET
 Special Operator LOCALLY
 Special Operator THE
 */
@@ -0,0 +1,1179 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::collections::LinkedList;
+use std::collections::hash_map::HashMap;
+
+use env::named_lambda::RLNamedLambda;
+use env::result::RLResult;
+use env::symb::RLEnvSymbol;
+
+use err::err::{RLError,
+               SimpleError};
+
+use expr::atom::RLAtom;
+use expr::nil::RLNil;
+use expr::num::RLNum;
+use expr::sexpr::SExpr;
+use expr::symb::RLSymbol;
+
+use hash::hash::RLHash;
+
+use crate::data_control::{list_items, make_form, variable_name};
+use crate::list::make_list;
+
+// The iteration macros expand into a BLOCK named NIL around a TAGBODY,
+// so RETURN leaves them and the body may contain tags and GO. The
+// names of the variables and tags of the expansions can't be read.
+pub struct IterationFuncs {
+}
+
+impl IterationFuncs {
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    pub fn init(&mut self,
+                cl_pack_hash: &mut HashMap<String, RLEnvSymbol, RLHash>) {
+
+        cl_pack_hash.insert("do".to_string(),
+            RLEnvSymbol::new_named_lambda("DO".to_string(),
+                RLNamedLambda::new_func(
+                    "DO".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("DO ({var | (var [init [step]])}*) (end-test result*) declaration* {tag | statement}*
+
+Bind the VARS to the values of the INITS in parallel, then evaluate the
+STATEMENTS until END-TEST is true and return the values of the RESULTS.
+The VARS are assigned the values of their STEPS in parallel after each
+iteration."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DO(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("do*".to_string(),
+            RLEnvSymbol::new_named_lambda("DO*".to_string(),
+                RLNamedLambda::new_func(
+                    "DO*".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("DO* ({var | (var [init [step]])}*) (end-test result*) declaration* {tag | statement}*
+
+Like DO, but the VARS are bound and stepped one after another."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DO(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("dotimes".to_string(),
+            RLEnvSymbol::new_named_lambda("DOTIMES".to_string(),
+                RLNamedLambda::new_func(
+                    "DOTIMES".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("DOTIMES (var count-form [result-form]) declaration* {tag | statement}*
+
+Evaluate the STATEMENTS with VAR bound to the integers from 0 up to the
+value of COUNT-FORM, exclusive. Then RESULT-FORM is evaluated."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DOTIMES(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("dolist".to_string(),
+            RLEnvSymbol::new_named_lambda("DOLIST".to_string(),
+                RLNamedLambda::new_func(
+                    "DOLIST".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("DOLIST (var list-form [result-form]) declaration* {tag | statement}*
+
+Evaluate the STATEMENTS with VAR bound to each element of the list, then
+RESULT-FORM is evaluated with VAR bound to NIL."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DOLIST(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("loop".to_string(),
+            RLEnvSymbol::new_named_lambda("LOOP".to_string(),
+                RLNamedLambda::new_func(
+                    "LOOP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("LOOP {compound-form}* | LOOP [name-clause] {variable-clause}* {main-clause}*
+
+Evaluate the compound forms forever, or iterate as the loop clauses say:
+FOR/AS with FROM, TO, BELOW, BY, IN, ON, ACROSS and =, WITH, REPEAT,
+WHILE, UNTIL, DO, COLLECT, APPEND, SUM, COUNT, MAXIMIZE, MINIMIZE, WHEN,
+UNLESS, IF, RETURN, INITIALLY and FINALLY."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            LOOP(&sexpr)?) }),
+                    None)));
+    }
+}
+
+// Macro DO, DO*
+// (do ((i 0 (1+ i))) ((= i 3) r) b) =>
+// (block nil
+//   (let ((i 0))
+//     (tagbody
+//      #:do-next
+//       (if (= i 3) (go #:do-end))
+//       b
+//       (psetq i (1+ i))
+//       (go #:do-next)
+//      #:do-end)
+//     (progn r)))
+// DO* binds with LET* and steps with SETQ.
+#[allow(non_snake_case)]
+pub fn DO(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    do ({var | (var [init-form [step-form]])}*)
+       (end-test-form result-form*)
+       declaration* {tag | statement}* => result*
+    do* ({var | (var [init-form [step-form]])}*)
+        (end-test-form result-form*)
+        declaration* {tag | statement}* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let var_specs = list_items(linked_list.pop_front().unwrap())?;
+
+            let mut end_clause = list_items(linked_list.pop_front().unwrap())?;
+
+            let end_test = match end_clause.pop_front() {
+                Some(end_test) => end_test,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let mut bindings = LinkedList::<SExpr>::new();
+            let mut steps = LinkedList::<SExpr>::new();
+
+            for var_spec in var_specs {
+                let mut items = match var_spec {
+                    SExpr::SList(slist) => slist.get_items(),
+
+                    var => LinkedList::from([var]),
+                };
+
+                if items.len() > 3 {
+                    let err = SimpleError::new(&format!(
+                        "Malformed {} variable specification: {}",
+                        symb.name.to_uppercase(),
+                        make_list(&items)));
+
+                    return Err(RLError::SimpleError(err))
+                }
+
+                let var = items.pop_front().unwrap();
+
+                variable_name(&var)?;
+
+                let init = items.pop_front().unwrap_or(SExpr::Nil(RLNil::new()));
+
+                if let Some(step) = items.pop_front() {
+                    steps.push_back(var.clone());
+                    steps.push_back(step);
+                }
+
+                bindings.push_back(make_list(&LinkedList::from([var, init])));
+            }
+
+            let (let_name, setq_name) = if symb.name.eq("do*") {
+                ("let*", "setq")
+            } else {
+                ("let", "psetq")
+            };
+
+            let mut statements = LinkedList::from([
+                make_form("if", LinkedList::from([
+                    end_test,
+                    go("#:do-end")]))]);
+
+            let mut body = linked_list;
+
+            let declarations = split_declarations(&mut body);
+
+            statements.append(&mut body);
+
+            if !steps.is_empty() {
+                statements.push_back(make_form(setq_name, steps));
+            }
+
+            let tagbody = loop_tagbody("#:do-next", statements, "#:do-end");
+
+            let mut let_body = LinkedList::from([make_list(&bindings)]);
+
+            let_body.extend(declarations);
+            let_body.push_back(tagbody);
+            let_body.push_back(make_form("progn", end_clause));
+
+            Ok(RLResult::ExpansionRes(nil_block(make_form(let_name,
+                                                          let_body))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro DOTIMES
+// (dotimes (i n r) b) =>
+// (block nil
+//   (let* ((#:dotimes-count n) (i 0))
+//     (tagbody
+//      #:dotimes-next
+//       (if (>= i #:dotimes-count) (go #:dotimes-end))
+//       b
+//       (setq i (1+ i))
+//       (go #:dotimes-next)
+//      #:dotimes-end)
+//     r))
+#[allow(non_snake_case)]
+pub fn DOTIMES(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    dotimes (var count-form [result-form])
+            declaration* {tag | statement}* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut body = ll.clone();
+
+            let (var, count_form, result_form) =
+                iteration_spec(body.pop_front())?;
+
+            let count = symbol("#:dotimes-count");
+
+            let bindings = make_list(&LinkedList::from([
+                make_list(&LinkedList::from([count.clone(), count_form])),
+                make_list(&LinkedList::from([var.clone(), integer(0)]))]));
+
+            let declarations = split_declarations(&mut body);
+
+            let mut statements = LinkedList::from([
+                make_form("if", LinkedList::from([
+                    make_form(">=", LinkedList::from([var.clone(), count])),
+                    go("#:dotimes-end")]))]);
+
+            statements.append(&mut body);
+
+            statements.push_back(make_form("setq", LinkedList::from([
+                var.clone(),
+                make_form("1+", LinkedList::from([var]))])));
+
+            let tagbody = loop_tagbody("#:dotimes-next",
+                                       statements,
+                                       "#:dotimes-end");
+
+            let mut let_body = LinkedList::from([bindings]);
+
+            let_body.extend(declarations);
+            let_body.push_back(tagbody);
+            let_body.push_back(result_form);
+
+            Ok(RLResult::ExpansionRes(nil_block(make_form("let*", let_body))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Macro DOLIST
+// (dolist (x l r) b) =>
+// (block nil
+//   (let* ((#:dolist-tail l) (x nil))
+//     (tagbody
+//      #:dolist-next
+//       (if #:dolist-tail nil (go #:dolist-end))
+//       (setq x (car #:dolist-tail))
+//       b
+//       (setq #:dolist-tail (cdr #:dolist-tail))
+//       (go #:dolist-next)
+//      #:dolist-end)
+//     (setq x nil)
+//     r))
+#[allow(non_snake_case)]
+pub fn DOLIST(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    dolist (var list-form [result-form])
+           declaration* {tag | statement}* => result*
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut body = ll.clone();
+
+            let (var, list_form, result_form) =
+                iteration_spec(body.pop_front())?;
+
+            let tail = symbol("#:dolist-tail");
+
+            let bindings = make_list(&LinkedList::from([
+                make_list(&LinkedList::from([tail.clone(), list_form])),
+                make_list(&LinkedList::from([var.clone(),
+                                             SExpr::Nil(RLNil::new())]))]));
+
+            let declarations = split_declarations(&mut body);
+
+            let mut statements = LinkedList::from([
+                end_unless(tail.clone(), "#:dolist-end"),
+                setq(var.clone(), make_form("car", LinkedList::from([
+                    tail.clone()])))]);
+
+            statements.append(&mut body);
+
+            statements.push_back(setq(tail.clone(),
+                                      make_form("cdr", LinkedList::from([
+                                          tail]))));
+
+            let tagbody = loop_tagbody("#:dolist-next",
+                                       statements,
+                                       "#:dolist-end");
+
+            let mut let_body = LinkedList::from([bindings]);
+
+            let_body.extend(declarations);
+            let_body.push_back(tagbody);
+            let_body.push_back(setq(var, SExpr::Nil(RLNil::new())));
+            let_body.push_back(result_form);
+
+            Ok(RLResult::ExpansionRes(nil_block(make_form("let*", let_body))))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// (var form [result-form]) of DOTIMES and DOLIST
+fn iteration_spec(spec: Option<SExpr>) ->
+    Result<(SExpr, SExpr, SExpr), RLError> {
+
+    let mut items = match spec {
+        Some(spec) => list_items(spec)?,
+
+        None => return Err(RLError::SimpleProgramError),
+    };
+
+    if items.len() < 2 || items.len() > 3 {
+        return Err(RLError::SimpleProgramError)
+    }
+
+    let var = items.pop_front().unwrap();
+
+    variable_name(&var)?;
+
+    let form = items.pop_front().unwrap();
+
+    let result_form = items.pop_front().unwrap_or(SExpr::Nil(RLNil::new()));
+
+    Ok((var, form, result_form))
+}
+
+// Macro LOOP
+// The clauses are translated into the bindings of a LET*, the forms of
+// the first iteration, the body, the stepping of the variables and the
+// epilogue:
+// (block name
+//   (let* (bindings)
+//     (tagbody
+//       initially
+//       first
+//      #:loop-next
+//       body
+//       steps
+//       (go #:loop-next)
+//      #:loop-end
+//       finally
+//       (return-from name result))))
+// FOR clauses test for their end when they are stepped, which leaves
+// the loop through the epilogue.
+#[allow(non_snake_case)]
+pub fn LOOP(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    loop compound-form* => result*
+    loop [name-clause] {variable-clause}* {main-clause}* => result*
+
+    variable-clause::= with-clause | initial-final | for-as-clause
+    main-clause::= unconditional | accumulation | conditional |
+                   termination-test | initial-final
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            // the simple loop, any other form starts with a loop keyword
+            if ll.iter().all(|form| matches!(form, SExpr::SList(_))) {
+                let mut statements = ll.clone();
+
+                statements.push_back(go("#:loop-next"));
+
+                statements.push_front(symbol("#:loop-next"));
+
+                return Ok(RLResult::ExpansionRes(nil_block(
+                    make_form("tagbody", statements))))
+            }
+
+            let mut expansion = LoopExpansion::new(ll.clone());
+
+            expansion.parse()?;
+
+            Ok(RLResult::ExpansionRes(expansion.expand()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// What to do with the values of an accumulation clause
+#[derive(PartialEq)]
+enum Accumulation {
+    Collect,
+    Append,
+    Sum,
+    Count,
+    Maximize,
+    Minimize,
+}
+
+struct LoopExpansion {
+    // the clauses not parsed yet
+    clauses: LinkedList<SExpr>,
+
+    // name of the block, NIL by default
+    name: SExpr,
+
+    // bindings of the LET*, in the order of the clauses
+    bindings: LinkedList<SExpr>,
+
+    initially: LinkedList<SExpr>,
+
+    // the end tests and assignments of the first iteration
+    first: LinkedList<SExpr>,
+
+    body: LinkedList<SExpr>,
+
+    steps: LinkedList<SExpr>,
+
+    finally: LinkedList<SExpr>,
+
+    // names of the accumulation variables
+    accumulators: Vec<String>,
+
+    // the variable of the accumulation clauses without INTO, it is
+    // returned by the epilogue
+    result: Option<SExpr>,
+
+    // numbers the variables of the expansion
+    count: usize,
+}
+
+impl LoopExpansion {
+    fn new(clauses: LinkedList<SExpr>) -> Self {
+        Self {
+            clauses,
+            name: SExpr::Nil(RLNil::new()),
+            bindings: LinkedList::new(),
+            initially: LinkedList::new(),
+            first: LinkedList::new(),
+            body: LinkedList::new(),
+            steps: LinkedList::new(),
+            finally: LinkedList::new(),
+            accumulators: Vec::new(),
+            result: None,
+            count: 0,
+        }
+    }
+
+    fn parse(&mut self) -> Result<(), RLError> {
+        // AND after a FOR clause introduces another one
+        let mut after_for = false;
+
+        while let Some(clause) = self.clauses.front().cloned() {
+            let keyword = loop_keyword(&clause);
+
+            match keyword.as_deref() {
+                Some("named") => {
+                    self.clauses.pop_front();
+
+                    self.name = self.next_form("NAMED")?;
+                }
+
+                Some("with") => {
+                    self.clauses.pop_front();
+
+                    self.parse_with()?;
+                }
+
+                Some("for") | Some("as") => {
+                    self.clauses.pop_front();
+
+                    self.parse_for()?;
+
+                    after_for = true;
+
+                    continue;
+                }
+
+                Some("and") if after_for => {
+                    self.clauses.pop_front();
+
+                    self.parse_for()?;
+
+                    continue;
+                }
+
+                Some("repeat") => {
+                    self.clauses.pop_front();
+
+                    let count = self.next_form("REPEAT")?;
+
+                    let var = self.variable("repeat");
+
+                    self.bind(var.clone(), count);
+
+                    let test = make_form("if", LinkedList::from([
+                        make_form("<=", LinkedList::from([var.clone(),
+                                                          integer(0)])),
+                        go("#:loop-end"),
+                        setq(var.clone(),
+                             make_form("1-", LinkedList::from([var])))]));
+
+                    self.first.push_back(test.clone());
+                    self.steps.push_back(test);
+                }
+
+                Some("initially") => {
+                    self.clauses.pop_front();
+
+                    let mut forms = self.compound_forms();
+
+                    self.initially.append(&mut forms);
+                }
+
+                Some("finally") => {
+                    self.clauses.pop_front();
+
+                    let mut forms = self.compound_forms();
+
+                    self.finally.append(&mut forms);
+                }
+
+                _ => {
+                    let mut forms = self.parse_main_clause()?;
+
+                    self.body.append(&mut forms);
+                }
+            }
+
+            after_for = false;
+        }
+
+        Ok(())
+    }
+
+    fn expand(self) -> SExpr {
+        let mut statements = self.initially;
+
+        statements.extend(self.first);
+
+        statements.push_back(symbol("#:loop-next"));
+
+        statements.extend(self.body);
+        statements.extend(self.steps);
+
+        statements.push_back(go("#:loop-next"));
+        statements.push_back(symbol("#:loop-end"));
+
+        statements.extend(self.finally);
+
+        statements.push_back(make_form("return-from", LinkedList::from([
+            self.name.clone(),
+            self.result.unwrap_or(SExpr::Nil(RLNil::new()))])));
+
+        let let_form = make_form("let*", LinkedList::from([
+            make_list(&self.bindings),
+            make_form("tagbody", statements)]));
+
+        make_form("block", LinkedList::from([self.name, let_form]))
+    }
+
+    // with var [= form] {and var [= form]}*
+    fn parse_with(&mut self) -> Result<(), RLError> {
+        loop {
+            let var = self.next_form("WITH")?;
+
+            variable_name(&var)?;
+
+            let init = if self.next_keyword_is("=") {
+                self.clauses.pop_front();
+
+                self.next_form("WITH")?
+            } else {
+                SExpr::Nil(RLNil::new())
+            };
+
+            self.bind(var, init);
+
+            if !self.next_keyword_is("and") {
+                return Ok(())
+            }
+
+            self.clauses.pop_front();
+        }
+    }
+
+    // for var {arithmetic | in | on | across | = form [then form]}
+    fn parse_for(&mut self) -> Result<(), RLError> {
+        let var = self.next_form("FOR")?;
+
+        variable_name(&var)?;
+
+        let preposition = self.clauses.front().and_then(loop_keyword);
+
+        match preposition.as_deref() {
+            Some("in") | Some("on") | Some("across") => {
+                self.clauses.pop_front();
+
+                let mut list_form = self.next_form("FOR")?;
+
+                let preposition = preposition.unwrap();
+
+                if preposition.eq("across") {
+                    list_form = make_form("concatenate", LinkedList::from([
+                        make_form("quote", LinkedList::from([
+                            symbol("list")])),
+                        list_form]));
+                }
+
+                // ON steps var itself
+                let tail = if preposition.eq("on") {
+                    var.clone()
+                } else {
+                    self.variable("tail")
+                };
+
+                self.bind(tail.clone(), list_form);
+
+                let step = if self.next_keyword_is("by") {
+                    self.clauses.pop_front();
+
+                    let function = self.variable("by");
+
+                    let by = self.next_form("BY")?;
+
+                    self.bind(function.clone(), by);
+
+                    make_form("funcall", LinkedList::from([function,
+                                                           tail.clone()]))
+                } else {
+                    make_form("cdr", LinkedList::from([tail.clone()]))
+                };
+
+                self.steps.push_back(setq(tail.clone(), step));
+
+                let mut tests = LinkedList::from([
+                    end_unless(tail.clone(), "#:loop-end")]);
+
+                if !preposition.eq("on") {
+                    self.bind(var.clone(), SExpr::Nil(RLNil::new()));
+
+                    tests.push_back(setq(var, make_form("car",
+                        LinkedList::from([tail]))));
+                }
+
+                self.first.extend(tests.clone());
+                self.steps.extend(tests);
+            }
+
+            Some("=") => {
+                self.clauses.pop_front();
+
+                let init = self.next_form("FOR")?;
+
+                self.bind(var.clone(), SExpr::Nil(RLNil::new()));
+
+                self.first.push_back(setq(var.clone(), init.clone()));
+
+                let step = if self.next_keyword_is("then") {
+                    self.clauses.pop_front();
+
+                    self.next_form("THEN")?
+                } else {
+                    init
+                };
+
+                self.steps.push_back(setq(var, step));
+            }
+
+            _ => self.parse_arithmetic(var)?,
+        }
+
+        Ok(())
+    }
+
+    // for var [from|downfrom|upfrom form] [to|upto|below|downto|above
+    // form] [by form], the prepositions in any order
+    fn parse_arithmetic(&mut self, var: SExpr) -> Result<(), RLError> {
+        let mut start = None;
+        let mut limit = None;
+        let mut step = None;
+
+        // the comparison which ends the iteration
+        let mut end_test = ">";
+        let mut down = false;
+
+        while let Some(preposition) = self.clauses.front().and_then(loop_keyword) {
+            match preposition.as_str() {
+                "from" | "upfrom" => {}
+
+                "downfrom" => down = true,
+
+                "to" | "upto" => end_test = ">",
+
+                "below" => end_test = ">=",
+
+                "downto" => {
+                    end_test = "<";
+                    down = true;
+                }
+
+                "above" => {
+                    end_test = "<=";
+                    down = true;
+                }
+
+                "by" => {}
+
+                _ => break,
+            }
+
+            self.clauses.pop_front();
+
+            let form = self.next_form(&preposition.to_uppercase())?;
+
+            match preposition.as_str() {
+                "from" | "upfrom" | "downfrom" => start = Some(form),
+
+                "by" => step = Some(form),
+
+                _ => limit = Some(form),
+            }
+        }
+
+        if start.is_none() && limit.is_none() && step.is_none() {
+            let err = SimpleError::new(&format!(
+                "Malformed LOOP FOR clause for {}.", var));
+
+            return Err(RLError::SimpleError(err))
+        }
+
+        if down && end_test.eq(">") {
+            end_test = "<";
+        }
+
+        self.bind(var.clone(), start.unwrap_or(integer(0)));
+
+        let step = match step {
+            Some(form) => {
+                let by = self.variable("by");
+
+                self.bind(by.clone(), form);
+
+                self.first.push_back(check_step(by.clone()));
+
+                by
+            }
+
+            None => integer(1),
+        };
+
+        let operator = if down { "-" } else { "+" };
+
+        self.steps.push_back(setq(var.clone(), make_form(operator,
+            LinkedList::from([var.clone(), step]))));
+
+        if let Some(form) = limit {
+            let limit = self.variable("limit");
+
+            self.bind(limit.clone(), form);
+
+            let test = make_form("if", LinkedList::from([
+                make_form(end_test, LinkedList::from([var, limit])),
+                go("#:loop-end")]));
+
+            self.first.push_back(test.clone());
+            self.steps.push_back(test);
+        }
+
+        Ok(())
+    }
+
+    // The forms of an unconditional, accumulation, conditional or
+    // termination clause, or of a return clause.
+    fn parse_main_clause(&mut self) -> Result<LinkedList<SExpr>, RLError> {
+        let clause = self.clauses.pop_front().unwrap();
+
+        let keyword = match loop_keyword(&clause) {
+            Some(keyword) => keyword,
+
+            None => {
+                let err = SimpleError::new(&format!(
+                    "{} is not a LOOP keyword.", clause));
+
+                return Err(RLError::SimpleError(err))
+            }
+        };
+
+        let accumulation = match keyword.as_str() {
+            "collect" | "collecting" => Some(Accumulation::Collect),
+
+            "append" | "appending" | "nconc" | "nconcing" => {
+                Some(Accumulation::Append)
+            }
+
+            "sum" | "summing" => Some(Accumulation::Sum),
+
+            "count" | "counting" => Some(Accumulation::Count),
+
+            "maximize" | "maximizing" => Some(Accumulation::Maximize),
+
+            "minimize" | "minimizing" => Some(Accumulation::Minimize),
+
+            _ => None,
+        };
+
+        if let Some(accumulation) = accumulation {
+            return self.parse_accumulation(&keyword, accumulation)
+        }
+
+        match keyword.as_str() {
+            "do" | "doing" => {
+                let forms = self.compound_forms();
+
+                if forms.is_empty() {
+                    let err = SimpleError::new("LOOP DO needs a form.");
+
+                    return Err(RLError::SimpleError(err))
+                }
+
+                Ok(forms)
+            }
+
+            "when" | "if" | "unless" => {
+                let test = self.next_form(&keyword.to_uppercase())?;
+
+                let then_forms = self.selectable_clauses()?;
+
+                let else_forms = if self.next_keyword_is("else") {
+                    self.clauses.pop_front();
+
+                    self.selectable_clauses()?
+                } else {
+                    LinkedList::new()
+                };
+
+                if self.next_keyword_is("end") {
+                    self.clauses.pop_front();
+                }
+
+                let (then_forms, else_forms) = if keyword.eq("unless") {
+                    (else_forms, then_forms)
+                } else {
+                    (then_forms, else_forms)
+                };
+
+                Ok(LinkedList::from([make_form("if", LinkedList::from([
+                    test,
+                    make_form("progn", then_forms),
+                    make_form("progn", else_forms)]))]))
+            }
+
+            "while" => {
+                let test = self.next_form("WHILE")?;
+
+                Ok(LinkedList::from([end_unless(test, "#:loop-end")]))
+            }
+
+            "until" => {
+                let test = self.next_form("UNTIL")?;
+
+                Ok(LinkedList::from([make_form("if", LinkedList::from([
+                    test,
+                    go("#:loop-end")]))]))
+            }
+
+            "return" => {
+                let form = self.next_form("RETURN")?;
+
+                Ok(LinkedList::from([make_form("return-from",
+                    LinkedList::from([self.name.clone(), form]))]))
+            }
+
+            _ => {
+                let err = SimpleError::new(&format!(
+                    "{} is not a LOOP keyword.", clause));
+
+                Err(RLError::SimpleError(err))
+            }
+        }
+    }
+
+    // clause {and clause}* of a conditional
+    fn selectable_clauses(&mut self) -> Result<LinkedList<SExpr>, RLError> {
+        let mut forms = LinkedList::<SExpr>::new();
+
+        loop {
+            if self.clauses.is_empty() {
+                let err = SimpleError::new(
+                    "A LOOP conditional needs a clause.");
+
+                return Err(RLError::SimpleError(err))
+            }
+
+            let mut clause_forms = self.parse_main_clause()?;
+
+            forms.append(&mut clause_forms);
+
+            if !self.next_keyword_is("and") {
+                return Ok(forms)
+            }
+
+            self.clauses.pop_front();
+        }
+    }
+
+    // collect form [into var] and the like
+    fn parse_accumulation(&mut self,
+                          keyword: &str,
+                          accumulation: Accumulation) ->
+        Result<LinkedList<SExpr>, RLError> {
+
+        let form = self.next_form(&keyword.to_uppercase())?;
+
+        let var = if self.next_keyword_is("into") {
+            self.clauses.pop_front();
+
+            let var = self.next_form("INTO")?;
+
+            variable_name(&var)?;
+
+            var
+        } else {
+            let var = symbol("#:loop-result");
+
+            self.result = Some(var.clone());
+
+            var
+        };
+
+        let name = var.to_string();
+
+        if !self.accumulators.contains(&name) {
+            let init = match accumulation {
+                Accumulation::Sum | Accumulation::Count => integer(0),
+
+                _ => SExpr::Nil(RLNil::new()),
+            };
+
+            self.bind(var.clone(), init);
+
+            self.accumulators.push(name);
+        }
+
+        // the evaluator extends the list in var in place
+        let form = match accumulation {
+            Accumulation::Collect => make_form("#:loop-append",
+                LinkedList::from([var,
+                                  make_form("list",
+                                            LinkedList::from([form]))])),
+
+            Accumulation::Append => make_form("#:loop-append",
+                LinkedList::from([var, form])),
+
+            Accumulation::Sum => setq(var.clone(), make_form("+",
+                LinkedList::from([var, form]))),
+
+            Accumulation::Count => make_form("if", LinkedList::from([
+                form,
+                setq(var.clone(), make_form("1+", LinkedList::from([var])))])),
+
+            Accumulation::Maximize | Accumulation::Minimize => {
+                let operator = if accumulation == Accumulation::Maximize {
+                    "max"
+                } else {
+                    "min"
+                };
+
+                setq(var.clone(), make_form("if", LinkedList::from([
+                    var.clone(),
+                    make_form(operator, LinkedList::from([var, form.clone()])),
+                    form])))
+            }
+        };
+
+        Ok(LinkedList::from([form]))
+    }
+
+    fn next_form(&mut self, keyword: &str) -> Result<SExpr, RLError> {
+        match self.clauses.pop_front() {
+            Some(form) => Ok(form),
+
+            None => {
+                let err = SimpleError::new(&format!(
+                    "LOOP {} needs a form.", keyword));
+
+                Err(RLError::SimpleError(err))
+            }
+        }
+    }
+
+    fn next_keyword_is(&self, keyword: &str) -> bool {
+        self.clauses
+            .front()
+            .and_then(loop_keyword)
+            .is_some_and(|next| next.eq(keyword))
+    }
+
+    // the compound forms up to the next loop keyword
+    fn compound_forms(&mut self) -> LinkedList<SExpr> {
+        let mut forms = LinkedList::<SExpr>::new();
+
+        while let Some(SExpr::SList(_)) = self.clauses.front() {
+            forms.push_back(self.clauses.pop_front().unwrap());
+        }
+
+        forms
+    }
+
+    fn bind(&mut self, var: SExpr, init: SExpr) {
+        self.bindings.push_back(make_list(&LinkedList::from([var, init])));
+    }
+
+    // a variable of the expansion, e.g. #:loop-tail-1
+    fn variable(&mut self, purpose: &str) -> SExpr {
+        self.count += 1;
+
+        symbol(&format!("#:loop-{}-{}", purpose, self.count))
+    }
+}
+
+// Loop keywords are compared by name, :for is FOR too.
+fn loop_keyword(sexpr: &SExpr) -> Option<String> {
+    match sexpr {
+        SExpr::Symb(symb) => {
+            let name = symb.get_symbol_name();
+
+            Some(name.strip_prefix(':').unwrap_or(&name).to_string())
+        }
+
+        _ => None,
+    }
+}
+
+// The declarations at the beginning of body, they are removed from it.
+fn split_declarations(body: &mut LinkedList<SExpr>) -> LinkedList<SExpr> {
+    let mut declarations = LinkedList::<SExpr>::new();
+
+    while let Some(SExpr::SList(slist)) = body.front() {
+        match slist.get_items().front() {
+            Some(SExpr::Symb(head))
+                if head.get_symbol_name().eq("declare") => {
+
+                declarations.push_back(body.pop_front().unwrap());
+            }
+
+            _ => break,
+        }
+    }
+
+    declarations
+}
+
+// (tagbody next statements (go next) end)
+fn loop_tagbody(next: &str,
+                mut statements: LinkedList<SExpr>,
+                end: &str) -> SExpr {
+
+    statements.push_front(symbol(next));
+
+    statements.push_back(go(next));
+    statements.push_back(symbol(end));
+
+    make_form("tagbody", statements)
+}
+
+fn nil_block(form: SExpr) -> SExpr {
+    make_form("block", LinkedList::from([SExpr::Nil(RLNil::new()), form]))
+}
+
+// (if test nil (go end))
+fn end_unless(test: SExpr, end: &str) -> SExpr {
+    make_form("if", LinkedList::from([test,
+                                      SExpr::Nil(RLNil::new()),
+                                      go(end)]))
+}
+
+// (if (and (numberp by) (plusp by)) nil (error 'type-error ...)), a
+// step which is not positive would never reach the limit
+fn check_step(by: SExpr) -> SExpr {
+    let positive = make_form("and", LinkedList::from([
+        make_form("numberp", LinkedList::from([by.clone()])),
+        make_form("plusp", LinkedList::from([by.clone()]))]));
+
+    let expected_type = make_list(&LinkedList::from([
+        symbol("real"),
+        make_list(&LinkedList::from([integer(0)]))]));
+
+    let error = make_form("error", LinkedList::from([
+        make_form("quote", LinkedList::from([symbol("type-error")])),
+        symbol(":datum"),
+        by,
+        symbol(":expected-type"),
+        make_form("quote", LinkedList::from([expected_type]))]));
+
+    make_form("if", LinkedList::from([positive,
+                                      SExpr::Nil(RLNil::new()),
+                                      error]))
+}
+
+fn go(tag: &str) -> SExpr {
+    make_form("go", LinkedList::from([symbol(tag)]))
+}
+
+fn setq(var: SExpr, form: SExpr) -> SExpr {
+    make_form("setq", LinkedList::from([var, form]))
+}
+
+fn symbol(name: &str) -> SExpr {
+    SExpr::Symb(RLSymbol::new_with_str(name))
+}
+
+fn integer(value: i64) -> SExpr {
+    SExpr::Num(RLNum::Fixnum(value))
+}
+
+/*
+Iteration
+
+// Macro DO, DO*
+
+// Macro DOTIMES
+
+// Macro DOLIST
+
+// Macro LOOP
+
+Local Macro LOOP-FINISH
+*/
@@ -5,15 +5,21 @@
 
 pub mod creator;
 
+pub mod condition;
 pub mod data_control;
 pub mod eval_comp;
+pub mod iteration;
 
 pub mod pack;
 pub mod symb;
 
+pub mod character;
 pub mod list;
 pub mod math;
 pub mod string;
+pub mod system;
+
+pub mod rlisp;
 
 
 
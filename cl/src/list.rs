@@ -3,6 +3,7 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::collections::LinkedList;
 use std::collections::hash_map::HashMap;
 
 use env::named_lambda::RLNamedLambda;
@@ -14,23 +15,17 @@ use err::err::{RLError,
                TypeError};
 
 use expr::atom::RLAtom;
-use expr::qexpr::QExpr;
+use expr::char::RLChar;
+use expr::cons::RLCons;
 use expr::sexpr::SExpr;
-use expr::expr::{sexpr_to_qexpr};
 use expr::list::RLList;
 use expr::nil::RLNil;
+use expr::num::RLNum;
 use expr::string::RLString;
 
 use hash::hash::RLHash;
 
-/*
-remember QExpr types
-    Atom(String),
-    Nil,
-    Symb(Symbol),
-    QList(LinkedList<QExpr>),
-    Cons(Box<QExpr>, Box<QExpr>),
-*/
+use crate::character::char_arg;
 
 pub struct ListFuncs {
 }
@@ -84,8 +79,8 @@ impl ListFuncs {
                     Some(SExpr::Atom(RLAtom::new("Return a list with SE1 as the CAR and SE2 as the CDR."))),
                     "COMMON-LISP".to_string(),
                     Some(|sexpr| {
-                        Ok(RLResult::QExprRes(
-                            CONS(&sexpr)?)) }),
+                        Ok::<RLResult, RLError>(
+                            CONS(&sexpr)?) }),
                     None)));
 
         cl_pack_hash.insert("list".to_string(),
@@ -98,6 +93,17 @@ impl ListFuncs {
                         Ok::<RLResult, RLError>(
                             LIST(&sexpr)?) }),
                     None)));
+
+        cl_pack_hash.insert("nth".to_string(),
+            RLEnvSymbol::new_named_lambda("NTH".to_string(),
+                RLNamedLambda::new_func(
+                    "NTH".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the nth object in a list where the car is the zero-th element."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            NTH(&sexpr)?) }),
+                    None)));
     }
 }
 
@@ -105,35 +111,12 @@ impl ListFuncs {
 pub fn LIST(sexpr: &SExpr) -> Result<RLResult, RLError> {
     /*
     Syntax:
-
+    list &rest objects => list
     */
 
-     println!("Hello from LIST");
-     println!("");
-
-     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            match &*symb.name {
-                "list" => {
-                    // let result_qlist = QExpr::new_list(ll.clone());
-
-                    let result_qlist = RLList::<QExpr>::new_qlist(ll);
-
-                    // println!("result_qlist: {:?}", result_qlist);
-                    // println!("");
-
-                    /*
-                    if result_qlist.is_list_with_cons() {
-                        return Ok(RLResult::QExprRes(result_qlist));
-                    } else {
-                        return Err(RLError::SimpleProgramError);
-                    }
-                    */
-
-                    return Ok(RLResult::QExprRes(QExpr::QList2(result_qlist)));
-                }
-                &_ => unreachable!()
-            }
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            Ok(RLResult::SExprRes(make_list(ll)))
         }
         _ => unreachable!()
     }
@@ -143,45 +126,35 @@ pub fn LIST(sexpr: &SExpr) -> Result<RLResult, RLError> {
 pub fn CAR(sexpr: &SExpr) -> Result<RLResult, RLError> {
     /*
     Syntax:
-
+    car x => object
     */
 
-    println!("Hello from CAR");
-    println!("");
-
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            match &*symb.name {
-                "car" => {
-
-                    let mut linked_list = ll.clone();
-
-                    if linked_list.is_empty() {
-                        return Err(RLError::SimpleProgramError);
-                    } else if linked_list.len() > 1 {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-                        if let Some(sexpr) = linked_list.pop_front() {
-                            let mut qlist = sexpr_to_qexpr(sexpr);
-
-                            println!("qlist: {:?}", qlist);
-                            println!("");
-
-                            match qlist.car() {
-                                Ok(result_car) => {
-                                    return Ok(RLResult::QExprRes(result_car));
-                                }
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError);
+            }
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        } else {
-                            return Err(RLError::SimpleProgramError);
-                        }
-                    }
+            match ll.front().unwrap() {
+                SExpr::Nil(nil) => {
+                    Ok(RLResult::SExprRes(SExpr::Nil(nil.clone())))
+                }
+
+                SExpr::SList(slist) => {
+                    let first = slist.get_items()
+                                     .pop_front()
+                                     .unwrap_or(SExpr::Nil(RLNil::new()));
+
+                    Ok(RLResult::SExprRes(first))
+                }
+
+                SExpr::SCons(cons) => Ok(RLResult::SExprRes(cons.car())),
+
+                other => {
+                    let err = TypeError::new(&other.to_string(), "LIST");
+
+                    Err(RLError::TypeError(err))
                 }
-                &_ => unreachable!(),
             }
         }
         _ => unreachable!(),
@@ -192,224 +165,204 @@ pub fn CAR(sexpr: &SExpr) -> Result<RLResult, RLError> {
 pub fn CDR(sexpr: &SExpr) -> Result<RLResult, RLError> {
     /*
     Syntax:
+    cdr x => object
     */
 
-    println!("Hello from CDR");
-    println!("");
-
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            match &*symb.name {
-                "cdr" => {
-                    let mut linked_list = ll.clone();
-
-                    if linked_list.len() > 1 {
-                        return Err(RLError::SimpleProgramError);
-                    } else {
-                        if let Some(sexpr) = linked_list.pop_front() {
-                            let mut qlist = sexpr_to_qexpr(sexpr);
-
-                            println!("qlist: {:?}", qlist);
-                            println!("");
-
-                            match qlist.cdr() {
-                                Ok(result_cdr) => {
-                                    return Ok(RLResult::QExprRes(result_cdr));
-                                }
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError);
+            }
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        } else {
-                            return Err(RLError::SimpleProgramError);
-                        }
-                    }
+            match ll.front().unwrap() {
+                SExpr::Nil(nil) => {
+                    Ok(RLResult::SExprRes(SExpr::Nil(nil.clone())))
                 }
 
-                &_ => unreachable!(),
+                SExpr::SList(slist) => {
+                    let mut items = slist.get_items();
+
+                    items.pop_front();
+
+                    Ok(RLResult::SExprRes(make_list(&items)))
+                }
+
+                SExpr::SCons(cons) => Ok(RLResult::SExprRes(cons.cdr())),
+
+                other => {
+                    let err = TypeError::new(&other.to_string(), "LIST");
+
+                    Err(RLError::TypeError(err))
+                }
             }
         }
-
         _ => unreachable!(),
     }
 }
 
 #[allow(non_snake_case)]
-pub fn CONCATENATE(sexpr: &SExpr) ->
-    Result<RLResult, RLError> {
-
+pub fn NTH(sexpr: &SExpr) -> Result<RLResult, RLError> {
     /*
     Syntax:
-    concatenate result-type &rest sequences => result-sequence
+    nth n list => object
     */
 
-    println!("Hello from CONCATENATE");
-    println!("");
-
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            let mut linked_list = ll.clone();
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 2 {
+                return Err(RLError::SimpleProgramError);
+            }
 
-            match &*symb.name {
-                "concatenate" => {
-                    let type_ident = linked_list.pop_front();
+            let mut linked_list = ll.clone();
 
-                    let mut type_ident_rlstring = RLString::new("");
+            let index_arg = linked_list.pop_front().unwrap();
 
-                    if let Some(SExpr::Symb(ref symb)) = type_ident {
-                        let symb_name = symb.get_symbol_name();
+            let index = match &index_arg {
+                SExpr::Num(RLNum::Fixnum(index)) if *index >= 0 => {
+                    *index as usize
+                }
 
-                        type_ident_rlstring =
-                            RLString::new(&symb_name).to_uppercase();
-                    }
+                _ => return Err(unsigned_byte_error(&index_arg)),
+            };
 
-                    if let Some(SExpr::Atom(atom)) = type_ident {
-                        type_ident_rlstring =
-                            atom.get_atom_rlstring().to_uppercase();
-                    }
+            match linked_list.pop_front().unwrap() {
+                SExpr::Nil(nil) => Ok(RLResult::SExprRes(SExpr::Nil(nil))),
 
-                    println!("type_ident: {:?}", type_ident_rlstring.get());
-                    println!("");
+                SExpr::SList(slist) => {
+                    let item = slist.get_items()
+                                    .into_iter()
+                                    .nth(index)
+                                    .unwrap_or(SExpr::Nil(RLNil::new()));
 
-                    let first_op = linked_list.pop_front().unwrap();
+                    Ok(RLResult::SExprRes(item))
+                }
 
-                    if "LIST".to_string()
-                             .eq(&type_ident_rlstring.get()) {
+                other => {
+                    let err = TypeError::new(&other.to_string(), "LIST");
 
-                        let mut result: QExpr;
+                    Err(RLError::TypeError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
 
-                        let first_op_qexpr = sexpr_to_qexpr(first_op);
+fn unsigned_byte_error(sexpr: &SExpr) -> RLError {
+    let err = SimpleTypeError::new(&sexpr.to_string(), "UNSIGNED-BYTE");
 
-                        let mut rllist = RLList::<QExpr>::new();
+    RLError::SimpleTypeError(err)
+}
 
-                        match first_op_qexpr {
-                            QExpr::Nil(ref _nil)     => {
-                                result = QExpr::Nil(RLNil::new());
-                            }
+#[allow(non_snake_case)]
+pub fn CONCATENATE(sexpr: &SExpr) ->
+    Result<RLResult, RLError> {
 
-                            QExpr::QList2(ref qlist) => {
-                                rllist = first_op_qexpr.get_rllist();
-                                result = QExpr::QList2(qlist.clone());
-                            }
+    /*
+    Syntax:
+    concatenate result-type &rest sequences => result-sequence
+    */
 
-                            _ => {
-                                let err = TypeError::new(
-                                    &first_op_qexpr.to_string(), "SEQUENCE");
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
 
-                                return Err(RLError::TypeError(err));
-                            }
-                        }
+            let type_ident = match linked_list.pop_front() {
+                Some(SExpr::Symb(symb)) => {
+                    RLString::new(&symb.get_symbol_name()).to_uppercase()
+                }
 
-                        for _n in 0..linked_list.len() {
-                            let next_op = linked_list.pop_front().unwrap();
+                Some(SExpr::Atom(atom)) => {
+                    atom.get_atom_rlstring().to_uppercase()
+                }
 
-                            let next_op_qexpr = sexpr_to_qexpr(next_op);
+                Some(other) => {
+                    let err = SimpleTypeError::new(&other.to_string(),
+                                                   "SEQUENCE");
 
-                            match next_op_qexpr {
-                                QExpr::Nil(ref _nil) => {
-                                    if let QExpr::Nil(nil) = result {
-                                        result = QExpr::Nil(nil);
-                                    } else {
-                                        result = QExpr::QList2(rllist.clone());
-                                    }
-                                }
+                    return Err(RLError::SimpleTypeError(err));
+                }
 
-                                QExpr::QList2(ref qlist) => {
-                                    if let QExpr::Nil(_nil) = result {
-                                        rllist = next_op_qexpr.get_rllist();
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                                        result = QExpr::QList2(qlist.clone());
-                                    } else {
-                                        result = QExpr::QList2(
-                                            rllist.concatenate_lists(
-                                                &next_op_qexpr));
-                                    }
-                                }
+            match &*type_ident.get() {
+                "LIST" => {
+                    let mut result = LinkedList::<SExpr>::new();
 
-                                _ => {
-                                    let err = TypeError::new(
-                                        &first_op_qexpr.to_string(),"SEQUENCE");
+                    for sequence in linked_list {
+                        match sequence {
+                            SExpr::Nil(_) => {}
 
-                                    return Err(RLError::TypeError(err));
-                                }
+                            SExpr::SList(slist) => {
+                                result.append(&mut slist.get_items());
                             }
 
-                        } // for
+                            SExpr::Atom(ref atom)
+                                if atom.is_rlstring_atom() => {
 
-                        return Ok(RLResult::QExprRes(result));
-
-                    } else if "STRING".to_string()
-                                      .eq(&type_ident_rlstring.get()) {
-
-                        let mut result = RLString::new("");
+                                for ch in atom.get_atom_rlstring().get().chars() {
+                                    result.push_back(
+                                        SExpr::Char(RLChar::new(ch)));
+                                }
+                            }
 
-                        if let SExpr::Atom(ref first_atom) = first_op {
+                            other => {
+                                let err = TypeError::new(&other.to_string(),
+                                                         "SEQUENCE");
 
-                            if first_atom.is_rlstring_atom() {
+                                return Err(RLError::TypeError(err));
+                            }
+                        }
+                    }
 
-                                let mut first_op_rlstring =
-                                    first_atom.get_atom_rlstring();
+                    Ok(RLResult::SExprRes(make_list(&result)))
+                }
 
-                                for _n in 0..linked_list.len() {
-                                    let next_op = linked_list.pop_front()
-                                                             .unwrap();
+                "STRING" => {
+                    let mut result = RLString::new("");
 
-                                    if let SExpr::Atom(ref next_atom) =
-                                        next_op {
+                    for sequence in linked_list {
+                        match sequence {
+                            SExpr::Atom(ref atom)
+                                if atom.is_rlstring_atom() => {
 
-                                        if next_atom.is_rlstring_atom() {
+                                result = result.concatenate_strings(
+                                    &atom.get_atom_rlstring());
+                            }
 
-                                            let next_op_rlstring =
-                                                next_atom.get_atom_rlstring();
+                            // a list of characters
+                            SExpr::SList(ref slist) => {
+                                let mut string = String::new();
 
-                                            result =
-                                                first_op_rlstring
-                                                    .concatenate_strings(
-                                                        &next_op_rlstring);
-                                        } else {
-                                            let err = TypeError::new(
-                                                &next_atom.to_string(),
-                                                "SEQUENCE");
+                                for item in slist.get_items() {
+                                    string.push(char_arg(&item)?);
+                                }
 
-                                            return Err(
-                                                RLError::TypeError(err));
-                                        }
+                                result = result.concatenate_strings(
+                                    &RLString::new(&string));
+                            }
 
-                                    } else {
-                                        let err = TypeError::new(
-                                            &next_op.to_string(),
-                                            "SEQUENCE");
+                            SExpr::Nil(_) => {}
 
-                                        return Err(RLError::TypeError(err));
-                                    }
-                                }
-                            } else {
-                                let err = TypeError::new(
-                                    &first_atom.to_string(),
-                                    "SEQUENCE");
+                            other => {
+                                let err = TypeError::new(&other.to_string(),
+                                                         "SEQUENCE");
 
                                 return Err(RLError::TypeError(err));
                             }
-
-                        } else {
-                                let err = TypeError::new(
-                                    &first_op.to_string(),
-                                    "SEQUENCE");
-                                return Err(RLError::TypeError(err));
                         }
+                    }
 
-                        return Ok(RLResult::StringRes(result));
+                    Ok(RLResult::StringRes(result))
+                }
 
-                    } else {
-                        let err = SimpleTypeError::new(
-                            &type_ident_rlstring.get(),
-                            "SEQUENCE");
+                _ => {
+                    let err = SimpleTypeError::new(&type_ident.get(),
+                                                   "SEQUENCE");
 
-                        return Err(RLError::SimpleTypeError(err));
-                    }
+                    Err(RLError::SimpleTypeError(err))
                 }
-                &_ => unreachable!(),
             }
         }
         _ => unreachable!(),
@@ -417,38 +370,55 @@ pub fn CONCATENATE(sexpr: &SExpr) ->
 }
 
 #[allow(non_snake_case)]
-pub fn CONS(sexpr: &SExpr) -> Result<QExpr, RLError> {
+pub fn CONS(sexpr: &SExpr) -> Result<RLResult, RLError> {
     /*
     Syntax:
-
+    cons object-1 object-2 => cons
     */
 
-    println!("Hello from CONS");
-    println!("");
-
     match sexpr {
-        SExpr::Cons(symb, ll) => {
-            match &*symb.name {
-                "cons" => {
-                    let mut s_list = ll.clone();
-
-                    if s_list.len() < 2 {
-                        return Err(RLError::SimpleProgramError);
-                    } else if s_list.len() == 2 {
-                        let first = s_list.pop_front().unwrap();
-                        let second = s_list.pop_front().unwrap();
-
-                        return Ok(QExpr::new_cons((
-                            sexpr_to_qexpr(first),
-                            sexpr_to_qexpr(second))))
-
-                    } else {
-                        return Err(RLError::SimpleProgramError);
-                    }
-                }
-                &_ => unreachable!(),
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 2 {
+                return Err(RLError::SimpleProgramError);
             }
+
+            let mut s_list = ll.clone();
+
+            let first = s_list.pop_front().unwrap();
+            let second = s_list.pop_front().unwrap();
+
+            let result = match second {
+                SExpr::Nil(_) => {
+                    let mut items = LinkedList::<SExpr>::new();
+
+                    items.push_back(first);
+
+                    make_list(&items)
+                }
+
+                SExpr::SList(slist) => {
+                    let mut items = slist.get_items();
+
+                    items.push_front(first);
+
+                    make_list(&items)
+                }
+
+                second => SExpr::SCons(RLCons::<SExpr>::new_cons(first,
+                                                                 second)),
+            };
+
+            Ok(RLResult::SExprRes(result))
         }
         _ => unreachable!(),
     }
 }
+
+// NIL for no items, a proper list otherwise
+pub fn make_list(items: &LinkedList<SExpr>) -> SExpr {
+    if items.is_empty() {
+        SExpr::Nil(RLNil::new())
+    } else {
+        SExpr::SList(RLList::<SExpr>::new_with_items(items))
+    }
+}
@@ -3,6 +3,8 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::cmp::Ordering;
+use std::collections::LinkedList;
 use std::collections::hash_map::HashMap;
 
 use env::named_lambda::RLNamedLambda;
@@ -10,11 +12,15 @@ use env::result::RLResult;
 use env::symb::RLEnvSymbol;
 
 use err::err::{RLError,
-               CustomParseFloatError,
-               TypeError};
+               SimpleError,
+               SimpleTypeError};
 
 use expr::atom::RLAtom;
+use expr::bool::RLBool;
+use expr::nil::RLNil;
+use expr::num::{RLNum, Rounding};
 use expr::sexpr::SExpr;
+use expr::t::RLT;
 
 use hash::hash::RLHash;
 
@@ -71,426 +77,945 @@ impl MathFuncs {
                     Some(|sexpr| {
                         Ok(RLResult::NumRes(DIV(&sexpr)?)) }),
                     None)));
+
+        cl_pack_hash.insert("=".to_string(),
+            RLEnvSymbol::new_named_lambda("=".to_string(),
+                RLNamedLambda::new_func(
+                    "=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if all of its arguments are numerically equal, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            NUM_EQ(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("/=".to_string(),
+            RLEnvSymbol::new_named_lambda("/=".to_string(),
+                RLNamedLambda::new_func(
+                    "/=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if no two of its arguments are numerically equal, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            NUM_NE(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("<".to_string(),
+            RLEnvSymbol::new_named_lambda("<".to_string(),
+                RLNamedLambda::new_func(
+                    "<".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if its arguments are in strictly increasing order, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MONOTONIC(&sexpr, Ordering::is_lt)?) }),
+                    None)));
+
+        cl_pack_hash.insert(">".to_string(),
+            RLEnvSymbol::new_named_lambda(">".to_string(),
+                RLNamedLambda::new_func(
+                    ">".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if its arguments are in strictly decreasing order, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MONOTONIC(&sexpr, Ordering::is_gt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("<=".to_string(),
+            RLEnvSymbol::new_named_lambda("<=".to_string(),
+                RLNamedLambda::new_func(
+                    "<=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if arguments are in strictly non-decreasing order, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MONOTONIC(&sexpr, Ordering::is_le)?) }),
+                    None)));
+
+        cl_pack_hash.insert(">=".to_string(),
+            RLEnvSymbol::new_named_lambda(">=".to_string(),
+                RLNamedLambda::new_func(
+                    ">=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if arguments are in strictly non-increasing order, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MONOTONIC(&sexpr, Ordering::is_ge)?) }),
+                    None)));
+
+        cl_pack_hash.insert("zerop".to_string(),
+            RLEnvSymbol::new_named_lambda("ZEROP".to_string(),
+                RLNamedLambda::new_func(
+                    "ZEROP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Is this number zero?"))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGN_P(&sexpr, Ordering::is_eq)?) }),
+                    None)));
+
+        cl_pack_hash.insert("plusp".to_string(),
+            RLEnvSymbol::new_named_lambda("PLUSP".to_string(),
+                RLNamedLambda::new_func(
+                    "PLUSP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Is this real number strictly positive?"))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGN_P(&sexpr, Ordering::is_gt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("minusp".to_string(),
+            RLEnvSymbol::new_named_lambda("MINUSP".to_string(),
+                RLNamedLambda::new_func(
+                    "MINUSP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Is this real number strictly negative?"))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SIGN_P(&sexpr, Ordering::is_lt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("evenp".to_string(),
+            RLEnvSymbol::new_named_lambda("EVENP".to_string(),
+                RLNamedLambda::new_func(
+                    "EVENP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Is this integer even?"))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            PARITY_P(&sexpr, true)?) }),
+                    None)));
+
+        cl_pack_hash.insert("oddp".to_string(),
+            RLEnvSymbol::new_named_lambda("ODDP".to_string(),
+                RLNamedLambda::new_func(
+                    "ODDP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Is this integer odd?"))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            PARITY_P(&sexpr, false)?) }),
+                    None)));
+
+        cl_pack_hash.insert("min".to_string(),
+            RLEnvSymbol::new_named_lambda("MIN".to_string(),
+                RLNamedLambda::new_func(
+                    "MIN".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the least of its arguments; among EQUALP least, return the first."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            EXTREMUM(&sexpr, Ordering::is_lt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("max".to_string(),
+            RLEnvSymbol::new_named_lambda("MAX".to_string(),
+                RLNamedLambda::new_func(
+                    "MAX".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the greatest of its arguments; among EQUALP greatest, return the first."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            EXTREMUM(&sexpr, Ordering::is_gt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("abs".to_string(),
+            RLEnvSymbol::new_named_lambda("ABS".to_string(),
+                RLNamedLambda::new_func(
+                    "ABS".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the absolute value of the number."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            ABS(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("1+".to_string(),
+            RLEnvSymbol::new_named_lambda("1+".to_string(),
+                RLNamedLambda::new_func(
+                    "1+".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return NUMBER + 1."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            ONE_PLUS(&sexpr, RLNum::add)?) }),
+                    None)));
+
+        cl_pack_hash.insert("1-".to_string(),
+            RLEnvSymbol::new_named_lambda("1-".to_string(),
+                RLNamedLambda::new_func(
+                    "1-".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return NUMBER - 1."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            ONE_PLUS(&sexpr, RLNum::sub)?) }),
+                    None)));
+
+        cl_pack_hash.insert("mod".to_string(),
+            RLEnvSymbol::new_named_lambda("MOD".to_string(),
+                RLNamedLambda::new_func(
+                    "MOD".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return second result of FLOOR."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            REMAINDER(&sexpr, Rounding::Floor)?) }),
+                    None)));
+
+        cl_pack_hash.insert("rem".to_string(),
+            RLEnvSymbol::new_named_lambda("REM".to_string(),
+                RLNamedLambda::new_func(
+                    "REM".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return second result of TRUNCATE."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            REMAINDER(&sexpr, Rounding::Truncate)?) }),
+                    None)));
+
+        cl_pack_hash.insert("floor".to_string(),
+            RLEnvSymbol::new_named_lambda("FLOOR".to_string(),
+                RLNamedLambda::new_func(
+                    "FLOOR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the greatest integer not greater than number, or number/divisor.
+  The second returned value is (mod number divisor)."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DIVIDE(&sexpr, Rounding::Floor)?) }),
+                    None)));
+
+        cl_pack_hash.insert("ceiling".to_string(),
+            RLEnvSymbol::new_named_lambda("CEILING".to_string(),
+                RLNamedLambda::new_func(
+                    "CEILING".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the smallest integer not less than number, or number/divisor.
+  The second returned value is the remainder."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DIVIDE(&sexpr, Rounding::Ceiling)?) }),
+                    None)));
+
+        cl_pack_hash.insert("truncate".to_string(),
+            RLEnvSymbol::new_named_lambda("TRUNCATE".to_string(),
+                RLNamedLambda::new_func(
+                    "TRUNCATE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return number (or number/divisor) as an integer, rounded toward 0.
+  The second returned value is the remainder."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DIVIDE(&sexpr, Rounding::Truncate)?) }),
+                    None)));
+
+        cl_pack_hash.insert("round".to_string(),
+            RLEnvSymbol::new_named_lambda("ROUND".to_string(),
+                RLNamedLambda::new_func(
+                    "ROUND".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Rounds number (or number/divisor) to nearest integer.
+  The second returned value is the remainder."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            DIVIDE(&sexpr, Rounding::Round)?) }),
+                    None)));
+
+        cl_pack_hash.insert("expt".to_string(),
+            RLEnvSymbol::new_named_lambda("EXPT".to_string(),
+                RLNamedLambda::new_func(
+                    "EXPT".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return BASE raised to the POWER."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            EXPT(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("sqrt".to_string(),
+            RLEnvSymbol::new_named_lambda("SQRT".to_string(),
+                RLNamedLambda::new_func(
+                    "SQRT".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the square root of NUMBER."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            FLOAT_FUNC(&sexpr, f64::sqrt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("exp".to_string(),
+            RLEnvSymbol::new_named_lambda("EXP".to_string(),
+                RLNamedLambda::new_func(
+                    "EXP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return e raised to the power NUMBER."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            FLOAT_FUNC(&sexpr, f64::exp)?) }),
+                    None)));
+
+        cl_pack_hash.insert("log".to_string(),
+            RLEnvSymbol::new_named_lambda("LOG".to_string(),
+                RLNamedLambda::new_func(
+                    "LOG".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the logarithm of NUMBER in the base BASE, which defaults to e."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            LOG(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("gcd".to_string(),
+            RLEnvSymbol::new_named_lambda("GCD".to_string(),
+                RLNamedLambda::new_func(
+                    "GCD".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the greatest common divisor of the arguments, which must be
+  integers. GCD with no arguments is defined to be 0."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            GCD(&sexpr, RLNum::gcd, 0)?) }),
+                    None)));
+
+        cl_pack_hash.insert("lcm".to_string(),
+            RLEnvSymbol::new_named_lambda("LCM".to_string(),
+                RLNamedLambda::new_func(
+                    "LCM".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the least common multiple of one or more integers. LCM of no
+  arguments is defined to be 1."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            GCD(&sexpr, RLNum::lcm, 1)?) }),
+                    None)));
+
+        cl_pack_hash.insert("numberp".to_string(),
+            RLEnvSymbol::new_named_lambda("NUMBERP".to_string(),
+                RLNamedLambda::new_func(
+                    "NUMBERP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return true if OBJECT is a NUMBER, and NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            TYPE_P(&sexpr, |_| true)?) }),
+                    None)));
+
+        cl_pack_hash.insert("integerp".to_string(),
+            RLEnvSymbol::new_named_lambda("INTEGERP".to_string(),
+                RLNamedLambda::new_func(
+                    "INTEGERP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return true if OBJECT is an INTEGER, and NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            TYPE_P(&sexpr, RLNum::is_integer)?) }),
+                    None)));
+
+        cl_pack_hash.insert("floatp".to_string(),
+            RLEnvSymbol::new_named_lambda("FLOATP".to_string(),
+                RLNamedLambda::new_func(
+                    "FLOATP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return true if OBJECT is a FLOAT, and NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            TYPE_P(&sexpr, RLNum::is_float)?) }),
+                    None)));
     }
 }
 
+// Integers and ratios are added exactly, floats are contagious.
 #[allow(non_snake_case)]
-pub fn ADD(sexpr: &SExpr) -> Result<f64, RLError> {
-    match sexpr {
-        SExpr::Atom(atom) => {
-            let it = atom.get_atom_string();
+pub fn ADD(sexpr: &SExpr) -> Result<RLNum, RLError> {
 
-            let mut number = 0.0;
+    /*
+    Syntax:
+    + &rest numbers => sum
+    */
 
-            if let Ok(f64_atom) = it.parse::<f64>() {
-                number = f64_atom;
-            }
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut sum = RLNum::Fixnum(0);
 
-            if let Err(err) = it.parse::<f64>() {
-                let err2 = CustomParseFloatError::new(&it, "NUMBER", err);
-                    return Err(RLError::ParseFloatError(err2))
+            for arg in ll {
+                sum = sum.add(&number_arg(arg)?);
             }
-            Ok(number)
+
+            sum.check_overflow()
         }
+        _ => unreachable!(),
+    }
+}
 
-        SExpr::Cons(symb, v) => {
-            match &*symb.name {
-                "+" => {
-                    let mut iter = v.iter().peekable();
+#[allow(non_snake_case)]
+pub fn MINUS(sexpr: &SExpr) -> Result<RLNum, RLError> {
 
-                    if let None = iter.peek() {
-                        return Err(RLError::SimpleProgramError);
-                    }
+    /*
+    Syntax:
+    - number => negation
+    - minuend &rest subtrahends+ => difference
+    */
 
-                    let mut add0;
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut iter = ll.iter();
 
-                    match ADD(iter.next().unwrap()) {
-                        Ok(num)  => add0 = num,
-                        Err(err) => return Err(err),
-                    }
+            let minuend = match iter.next() {
+                Some(arg) => number_arg(arg)?,
 
-                    while let Some(elem) = iter.next() {
-                        let summand;
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-                        match ADD(elem) {
-                            Ok(num)  => summand = num,
-                            Err(err) => return Err(err),
-                        }
+            // If there is no subtrahend, change sign
+            if ll.len() == 1 {
+                return minuend.negate().check_overflow()
+            }
 
-                        add0 = add0 + summand
-                    }
-                    Ok(add0)
-                }
+            let mut difference = minuend;
 
-                &_ => unreachable!(),
+            for arg in iter {
+                difference = difference.sub(&number_arg(arg)?);
             }
-        }
 
-        SExpr::Nil(_nil) => {
-            let err = TypeError::new("NIL", "NUMBER");
-                return Err(RLError::TypeError(err));
+            difference.check_overflow()
         }
+        _ => unreachable!(),
+    }
+}
 
-        _ => Ok(0.0)
+#[allow(non_snake_case)]
+pub fn MUL(sexpr: &SExpr) -> Result<RLNum, RLError> {
+
+    /*
+    Syntax:
+    * &rest numbers => product
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut product = RLNum::Fixnum(1);
+
+            for arg in ll {
+                product = product.mul(&number_arg(arg)?);
+            }
+
+            product.check_overflow()
+        }
+        _ => unreachable!(),
     }
 }
 
+// (/ 1 3) is the ratio 1/3, only floats give inexact quotients.
 #[allow(non_snake_case)]
-pub fn MINUS(sexpr: &SExpr) -> Result<f64, RLError> {
+pub fn DIV(sexpr: &SExpr) -> Result<RLNum, RLError> {
+
+    /*
+    Syntax:
+    / number => reciprocal
+    / numerator &rest denominators+ => quotient
+    */
+
     match sexpr {
-        SExpr::Atom(atom) => {
-            let it = atom.get_atom_string();
+        SExpr::Cons(_symb, ll) => {
+            let mut iter = ll.iter();
 
-            let mut number = 0.0;
+            let numerator = match iter.next() {
+                Some(arg) => number_arg(arg)?,
 
-            if let Ok(f64_atom) = it.parse::<f64>() {
-                number = f64_atom;
-            }
+                None => return Err(RLError::SimpleProgramError),
+            };
 
-            if let Err(err) = it.parse::<f64>() {
-                let err2 = CustomParseFloatError::new(&it, "NUMBER", err);
-                    return Err(RLError::ParseFloatError(err2))
+            // With one argument, return reciprocal
+            if ll.len() == 1 {
+                return RLNum::Fixnum(1).div(&numerator)?.check_overflow()
             }
-            Ok(number)
-        }
 
-        SExpr::Cons(symb, v) => {
-            match &*symb.name {
-                "-" => {
-                    let mut iter = v.iter().peekable();
-
-                    // Are there any arguments?!
-                    if let None = iter.peek() {
-                        return Err(RLError::SimpleProgramError);
-                    }
-
-                    // Define minuend first
-                    let mut sub0;
-
-                    // Check for possible type errors
-                    match MINUS(iter.next().unwrap()) {
-                        Ok(num)  => sub0 = num,
-                        Err(err) => return Err(err),
-                    }
-
-                    // If there is no subtrahend, change sign
-                    if let None = iter.peek() {
-                        sub0 = -1.0 * sub0;
-                    }
-
-                    // There exist subtrahends
-                    while let Some(elem) = iter.next() {
-                        let subtrahend;
-
-                        // Check for possible type errors
-                        match MINUS(elem) {
-                            Ok(num)  => subtrahend = num,
-                            Err(err) => return Err(err),
-                        }
-
-                        sub0 = sub0 -subtrahend
-                    }
-                    Ok(sub0)
-                }
+            let mut quotient = numerator;
 
-                &_ => unreachable!(),
+            for arg in iter {
+                quotient = quotient.div(&number_arg(arg)?)?;
             }
+
+            quotient.check_overflow()
         }
+        _ => unreachable!(),
+    }
+}
+
+// Numbers are atoms, everything else is not of type NUMBER.
+pub fn number_arg(sexpr: &SExpr) -> Result<RLNum, RLError> {
+    typed_number_arg(sexpr, "NUMBER", |_| true)
+}
+
+#[allow(non_snake_case)]
+pub fn NUM_EQ(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    = &rest numbers+ => generalized-boolean
+    */
 
-        SExpr::Nil(_nil) => {
-            let err = TypeError::new("NIL", "NUMBER");
-                return Err(RLError::TypeError(err));
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let numbers = number_args(ll, 1, None, "NUMBER")?;
+
+            let first = numbers.front().unwrap();
+
+            Ok(bool_res(numbers.iter().all(|num| {
+                first.compare(num) == Some(Ordering::Equal)
+            })))
         }
+        _ => unreachable!(),
+    }
+}
 
-        _ => Ok(0.0)
+#[allow(non_snake_case)]
+pub fn NUM_NE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    /= &rest numbers+ => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let numbers = number_args(ll, 1, None, "NUMBER")?;
+
+            for (idx, num) in numbers.iter().enumerate() {
+                if numbers.iter()
+                          .skip(idx + 1)
+                          .any(|other| num.compare(other) ==
+                                       Some(Ordering::Equal)) {
+                    return Ok(bool_res(false))
+                }
+            }
+
+            Ok(bool_res(true))
+        }
+        _ => unreachable!(),
     }
 }
 
+// <, >, <= and >= hold if accept is true for every pair of
+// neighbouring arguments.
 #[allow(non_snake_case)]
-pub fn MUL(sexpr: &SExpr) -> Result<f64, RLError> {
+pub fn MONOTONIC(sexpr: &SExpr, accept: fn(Ordering) -> bool) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    < &rest numbers+ => generalized-boolean
+    */
+
     match sexpr {
-        SExpr::Atom(atom) => {
-            let it = atom.get_atom_string();
+        SExpr::Cons(_symb, ll) => {
+            let numbers = number_args(ll, 1, None, "REAL")?;
 
-            let mut number = 0.0;
+            let ordered = numbers.iter()
+                                 .zip(numbers.iter().skip(1))
+                                 .all(|(a, b)| a.compare(b)
+                                                .is_some_and(accept));
 
-            if let Ok(f64_atom) = it.parse::<f64>() {
-                number = f64_atom;
-            }
+            Ok(bool_res(ordered))
+        }
+        _ => unreachable!(),
+    }
+}
 
-            if let Err(err) = it.parse::<f64>() {
-                let err2 = CustomParseFloatError::new(&it, "NUMBER", err);
-                    return Err(RLError::ParseFloatError(err2))
-            }
-            Ok(number)
+// zerop, plusp and minusp compare the argument with 0.
+#[allow(non_snake_case)]
+pub fn SIGN_P(sexpr: &SExpr, accept: fn(Ordering) -> bool) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    zerop number => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let numbers = number_args(ll, 1, Some(1), "REAL")?;
+
+            let num = numbers.front().unwrap();
+
+            Ok(bool_res(num.compare(&RLNum::Fixnum(0))
+                           .is_some_and(accept)))
         }
+        _ => unreachable!(),
+    }
+}
 
-        SExpr::Cons(symb, v) => {
-            match &*symb.name {
-                "*" => {
-                    let mut iter = v.iter().peekable();
+#[allow(non_snake_case)]
+pub fn PARITY_P(sexpr: &SExpr, even: bool) -> Result<RLResult, RLError> {
 
-                    if let None = iter.peek() {
-                        return Err(RLError::SimpleProgramError);
-                    }
+    /*
+    Syntax:
+    evenp integer => generalized-boolean
+    oddp integer => generalized-boolean
+    */
 
-                    let mut mul0;
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let numbers = integer_args(ll, 1, Some(1))?;
 
-                    match MUL(iter.next().unwrap()) {
-                        Ok(num)  => mul0 = num,
-                        Err(err) => return Err(err),
-                    }
+            let (_, remainder) = numbers.front()
+                                        .unwrap()
+                                        .divide(&RLNum::Fixnum(2),
+                                                Rounding::Floor)?;
 
-                    while let Some(elem) = iter.next() {
-                        let factor;
+            Ok(bool_res(remainder.is_zero() == even))
+        }
+        _ => unreachable!(),
+    }
+}
 
-                        match MUL(elem) {
-                            Ok(num)  => factor = num,
-                            Err(err) => return Err(err),
-                        }
+// min and max return the first argument for which accept holds
+// against all others.
+#[allow(non_snake_case)]
+pub fn EXTREMUM(sexpr: &SExpr, accept: fn(Ordering) -> bool) ->
+    Result<RLResult, RLError> {
 
-                        mul0 = mul0 * factor
-                    }
-                    Ok(mul0)
-                }
+    /*
+    Syntax:
+    max &rest reals+ => max-real
+    min &rest reals+ => min-real
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut numbers = number_args(ll, 1, None, "REAL")?;
 
-                &_ => unreachable!(),
+            let mut extremum = numbers.pop_front().unwrap();
+
+            for num in numbers {
+                if num.compare(&extremum).is_some_and(accept) {
+                    extremum = num;
+                }
             }
+
+            Ok(RLResult::NumRes(extremum))
         }
+        _ => unreachable!(),
+    }
+}
 
-        SExpr::Nil(_nil) => {
-            let err = TypeError::new("NIL", "NUMBER");
-                return Err(RLError::TypeError(err));
+#[allow(non_snake_case)]
+pub fn ABS(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    abs number => absolute-value
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let numbers = number_args(ll, 1, Some(1), "NUMBER")?;
+
+            Ok(RLResult::NumRes(numbers.front().unwrap().abs()))
         }
+        _ => unreachable!(),
+    }
+}
+
+// 1+ and 1- apply op to the argument and 1.
+#[allow(non_snake_case)]
+pub fn ONE_PLUS(sexpr: &SExpr, op: fn(&RLNum, &RLNum) -> RLNum) ->
+    Result<RLResult, RLError> {
 
-        _ => Ok(0.0)
+    /*
+    Syntax:
+    1+ number => successor
+    1- number => predecessor
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let numbers = number_args(ll, 1, Some(1), "NUMBER")?;
+
+            let result = op(numbers.front().unwrap(), &RLNum::Fixnum(1));
+
+            Ok(RLResult::NumRes(result.check_overflow()?))
+        }
+        _ => unreachable!(),
     }
 }
 
+// floor, ceiling, truncate and round return the quotient and the
+// remainder as two values.
 #[allow(non_snake_case)]
-pub fn DIV(sexpr: &SExpr) -> Result<f64, RLError> {
+pub fn DIVIDE(sexpr: &SExpr, rounding: Rounding) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    floor number &optional divisor => quotient, remainder
+    */
+
     match sexpr {
-        SExpr::Atom(atom) => {
-            let it = atom.get_atom_string();
+        SExpr::Cons(_symb, ll) => {
+            let mut numbers = number_args(ll, 1, Some(2), "REAL")?;
 
-            let mut number = 0.0;
+            let number = numbers.pop_front().unwrap();
 
-            if let Ok(f64_atom) = it.parse::<f64>() {
-                number = f64_atom;
-            }
+            let divisor = numbers.pop_front().unwrap_or(RLNum::Fixnum(1));
 
-            if let Err(err) = it.parse::<f64>() {
-                let err2 = CustomParseFloatError::new(&it, "NUMBER", err);
-                    return Err(RLError::ParseFloatError(err2))
-            }
-            Ok(number)
+            let (quotient, remainder) = number.divide(&divisor, rounding)?;
+
+            let values = LinkedList::from([SExpr::Num(quotient),
+                                           SExpr::Num(remainder)]);
+
+            Ok(RLResult::ValuesRes(values))
         }
+        _ => unreachable!(),
+    }
+}
 
-        SExpr::Cons(symb, v) => {
-            match &*symb.name {
-                "/" => {
-                    let mut iter = v.iter().peekable();
-
-                    if let None = iter.peek() {
-                        return Err(RLError::SimpleProgramError);
-                    }
-
-                    let mut div0;
-
-                    match DIV(iter.next().unwrap()) {
-                        Ok(num)  => div0 = num,
-                        Err(err) => return Err(err),
-                    }
-
-                    if let None = iter.peek() {
-                        div0 = 1.0 / div0;
-                    }
-
-                    while let Some(elem) = iter.next() {
-                        let divisor;
-
-                        match DIV(elem) {
-                            Ok(num)  => divisor = num,
-                            Err(err) => return Err(err),
-                        }
-
-                        if divisor == 0.0 {
-                            return Err(RLError::DivisionByZero);
-                        } else {
-                            div0 = div0 / divisor;
-                        }
-                    }
-                    Ok(div0)
-                }
+// mod and rem, the remainder of floor and truncate.
+#[allow(non_snake_case)]
+pub fn REMAINDER(sexpr: &SExpr, rounding: Rounding) ->
+    Result<RLResult, RLError> {
 
-                &_ => unreachable!(),
-            }
+    /*
+    Syntax:
+    mod number divisor => modulus
+    rem number divisor => remainder
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut numbers = number_args(ll, 2, Some(2), "REAL")?;
+
+            let number = numbers.pop_front().unwrap();
+            let divisor = numbers.pop_front().unwrap();
+
+            let (_, remainder) = number.divide(&divisor, rounding)?;
+
+            Ok(RLResult::NumRes(remainder))
         }
+        _ => unreachable!(),
+    }
+}
 
-        SExpr::Nil(_nil) => {
-            let err = TypeError::new("NIL", "NUMBER");
-                return Err(RLError::TypeError(err));
+#[allow(non_snake_case)]
+pub fn EXPT(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    expt base power => result
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut numbers = number_args(ll, 2, Some(2), "NUMBER")?;
+
+            let base = numbers.pop_front().unwrap();
+            let power = numbers.pop_front().unwrap();
+
+            Ok(RLResult::NumRes(base.expt(&power)?.check_overflow()?))
         }
+        _ => unreachable!(),
+    }
+}
+
+// sqrt and exp, the result is a float of the format of the argument.
+#[allow(non_snake_case)]
+pub fn FLOAT_FUNC(sexpr: &SExpr, func: fn(f64) -> f64) ->
+    Result<RLResult, RLError> {
 
-        _ => Ok(0.0)
+    /*
+    Syntax:
+    sqrt number => root
+    exp number => result
+    */
+
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let numbers = number_args(ll, 1, Some(1), "NUMBER")?;
+
+            let num = numbers.front().unwrap();
+
+            let result = func(num.to_f64());
+
+            if result.is_nan() {
+                return Err(complex_result(&symb.name, &numbers))
+            }
+
+            let result = RLNum::make_float(result, num.is_double_float());
+
+            Ok(RLResult::NumRes(result.check_overflow()?))
+        }
+        _ => unreachable!(),
     }
 }
 
-/*
 #[allow(non_snake_case)]
-pub fn SIMPLEMATH(sexpr: &SExpr) -> Result<f64, RLError> {
-        match sexpr {
-            SExpr::Atom(it) => {
-                let mut number = 0.0;
+pub fn LOG(sexpr: &SExpr) -> Result<RLResult, RLError> {
 
-                if let Ok(f64_atom) = it.parse::<f64>() {
-                    number = f64_atom;
-                }
+    /*
+    Syntax:
+    log number &optional base => logarithm
+    */
 
-                if let Err(err) = it.parse::<f64>() {
-                    let err2 = CustomParseFloatError::new(it, "NUMBER", err);
-                    return Err(RLError::ParseFloatError(err2))
-                }
-                Ok(number)
+    match sexpr {
+        SExpr::Cons(symb, ll) => {
+            let numbers = number_args(ll, 1, Some(2), "NUMBER")?;
+
+            let mut iter = numbers.iter();
+
+            let number = iter.next().unwrap();
+
+            let base = iter.next();
+
+            if number.is_zero() || base.is_some_and(RLNum::is_zero) {
+                return Err(RLError::DivisionByZero)
             }
 
-            SExpr::Cons(symb, v) => {
-                match &*symb.name {
-                    "+" => {
-                        let mut iter = v.iter().peekable();
+            let result = match base {
+                Some(base) => number.to_f64().log(base.to_f64()),
+                None => number.to_f64().ln(),
+            };
+
+            if result.is_nan() {
+                return Err(complex_result(&symb.name, &numbers))
+            }
+
+            let double = numbers.iter().any(RLNum::is_double_float);
+
+            Ok(RLResult::NumRes(RLNum::make_float(result,
+                                                  double).check_overflow()?))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// gcd and lcm combine the integers with op, init is the result
+// without arguments.
+#[allow(non_snake_case)]
+pub fn GCD(sexpr: &SExpr,
+           op: fn(&RLNum, &RLNum) -> Option<RLNum>,
+           init: i64) -> Result<RLResult, RLError> {
 
-                        if let None = iter.peek() {
-                            return Err(RLError::SimpleProgramError);
-                        }
+    /*
+    Syntax:
+    gcd &rest integers => greatest-common-denominator
+    lcm &rest integers => least-common-multiple
+    */
 
-                        let mut add0;
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut numbers = integer_args(ll, 0, None)?;
 
-                        match SIMPLEMATH(iter.next().unwrap()) {
-                            Ok(num)  => add0 = num,
-                            Err(err) => return Err(err),
-                        }
+            let mut result = match numbers.pop_front() {
+                Some(first) => first.abs(),
 
-                        while let Some(elem) = iter.next() {
-                            let summand;
+                None => RLNum::Fixnum(init),
+            };
 
-                            match SIMPLEMATH(elem) {
-                                Ok(num)  => summand = num,
-                                Err(err) => return Err(err),
-                            }
-
-                            add0 = add0 + summand
-                        }
-                        Ok(add0)
-                    }
-
-                    "-" => {
-                        let mut iter = v.iter().peekable();
-
-                        // Are there any arguments?!
-                        if let None = iter.peek() {
-                            return Err(RLError::SimpleProgramError);
-                        }
-
-                        // Define minuend first
-                        let mut sub0;
-
-                        // Check for possible type errors
-                        match SIMPLEMATH(iter.next().unwrap()) {
-                            Ok(num)  => sub0 = num,
-                            Err(err) => return Err(err),
-                        }
-
-                        // If there is no subtrahend, change sign
-                        if let None = iter.peek() {
-                            sub0 = -1.0 * sub0;
-                        }
-
-                        // There exist subtrahends
-                        while let Some(elem) = iter.next() {
-                            let subtrahend;
-
-                            // Check for possible type errors
-                            match SIMPLEMATH(elem) {
-                                Ok(num)  => subtrahend = num,
-                                Err(err) => return Err(err),
-                            }
-
-                            sub0 = sub0 -subtrahend
-                        }
-                        Ok(sub0)
-                    }
-
-                    "*" => {
-                        let mut iter = v.iter().peekable();
-
-                        if let None = iter.peek() {
-                            return Err(RLError::SimpleProgramError);
-                        }
-
-                        let mut mul0;
-
-                        match SIMPLEMATH(iter.next().unwrap()) {
-                            Ok(num)  => mul0 = num,
-                            Err(err) => return Err(err),
-                        }
-
-                        while let Some(elem) = iter.next() {
-                            let factor;
-
-                            match SIMPLEMATH(elem) {
-                                Ok(num)  => factor = num,
-                                Err(err) => return Err(err),
-                            }
-
-                            mul0 = mul0 * factor
-                        }
-                    Ok(mul0)
-                    }
-
-                    "/" => {
-                        let mut iter = v.iter().peekable();
-
-                        if let None = iter.peek() {
-                            return Err(RLError::SimpleProgramError);
-                        }
-
-                        let mut div0;
-
-                        match SIMPLEMATH(iter.next().unwrap()) {
-                            Ok(num)  => div0 = num,
-                            Err(err) => return Err(err),
-                        }
-
-                        if let None = iter.peek() {
-                            div0 = 1.0 / div0;
-                        }
-
-                        while let Some(elem) = iter.next() {
-                            let divisor;
-
-                            match SIMPLEMATH(elem) {
-                                Ok(num)  => divisor = num,
-                                Err(err) => return Err(err),
-                            }
-
-                            if divisor == 0.0 {
-                                return Err(RLError::DivisionByZero);
-                            } else {
-                                div0 = div0 / divisor;
-                        }
-                    }
-                    Ok(div0)
-                    }
-                    &_ => todo!(),
-                }
+            for num in numbers {
+                result = op(&result, &num).unwrap();
             }
 
-            SExpr::Nil => {
-                let err = TypeError::new("NIL", "NUMBER");
-                return Err(RLError::TypeError(err));
+            Ok(RLResult::NumRes(result))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// numberp, integerp and floatp accept any object.
+#[allow(non_snake_case)]
+pub fn TYPE_P(sexpr: &SExpr, accept: fn(&RLNum) -> bool) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    numberp object => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
             }
 
-            _ => Ok(0.0)
+            let is_type = match ll.front().unwrap() {
+                SExpr::Num(num) => accept(num),
+
+                _ => false,
+            };
+
+            Ok(bool_res(is_type))
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn typed_number_arg(sexpr: &SExpr,
+                    expected_type: &str,
+                    accept: fn(&RLNum) -> bool) -> Result<RLNum, RLError> {
+
+    match sexpr {
+        SExpr::Num(num) if accept(num) => Ok(num.clone()),
+
+        _ => {
+            let err = SimpleTypeError::new(&sexpr.to_string(),
+                                           expected_type);
+
+            Err(RLError::SimpleTypeError(err))
         }
     }
-*/
+}
+
+// The arguments as numbers, there must be at least min and at most
+// max of them.
+fn number_args(ll: &LinkedList<SExpr>,
+               min: usize,
+               max: Option<usize>,
+               expected_type: &str) ->
+    Result<LinkedList<RLNum>, RLError> {
+
+    if ll.len() < min || max.is_some_and(|max| ll.len() > max) {
+        return Err(RLError::SimpleProgramError)
+    }
+
+    ll.iter()
+      .map(|arg| typed_number_arg(arg, expected_type, |_| true))
+      .collect()
+}
+
+fn integer_args(ll: &LinkedList<SExpr>,
+                min: usize,
+                max: Option<usize>) ->
+    Result<LinkedList<RLNum>, RLError> {
+
+    if ll.len() < min || max.is_some_and(|max| ll.len() > max) {
+        return Err(RLError::SimpleProgramError)
+    }
+
+    ll.iter()
+      .map(|arg| typed_number_arg(arg, "INTEGER", RLNum::is_integer))
+      .collect()
+}
+
+fn bool_res(value: bool) -> RLResult {
+    if value {
+        RLResult::BoolRes(RLBool::T(RLT::new()))
+    } else {
+        RLResult::BoolRes(RLBool::Nil(RLNil::new()))
+    }
+}
+
+fn complex_result(name: &str, numbers: &LinkedList<RLNum>) -> RLError {
+    let args = numbers.iter()
+                      .map(|num| num.to_string())
+                      .collect::<Vec<String>>();
+
+    let err = SimpleError::new(&format!(
+        "Complex results are not supported: ({} {})",
+        name.to_uppercase(),
+        args.join(" ")));
+
+    RLError::SimpleError(err)
+}
@@ -0,0 +1,162 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::collections::hash_map::HashMap;
+
+use env::named_lambda::RLNamedLambda;
+use env::result::RLResult;
+use env::symb::RLEnvSymbol;
+
+use err::err::{RLError,
+               TypeError};
+
+use expr::atom::RLAtom;
+use expr::sexpr::SExpr;
+use expr::symb::RLSymbol;
+
+use hash::hash::RLHash;
+
+use trace::trace::{TraceCategory, TraceLevel, set_trace};
+
+// Functions of the RLISP package, they are not part of Common Lisp.
+pub struct RLispFuncs {
+}
+
+impl RLispFuncs {
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    pub fn init(&mut self,
+                rlisp_pack_hash: &mut HashMap<String, RLEnvSymbol, RLHash>) {
+
+        // rlisp_pack_hash
+        rlisp_pack_hash.insert("set-trace".to_string(),
+            RLEnvSymbol::new_named_lambda("SET-TRACE".to_string(),
+                RLNamedLambda::new_func(
+                    "SET-TRACE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Set the trace level of CATEGORY (:lexer, :parser, :zipper, :macroexpand, :env or :all) to NIL, T, :INFO or :DEBUG."))),
+                    "RLISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SET_TRACE(&sexpr)?) }),
+                    None)));
+
+        rlisp_pack_hash.insert("backtrace".to_string(),
+            RLEnvSymbol::new_named_lambda("BACKTRACE".to_string(),
+                RLNamedLambda::new_func(
+                    "BACKTRACE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the function calls in progress as a list of (name argument*) lists, the innermost first."))),
+                    "RLISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            BACKTRACE(&sexpr)?) }),
+                    None)));
+    }
+}
+
+// The evaluator keeps the call frames and returns them for the
+// BacktraceRes.
+#[allow(non_snake_case)]
+pub fn BACKTRACE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+
+    backtrace => frames
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if !ll.is_empty() {
+                return Err(RLError::SimpleProgramError);
+            }
+
+            Ok(RLResult::BacktraceRes)
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn SET_TRACE(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+
+    set-trace category &optional (level t) => level
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.is_empty() || linked_list.len() > 2 {
+                return Err(RLError::SimpleProgramError);
+            }
+
+            let category_arg = linked_list.pop_front().unwrap();
+
+            let categories = match category_arg {
+                SExpr::Symb(ref symb) if symb.get_symbol_name().eq(":all") => {
+                    TraceCategory::ALL.to_vec()
+                }
+
+                SExpr::Symb(ref symb) => {
+                    match TraceCategory::from_name(&symb.get_symbol_name()) {
+                        Some(category) => vec![category],
+
+                        None => {
+                            let err = TypeError::new(
+                                &category_arg.to_string(),
+                                "(MEMBER :LEXER :PARSER :ZIPPER :MACROEXPAND \
+                                 :ENV :ALL)");
+
+                            return Err(RLError::TypeError(err))
+                        }
+                    }
+                }
+
+                other => {
+                    let err = TypeError::new(&other.to_string(), "KEYWORD");
+
+                    return Err(RLError::TypeError(err))
+                }
+            };
+
+            let level_arg = linked_list.pop_front()
+                                       .unwrap_or(SExpr::Symb(
+                                           RLSymbol::new_with_str("t")));
+
+            let level = match level_arg {
+                SExpr::Nil(_) => Some(TraceLevel::Off),
+
+                SExpr::Symb(ref symb) => {
+                    TraceLevel::from_name(&symb.get_symbol_name())
+                }
+
+                _ => None,
+            };
+
+            match level {
+                Some(level) => {
+                    for category in categories {
+                        set_trace(category, level);
+                    }
+
+                    Ok(RLResult::SExprRes(level_arg))
+                }
+
+                None => {
+                    let err = TypeError::new(&level_arg.to_string(),
+                                             "(MEMBER NIL T :INFO :DEBUG)");
+
+                    Err(RLError::TypeError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
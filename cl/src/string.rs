@@ -3,23 +3,37 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::cmp::Ordering;
+use std::collections::LinkedList;
 use std::collections::hash_map::HashMap;
-// use std::ops::Not;
 
-// use env::env::RLEnvironment;
-// use env::env_trait::EnvTrait;
 use env::named_lambda::RLNamedLambda;
 use env::result::RLResult;
 use env::symb::RLEnvSymbol;
-// use env::var::RLVar;
 
-use err::err::{RLError};
+use err::err::{ParseError,
+               RLError,
+               SimpleError,
+               SimpleTypeError};
 
+use expr::atom::RLAtom;
+use expr::bool::RLBool;
+use expr::char::RLChar;
 use expr::nil::RLNil;
+use expr::num::RLNum;
 use expr::sexpr::SExpr;
+use expr::string::RLString;
+use expr::t::RLT;
 
 use hash::hash::RLHash;
 
+use pars_symb::token::Token;
+
+use crate::character::char_arg;
+use crate::list::make_list;
+
+// Strings are indexed by characters, the functions work on the
+// characters of RLString and never split a character.
 pub struct StrFuncs {
 }
 
@@ -30,44 +44,1080 @@ impl StrFuncs {
 
     pub fn init(&mut self,
                 cl_pack_hash: &mut HashMap<String, RLEnvSymbol, RLHash>) {
-                           // func_hash: &mut HashMap<String,
-        // Box<dyn FnMut(SExpr) -> Result<RLResult, RLError>>>) {
 
         // cl_pack_hash
-        cl_pack_hash.insert("dummy".to_string(),
-            RLEnvSymbol::new_named_lambda("DUMMY".to_string(),
+        cl_pack_hash.insert("string=".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING=".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if the strings are the same from START1 to END1 and START2 to END2, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, true, Ordering::is_eq)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string/=".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING/=".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING/=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index of the first mismatch if the strings differ, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, true, Ordering::is_ne)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string<".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING<".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING<".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index of the first mismatch if STRING1 is less than STRING2, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, true, Ordering::is_lt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string>".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING>".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING>".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index of the first mismatch if STRING1 is greater than STRING2, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, true, Ordering::is_gt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string<=".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING<=".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING<=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index of the first mismatch if STRING1 is less than or equal to STRING2, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, true, Ordering::is_le)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string>=".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING>=".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING>=".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index of the first mismatch if STRING1 is greater than or equal to STRING2, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, true, Ordering::is_ge)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-equal".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-EQUAL".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-EQUAL".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Like STRING=, but the case of the characters is ignored."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, false, Ordering::is_eq)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-not-equal".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-NOT-EQUAL".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-NOT-EQUAL".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Like STRING/=, but the case of the characters is ignored."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, false, Ordering::is_ne)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-lessp".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-LESSP".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-LESSP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Like STRING<, but the case of the characters is ignored."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, false, Ordering::is_lt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-greaterp".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-GREATERP".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-GREATERP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Like STRING>, but the case of the characters is ignored."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, false, Ordering::is_gt)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-not-greaterp".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-NOT-GREATERP".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-NOT-GREATERP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Like STRING<=, but the case of the characters is ignored."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, false, Ordering::is_le)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-not-lessp".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-NOT-LESSP".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-NOT-LESSP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Like STRING>=, but the case of the characters is ignored."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_COMPARE(&sexpr, false, Ordering::is_ge)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-upcase".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-UPCASE".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-UPCASE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of STRING with the characters from START to END converted to upper case."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_CASE(&sexpr, RLString::string_upcase)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-downcase".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-DOWNCASE".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-DOWNCASE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of STRING with the characters from START to END converted to lower case."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_CASE(&sexpr, RLString::string_downcase)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-capitalize".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-CAPITALIZE".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-CAPITALIZE".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of STRING with the first character of each word from START to END in upper case and the others in lower case."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_CASE(&sexpr, RLString::string_capitalize)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-trim".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-TRIM".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-TRIM".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of STRING without the characters of CHARACTER-BAG at the beginning and end."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_TRIM(&sexpr, true, true)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-left-trim".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-LEFT-TRIM".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-LEFT-TRIM".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of STRING without the characters of CHARACTER-BAG at the beginning."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_TRIM(&sexpr, true, false)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string-right-trim".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING-RIGHT-TRIM".to_string(),
+                RLNamedLambda::new_func(
+                    "STRING-RIGHT-TRIM".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of STRING without the characters of CHARACTER-BAG at the end."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRING_TRIM(&sexpr, false, true)?) }),
+                    None)));
+
+        cl_pack_hash.insert("char".to_string(),
+            RLEnvSymbol::new_named_lambda("CHAR".to_string(),
+                RLNamedLambda::new_func(
+                    "CHAR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the character of STRING at INDEX."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("schar".to_string(),
+            RLEnvSymbol::new_named_lambda("SCHAR".to_string(),
+                RLNamedLambda::new_func(
+                    "SCHAR".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the character of the simple string STRING at INDEX."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            CHAR(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("make-string".to_string(),
+            RLEnvSymbol::new_named_lambda("MAKE-STRING".to_string(),
+                RLNamedLambda::new_func(
+                    "MAKE-STRING".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a string of SIZE characters, all of them INITIAL-ELEMENT."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            MAKE_STRING(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("string".to_string(),
+            RLEnvSymbol::new_named_lambda("STRING".to_string(),
                 RLNamedLambda::new_func(
-                    "DUMMY".to_string(),
-                    None,
+                    "STRING".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the string a string designator stands for: a string, the name of a symbol or a string of one character."))),
                     "COMMON-LISP".to_string(),
                     Some(|sexpr| {
                         Ok::<RLResult, RLError>(
-                            DUMMY(&sexpr)?) }),
+                            STRING(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("stringp".to_string(),
+            RLEnvSymbol::new_named_lambda("STRINGP".to_string(),
+                RLNamedLambda::new_func(
+                    "STRINGP".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return T if OBJECT is a string, NIL otherwise."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            STRINGP(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("parse-integer".to_string(),
+            RLEnvSymbol::new_named_lambda("PARSE-INTEGER".to_string(),
+                RLNamedLambda::new_func(
+                    "PARSE-INTEGER".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Parse an integer in RADIX from STRING between START and END. Return the integer and the index where parsing stopped. With JUNK-ALLOWED, return NIL instead of signalling a PARSE-ERROR."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            PARSE_INTEGER(&sexpr)?) }),
+                    None)));
+
+        // the sequence functions of strings, they work on lists too
+        cl_pack_hash.insert("length".to_string(),
+            RLEnvSymbol::new_named_lambda("LENGTH".to_string(),
+                RLNamedLambda::new_func(
+                    "LENGTH".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the number of elements of SEQUENCE."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            LENGTH(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("subseq".to_string(),
+            RLEnvSymbol::new_named_lambda("SUBSEQ".to_string(),
+                RLNamedLambda::new_func(
+                    "SUBSEQ".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return a copy of the elements of SEQUENCE from START to END."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SUBSEQ(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("search".to_string(),
+            RLEnvSymbol::new_named_lambda("SEARCH".to_string(),
+                RLNamedLambda::new_func(
+                    "SEARCH".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index in SEQUENCE-2 of the first, or with FROM-END the last, subsequence matching SEQUENCE-1, or NIL."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            SEARCH(&sexpr)?) }),
+                    None)));
+
+        cl_pack_hash.insert("position".to_string(),
+            RLEnvSymbol::new_named_lambda("POSITION".to_string(),
+                RLNamedLambda::new_func(
+                    "POSITION".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Return the index of the first, or with FROM-END the last, element of SEQUENCE which is EQL to ITEM, or NIL."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            POSITION(&sexpr)?) }),
                     None)));
     }
 }
 
+// string=, string<, string-equal, ... compare the strings from start1
+// to end1 and from start2 to end2. string= and string-equal return a
+// boolean, the others the index of the first mismatch in string1 if
+// accept is true for the comparison.
 #[allow(non_snake_case)]
-pub fn DUMMY(sexpr: &SExpr) ->
+pub fn STRING_COMPARE(sexpr: &SExpr,
+                      case_sensitive: bool,
+                      accept: fn(Ordering) -> bool) ->
     Result<RLResult, RLError> {
 
     /*
     Syntax:
-
+    string= string1 string2 &key start1 end1 start2 end2 => generalized-boolean
+    string< string1 string2 &key start1 end1 start2 end2 => mismatch-index
     */
 
     match sexpr {
         SExpr::Cons(symb, ll) => {
-            let _linked_list = ll.clone();
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let string1 = string_designator(&linked_list.pop_front().unwrap())?
+                              .chars();
+            let string2 = string_designator(&linked_list.pop_front().unwrap())?
+                              .chars();
+
+            let keys = key_args(linked_list,
+                                &[":start1", ":end1", ":start2", ":end2"])?;
+
+            let (start1, end1) = bounds(&keys, ":start1", ":end1",
+                                        string1.len())?;
+            let (start2, end2) = bounds(&keys, ":start2", ":end2",
+                                        string2.len())?;
+
+            let fold = |ch: &char| {
+                if case_sensitive {
+                    *ch
+                } else {
+                    RLChar::new(*ch).downcase().get()
+                }
+            };
+
+            let chars1 = string1[start1..end1].iter().map(fold);
+            let chars2 = string2[start2..end2].iter().map(fold);
+
+            let mismatch = chars1.clone()
+                                 .zip(chars2.clone())
+                                 .position(|(ch1, ch2)| ch1 != ch2);
+
+            let (index, ordering) = match mismatch {
+                Some(index) => {
+                    let ch1 = chars1.clone().nth(index).unwrap();
+                    let ch2 = chars2.clone().nth(index).unwrap();
+
+                    (index, ch1.cmp(&ch2))
+                }
+
+                None => {
+                    let length1 = end1 - start1;
+                    let length2 = end2 - start2;
+
+                    (length1.min(length2), length1.cmp(&length2))
+                }
+            };
+
+            if symb.name.eq("string=") || symb.name.eq("string-equal") {
+                return Ok(bool_res(accept(ordering)))
+            }
+
+            if accept(ordering) {
+                Ok(RLResult::NumRes(RLNum::Fixnum((start1 + index) as i64)))
+            } else {
+                Ok(RLResult::NilRes(RLNil::new()))
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// string-upcase, string-downcase and string-capitalize convert the
+// characters from start to end.
+#[allow(non_snake_case)]
+pub fn STRING_CASE(sexpr: &SExpr,
+                   convert: fn(&RLString, usize, usize) -> RLString) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    string-upcase string &key start end => cased-string
+    string-downcase string &key start end => cased-string
+    string-capitalize string &key start end => cased-string
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let string = match linked_list.pop_front() {
+                Some(string) => string_designator(&string)?,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let keys = key_args(linked_list, &[":start", ":end"])?;
+
+            let (start, end) = bounds(&keys, ":start", ":end",
+                                      string.length())?;
+
+            Ok(RLResult::StringRes(convert(&string, start, end)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn STRING_TRIM(sexpr: &SExpr, left: bool, right: bool) ->
+    Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    string-trim character-bag string => trimmed-string
+    string-left-trim character-bag string => trimmed-string
+    string-right-trim character-bag string => trimmed-string
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let bag = sequence_items(ll.front().unwrap())?
+                          .iter()
+                          .map(char_arg)
+                          .collect::<Result<Vec<char>, RLError>>()?;
+
+            let chars = string_designator(ll.back().unwrap())?.chars();
+
+            let mut start = 0;
+            let mut end = chars.len();
+
+            if left {
+                while start < end && bag.contains(&chars[start]) {
+                    start += 1;
+                }
+            }
+
+            if right {
+                while end > start && bag.contains(&chars[end - 1]) {
+                    end -= 1;
+                }
+            }
+
+            Ok(RLResult::StringRes(RLString::from_chars(&chars[start..end])))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn CHAR(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    char string index => character
+    schar string index => character
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let chars = string_arg(ll.front().unwrap())?.chars();
 
-            match &*symb.name {
-                "dummy" => {
+            let index = index_arg(ll.back().unwrap())?;
 
-                    return Ok(RLResult::NilRes(RLNil::new()));
+            match chars.get(index) {
+                Some(ch) => {
+                    Ok(RLResult::SExprRes(SExpr::Char(RLChar::new(*ch))))
                 }
-                &_ => unreachable!(),
+
+                None => Err(index_error(index, chars.len())),
             }
         }
         _ => unreachable!(),
     }
 }
+
+#[allow(non_snake_case)]
+pub fn MAKE_STRING(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    make-string size &key initial-element element-type => string
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let size = match linked_list.pop_front() {
+                Some(size) => index_arg(&size)?,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let keys = key_args(linked_list,
+                                &[":initial-element", ":element-type"])?;
+
+            let initial_element = match keys.get(":initial-element") {
+                Some(initial_element) => char_arg(initial_element)?,
+
+                None => ' ',
+            };
+
+            Ok(RLResult::StringRes(RLString::from_chars(
+                &vec![initial_element; size])))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn STRING(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    string x => string
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            Ok(RLResult::StringRes(string_designator(ll.front().unwrap())?))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn STRINGP(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    stringp object => generalized-boolean
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            Ok(bool_res(string_arg(ll.front().unwrap()).is_ok()))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// Whitespace around the integer is skipped. The second value is the
+// index of the first character which is not parsed, with junk-allowed
+// parsing stops at the first character which is not a digit.
+#[allow(non_snake_case)]
+pub fn PARSE_INTEGER(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    parse-integer string &key start end radix junk-allowed => integer, pos
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let string = match linked_list.pop_front() {
+                Some(string) => string_arg(&string)?,
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            let chars = string.chars();
+
+            let keys = key_args(linked_list, &[":start", ":end", ":radix",
+                                               ":junk-allowed"])?;
+
+            let (start, end) = bounds(&keys, ":start", ":end", chars.len())?;
+
+            let radix = match keys.get(":radix") {
+                Some(radix) => match index_arg(radix) {
+                    Ok(radix) if (2..=36).contains(&radix) => radix as u32,
+
+                    _ => {
+                        let err = SimpleTypeError::new(&radix.to_string(),
+                                                       "(INTEGER 2 36)");
+
+                        return Err(RLError::SimpleTypeError(err))
+                    }
+                },
+
+                None => 10,
+            };
+
+            let junk_allowed = keys.get(":junk-allowed")
+                                   .is_some_and(|junk| !is_nil(junk));
+
+            let mut pos = start;
+
+            while pos < end && chars[pos].is_whitespace() {
+                pos += 1;
+            }
+
+            let mut digits = String::new();
+
+            if pos < end && (chars[pos] == '+' || chars[pos] == '-') {
+                digits.push(chars[pos]);
+
+                pos += 1;
+            }
+
+            let digits_start = pos;
+
+            while pos < end && chars[pos].is_digit(radix) {
+                digits.push(chars[pos]);
+
+                pos += 1;
+            }
+
+            let no_digits = pos == digits_start;
+
+            if !junk_allowed {
+                while pos < end && chars[pos].is_whitespace() {
+                    pos += 1;
+                }
+
+                if no_digits || pos < end {
+                    let message = if no_digits {
+                        "No integer in string"
+                    } else {
+                        "Junk in string"
+                    };
+
+                    let err = ParseError::new(message,
+                                              Token::Atom(string.to_string()));
+
+                    return Err(RLError::ParseError(err))
+                }
+            }
+
+            let integer = match RLNum::read_radix(&digits, radix) {
+                Some(integer) if !no_digits => SExpr::Num(integer),
+
+                _ => SExpr::Nil(RLNil::new()),
+            };
+
+            let pos = SExpr::Num(RLNum::Fixnum(pos as i64));
+
+            Ok(RLResult::ValuesRes(LinkedList::from([integer, pos])))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn LENGTH(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    length sequence => n
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() != 1 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let length = match ll.front().unwrap() {
+                SExpr::Atom(atom) if atom.is_rlstring_atom() => {
+                    atom.get_atom_rlstring().length()
+                }
+
+                sequence => sequence_items(sequence)?.len(),
+            };
+
+            Ok(RLResult::NumRes(RLNum::Fixnum(length as i64)))
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn SUBSEQ(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    subseq sequence start &optional end => subsequence
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            if ll.len() < 2 || ll.len() > 3 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut linked_list = ll.clone();
+
+            let sequence = linked_list.pop_front().unwrap();
+
+            let mut keys = HashMap::<String, SExpr>::new();
+
+            keys.insert(":start".to_string(), linked_list.pop_front().unwrap());
+
+            if let Some(end) = linked_list.pop_front() {
+                keys.insert(":end".to_string(), end);
+            }
+
+            match sequence {
+                SExpr::Atom(ref atom) if atom.is_rlstring_atom() => {
+                    let chars = atom.get_atom_rlstring().chars();
+
+                    let (start, end) = bounds(&keys, ":start", ":end",
+                                              chars.len())?;
+
+                    Ok(RLResult::StringRes(RLString::from_chars(
+                        &chars[start..end])))
+                }
+
+                _ => {
+                    let items = sequence_items(&sequence)?;
+
+                    let (start, end) = bounds(&keys, ":start", ":end",
+                                              items.len())?;
+
+                    let items = items[start..end].iter()
+                                                 .cloned()
+                                                 .collect::<LinkedList<SExpr>>();
+
+                    Ok(RLResult::SExprRes(make_list(&items)))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+// The elements are compared with EQL.
+#[allow(non_snake_case)]
+pub fn SEARCH(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    search sequence-1 sequence-2 &key from-end start1 end1 start2 end2
+        => position
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let items1 = sequence_items(&linked_list.pop_front().unwrap())?;
+            let items2 = sequence_items(&linked_list.pop_front().unwrap())?;
+
+            let keys = key_args(linked_list, &[":from-end", ":start1", ":end1",
+                                               ":start2", ":end2"])?;
+
+            let (start1, end1) = bounds(&keys, ":start1", ":end1",
+                                        items1.len())?;
+            let (start2, end2) = bounds(&keys, ":start2", ":end2",
+                                        items2.len())?;
+
+            let pattern = &items1[start1..end1];
+
+            if pattern.len() > end2 - start2 {
+                return Ok(RLResult::NilRes(RLNil::new()))
+            }
+
+            let mut matches = (start2..=end2 - pattern.len()).filter(|idx| {
+                pattern.iter()
+                       .zip(&items2[*idx..])
+                       .all(|(item1, item2)| item1.is_eql(item2))
+            });
+
+            let found = if from_end(&keys) {
+                matches.next_back()
+            } else {
+                matches.next()
+            };
+
+            Ok(index_res(found))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// The elements are compared with EQL.
+#[allow(non_snake_case)]
+pub fn POSITION(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+    position item sequence &key from-end start end => position
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            if linked_list.len() < 2 {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let item = linked_list.pop_front().unwrap();
+
+            let items = sequence_items(&linked_list.pop_front().unwrap())?;
+
+            let keys = key_args(linked_list, &[":from-end", ":start", ":end"])?;
+
+            let (start, end) = bounds(&keys, ":start", ":end", items.len())?;
+
+            let mut matches = (start..end).filter(|idx| {
+                item.is_eql(&items[*idx])
+            });
+
+            let found = if from_end(&keys) {
+                matches.next_back()
+            } else {
+                matches.next()
+            };
+
+            Ok(index_res(found))
+        }
+        _ => unreachable!(),
+    }
+}
+
+// A string, the name of a symbol or a character stands for a string.
+pub fn string_designator(sexpr: &SExpr) -> Result<RLString, RLError> {
+    match sexpr {
+        SExpr::Atom(atom) if atom.is_rlstring_atom() => {
+            Ok(atom.get_atom_rlstring())
+        }
+
+        SExpr::Symb(symb) => {
+            Ok(RLString::new(&symb.get_symbol_name().to_uppercase()))
+        }
+
+        SExpr::Nil(nil) => Ok(RLString::new(&nil.get_nil_name())),
+
+        SExpr::Char(ch) => Ok(RLString::from_chars(&[ch.get()])),
+
+        _ => {
+            let err = SimpleTypeError::new(&sexpr.to_string(),
+                                           "(OR STRING SYMBOL CHARACTER)");
+
+            Err(RLError::SimpleTypeError(err))
+        }
+    }
+}
+
+fn string_arg(sexpr: &SExpr) -> Result<RLString, RLError> {
+    match sexpr {
+        SExpr::Atom(atom) if atom.is_rlstring_atom() => {
+            Ok(atom.get_atom_rlstring())
+        }
+
+        _ => {
+            let err = SimpleTypeError::new(&sexpr.to_string(), "STRING");
+
+            Err(RLError::SimpleTypeError(err))
+        }
+    }
+}
+
+// The elements of a string or a proper list.
+fn sequence_items(sexpr: &SExpr) -> Result<Vec<SExpr>, RLError> {
+    match sexpr {
+        SExpr::Atom(atom) if atom.is_rlstring_atom() => {
+            Ok(atom.get_atom_rlstring()
+                   .chars()
+                   .into_iter()
+                   .map(|ch| SExpr::Char(RLChar::new(ch)))
+                   .collect())
+        }
+
+        SExpr::Nil(_) => Ok(Vec::new()),
+
+        SExpr::SList(slist) => Ok(slist.get_items().into_iter().collect()),
+
+        _ => {
+            let err = SimpleTypeError::new(&sexpr.to_string(), "SEQUENCE");
+
+            Err(RLError::SimpleTypeError(err))
+        }
+    }
+}
+
+// The keyword arguments, by their lower case names. Keywords which
+// are not in allowed are an error.
+fn key_args(mut ll: LinkedList<SExpr>, allowed: &[&str]) ->
+    Result<HashMap<String, SExpr>, RLError> {
+
+    if !ll.len().is_multiple_of(2) {
+        let err = SimpleError::new(&format!(
+            "Odd number of &KEY arguments: {}", make_list(&ll)));
+
+        return Err(RLError::SimpleError(err))
+    }
+
+    let mut keys = HashMap::<String, SExpr>::new();
+
+    while let (Some(key), Some(value)) = (ll.pop_front(), ll.pop_front()) {
+        let name = match key {
+            SExpr::Symb(ref symb) => symb.get_symbol_name(),
+
+            _ => String::new(),
+        };
+
+        if !allowed.contains(&name.as_str()) {
+            let err = SimpleError::new(&format!(
+                "Unknown &KEY argument: {}", key));
+
+            return Err(RLError::SimpleError(err))
+        }
+
+        // the leftmost occurrence of a keyword counts
+        keys.entry(name).or_insert(value);
+    }
+
+    Ok(keys)
+}
+
+// The bounding indices start and end of a sequence of length, by
+// default the whole sequence. An end of NIL is the length.
+fn bounds(keys: &HashMap<String, SExpr>,
+          start_key: &str,
+          end_key: &str,
+          length: usize) -> Result<(usize, usize), RLError> {
+
+    let start = match keys.get(start_key) {
+        Some(start) => index_arg(start)?,
+
+        None => 0,
+    };
+
+    let end = match keys.get(end_key) {
+        Some(end) if !is_nil(end) => index_arg(end)?,
+
+        _ => length,
+    };
+
+    if start > end || end > length {
+        let err = SimpleError::new(&format!(
+            "The bounding indices {} and {} are bad for a sequence of length {}.",
+            start, end, length));
+
+        return Err(RLError::SimpleError(err))
+    }
+
+    Ok((start, end))
+}
+
+fn index_arg(sexpr: &SExpr) -> Result<usize, RLError> {
+    match sexpr {
+        SExpr::Num(RLNum::Fixnum(index)) if *index >= 0 => Ok(*index as usize),
+
+        _ => Err(unsigned_byte_error(sexpr)),
+    }
+}
+
+fn index_error(index: usize, length: usize) -> RLError {
+    let err = SimpleError::new(&format!(
+        "Invalid index {} for a string of length {}.", index, length));
+
+    RLError::SimpleError(err)
+}
+
+fn unsigned_byte_error(sexpr: &SExpr) -> RLError {
+    let err = SimpleTypeError::new(&sexpr.to_string(), "UNSIGNED-BYTE");
+
+    RLError::SimpleTypeError(err)
+}
+
+fn from_end(keys: &HashMap<String, SExpr>) -> bool {
+    keys.get(":from-end").is_some_and(|from_end| !is_nil(from_end))
+}
+
+fn is_nil(sexpr: &SExpr) -> bool {
+    matches!(sexpr, SExpr::Nil(_))
+}
+
+fn index_res(index: Option<usize>) -> RLResult {
+    match index {
+        Some(index) => RLResult::NumRes(RLNum::Fixnum(index as i64)),
+
+        None => RLResult::NilRes(RLNil::new()),
+    }
+}
+
+fn bool_res(value: bool) -> RLResult {
+    if value {
+        RLResult::BoolRes(RLBool::T(RLT::new()))
+    } else {
+        RLResult::BoolRes(RLBool::Nil(RLNil::new()))
+    }
+}
+
+/*
+Strings
+
+System Class STRING
+
+Type BASE-STRING
+
+Type SIMPLE-STRING
+
+Type SIMPLE-BASE-STRING
+
+Function SIMPLE-STRING-P
+
+// Accessor CHAR, SCHAR
+
+// Function STRING
+
+// Function STRING-UPCASE, STRING-DOWNCASE, STRING-CAPITALIZE,
+// NSTRING-UPCASE, NSTRING-DOWNCASE, NSTRING-CAPITALIZE
+// (not the destructive ones)
+
+// Function STRING-TRIM, STRING-LEFT-TRIM, STRING-RIGHT-TRIM
+
+// Function STRING=, STRING/=, STRING<, STRING>, STRING<=, STRING>=,
+// STRING-EQUAL, STRING-NOT-EQUAL, STRING-LESSP, STRING-GREATERP,
+// STRING-NOT-GREATERP, STRING-NOT-LESSP
+
+// Function STRINGP
+
+// Function MAKE-STRING
+
+Sequences, on strings and lists
+
+// Function LENGTH
+
+// Accessor SUBSEQ
+
+// Function SEARCH (without TEST and KEY)
+
+// Function POSITION, POSITION-IF, POSITION-IF-NOT
+// (only POSITION, without TEST and KEY)
+
+Reader
+
+// Function PARSE-INTEGER
+*/
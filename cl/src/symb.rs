@@ -11,7 +11,10 @@ use env::named_lambda::RLNamedLambda;
 use env::result::RLResult;
 use env::symb::RLEnvSymbol;
 
-use err::err::{RLError, UnboundVariableError, UndefinedFuncError};
+use err::err::{RLError,
+               TypeError,
+               UnboundVariableError,
+               UndefinedFuncError};
 
 use expr::atom::RLAtom;
 use expr::sexpr::SExpr;
@@ -68,9 +71,6 @@ impl SymbolFuncs {
 pub fn SYMBOL_FUNCTION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from SYMBOL-FUNCTION");
-    println!("");
-
     match sexpr {
         SExpr::Cons(symb, ll) => {
             let mut linked_list = ll.clone();
@@ -91,9 +91,6 @@ pub fn SYMBOL_FUNCTION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
 
                             symbol_name = symb_name.to_lowercase();
 
-                            println!("Symb - symbol_name: {:?}", symbol_name);
-                            println!("");
-
                         } else if let Some(SExpr::Atom(atom)) = symbol {
 
                             let mut atom_string = atom.get_atom_string();
@@ -105,19 +102,20 @@ pub fn SYMBOL_FUNCTION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
                             } else {
                                 symbol_name = atom_string.to_lowercase();
                             }
+                        } else if let Some(other) = symbol {
+                            return Err(symbol_type_error(&other));
                         }
 
                         if let Some(rl_symbol) =
                             env_ref.get_symbol(&symbol_name) {
 
                             let mut named_lambda = rl_symbol
-                                                       .named_lambda
-                                                       .clone()
+                                                       .get_named_lambda()
                                                        .unwrap();
 
                             named_lambda.package_formatter(false);
 
-                            Ok(RLResult::FuncRes(named_lambda))
+                            Ok(RLResult::FuncRes(Box::new(named_lambda)))
                         } else {
                             let err = UndefinedFuncError::new(&symbol_name);
                             return Err(RLError::UndefinedFuncError(err))
@@ -135,9 +133,6 @@ pub fn SYMBOL_FUNCTION(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
 pub fn SYMBOL_PACKAGE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from SYMBOL-PACKAGE");
-    println!("");
-
     match sexpr {
         SExpr::Cons(symb, ll) => {
             let mut linked_list = ll.clone();
@@ -162,19 +157,18 @@ pub fn SYMBOL_PACKAGE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
                             let atom_string = atom.get_atom_string();
 
                             symbol_name = atom_string;
+                        } else if let Some(other) = symbol {
+                            return Err(symbol_type_error(&other));
                         }
 
                         if let Some(rl_symbol) =
                             env_ref.get_symbol(&symbol_name) {
 
-                            println!("rl_symbol: {:?}", rl_symbol);
-                            println!("");
-
                             if let Some(mut named_lambda) =
                                 rl_symbol.get_named_lambda() {
 
                                 named_lambda.package_formatter(true);
-                                Ok(RLResult::FuncRes(named_lambda))
+                                Ok(RLResult::FuncRes(Box::new(named_lambda)))
                             } else if let Some(mut dyn_var) =
                                 rl_symbol.get_dyn_var() {
 
@@ -201,9 +195,6 @@ pub fn SYMBOL_PACKAGE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
 pub fn SYMBOL_VALUE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     Result<RLResult, RLError> {
 
-    println!("Hello from SYMBOL-VALUE");
-    println!("");
-
     // let cl_pack = &mut env_ref.cl_package;
     // let cl_user_pack = &mut env_ref.cl_user_package;
     // let curr_pack: &mut RLPackage = env_ref.get_mut_current_package();
@@ -221,9 +212,6 @@ pub fn SYMBOL_VALUE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
                             Some(SExpr::Atom(atom)) => {
                                 let atom_string = atom.get_atom_string();
 
-                                println!("atom_string: {:?}", atom_string);
-                                println!("");
-
                                 atom_string.to_uppercase()
                             }
 
@@ -233,11 +221,14 @@ pub fn SYMBOL_VALUE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
                                 symb_name.to_uppercase()
                             }
 
-                            _ => todo!(),
-                        };
+                            Some(SExpr::Nil(nil)) => {
+                                return Ok(RLResult::SExprRes(SExpr::Nil(nil)))
+                            }
+
+                            Some(other) => return Err(symbol_type_error(&other)),
 
-                        println!("symbol_name: {:?}", symbol_name);
-                        println!("");
+                            None => return Err(RLError::SimpleProgramError),
+                        };
 
                         if let Some(symbol) = env_ref.get_symbol(
                             &symbol_name) {
@@ -261,6 +252,12 @@ pub fn SYMBOL_VALUE(sexpr: &SExpr, env_ref: &mut RLEnvironment) ->
     }
 }
 
+fn symbol_type_error(sexpr: &SExpr) -> RLError {
+    let err = TypeError::new(&sexpr.to_string(), "SYMBOL");
+
+    RLError::TypeError(err)
+}
+
 /*
 System Class SYMBOL
 
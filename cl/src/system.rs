@@ -0,0 +1,137 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::collections::hash_map::HashMap;
+use std::fs;
+
+use env::named_lambda::RLNamedLambda;
+use env::result::RLResult;
+use env::symb::RLEnvSymbol;
+
+use err::err::{RLError,
+               SimpleError,
+               TypeError};
+
+use expr::atom::RLAtom;
+use expr::nil::RLNil;
+use expr::sexpr::SExpr;
+
+use hash::hash::RLHash;
+
+pub struct SystemFuncs {
+}
+
+impl SystemFuncs {
+    pub fn new() -> Self {
+        Self { }
+    }
+
+    pub fn init(&mut self,
+                cl_pack_hash: &mut HashMap<String, RLEnvSymbol, RLHash>) {
+
+        // cl_pack_hash
+        cl_pack_hash.insert("load".to_string(),
+            RLEnvSymbol::new_named_lambda("LOAD".to_string(),
+                RLNamedLambda::new_func(
+                    "LOAD".to_string(),
+                    Some(SExpr::Atom(RLAtom::new("Load the file given by FILESPEC into the Lisp environment, returning T on success."))),
+                    "COMMON-LISP".to_string(),
+                    Some(|sexpr| {
+                        Ok::<RLResult, RLError>(
+                            LOAD(&sexpr)?) }),
+                    None)));
+    }
+}
+
+#[allow(non_snake_case)]
+pub fn LOAD(sexpr: &SExpr) -> Result<RLResult, RLError> {
+
+    /*
+    Syntax:
+
+    load filespec &key verbose print if-does-not-exist external-format
+    => generalized-boolean
+
+    The file is read here, its forms are read and evaluated one after
+    another by the evaluator, which gets the file from the LoadRes.
+    */
+
+    match sexpr {
+        SExpr::Cons(_symb, ll) => {
+            let mut linked_list = ll.clone();
+
+            let file_name = match linked_list.pop_front() {
+                Some(SExpr::Atom(atom)) if atom.is_rlstring_atom() => {
+                    let name = atom.get_atom_string();
+
+                    name[1..name.len() - 1].to_string()
+                }
+
+                Some(other) => {
+                    let err = TypeError::new(&other.to_string(),
+                                             "PATHNAME-DESIGNATOR");
+
+                    return Err(RLError::TypeError(err))
+                }
+
+                None => return Err(RLError::SimpleProgramError),
+            };
+
+            if !linked_list.len().is_multiple_of(2) {
+                return Err(RLError::SimpleProgramError)
+            }
+
+            let mut if_does_not_exist = true;
+
+            while let (Some(key), Some(value)) =
+                (linked_list.pop_front(), linked_list.pop_front()) {
+
+                match key {
+                    SExpr::Symb(symb)
+                        if symb.get_symbol_name()
+                               .eq(":if-does-not-exist") => {
+
+                        if_does_not_exist = !matches!(value, SExpr::Nil(_));
+                    }
+
+                    SExpr::Symb(symb)
+                        if matches!(&*symb.get_symbol_name(),
+                                    ":verbose" | ":print" |
+                                    ":external-format") => {}
+
+                    other => {
+                        let err_description = format!(
+                            "Unknown &KEY argument: {}",
+                            other);
+
+                        let err = SimpleError::new(&err_description);
+
+                        return Err(RLError::SimpleError(err))
+                    }
+                }
+            }
+
+            match fs::read_to_string(&file_name) {
+                Ok(source) => Ok(RLResult::LoadRes(file_name, source)),
+
+                Err(_) if !if_does_not_exist => {
+                    Ok(RLResult::NilRes(RLNil::new()))
+                }
+
+                Err(io_err) => {
+                    let err_description = format!(
+                        "Couldn't load \"{}\": {}",
+                        file_name,
+                        io_err);
+
+                    let err = SimpleError::new(&err_description);
+
+                    Err(RLError::SimpleError(err))
+                }
+            }
+        }
+        _ => unreachable!(),
+    }
+}
@@ -5,9 +5,12 @@
 
 use std::fmt;
 use std::collections::{HashMap, LinkedList};
+use std::rc::Rc;
 
 // use crate::env::RLEnvironment;
 // use crate::result::RLResult;
+use crate::lambda::RLLambda;
+use crate::named_lambda::RLNamedLambda;
 use crate::var::{RLVar, make_rlvar_hash_map};
 
 // use err::err::RLError;
@@ -31,7 +34,8 @@ pub struct RLBlock {
 
     name: String,
 
-    progn_ll: LinkedList<SExpr>,
+    // shared by the copies of the block on the block chain
+    progn_ll: Rc<LinkedList<SExpr>>,
 
     return_from: bool,
 
@@ -41,6 +45,34 @@ pub struct RLBlock {
     */
 
     dyn_env_block: HashMap<String, RLVar, RLHash>,
+
+    // (name, init-form) pairs of LET and LET*, they are evaluated and
+    // bound before progn_ll, one after another if sequential_bindings
+    let_bindings: LinkedList<(String, SExpr)>,
+
+    sequential_bindings: bool,
+
+    // tags of a TAGBODY and the index of the statement in progn_ll
+    // which follows them
+    tags: Vec<(String, usize)>,
+
+    // the evaluated tag of a CATCH
+    catch_tag: Option<SExpr>,
+
+    // the local functions of FLET and LABELS, the functions of LABELS
+    // can call each other
+    functions: Vec<(String, RLLambda)>,
+
+    recursive_functions: bool,
+
+    // local macros of MACROLET and the (symbol, expansion) pairs of
+    // SYMBOL-MACROLET
+    macros: Vec<(String, RLNamedLambda)>,
+
+    symbol_macros: Vec<(String, SExpr)>,
+
+    // variables declared special by the binding form
+    specials: Vec<String>,
 }
 
 impl RLBlock {
@@ -61,7 +93,7 @@ impl RLBlock {
 
         let name = name.clone();
 
-        let progn_ll = LinkedList::<SExpr>::new();
+        let progn_ll = Rc::new(LinkedList::<SExpr>::new());
 
         let return_from = false;
 
@@ -75,6 +107,15 @@ impl RLBlock {
                   return_from,
                   // closure_env,
                   dyn_env_block,
+                  let_bindings: LinkedList::new(),
+                  sequential_bindings: false,
+                  tags: Vec::new(),
+                  catch_tag: None,
+                  functions: Vec::new(),
+                  recursive_functions: false,
+                  macros: Vec::new(),
+                  symbol_macros: Vec::new(),
+                  specials: Vec::new(),
         }
     }
 
@@ -89,7 +130,7 @@ impl RLBlock {
 
         let name = id.clone();
 
-        let progn_ll = LinkedList::<SExpr>::new();
+        let progn_ll = Rc::new(LinkedList::<SExpr>::new());
 
         let return_from = false;
 
@@ -100,6 +141,15 @@ impl RLBlock {
                   progn_ll,
                   return_from,
                   dyn_env_block,
+                  let_bindings: LinkedList::new(),
+                  sequential_bindings: false,
+                  tags: Vec::new(),
+                  catch_tag: None,
+                  functions: Vec::new(),
+                  recursive_functions: false,
+                  macros: Vec::new(),
+                  symbol_macros: Vec::new(),
+                  specials: Vec::new(),
         }
     }
 
@@ -116,11 +166,15 @@ impl RLBlock {
     }
 
     pub fn set_progn_ll(&mut self, progn_ll: &LinkedList<SExpr>) {
-        self.progn_ll = progn_ll.clone();
+        self.progn_ll = Rc::new(progn_ll.clone());
     }
 
     pub fn get_progn_ll(& self) -> LinkedList<SExpr> {
-        self.progn_ll.clone()
+        (*self.progn_ll).clone()
+    }
+
+    pub fn get_ref_progn_ll(&self) -> &LinkedList<SExpr> {
+        &self.progn_ll
     }
 
     pub fn set_return_from(&mut self, value: bool) {
@@ -168,8 +222,89 @@ impl RLBlock {
         &self.dyn_env_block
     }
 
+    pub fn set_let_bindings(&mut self,
+                            bindings: &LinkedList<(String, SExpr)>,
+                            sequential: bool) {
+        self.let_bindings = bindings.clone();
+        self.sequential_bindings = sequential;
+    }
+
+    pub fn get_let_bindings(&self) -> LinkedList<(String, SExpr)> {
+        self.let_bindings.clone()
+    }
+
+    pub fn has_sequential_bindings(&self) -> bool {
+        self.sequential_bindings
+    }
+
+    pub fn add_tag(&mut self, tag: &str, position: usize) {
+        self.tags.push((tag.to_string(), position));
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.get_tag_position(tag).is_some()
+    }
+
+    pub fn get_tag_position(&self, tag: &str) -> Option<usize> {
+        self.tags
+            .iter()
+            .find(|(name, _)| name.eq(tag))
+            .map(|(_, position)| *position)
+    }
+
+    pub fn set_catch_tag(&mut self, tag: &SExpr) {
+        self.catch_tag = Some(tag.clone());
+    }
+
+    // the tags are compared as by EQL
+    pub fn is_catch_for(&self, tag: &SExpr) -> bool {
+        self.catch_tag
+            .as_ref()
+            .is_some_and(|catch_tag| catch_tag.is_eql(tag))
+    }
+
+    pub fn add_function(&mut self, name: &str, lambda: RLLambda) {
+        self.functions.push((name.to_string(), lambda));
+    }
+
+    pub fn get_functions(&self) -> Vec<(String, RLLambda)> {
+        self.functions.clone()
+    }
+
+    pub fn set_recursive_functions(&mut self, recursive: bool) {
+        self.recursive_functions = recursive;
+    }
+
+    pub fn has_recursive_functions(&self) -> bool {
+        self.recursive_functions
+    }
+
+    pub fn add_macro(&mut self, name: &str, named_lambda: RLNamedLambda) {
+        self.macros.push((name.to_string(), named_lambda));
+    }
+
+    pub fn get_macros(&self) -> Vec<(String, RLNamedLambda)> {
+        self.macros.clone()
+    }
+
+    pub fn add_symbol_macro(&mut self, name: &str, expansion: SExpr) {
+        self.symbol_macros.push((name.to_string(), expansion));
+    }
+
+    pub fn get_symbol_macros(&self) -> Vec<(String, SExpr)> {
+        self.symbol_macros.clone()
+    }
+
+    pub fn set_specials(&mut self, specials: &[String]) {
+        self.specials = specials.to_vec();
+    }
+
+    pub fn get_specials(&self) -> Vec<String> {
+        self.specials.clone()
+    }
+
     pub fn contains_function(&self) -> bool {
-        let mut progn_ll = self.progn_ll.clone();
+        let mut progn_ll = self.get_progn_ll();
 
         if let Some(SExpr::SForm(mut vec_token)) = progn_ll.pop_front() {
 
@@ -233,6 +368,16 @@ impl Clone for RLBlock {
             */
 
             dyn_env_block: clone_hash_map(&self.dyn_env_block),
+
+            let_bindings: self.let_bindings.clone(),
+            sequential_bindings: self.sequential_bindings,
+            tags: self.tags.clone(),
+            catch_tag: self.catch_tag.clone(),
+            functions: self.functions.clone(),
+            recursive_functions: self.recursive_functions,
+            macros: self.macros.clone(),
+            symbol_macros: self.symbol_macros.clone(),
+            specials: self.specials.clone(),
         }
     }
 }
@@ -247,6 +392,15 @@ impl fmt::Display for RLBlock {
                 return_from: _return_from,
                 // closure_env: _closure_env,
                 dyn_env_block: _,
+                let_bindings: _,
+                sequential_bindings: _,
+                tags: _,
+                catch_tag: _,
+                functions: _,
+                recursive_functions: _,
+                macros: _,
+                symbol_macros: _,
+                specials: _,
             } => {
                 write!(f, "{:?}", progn_ll)
                 // write!(f, "{}", sexpr)
@@ -0,0 +1,146 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::fmt;
+use std::collections::HashMap;
+
+use expr::nil::RLNil;
+use expr::sexpr::SExpr;
+
+use hash::hash::RLHash;
+
+// A slot of a condition type, from the slot specifiers of
+// DEFINE-CONDITION
+#[derive(Debug, Clone)]
+pub struct RLSlot {
+    // uppercase
+    pub name: String,
+
+    // the keyword, e.g. ":DATUM", uppercase
+    pub initarg: Option<String>,
+
+    pub initform: Option<SExpr>,
+
+    // functions which read the slot, uppercase
+    pub readers: Vec<String>,
+}
+
+impl RLSlot {
+    // a slot initialized by the keyword of its name, like the ones of
+    // the standard condition types
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_uppercase(),
+            initarg: Some(format!(":{}", name.to_uppercase())),
+            initform: None,
+            readers: Vec::new(),
+        }
+    }
+}
+
+// A condition type, the standard ones and those of DEFINE-CONDITION
+#[derive(Debug, Clone)]
+pub struct RLConditionType {
+    name: String,
+
+    // direct supertypes, uppercase
+    parents: Vec<String>,
+
+    // the direct slots, the slots of the supertypes are inherited
+    slots: Vec<RLSlot>,
+
+    // the string of the :report option
+    report: Option<String>,
+}
+
+impl RLConditionType {
+    pub fn new(name: &str,
+               parents: Vec<String>,
+               slots: Vec<RLSlot>,
+               report: Option<String>) -> Self {
+
+        Self {
+            name: name.to_uppercase(),
+            parents: parents.iter().map(|p| p.to_uppercase()).collect(),
+            slots,
+            report,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_parents(&self) -> Vec<String> {
+        self.parents.clone()
+    }
+
+    pub fn get_slots(&self) -> Vec<RLSlot> {
+        self.slots.clone()
+    }
+
+    pub fn get_report(&self) -> Option<String> {
+        self.report.clone()
+    }
+}
+
+impl fmt::Display for RLConditionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#<CONDITION-CLASS {}>", self.name)
+    }
+}
+
+// The standard condition types of CLHS chapter 9 which rlisp signals
+pub fn make_condition_type_hash_map() ->
+    HashMap<String, RLConditionType, RLHash> {
+
+    let mut map = HashMap::with_hasher(RLHash { });
+
+    let types: [(&str, &[&str], &[&str]); 19] = [
+        ("condition", &[], &[]),
+        ("serious-condition", &["condition"], &[]),
+        ("error", &["serious-condition"], &[]),
+        ("warning", &["condition"], &[]),
+        ("style-warning", &["warning"], &[]),
+        ("simple-condition", &["condition"],
+            &["format-control", "format-arguments"]),
+        ("simple-error", &["simple-condition", "error"], &[]),
+        ("simple-warning", &["simple-condition", "warning"], &[]),
+        ("type-error", &["error"], &["datum", "expected-type"]),
+        ("simple-type-error", &["simple-condition", "type-error"], &[]),
+        ("program-error", &["error"], &[]),
+        ("control-error", &["error"], &[]),
+        ("cell-error", &["error"], &["name"]),
+        ("unbound-variable", &["cell-error"], &[]),
+        ("undefined-function", &["cell-error"], &[]),
+        ("arithmetic-error", &["error"], &["operation", "operands"]),
+        ("division-by-zero", &["arithmetic-error"], &[]),
+        ("floating-point-overflow", &["arithmetic-error"], &[]),
+        ("parse-error", &["error"], &[]),
+    ];
+
+    for (name, parents, slots) in types {
+        let mut slots = slots.iter()
+                             .map(|slot| RLSlot::new(slot))
+                             .collect::<Vec<RLSlot>>();
+
+        // (error "text") has no format arguments
+        for slot in slots.iter_mut() {
+            if slot.name.eq("FORMAT-ARGUMENTS") {
+                slot.initform = Some(SExpr::Nil(RLNil::new()));
+            }
+        }
+
+        let condition_type = RLConditionType::new(
+            name,
+            parents.iter().map(|parent| parent.to_string()).collect(),
+            slots,
+            None);
+
+        map.insert(name.to_uppercase(), condition_type);
+    }
+
+    map
+}
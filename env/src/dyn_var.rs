@@ -23,6 +23,10 @@ pub struct RLDynVar {
     package: String,
     package_formatter: bool,
 
+    // declared by DEFVAR or DEFPARAMETER, bindings of the variable
+    // are dynamic
+    special: bool,
+
     value: Option<RLVar>,
 }
 
@@ -44,6 +48,7 @@ impl RLDynVar {
                docstring,
                package,
                package_formatter,
+               special: false,
                value,
         }
     }
@@ -60,6 +65,19 @@ impl RLDynVar {
         self.value = Some(val);
     }
 
+    // None makes the variable unbound
+    pub fn restore_var(&mut self, val: Option<RLVar>) {
+        self.value = val;
+    }
+
+    pub fn is_special(&self) -> bool {
+        self.special
+    }
+
+    pub fn set_special(&mut self, special: bool) {
+        self.special = special;
+    }
+
     pub fn get_doc_string(&self) -> Result<RLResult, RLError> {
         if let Some(string) = &self.docstring {
             Ok(RLResult::StringRes(string.clone()))
@@ -82,34 +100,11 @@ impl RLDynVar {
     }
 
     pub fn var_to_sexpr(&mut self) -> SExpr {
-        let result: SExpr;
-
-        match &self.value {
-            Some(RLVar::SAtomVar(SExpr::Atom(atom))) => {
-                result = SExpr::Atom(atom.clone());
-            }
-
-            /*
-            Some(RLVar::SAtomVar(SExpr::Func(atom))) => {
-                result = SExpr::Func(atom.clone());
-            }
-            */
-
-            Some(RLVar::SAtomVar(SExpr::Lambda(atom))) => {
-                result = SExpr::Lambda(atom.clone());
-            }
-
-            Some(RLVar::NilVar(nil)) => {
-                result = SExpr::Nil(nil.clone());
-            }
-
-            Some(RLVar::SListVar(SExpr::SList(slist))) => {
-                result = SExpr::SList(slist.clone());
-            }
+        match &mut self.value {
+            Some(var) => var.var_to_sexpr(),
 
-            _ => todo!(),
+            None => SExpr::Nil(RLNil::new()),
         }
-        result
     }
 }
 
@@ -126,6 +121,7 @@ impl Clone for RLDynVar {
             },
             package: self.package.clone(),
             package_formatter: self.package_formatter.clone(),
+            special: self.special,
             value: match &self.value {
                 Some(x) => Some(x.clone()),
                 None => None,
@@ -141,6 +137,7 @@ impl fmt::Display for RLDynVar {
                 docstring: _doc,
                 package: pack,
                 package_formatter: pack_form,
+                special: _,
                 value: val
             } => {
                 if *pack_form {
@@ -9,8 +9,10 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 
 use crate::block::RLBlock;
+use crate::condition::{RLConditionType, RLSlot, make_condition_type_hash_map};
 use crate::dyn_var::RLDynVar;
 use crate::env_trait::{EnvRef, EnvTrait};
+use crate::named_lambda::RLNamedLambda;
 use crate::pack::{RLPackage, make_rlpackage_hash_map};
 use crate::symb::RLEnvSymbol;
 use crate::result::RLResult;
@@ -26,6 +28,9 @@ use expr::t::RLT;
 
 use hash::hash::{RLHash, clone_hash_map};
 
+use trace::rltrace;
+use trace::trace::{TraceCategory, TraceLevel};
+
 /*
 use rand::Rng;
 
@@ -53,6 +58,9 @@ pub struct RLEnvironment {
     curr_eval_dyn_env: Option<HashMap<String, RLVar, RLHash>>,
 
     dyn_env_stack: Vec<HashMap<String, RLVar, RLHash>>,
+
+    // the standard condition types and those of DEFINE-CONDITION
+    condition_types: HashMap<String, RLConditionType, RLHash>,
 }
 
 impl RLEnvironment {
@@ -74,6 +82,8 @@ impl RLEnvironment {
 
         let dyn_env_stack = Vec::new();
 
+        let condition_types = make_condition_type_hash_map();
+
         Rc::new(RefCell::new(Self {
             packages: packages,
 
@@ -88,6 +98,8 @@ impl RLEnvironment {
             curr_eval_dyn_env: curr_eval_dyn_env,
 
             dyn_env_stack: dyn_env_stack,
+
+            condition_types: condition_types,
         }))
     }
 
@@ -98,6 +110,9 @@ impl RLEnvironment {
 
         let mut keyword_package = RLPackage::new("KEYWORD");
 
+        // extensions of rlisp, e.g. rlisp:set-trace
+        let rlisp_package = RLPackage::new("RLISP");
+
         keyword_package.add_symbol(
             ":compile-toplevel".to_string(),
             RLEnvSymbol::new_var(":compile-toplevel".to_string(),
@@ -128,6 +143,8 @@ impl RLEnvironment {
 
         self.add_package(RLString::new("KEYWORD"), keyword_package);
 
+        self.add_package(RLString::new("RLISP"), rlisp_package);
+
         self.curr_eval_dyn_env = Some(clone_hash_map(&self.toplevel_dyn_env));
         self.dyn_env_stack.push(clone_hash_map(&self.toplevel_dyn_env));
     }
@@ -142,12 +159,11 @@ impl EnvTrait for RLEnvironment {
         self.init();
     }
 
-    fn hello(&self) {
-       println!("Hello, hello from env");
-       println!("");
-    }
-
     fn is_keyword(&self, mut keyword: String) -> bool {
+        if keyword.is_empty() {
+            return false
+        }
+
         let rest = keyword.split_off(1);
 
         let mut chars = rest.chars();
@@ -206,6 +222,10 @@ impl EnvTrait for RLEnvironment {
         self.get_package(&RLString::new("KEYWORD")).unwrap()
     }
 
+    fn get_mut_rlisp_package(&mut self) -> &mut RLPackage {
+        self.get_package(&RLString::new("RLISP")).unwrap()
+    }
+
     fn get_mut_current_package(&mut self) -> &mut RLPackage {
         self.get_package(&self.get_name_current_package()).unwrap()
     }
@@ -348,23 +368,18 @@ impl EnvTrait for RLEnvironment {
     }
     */
 
-    fn run_closure_rl_symbol(&mut self,
-                             sexpr: SExpr,
-                             rl_symbol: &mut RLEnvSymbol) ->
+    fn run_closure_named_lambda(&mut self,
+                                sexpr: SExpr,
+                                named_lambda: &RLNamedLambda) ->
         Result<RLResult, RLError> {
 
-        rl_symbol.run_closure(sexpr, self)
+        named_lambda.run_closure(sexpr, self)
     }
 
     fn symbol_in_current_package(&self, key: &String) -> bool {
         let name_curr_pack = self.get_name_current_package();
 
-        println!("name_curr_pack: {:?}", name_curr_pack);
-        println!("");
-
         let curr_pack = self.get_ref_package(&name_curr_pack).expect("REASON");
-        println!("curr_pack: {:?}", curr_pack.get_name());
-        println!("");
 
 
         curr_pack.check_for_symbol(key)
@@ -382,7 +397,14 @@ impl EnvTrait for RLEnvironment {
 
     #[allow(unused_assignments)]
     fn get_symbol(&mut self, key: &String) -> Option<&mut RLEnvSymbol> {
-        println!("begin get_symbol...");
+        rltrace!(TraceCategory::Env, TraceLevel::Debug,
+                 "get_symbol {}", key);
+
+        // package:name and package::name
+        if let Some((pack_name, name)) = split_qualified_name(key) {
+            return self.get_package(&RLString::new(&pack_name))?
+                       .get_symbol(&name)
+        }
 
         let mut pack: Option<&mut RLPackage> = None;
 
@@ -448,9 +470,6 @@ impl EnvTrait for RLEnvironment {
 
         id.push_str(lambda_id.as_str());
 
-        println!("ID: {:?}", id);
-        println!("");
-
         id
     }
 
@@ -504,6 +523,12 @@ impl EnvTrait for RLEnvironment {
         }
     }
 
+    fn remove_curr_eval_dyn_env_var(&mut self, name: &str) {
+        if let Some(ref mut map) = self.curr_eval_dyn_env {
+            map.remove(&name.to_uppercase());
+        }
+    }
+
     fn replace_curr_eval_dyn_env(&mut self,
                                  map: &HashMap<String, RLVar, RLHash>) {
 
@@ -533,6 +558,118 @@ impl EnvTrait for RLEnvironment {
         }
     }
 
+    // special variables are bound in the value of their symbol
+    fn is_special_var(&mut self, name: &str) -> bool {
+        self.get_symbol(&name.to_uppercase())
+            .and_then(|symbol| symbol.dyn_var.as_ref())
+            .is_some_and(|dyn_var| dyn_var.is_special())
+    }
+
+    // Gives the variable the value var, a variable which is only
+    // declared special locally is created in the current package.
+    // The previous value is returned, None if it was unbound.
+    fn bind_special_var(&mut self, name: &str, var: RLVar) -> Option<RLVar> {
+        let var_name = name.to_uppercase();
+
+        if let Some(symbol) = self.get_symbol(&var_name) {
+            if let Some(dyn_var) = symbol.dyn_var.as_mut() {
+                let old_var = dyn_var.get_var();
+
+                dyn_var.set_var(var);
+
+                return old_var
+            }
+
+            let pack_name = symbol.get_pack_name();
+
+            symbol.set_dyn_var(RLDynVar::new(None, pack_name, var));
+
+            return None
+        }
+
+        let curr_pack = self.get_mut_current_package();
+
+        let dyn_var = RLDynVar::new(None, curr_pack.get_name(), var);
+
+        curr_pack.add_symbol(var_name.clone(),
+                             RLEnvSymbol::new_var(var_name, dyn_var));
+
+        None
+    }
+
+    fn restore_special_var(&mut self, name: &str, var: Option<RLVar>) {
+        if let Some(dyn_var) = self.get_symbol(&name.to_uppercase())
+                                   .and_then(|symbol| symbol.dyn_var.as_mut()) {
+            dyn_var.restore_var(var);
+        }
+    }
+
+    // The variables of the current environment for a closure. They
+    // become cells, so assignments by the closure and by the frames
+    // which bound them are seen by both.
+    fn capture_curr_eval_dyn_env(&mut self) ->
+        HashMap<String, RLVar, RLHash> {
+
+        let mut captured = make_rlvar_hash_map();
+
+        if let Some(ref mut map) = self.curr_eval_dyn_env {
+            for (name, var) in map.iter_mut() {
+                *var = var.clone().into_cell();
+
+                captured.insert(name.clone(), var.clone());
+            }
+        }
+
+        captured
+    }
+
+    fn add_condition_type(&mut self, condition_type: RLConditionType) {
+        self.condition_types.insert(condition_type.get_name(), condition_type);
+    }
+
+    fn get_condition_type(&self, name: &str) -> Option<RLConditionType> {
+        self.condition_types.get(&name.to_uppercase()).cloned()
+    }
+
+    // The slots of the type name and of its supertypes, a slot of a
+    // subtype hides the one of the same name of a supertype.
+    fn get_condition_slots(&self, name: &str) -> Vec<RLSlot> {
+        let mut slots = Vec::<RLSlot>::new();
+
+        if let Some(condition_type) = self.condition_types.get(&name.to_uppercase()) {
+            slots.extend(condition_type.get_slots());
+
+            for parent in condition_type.get_parents() {
+                for slot in self.get_condition_slots(&parent) {
+                    if !slots.iter().any(|known| known.name.eq(&slot.name)) {
+                        slots.push(slot);
+                    }
+                }
+            }
+        }
+
+        slots
+    }
+
+    // true if name is super_type or one of its subtypes
+    fn condition_subtypep(&self, name: &str, super_type: &str) -> bool {
+        let name = name.to_uppercase();
+
+        if name == super_type.to_uppercase() {
+            return true
+        }
+
+        match self.condition_types.get(&name) {
+            Some(condition_type) =>
+                condition_type.get_parents()
+                              .iter()
+                              .any(|parent| self.condition_subtypep(parent,
+                                                                    super_type)),
+
+            None => false,
+        }
+    }
+
     fn is_toplevel(&self) -> bool {
         if self.dyn_env_stack.len() == 1 {
             return true;
@@ -568,6 +705,7 @@ impl Clone for RLEnvironment {
             toplevel_dyn_env: clone_hash_map(&self.toplevel_dyn_env),
             curr_eval_dyn_env: option_curr_eval_dyn_env,
             dyn_env_stack: self.dyn_env_stack.clone(),
+            condition_types: self.condition_types.clone(),
         }
     }
 }
@@ -590,6 +728,8 @@ impl fmt::Display for RLEnvironment {
                 curr_eval_dyn_env: _curr_eval_dyn_env,
 
                 dyn_env_stack: _dyn_env_stack,
+
+                condition_types: _condition_types,
             } => {
                 if *pack_form {
                     write!(f, "#<PACKAGE {}>", curr_pack)
@@ -600,3 +740,20 @@ impl fmt::Display for RLEnvironment {
         }
     }
 }
+
+// "cl:car" => ("COMMON-LISP", "car"), None for unqualified names
+// and keywords
+fn split_qualified_name(key: &str) -> Option<(String, String)> {
+    let pos = key.find(':').filter(|pos| *pos > 0)?;
+
+    let pack_name = key[..pos].to_uppercase();
+    let name = key[pos..].trim_start_matches(':').to_string();
+
+    let pack_name = match &*pack_name {
+        "CL"      => "COMMON-LISP".to_string(),
+        "CL-USER" => "COMMON-LISP-USER".to_string(),
+        _         => pack_name,
+    };
+
+    Some((pack_name, name))
+}
@@ -8,7 +8,8 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::block::RLBlock;
-// use crate::named_lambda::RLNamedLambda;
+use crate::condition::{RLConditionType, RLSlot};
+use crate::named_lambda::RLNamedLambda;
 use crate::pack::RLPackage;
 use crate::result::RLResult;
 use crate::symb::RLEnvSymbol;
@@ -26,8 +27,6 @@ pub type EnvRef = Rc<RefCell<dyn EnvTrait>>;
 pub trait EnvTrait {
     fn init(&mut self);
 
-    fn hello (&self);
-
     fn is_keyword(&self, keyword: String) -> bool;
 
     fn package_formatter(&mut self, setter: bool);
@@ -44,6 +43,8 @@ pub trait EnvTrait {
 
     fn get_mut_keyword_package(&mut self) -> &mut RLPackage;
 
+    fn get_mut_rlisp_package(&mut self) -> &mut RLPackage;
+
     fn get_mut_current_package(&mut self) -> &mut RLPackage;
 
     fn delete_package(&mut self, id: RLString);
@@ -103,9 +104,9 @@ pub trait EnvTrait {
         Result<RLResult, RLError>;
     */
 
-    fn run_closure_rl_symbol(&mut self,
-                             sexpr: SExpr,
-                             rl_symbol: &mut RLEnvSymbol) ->
+    fn run_closure_named_lambda(&mut self,
+                                sexpr: SExpr,
+                                named_lambda: &RLNamedLambda) ->
         Result<RLResult, RLError>;
 
     fn symbol_in_current_package(&self, key: &String) -> bool;
@@ -145,11 +146,36 @@ pub trait EnvTrait {
 
     fn add_curr_eval_dyn_env_var(&mut self, name: String, var: RLVar);
 
+    fn remove_curr_eval_dyn_env_var(&mut self, name: &str);
+
     fn replace_curr_eval_dyn_env(&mut self,
                                  map: &HashMap<String, RLVar, RLHash>);
 
     fn release_curr_eval_dyn_env(&mut self);
 
+    ///////////////////////////////////////////////////////////////
+
+    // special variables
+    fn is_special_var(&mut self, name: &str) -> bool;
+
+    fn bind_special_var(&mut self, name: &str, var: RLVar) -> Option<RLVar>;
+
+    fn restore_special_var(&mut self, name: &str, var: Option<RLVar>);
+
+    fn capture_curr_eval_dyn_env(&mut self) ->
+        HashMap<String, RLVar, RLHash>;
+
+    ///////////////////////////////////////////////////////////////
+
+    // condition types
+    fn add_condition_type(&mut self, condition_type: RLConditionType);
+
+    fn get_condition_type(&self, name: &str) -> Option<RLConditionType>;
+
+    fn get_condition_slots(&self, name: &str) -> Vec<RLSlot>;
+
+    fn condition_subtypep(&self, name: &str, super_type: &str) -> bool;
+
     fn is_toplevel(&self) -> bool;
 
     fn get_ref_curr_eval_dyn_env(&mut self) ->
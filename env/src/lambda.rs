@@ -38,6 +38,9 @@ pub struct RLLambda {
     body: SExpr,
 
     dyn_env_lambda: HashMap<String, RLVar, RLHash>,
+
+    // parameters declared special in the body
+    specials: Vec<String>,
 }
 
 impl RLLambda {
@@ -52,9 +55,6 @@ impl RLLambda {
                 CHARSET[idx] as char
             }).collect();
 
-        println!("ID: {:?}", id);
-        println!("");
-
         let named_lambda_name = option_name;
 
         let parameters = RLOrdinaryLambdaList::new();
@@ -72,6 +72,7 @@ impl RLLambda {
             docstring,
             body,
             dyn_env_lambda,
+            specials: Vec::new(),
         }
     }
 
@@ -117,23 +118,18 @@ impl RLLambda {
         self.parameters.clone()
     }
 
-    pub fn get_cleaned_req_params(&self) -> LinkedList<SExpr> {
-        println!("in get_cleaned_req_params()");
-        println!("");
+    pub fn get_ref_parameters(&self) -> &RLOrdinaryLambdaList {
+        &self.parameters
+    }
 
+    pub fn get_cleaned_req_params(&self) -> LinkedList<SExpr> {
         let mut result_ll = LinkedList::<SExpr>::new();
 
-        println!("self.parameters: {:?}", self.parameters);
-        println!("");
-
         let rl_params = self.parameters.get_required_params();
 
         if let SExpr::SList(slist) = rl_params {
             let mut params_ll = slist.get_linked_list();
 
-            println!("params_ll: {:?}", params_ll);
-            println!("");
-
             for _n in 0..params_ll.len() {
                 let sexpr = params_ll.pop_front().unwrap();
 
@@ -142,9 +138,6 @@ impl RLLambda {
                         let atom_string = atom.get_atom_string();
 
                         for item in self.dyn_env_lambda.keys() {
-                            println!("item: {:?}", item);
-                            println!("");
-
                             let string = item.clone().to_lowercase();
 
                             if atom_string.eq(&string) {
@@ -177,17 +170,27 @@ impl RLLambda {
         self.dyn_env_lambda.insert(name.to_uppercase(), var);
     }
 
-    pub fn get_ref_dyn_env_lambda(&mut self) ->
+    pub fn set_specials(&mut self, specials: &[String]) {
+        self.specials = specials.to_vec();
+    }
+
+    pub fn get_specials(&self) -> Vec<String> {
+        self.specials.clone()
+    }
+
+    // the variables captured where the lambda was created
+    pub fn set_dyn_env_lambda(&mut self,
+                              map: HashMap<String, RLVar, RLHash>) {
+        self.dyn_env_lambda = map;
+    }
+
+    pub fn get_ref_dyn_env_lambda(&self) ->
         &HashMap<String, RLVar, RLHash> {
 
         &self.dyn_env_lambda
     }
 }
 
-pub fn make_rllambda_hash_map() -> HashMap<String, RLLambda, RLHash> {
-    HashMap::with_hasher(RLHash { })
-}
-
 impl Clone for RLLambda {
     fn clone(&self) -> Self {
         Self {
@@ -205,6 +208,8 @@ impl Clone for RLLambda {
             body: self.body.clone(),
 
             dyn_env_lambda: clone_hash_map(&self.dyn_env_lambda),
+
+            specials: self.specials.clone(),
         }
     }
 }
@@ -224,11 +229,9 @@ impl fmt::Display for RLLambda {
                 body: _b,
 
                 dyn_env_lambda: _dyn_env,
-            } => {
 
-                println!("params.get_required_params(): {:?}",
-                    params.get_required_params());
-                println!("");
+                specials: _,
+            } => {
 
                 if let Some(name) = &nl_name {
                     write!(f, "#<FUNCTION (LAMBDA {} :in {})  {{{}}}>",
@@ -4,6 +4,7 @@
 // file that was distributed with this source code.
 
 pub mod block;
+pub mod condition;
 pub mod dyn_var;
 pub mod env;
 pub mod env_trait;
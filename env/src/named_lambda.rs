@@ -199,7 +199,7 @@ impl RLNamedLambda {
     }
     */
 
-    pub fn run_closure(&mut self,
+    pub fn run_closure(&self,
                        sexpr: SExpr,
                        env_ref: &mut RLEnvironment) ->
         Result<RLResult, RLError> {
@@ -209,14 +209,14 @@ impl RLNamedLambda {
 
         if let Some(_closure) = self.closure {
             result = match self.closure {
-                Some(ref mut f)  => f(sexpr),
-                               _ => panic!("Closure not found."),
+                Some(f)  => f(sexpr),
+                       _ => panic!("Closure not found."),
             };
 
         } else if let Some(_closure) = self.closure_env {
             result = match self.closure_env {
-                Some(ref mut f)  => f(sexpr, env_ref),
-                               _ => panic!("Closure_env not found."),
+                Some(f)  => f(sexpr, env_ref),
+                       _ => panic!("Closure_env not found."),
             };
         }
 
@@ -230,6 +230,10 @@ impl RLNamedLambda {
         self.parameters.clone()
     }
 
+    pub fn get_ref_parameters(&self) -> &RLOrdinaryLambdaList {
+        &self.parameters
+    }
+
     pub fn set_parameters(&mut self, lambda_list: RLOrdinaryLambdaList) {
         self.parameters = lambda_list;
     }
@@ -323,8 +327,13 @@ impl RLNamedLambda {
         }
     }
 
+    // macros take a destructuring lambda list
     pub fn init_parameters(&mut self, sexpr: SExpr) -> Result<(), RLError> {
-        self.parameters.parse_parameters(sexpr)
+        if self.is_macro {
+            self.parameters.parse_destructuring_parameters(sexpr)
+        } else {
+            self.parameters.parse_parameters(sexpr)
+        }
     }
 
     pub fn get_ref_dyn_env(&mut self) ->
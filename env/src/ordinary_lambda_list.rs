@@ -4,37 +4,69 @@
 // file that was distributed with this source code.
 
 use std::fmt;
-use std::ops::Not;
+use std::collections::LinkedList;
 
 use expr::list::RLList;
+use expr::nil::RLNil;
 use expr::sexpr::SExpr;
-use expr::symb::RLSymbol;
 
-use err::err::RLError;
+use err::err::{ProgramError, RLError, TypeError};
+
+// A parameter of a lambda list. The init form of &optional, &key and
+// &aux parameters is NIL if there is none.
+#[derive(Debug, Clone)]
+pub struct RLParam {
+    // upper case, empty for a pattern
+    pub name: String,
+
+    // nested lambda list of a destructuring lambda list, like (b c)
+    // of (a (b c))
+    pub pattern: Option<Box<RLOrdinaryLambdaList>>,
+
+    // keyword of a &key parameter, :name unless given as ((keyword name))
+    pub keyword: String,
+
+    pub init_form: SExpr,
+
+    // name of the supplied-p variable, upper case
+    pub supplied_p: Option<String>,
+}
 
 #[derive(Debug, Clone)]
 pub struct RLOrdinaryLambdaList {
+    // the lambda list as written, for error messages
+    pub lambda_list: SExpr,
+
+    // macro lambda lists and the ones of DESTRUCTURING-BIND accept
+    // patterns, &whole, &environment and a dotted tail
+    pub destructuring: bool,
+
+    pub whole: Option<String>,
+
+    // there are no environment objects, the variable is bound to NIL
+    pub environment: Option<String>,
+
     // pub required_params: RLList<SExpr>,
     pub required_params: SExpr,
 
-    // possible lambda-list keywords
-    /*
-    &allow-other-keys
-    &key
-    &rest
-    &aux
-    &optional
-    */
+    pub required: Vec<RLParam>,
 
-    /*
-    a_o_k_params: RLList,
-    key_params: RLList,
-    aux_params: RLList,
-    optional_params: RLList,
-    */
+    pub optional_params: Vec<RLParam>,
 
     // pub rest_params: RLList<SExpr>,
     pub rest_params: SExpr,
+
+    pub rest: Option<RLParam>,
+
+    pub key_params: Vec<RLParam>,
+
+    // &key was given, keyword arguments are checked even without
+    // key parameters
+    pub has_key: bool,
+
+    pub allow_other_keys: bool,
+
+    pub aux_params: Vec<RLParam>,
 }
 
 impl RLOrdinaryLambdaList {
@@ -46,8 +78,19 @@ impl RLOrdinaryLambdaList {
         let rest_params = SExpr::SList(RLList::<SExpr>::new());
 
         Self {
+            lambda_list: SExpr::Nil(RLNil::new()),
+            destructuring: false,
+            whole: None,
+            environment: None,
             required_params,
+            required: Vec::new(),
+            optional_params: Vec::new(),
             rest_params,
+            rest: None,
+            key_params: Vec::new(),
+            has_key: false,
+            allow_other_keys: false,
+            aux_params: Vec::new(),
         }
     }
 
@@ -109,135 +152,399 @@ impl RLOrdinaryLambdaList {
     }
     */
 
+    // required-var* [&optional {var | (var [init-form [supplied-p]])}*]
+    // [&rest var] [&key {var | ({var | (keyword var)} [init-form
+    // [supplied-p]])}* [&allow-other-keys]] [&aux {var | (var
+    // [init-form])}*]
     pub fn parse_parameters(&mut self, sexpr: SExpr) -> Result<(), RLError> {
-        let mut required_params = RLList::<SExpr>::new();
+        self.lambda_list = sexpr.clone();
 
-        let mut required_params_ll = required_params.get_linked_list();
+        let mut required_params_ll = LinkedList::<SExpr>::new();
 
-        required_params_ll.pop_front();
-        required_params_ll.pop_back();
+        let mut rest_params_ll = LinkedList::<SExpr>::new();
 
-        let mut rest_params = RLList::<SExpr>::new();
+        let mut section = Section::Required;
 
-        let mut rest_params_ll = rest_params.get_linked_list();
+        let (list, tail) = self.lambda_list_items(sexpr)?;
 
-        // let mut work_list: &mut RLList<SExpr> = &mut required_params;
+        // &whole and &environment are followed by their variable
+        let mut pending_keyword: Option<String> = None;
 
-        let mut work_list = &mut required_params_ll;
+        for (idx, item) in list.into_iter().enumerate() {
+            if let Some(keyword) = pending_keyword.take() {
+                let name = variable_name(&item)?;
 
-        println!("work_list: {:?}", work_list);
-        println!("");
+                if keyword.eq("&whole") {
+                    self.whole = Some(name);
+                } else {
+                    self.environment = Some(name);
+                }
 
-        println!("parameters_sexpr: {:?}", sexpr);
-        println!("");
+                continue;
+            }
 
-        // parameters: SList([Atom("x"), Symb(Symbol { name: "&rest" }), Atom("y")])
+            if let SExpr::Symb(ref symb) = item {
+                let symb_name = symb.get_symbol_name();
 
-        match sexpr {
-            SExpr::SList(slist) => {
-                let mut list = slist.get_linked_list();
+                if self.destructuring &&
+                   ((symb_name.eq("&whole") && idx == 0) ||
+                    (symb_name.eq("&environment") &&
+                     self.environment.is_none())) {
+                    pending_keyword = Some(symb_name);
+
+                    continue;
+                }
+
+                if symb_name.starts_with('&') {
+                    section = next_section(section, &symb_name)?;
+
+                    match section {
+                        Section::Key => self.has_key = true,
+
+                        Section::AllowOtherKeys => self.allow_other_keys = true,
+
+                        _ => {}
+                    }
+
+                    continue;
+                }
+            }
+
+            match section {
+                Section::Required => {
+                    let param = match item {
+                        SExpr::Symb(_) | SExpr::Atom(_) => RLParam {
+                            name: param_name(&item),
+                            pattern: None,
+                            keyword: String::new(),
+                            init_form: SExpr::Nil(RLNil::new()),
+                            supplied_p: None,
+                        },
+
+                        SExpr::SList(_) | SExpr::SCons(_) | SExpr::Nil(_)
+                            if self.destructuring => {
+                            self.pattern_or_name(&item)?
+                        }
 
-                if list.is_empty() {
-                    self.set_required_params_with_rllist(
-                        required_params);
+                        other => {
+                            let err = TypeError::new(&other.to_string(),
+                                                     "SYMBOL");
 
-                    println!("required_params: {:?}", self.required_params);
-                    println!("");
+                            return Err(RLError::TypeError(err))
+                        }
+                    };
+
+                    self.required.push(param);
 
-                    return Ok(())
+                    required_params_ll.push_back(item);
                 }
 
-                while list.is_empty().not() {
-                    let item = list.pop_front();
+                Section::Optional => {
+                    let param = self.parse_param(&item, false)?;
 
-                    match item {
-                        Some(SExpr::Symb(symb)) => {
+                    self.optional_params.push(param);
+                }
 
-                            let symb_name = symb.get_symbol_name();
+                Section::Rest if rest_params_ll.is_empty() => {
+                    self.rest = Some(self.pattern_or_name(&item)?);
 
-                            match &*symb_name {
-                                /*
-                                "&allow_other_keys" => {
-                                }
+                    rest_params_ll.push_back(item);
+                }
 
-                                "&key" => {
-                                }
-                                */
+                Section::Key => {
+                    let param = self.parse_param(&item, true)?;
 
-                                "&rest" => {
-                                   work_list.push_front(
-                                       SExpr::Symb(
-                                           RLSymbol::new_with_str("(")));
+                    self.key_params.push(param);
+                }
 
-                                    work_list.push_back(
-                                        SExpr::Symb(
-                                            RLSymbol::new_with_str(")")));
+                Section::Aux => {
+                    let param = self.parse_param(&item, false)?;
 
-                                    work_list = &mut rest_params_ll;
+                    if param.supplied_p.is_some() || param.pattern.is_some() {
+                        return Err(lambda_list_error(&format!(
+                            "malformed &AUX parameter {}", item)))
+                    }
 
-                                    work_list.clear();
-                                }
+                    self.aux_params.push(param);
+                }
 
-                                /*
-                                "&aux" => {
-                                }
+                _ => {
+                    return Err(lambda_list_error(&format!(
+                        "unexpected {} in lambda list",
+                        item.to_string().to_uppercase())))
+                }
+            }
+        }
 
-                                "&optional" => {
-                                }
-                                */
+        if let Some(keyword) = pending_keyword {
+            return Err(lambda_list_error(&format!(
+                "{} without a variable", keyword.to_uppercase())))
+        }
 
-                                &_ => unreachable!(),
-                            }
-                        }
+        // (a b . rest) is (a b &rest rest)
+        if let Some(tail) = tail {
+            if section >= Section::Rest {
+                return Err(lambda_list_error(&format!(
+                    "misplaced dotted tail {} in lambda list",
+                    tail.to_string().to_uppercase())))
+            }
 
-                        Some(SExpr::Atom(atom)) => {
-                            println!("atom: {:?}", atom);
-                            println!("");
+            self.rest = Some(self.pattern_or_name(&tail)?);
 
-                            work_list.push_back(SExpr::Atom(atom));
-                        }
+            rest_params_ll.push_back(tail);
+        } else if section == Section::Rest && rest_params_ll.is_empty() {
+            return Err(lambda_list_error("&REST without a variable"))
+        }
 
-                        Some(SExpr::SList(list)) =>  {
-                            println!("slist: {:?}", list);
-                            println!("");
+        self.set_required_params_with_rllist(
+            RLList::<SExpr>::new_with_items(&required_params_ll));
 
-                            work_list.push_back(SExpr::SList(list));
-                        }
+        self.set_rest_params_with_rllist(
+            RLList::<SExpr>::new_with_items(&rest_params_ll));
 
-                        _ => unreachable!(),
+        Ok(())
+    }
+
+    // The lambda list of DEFMACRO and DESTRUCTURING-BIND.
+    pub fn parse_destructuring_parameters(&mut self, sexpr: SExpr) ->
+        Result<(), RLError> {
+
+        self.destructuring = true;
+
+        self.parse_parameters(sexpr)
+    }
+
+    // The items of a lambda list and the tail of a dotted one.
+    fn lambda_list_items(&self, sexpr: SExpr) ->
+        Result<(LinkedList<SExpr>, Option<SExpr>), RLError> {
+
+        match sexpr {
+            SExpr::SList(slist) => Ok((slist.get_items(), None)),
+
+            SExpr::Nil(_) => Ok((LinkedList::<SExpr>::new(), None)),
+
+            SExpr::SCons(_) if self.destructuring => {
+                let mut items = LinkedList::<SExpr>::new();
+
+                let mut rest = sexpr;
+
+                while let SExpr::SCons(cons) = rest {
+                    items.push_back(cons.car());
+
+                    rest = cons.cdr();
+                }
+
+                match rest {
+                    SExpr::SList(slist) => {
+                        items.append(&mut slist.get_items());
+
+                        Ok((items, None))
                     }
+
+                    SExpr::Nil(_) => Ok((items, None)),
+
+                    tail => Ok((items, Some(tail))),
                 }
             }
 
-            _ => unreachable!(),
+            other => {
+                let err = TypeError::new(&other.to_string(), "LIST");
+
+                Err(RLError::TypeError(err))
+            }
         }
+    }
 
-        work_list.push_front(SExpr::Symb(RLSymbol::new_with_str("(")));
+    // var or (var [init-form [supplied-p]]), the var of a &key parameter
+    // may be (keyword var). In a destructuring lambda list var may be
+    // a pattern.
+    fn parse_param(&self, sexpr: &SExpr, key: bool) -> Result<RLParam, RLError> {
+        let mut items = match sexpr {
+            SExpr::Symb(_) => LinkedList::from([sexpr.clone()]),
 
-        work_list.push_back(SExpr::Symb(RLSymbol::new_with_str(")")));
+            SExpr::SList(slist)
+                if (1..=3).contains(&slist.get_items().len()) => {
+                slist.get_items()
+            }
 
-        required_params.set_linked_list(&required_params_ll);
-        self.set_required_params_with_rllist(required_params);
+            _ => {
+                return Err(lambda_list_error(&format!(
+                    "malformed parameter {} in lambda list", sexpr)))
+            }
+        };
 
-        rest_params.set_linked_list(&rest_params_ll);
-        self.set_rest_params_with_rllist(rest_params);
+        let (keyword, mut param) = match items.pop_front().unwrap() {
+            SExpr::Symb(symb) => {
+                (format!(":{}", symb.get_symbol_name()),
+                 self.pattern_or_name(&SExpr::Symb(symb))?)
+            }
 
-        println!("required_params_ll: {:?}", required_params_ll);
-        println!("");
+            SExpr::SList(slist) if key && slist.get_items().len() == 2 => {
+                let mut pair = slist.get_items();
 
-        println!("rest_params_ll: {:?}", rest_params_ll);
-        println!("");
+                let keyword = match pair.pop_front().unwrap() {
+                    SExpr::Symb(symb) => symb.get_symbol_name(),
 
-        Ok(())
+                    other => {
+                        let err = TypeError::new(&other.to_string(),
+                                                 "SYMBOL");
+
+                        return Err(RLError::TypeError(err))
+                    }
+                };
+
+                (keyword, self.pattern_or_name(&pair.pop_front().unwrap())?)
+            }
+
+            pattern @ (SExpr::SList(_) | SExpr::SCons(_))
+                if self.destructuring && !key => {
+                (String::new(), self.pattern_or_name(&pattern)?)
+            }
+
+            other => {
+                let err = TypeError::new(&other.to_string(), "SYMBOL");
+
+                return Err(RLError::TypeError(err))
+            }
+        };
+
+        param.keyword = keyword;
+
+        param.init_form = items.pop_front()
+                               .unwrap_or(SExpr::Nil(RLNil::new()));
+
+        param.supplied_p = match items.pop_front() {
+            Some(sexpr) => Some(variable_name(&sexpr)?),
+
+            None => None,
+        };
+
+        Ok(param)
+    }
+
+    // A variable, or a nested lambda list in a destructuring one.
+    fn pattern_or_name(&self, sexpr: &SExpr) -> Result<RLParam, RLError> {
+        let mut param = RLParam {
+            name: String::new(),
+            pattern: None,
+            keyword: String::new(),
+            init_form: SExpr::Nil(RLNil::new()),
+            supplied_p: None,
+        };
+
+        match sexpr {
+            SExpr::SList(_) | SExpr::SCons(_) | SExpr::Nil(_)
+                if self.destructuring => {
+                let mut pattern = RLOrdinaryLambdaList::new();
+
+                pattern.parse_destructuring_parameters(sexpr.clone())?;
+
+                param.pattern = Some(Box::new(pattern));
+            }
+
+            _ => param.name = variable_name(sexpr)?,
+        }
+
+        Ok(param)
+    }
+
+    // names of the required parameters, upper case
+    pub fn get_required_names(&self) -> Vec<String> {
+        let mut names = Vec::<String>::new();
+
+        if let SExpr::SList(slist) = &self.required_params {
+            for item in slist.get_items() {
+                names.push(param_name(&item));
+            }
+        }
+
+        names
+    }
+
+    // name of the &rest parameter, upper case
+    pub fn get_rest_name(&self) -> Option<String> {
+        if let SExpr::SList(slist) = &self.rest_params {
+            slist.get_items()
+                 .front()
+                 .map(param_name)
+        } else {
+            None
+        }
+    }
+}
+
+// The parts of a lambda list, in the order they have to appear.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Section {
+    Required,
+    Optional,
+    Rest,
+    Key,
+    AllowOtherKeys,
+    Aux,
+}
+
+fn next_section(section: Section, keyword: &str) -> Result<Section, RLError> {
+    let next = match keyword {
+        "&optional" => Section::Optional,
+
+        // &body is a synonym of &rest in macro lambda lists
+        "&rest" | "&body" => Section::Rest,
+
+        "&key" => Section::Key,
+
+        "&allow-other-keys" if section == Section::Key => {
+            Section::AllowOtherKeys
+        }
+
+        "&aux" => Section::Aux,
+
+        _ => {
+            return Err(lambda_list_error(&format!(
+                "{} is not a supported lambda list keyword",
+                keyword.to_uppercase())))
+        }
+    };
+
+    if next <= section {
+        return Err(lambda_list_error(&format!(
+            "misplaced {} in lambda list", keyword.to_uppercase())))
+    }
+
+    Ok(next)
+}
+
+fn variable_name(sexpr: &SExpr) -> Result<String, RLError> {
+    match sexpr {
+        SExpr::Symb(symb) if !symb.get_symbol_name().starts_with(['&', ':']) => {
+            Ok(symb.get_symbol_name().to_uppercase())
+        }
+
+        other => {
+            let err = TypeError::new(&other.to_string(), "SYMBOL");
+
+            Err(RLError::TypeError(err))
+        }
+    }
+}
+
+fn lambda_list_error(msg: &str) -> RLError {
+    RLError::ProgramError(ProgramError::new(msg))
+}
+
+fn param_name(sexpr: &SExpr) -> String {
+    match sexpr {
+        SExpr::Symb(symb) => symb.get_symbol_name().to_uppercase(),
+
+        SExpr::Atom(atom) => atom.get_atom_string().to_uppercase(),
+
+        other => other.to_string(),
     }
 }
 
 impl fmt::Display for RLOrdinaryLambdaList {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         /*
-        println!("in fmt for OrdinaryLambdaList");
-        println!("self: {:?}", self);
-        println!("");
         */
 
         match self {
@@ -254,6 +561,7 @@ impl fmt::Display for RLOrdinaryLambdaList {
                */
 
                rest_params: _,
+               ..
            } => {
                write!(f, "{}", req_params)
            }
@@ -6,7 +6,6 @@
 use std::collections::hash_map::HashMap;
 use std::mem::drop;
 
-use crate::lambda::{RLLambda, make_rllambda_hash_map};
 use crate::symb::{RLEnvSymbol, make_rlenvsymbol_hash_map};
 
 use expr::string::RLString;
@@ -17,7 +16,6 @@ use hash::hash::{RLHash, clone_hash_map};
 pub struct RLPackage {
     name: String,
 
-    lambdas: HashMap<String, RLLambda, RLHash>,
     symbols: HashMap<String, RLEnvSymbol, RLHash>,
 }
 
@@ -26,21 +24,15 @@ impl RLPackage {
 
         let name = name.to_string();
 
-        let lambdas = make_rllambda_hash_map();
-
         let symbols = make_rlenvsymbol_hash_map();
 
         RLPackage {
             name,
-            lambdas,
             symbols,
         }
     }
 
     pub fn check_for_symbol(&self, symbol: &String) -> bool {
-        println!("self.symbols: {:?}",  self.symbols);
-        println!("");
-
         self.symbols.contains_key(symbol)
     }
 
@@ -48,35 +40,6 @@ impl RLPackage {
         self.name.clone()
     }
 
-    pub fn check_for_lambda(&mut self, id: &String) -> bool {
-        self.lambdas.contains_key(id)
-    }
-
-    pub fn set_lambdas_hash_map(&mut self,
-                                hash_map: &HashMap<String, RLLambda, RLHash>) {
-        self.lambdas = clone_hash_map(&hash_map);
-    }
-
-    pub fn get_lambdas_hash_map(&mut self) ->
-        &mut HashMap<String, RLLambda, RLHash> {
-
-        &mut self.lambdas
-    }
-
-    pub fn get_lambda(&mut self, key: &String) -> Option<&mut RLLambda> {
-        if let Some(lambda) = self.lambdas.get_mut(key) {
-            return Some(lambda);
-        } else { return None }
-    }
-
-    pub fn add_lambda(&mut self, id: String , val: RLLambda) {
-        self.lambdas.insert(id, val);
-    }
-
-    pub fn delete_lambda(&mut self, id: String) {
-        self.lambdas.remove(&id);
-    }
-
     pub fn get_symbols_hash_map(&mut self) ->
         &mut HashMap<String, RLEnvSymbol, RLHash> {
 
@@ -110,7 +73,6 @@ impl Clone for RLPackage {
     fn clone(&self) -> Self {
         Self {
             name: self.name.clone(),
-            lambdas: clone_hash_map(&self.lambdas),
             symbols: clone_hash_map(&self.symbols),
         }
     }
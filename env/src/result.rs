@@ -3,12 +3,15 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::collections::LinkedList;
 use std::fmt;
+use std::rc::Rc;
 
 use crate::block::RLBlock;
 use crate::env::RLEnvironment;
 use crate::lambda::RLLambda;
 use crate::named_lambda::RLNamedLambda;
+use crate::ordinary_lambda_list::RLOrdinaryLambdaList;
 use crate::symb::RLEnvSymbol;
 use crate::dyn_var::RLDynVar;
 
@@ -17,8 +20,10 @@ use err::err::{RLError, TypeError};
 use expr::atom::RLAtom;
 use expr::bool::RLBool;
 use expr::nil::RLNil;
+use expr::num::RLNum;
 // use expr::QuoteTrait;
 use expr::expr::Expr;
+use expr::function::RLFunction;
 use expr::qexpr::QExpr;
 use expr::sexpr::SExpr;
 use expr::string::RLString;
@@ -39,18 +44,91 @@ pub enum RLResult {
     NilRes(RLNil),
     BoolRes(RLBool),
 
-    NumRes(f64),
+    NumRes(RLNum),
     StrRes(String),
     StringRes(RLString),
     SymbolRes(RLEnvSymbol),
     SymbRes(Symbol),
     // SymbRes(RLSymbol),
     EnvRes(RLEnvironment),
-    FuncRes(RLNamedLambda),
-    LambdaRes(RLLambda),
-    MacroRes(RLNamedLambda),
+    FuncRes(Box<RLNamedLambda>),
+    LambdaRes(Box<RLLambda>),
+    MacroRes(Box<RLNamedLambda>),
     NamedDsBindRes(RLBlock),
     VarRes(RLDynVar),
+
+    // file name and source text of a file to be loaded
+    LoadRes(String, String),
+
+    // RLISP:BACKTRACE, the evaluator returns its call frames
+    BacktraceRes,
+
+    // test, then and else form of IF, only one branch is evaluated
+    IfRes(SExpr, SExpr, SExpr),
+
+    // expansion of a macro of the cl package, it is evaluated in
+    // place of the macro form
+    ExpansionRes(SExpr),
+
+    // multiple values, like the quotient and remainder of FLOOR
+    ValuesRes(LinkedList<SExpr>),
+
+    // the statements of a TAGBODY and the positions of its tags
+    TagbodyRes(RLBlock),
+
+    // id of the target TAGBODY and the tag
+    GoRes(String, String),
+
+    // protected form and cleanup forms of UNWIND-PROTECT
+    UnwindProtectRes(SExpr, LinkedList<SExpr>),
+
+    // variables and values form of MULTIPLE-VALUE-BIND, the block
+    // holds the body
+    MultipleValueBindRes(LinkedList<String>, SExpr, RLBlock),
+
+    // function form and the forms whose values are the arguments
+    MultipleValueCallRes(SExpr, LinkedList<SExpr>),
+
+    // the form whose values are returned and the forms which follow it
+    MultipleValueProg1Res(SExpr, LinkedList<SExpr>),
+
+    // lambda list and expression of DESTRUCTURING-BIND, the block
+    // holds the body
+    DestructuringBindRes(Box<RLOrdinaryLambdaList>, SExpr, RLBlock),
+
+    // expression form of HANDLER-CASE, its clauses (type specifier,
+    // variable, block holding the body) and the lambda form of the
+    // :no-error clause
+    HandlerCaseRes(SExpr, Vec<(SExpr, Option<String>, RLBlock)>, Option<SExpr>),
+
+    // (type specifier, handler form) bindings of HANDLER-BIND, its body
+    HandlerBindRes(Vec<(SExpr, SExpr)>, LinkedList<SExpr>),
+
+    // functions of the condition system with their evaluated
+    // arguments, e.g. ERROR or INVOKE-RESTART. The evaluator makes and
+    // signals the conditions and finds the restarts.
+    ConditionRes(String, LinkedList<SExpr>),
+
+    // restartable form of RESTART-CASE and its clauses
+    RestartCaseRes(SExpr, Vec<RLRestartClause>),
+}
+
+// A clause of RESTART-CASE or WITH-SIMPLE-RESTART
+#[derive(Debug, Clone)]
+pub struct RLRestartClause {
+    // NIL for an anonymous restart
+    pub name: String,
+
+    // a format control and the forms of its arguments, evaluated when
+    // the restart is established
+    pub report: LinkedList<SExpr>,
+
+    // (lambda lambda-list form*) called with the arguments of
+    // INVOKE-RESTART
+    pub function: SExpr,
+
+    // number of the required parameters
+    pub arity: usize,
 }
 
 impl RLResult {
@@ -69,12 +147,16 @@ impl RLResult {
                return Ok(SExpr::Nil(nil));
             }
 
-            /*
-            RLResult::BoolRes(bool)   => {}
-            */
+            RLResult::BoolRes(RLBool::T(_)) => {
+                return Ok(SExpr::Symb(RLSymbol::new_with_str("t")));
+            }
+
+            RLResult::BoolRes(RLBool::Nil(nil)) => {
+                return Ok(SExpr::Nil(nil));
+            }
 
             RLResult::NumRes(num)     => {
-                return Ok(SExpr::Atom(RLAtom::new(&num.to_string())));
+                return Ok(SExpr::Num(num));
             }
 
             RLResult::StrRes(string)  => {
@@ -82,7 +164,7 @@ impl RLResult {
             }
 
             RLResult::StringRes(rlstring) => {
-                let string = rlstring.to_string();
+                let string = rlstring.to_atom_string();
 
                 return Ok(SExpr::Atom(RLAtom::new(&string)));
             }
@@ -91,7 +173,70 @@ impl RLResult {
                 return Ok(SExpr::Symb(RLSymbol::new_with_symb(&symb)));
             }
 
-            _ => unreachable!(),
+            RLResult::SymbolRes(symbol) => {
+                let name = symbol.get_name().to_lowercase();
+
+                return Ok(SExpr::Symb(RLSymbol::new_with_str(&name)));
+            }
+
+            RLResult::FuncRes(named_lambda) |
+            RLResult::MacroRes(named_lambda) => {
+                let name = named_lambda.get_name().to_lowercase();
+
+                return Ok(SExpr::Func(RLAtom::new(&name)));
+            }
+
+            // the evaluator makes the function values of lambdas with
+            // their local definitions, here it is the lambda alone
+            RLResult::LambdaRes(lambda) => {
+                let id = lambda.get_id();
+
+                return Ok(SExpr::Lambda(RLFunction::new(&id, Rc::new(*lambda))));
+            }
+
+            RLResult::VarRes(mut dyn_var) => {
+                return Ok(dyn_var.var_to_sexpr());
+            }
+
+            RLResult::ExprRes(Expr::SExpr(sexpr)) => {
+                return Ok(sexpr);
+            }
+
+            RLResult::ExprRes(Expr::QExpr(qexpr)) => {
+                return Ok(expr::expr::qexpr_to_sexpr(qexpr));
+            }
+
+            RLResult::EnvRes(env) => {
+                return Ok(SExpr::Atom(RLAtom::new(&env.to_string())));
+            }
+
+            // only the primary value is used where one value is expected
+            RLResult::ValuesRes(values) => {
+                return Ok(values.front()
+                                .cloned()
+                                .unwrap_or(SExpr::Nil(RLNil::new())));
+            }
+
+            RLResult::BlockRes(_) |
+            RLResult::ReturnFromRes(_) |
+            RLResult::NamedDsBindRes(_) |
+            RLResult::LoadRes(_, _) |
+            RLResult::BacktraceRes |
+            RLResult::IfRes(_, _, _) |
+            RLResult::ExpansionRes(_) |
+            RLResult::TagbodyRes(_) |
+            RLResult::GoRes(_, _) |
+            RLResult::UnwindProtectRes(_, _) |
+            RLResult::MultipleValueBindRes(_, _, _) |
+            RLResult::MultipleValueCallRes(_, _) |
+            RLResult::MultipleValueProg1Res(_, _) |
+            RLResult::DestructuringBindRes(_, _, _) |
+            RLResult::HandlerCaseRes(_, _, _) |
+            RLResult::HandlerBindRes(_, _) |
+            RLResult::ConditionRes(_, _) |
+            RLResult::RestartCaseRes(_, _) => {
+                return Err(RLError::SimpleProgramError);
+            }
         }
     }
 
@@ -110,7 +255,7 @@ impl RLResult {
     pub fn get_sexpr_res(res: SExprRes)     -> {}
     pub fn get_nil_res(res: NilRes)         -> {}
     pub fn get_bool_res(res: BoolRes)       -> {}
-    pub fn get_num_res(res: f64)            -> {}
+    pub fn get_num_res(res: RLNum)          -> {}
     pub fn get str_res(res: String)         -> {}
     pub fn get_string_res(res: RLString)    -> {}
     pub fn get_symbol_res(res: RLSymbol)    -> {}
@@ -139,7 +284,7 @@ impl fmt::Display for RLResult {
             RLResult::SExprRes(sexpr)      => write!(f, "{}", sexpr),
             RLResult::NilRes(nil)          => write!(f, "{}", nil),
             RLResult::BoolRes(rlbool)      => write!(f, "{}", rlbool),
-            RLResult::NumRes(f64)          => write!(f, "{}", f64),
+            RLResult::NumRes(num)          => write!(f, "{}", num),
             RLResult::StrRes(str)          => write!(f, "{}", str),
             RLResult::StringRes(rlstr)     => write!(f, "{}", rlstr),
             RLResult::SymbolRes(symb)      =>
@@ -152,6 +297,44 @@ impl fmt::Display for RLResult {
             RLResult::NamedDsBindRes(block) => write!(f, "{}", block),
             RLResult::MacroRes(func)       => write!(f, "{}", func),
             RLResult::VarRes(var)          => write!(f, "{}", var),
+            RLResult::LoadRes(file, _)     => write!(f, "{}", file),
+            RLResult::BacktraceRes         => write!(f, "(RLISP:BACKTRACE)"),
+            RLResult::IfRes(test, then, other) =>
+                write!(f, "(IF {} {} {})", test, then, other),
+            RLResult::ExpansionRes(sexpr)  => write!(f, "{}", sexpr),
+            RLResult::TagbodyRes(block)    => write!(f, "{}", block),
+            RLResult::GoRes(_, tag)        =>
+                write!(f, "(GO {})", tag.to_uppercase()),
+            RLResult::UnwindProtectRes(form, _) =>
+                write!(f, "(UNWIND-PROTECT {} ...)", form),
+            RLResult::MultipleValueBindRes(_, form, _) =>
+                write!(f, "(MULTIPLE-VALUE-BIND ... {} ...)", form),
+            RLResult::MultipleValueCallRes(function, _) =>
+                write!(f, "(MULTIPLE-VALUE-CALL {} ...)", function),
+            RLResult::MultipleValueProg1Res(form, _) =>
+                write!(f, "(MULTIPLE-VALUE-PROG1 {} ...)", form),
+            RLResult::DestructuringBindRes(lambda_list, form, _) =>
+                write!(f, "(DESTRUCTURING-BIND {} {} ...)",
+                       lambda_list.lambda_list, form),
+            RLResult::HandlerCaseRes(form, _, _) =>
+                write!(f, "(HANDLER-CASE {} ...)", form),
+            RLResult::HandlerBindRes(_, _) =>
+                write!(f, "(HANDLER-BIND ...)"),
+            RLResult::RestartCaseRes(form, _) =>
+                write!(f, "(RESTART-CASE {} ...)", form),
+            RLResult::ConditionRes(operator, args) =>
+                write!(f, "({} {})", operator.to_uppercase(),
+                       args.iter()
+                           .map(|arg| arg.to_string())
+                           .collect::<Vec<String>>()
+                           .join(" ")),
+            RLResult::ValuesRes(values)    => {
+                let values = values.iter()
+                                   .map(|value| value.to_string())
+                                   .collect::<Vec<String>>();
+
+                write!(f, "{}", values.join(" ; "))
+            }
         }
     }
 }
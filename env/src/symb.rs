@@ -5,6 +5,7 @@
 
 use std::fmt;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 use crate::dyn_var::RLDynVar;
 use crate::env::RLEnvironment;
@@ -28,7 +29,8 @@ pub struct RLEnvSymbol {
 
     // p_list: Option<p-list?>,
 
-    pub named_lambda: Option<RLNamedLambda>,
+    // shared with the callers, a call doesn't copy the body
+    pub named_lambda: Option<Rc<RLNamedLambda>>,
     pub dyn_var: Option<RLDynVar>,
 }
 
@@ -42,7 +44,7 @@ impl RLEnvSymbol {
 
         let home_package = named_lambda.get_pack_string();
 
-        let named_lambda = Some(named_lambda);
+        let named_lambda = Some(Rc::new(named_lambda));
         let dyn_var = None;
 
         RLEnvSymbol {
@@ -81,7 +83,7 @@ impl RLEnvSymbol {
 
     pub fn get_named_lambda(&mut self) -> Option<RLNamedLambda> {
         // <Option<RLNamedLambda> as Clone>::clone(&self.named_lambda)
-        self.named_lambda.clone()
+        self.named_lambda.as_deref().cloned()
     }
 
     pub fn set_named_lambda(&mut self, named_lambda: &RLNamedLambda) {
@@ -89,7 +91,7 @@ impl RLEnvSymbol {
 
         self.is_macro = is_macro;
 
-        self.named_lambda = Some(named_lambda.clone());
+        self.named_lambda = Some(Rc::new(named_lambda.clone()));
     }
 
     /*
@@ -149,10 +151,8 @@ impl RLEnvSymbol {
     }
 
     pub fn set_dyn_var_value(&mut self, var: RLVar) {
-        if let Some(_dyn_var) = &self.dyn_var  {
-            // <Option<RLDynVar> as Clone>::clone(
-            //     &self.dyn_var).unwrap().set_var(var)
-            self.dyn_var.clone().unwrap().set_var(var)
+        if let Some(dyn_var) = &mut self.dyn_var {
+            dyn_var.set_var(var)
         }
     }
 }
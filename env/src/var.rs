@@ -4,15 +4,19 @@
 // file that was distributed with this source code.
 
 use std::fmt;
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 // use crate::lambda::RLLambda;
 // use crate::named_lambda::RLNamedLambda;
 
 use expr::bool::RLBool;
+use expr::expr::qexpr_to_sexpr;
 use expr::nil::RLNil;
 use expr::qexpr::QExpr;
 use expr::sexpr::SExpr;
+use expr::symb::RLSymbol;
 
 use hash::hash::RLHash;
 
@@ -51,6 +55,10 @@ pub enum RLVar {
     NilVar(RLNil),
     BoolVar(RLBool),
 
+    // a variable captured by a closure, the closure and the frame
+    // which bound the variable share the cell
+    CellVar(Rc<RefCell<RLVar>>),
+
     // NamedLambdaVar(RLNamedLambda),
     // LambdaVar(RLLambda),
 
@@ -60,25 +68,54 @@ pub enum RLVar {
 }
 
 impl RLVar {
-    pub fn var_to_sexpr(&mut self) -> SExpr {
-        let result: SExpr;
+    pub fn from_sexpr(sexpr: SExpr) -> RLVar {
+        match sexpr {
+            SExpr::Nil(nil) => RLVar::NilVar(nil),
+
+            SExpr::SList(_) | SExpr::SCons(_) => RLVar::SListVar(sexpr),
+
+            _ => RLVar::SAtomVar(sexpr),
+        }
+    }
 
+    // The variable as a cell which can be shared, a cell is
+    // returned as it is.
+    pub fn into_cell(self) -> RLVar {
         match self {
-            RLVar::SAtomVar(SExpr::Atom(atom)) => {
-                result = SExpr::Atom(atom.clone());
-            }
+            RLVar::CellVar(_) => self,
 
-            RLVar::NilVar(nil) => {
-                result = SExpr::Nil(nil.clone());
-            }
+            var => RLVar::CellVar(Rc::new(RefCell::new(var))),
+        }
+    }
+
+    // Assigns var to the cell, false if self is no cell.
+    pub fn set_cell_value(&self, var: RLVar) -> bool {
+        match self {
+            RLVar::CellVar(cell) => {
+                *cell.borrow_mut() = var;
 
-            RLVar::SListVar(SExpr::SList(slist)) => {
-                result = SExpr::SList(slist.clone());
+                true
             }
 
-            _ => todo!(),
+            _ => false,
+        }
+    }
+
+    pub fn var_to_sexpr(&mut self) -> SExpr {
+        match self {
+            RLVar::QListVar(qexpr) => qexpr_to_sexpr(qexpr.clone()),
+
+            RLVar::SListVar(sexpr) | RLVar::SAtomVar(sexpr) => sexpr.clone(),
+
+            RLVar::NilVar(nil) => SExpr::Nil(nil.clone()),
+
+            RLVar::BoolVar(RLBool::T(_)) =>
+                SExpr::Symb(RLSymbol::new_with_str("t")),
+
+            RLVar::BoolVar(RLBool::Nil(nil)) => SExpr::Nil(nil.clone()),
+
+            RLVar::CellVar(cell) => cell.borrow_mut().var_to_sexpr(),
         }
-        result
     }
 }
 
@@ -104,6 +141,7 @@ impl fmt::Display for RLVar {
 
             RLVar::NilVar(nil)     => write!(f, "{}", nil),
             RLVar::BoolVar(rlbool) => write!(f, "{}", rlbool),
+            RLVar::CellVar(cell)   => write!(f, "{}", cell.borrow()),
 
             // RLVar::NamedLambdaVar(_named_lambda) => todo!(),
             // RLVar::LambdaVar(_lambda) => todo!(),
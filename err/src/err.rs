@@ -8,7 +8,10 @@ use std::fmt;
 
 use std::num::{ParseFloatError, ParseIntError};
 
+use pars_symb::span::Span;
 use pars_symb::token::Token;
+
+use crate::frame::RLFrame;
 ////
 
 #[derive(Debug, Clone)]
@@ -38,21 +41,66 @@ impl Error for BlockError {}
 #[derive(Debug, Clone)]
 pub struct ParseError {
     details: String,
-    token:   Token
+    token:   Token,
+    span:    Span,
+
+    // the input ended inside a form, more input may complete it
+    end_of_input: bool
 }
 
 impl ParseError {
     pub fn new(msg: &str, tok: Token) -> ParseError {
         ParseError {
             details: msg.to_string(),
-            token:   tok
+            token:   tok,
+            span:    Span::default(),
+            end_of_input: false
+        }
+    }
+
+    pub fn new_with_span(msg: &str, tok: Token, span: Span) -> ParseError {
+        ParseError {
+            details: msg.to_string(),
+            token:   tok,
+            span,
+            end_of_input: false
         }
     }
+
+    pub fn new_end_of_input(msg: &str, tok: Token, span: Span) -> ParseError {
+        ParseError {
+            details: msg.to_string(),
+            token:   tok,
+            span,
+            end_of_input: true
+        }
+    }
+
+    pub fn is_end_of_input(&self) -> bool {
+        self.end_of_input
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.span.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.span.column
+    }
+
+    pub fn get_span(&self) -> Span {
+        self.span.clone()
+    }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.details, self.token)
+        if self.span.line > 0 {
+            write!(f, "{} {} at line {}, column {}",
+                self.details, self.token, self.span.line, self.span.column)
+        } else {
+            write!(f, "{} {}", self.details, self.token)
+        }
     }
 }
 
@@ -73,6 +121,15 @@ impl TypeError {
             details: msg.to_string()
         }
     }
+
+    // the printed representation of the offending value
+    pub fn get_datum(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_expected_type(&self) -> String {
+        self.details.clone()
+    }
 }
 
 impl fmt::Display for TypeError {
@@ -222,6 +279,15 @@ impl SimpleTypeError {
             details: msg.to_string()
         }
     }
+
+    // the printed representation of the offending value
+    pub fn get_datum(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn get_expected_type(&self) -> String {
+        self.details.clone()
+    }
 }
 
 impl fmt::Display for SimpleTypeError {
@@ -232,22 +298,101 @@ impl fmt::Display for SimpleTypeError {
 
 impl Error for SimpleTypeError {}
 
+// Non-local exits to an exit point which is no longer established,
+// like a GO to a tag of a TAGBODY which has been left.
+#[derive(Debug, Clone)]
+pub struct ControlError {
+    details: String
+}
+
+impl ControlError {
+    pub fn new(msg: &str) -> ControlError {
+        ControlError {
+            details: msg.to_string()
+        }
+    }
+}
+
+impl fmt::Display for ControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl Error for ControlError {}
+
+// Errors in the arguments of a call which are not about their number,
+// like an unknown keyword argument.
+#[derive(Debug, Clone)]
+pub struct ProgramError {
+    details: String
+}
+
+impl ProgramError {
+    pub fn new(msg: &str) -> ProgramError {
+        ProgramError {
+            details: msg.to_string()
+        }
+    }
+}
+
+impl fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl Error for ProgramError {}
+
+// A condition of a type defined with DEFINE-CONDITION, or of a
+// standard type no Rust error stands for, which no handler took.
+#[derive(Debug, Clone)]
+pub struct ConditionError {
+    pub condition_type: String,
+    details: String
+}
+
+impl ConditionError {
+    pub fn new(condition_type: &str, msg: &str) -> ConditionError {
+        ConditionError {
+            condition_type: condition_type.to_uppercase(),
+            details: msg.to_string()
+        }
+    }
+}
+
+impl fmt::Display for ConditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.details)
+    }
+}
+
+impl Error for ConditionError {}
+
 ////
 
 #[derive(Debug, Clone)]
 pub enum RLError {
     BlockError(BlockError),
+    ConditionError(ConditionError),
+    ControlError(ControlError),
     DivisionByZero,
+    FloatingPointOverflow,
     ParseError(ParseError),
     TypeError(TypeError),
     ParseFloatError(CustomParseFloatError),
     ParseIntError(ParseIntError),
+    ProgramError(ProgramError),
     ReturnFromError(ReturnFromError),
     SimpleError(SimpleError),
     SimpleProgramError,
     SimpleTypeError(SimpleTypeError),
     UnboundVariableError(UnboundVariableError),
     UndefinedFuncError(UndefinedFuncError),
+
+    // an error with the call frames which were active where it was
+    // signalled, the innermost first
+    WithBacktrace(Box<RLError>, Vec<RLFrame>),
 }
 
 impl fmt::Display for RLError {
@@ -257,13 +402,32 @@ impl fmt::Display for RLError {
             write!(f, "The block name {} is not a symbol.",
                 block_error.source),
 
+        RLError::ConditionError(condition_error) =>
+            write!(f, "{} {}", condition_error.condition_type,
+                condition_error),
+
+        RLError::ControlError(control_error) =>
+            write!(f, "CONTROL-ERROR {}", control_error),
+
         RLError::DivisionByZero =>
             write!(f, "DIVISION-BY-ZERO"),
 
+        RLError::FloatingPointOverflow =>
+            write!(f, "FLOATING-POINT-OVERFLOW"),
+
         RLError::ParseError(parse_error) =>
-            write!(f, "PARSE-ERROR {} - bad token: {}",
-                parse_error.details,
-                parse_error.token),
+            if parse_error.span.line > 0 {
+                write!(f,
+                    "PARSE-ERROR {} - bad token: {} (line {}, column {})",
+                    parse_error.details,
+                    parse_error.token,
+                    parse_error.span.line,
+                    parse_error.span.column)
+            } else {
+                write!(f, "PARSE-ERROR {} - bad token: {}",
+                    parse_error.details,
+                    parse_error.token)
+            },
 
         RLError::TypeError(type_error) =>
             write!(f, "TYPE-ERROR expected-type: {} datum: {}",
@@ -278,6 +442,9 @@ impl fmt::Display for RLError {
         RLError::ParseIntError(parse_int_error) =>
             write!(f, "TYPE-ERROR {}", parse_int_error),
 
+        RLError::ProgramError(program_error) =>
+            write!(f, "SIMPLE-PROGRAM-ERROR {}", program_error),
+
         RLError::ReturnFromError(return_from_error) =>
             write!(f, "Return for unknown block: {}",
                 return_from_error.block.to_uppercase()),
@@ -299,9 +466,103 @@ impl fmt::Display for RLError {
             write!(f, "UNBOUND-VARIABLE {}", unbound_var_error),
 
         RLError::UndefinedFuncError(undef_func_error) =>
-            write!(f, "UNDEFINED-FUNCTION {}", undef_func_error)
+            write!(f, "UNDEFINED-FUNCTION {}", undef_func_error),
+
+        RLError::WithBacktrace(err, _frames) =>
+            write!(f, "{}", err)
+        }
+    }
+}
+
+impl RLError {
+    // Parse errors know where they occurred, all other errors get
+    // their span from the form the evaluator was working on.
+    pub fn get_span(&self) -> Option<Span> {
+        match self {
+            RLError::ParseError(parse_error) if parse_error.span.line > 0 =>
+                Some(parse_error.get_span()),
+
+            RLError::WithBacktrace(err, _frames) => err.get_span(),
+
+            _ => None,
+        }
+    }
+
+    pub fn is_end_of_input(&self) -> bool {
+        match self {
+            RLError::ParseError(parse_error) => parse_error.is_end_of_input(),
+
+            RLError::WithBacktrace(err, _frames) => err.is_end_of_input(),
+
+            _ => false,
+        }
+    }
+
+    // The error with the frames as its backtrace, the error stays as it
+    // is if there are no frames.
+    pub fn with_backtrace(self, frames: Vec<RLFrame>) -> RLError {
+        match self {
+            _ if frames.is_empty() => self,
+
+            RLError::WithBacktrace(err, _frames) => {
+                RLError::WithBacktrace(err, frames)
+            }
+
+            err => RLError::WithBacktrace(Box::new(err), frames),
+        }
+    }
+
+    // the frames of the backtrace, the innermost first
+    pub fn get_backtrace(&self) -> Vec<RLFrame> {
+        match self {
+            RLError::WithBacktrace(_err, frames) => frames.clone(),
+
+            _ => Vec::new(),
         }
     }
+
+    // The error message followed by the source line of span with
+    // carets under the offending characters:
+    //
+    // UNBOUND-VARIABLE Y
+    //  --> <stdin>:1:4
+    //   |
+    // 1 | (+ y 1)
+    //   |    ^
+    pub fn render(&self, span: &Span, source: &str) -> String {
+        let mut out = format!("{}", self);
+
+        let line_start = source[..span.start.min(source.len())]
+                             .rfind('\n')
+                             .map(|pos| pos + 1)
+                             .unwrap_or(0);
+
+        let line_text = source[line_start..]
+                            .lines()
+                            .next()
+                            .unwrap_or("");
+
+        let line_end = line_start + line_text.len();
+
+        let caret_start = span.start.clamp(line_start, line_end);
+        let caret_end = span.end.clamp(caret_start, line_end);
+
+        let indent = source[line_start..caret_start].chars().count();
+        let width = source[caret_start..caret_end].chars().count().max(1);
+
+        let line_number = span.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        out.push_str(&format!("\n{} --> {}", gutter, span));
+        out.push_str(&format!("\n{} |", gutter));
+        out.push_str(&format!("\n{} | {}", line_number, line_text));
+        out.push_str(&format!("\n{} | {}{}",
+                              gutter,
+                              " ".repeat(indent),
+                              "^".repeat(width)));
+
+        out
+    }
 }
 
 impl Error for RLError {
@@ -310,8 +571,16 @@ impl Error for RLError {
            RLError::BlockError(block_error)            =>
                Some(block_error),
 
+           RLError::ConditionError(condition_error)    =>
+               Some(condition_error),
+
+           RLError::ControlError(control_error)        =>
+               Some(control_error),
+
            RLError::DivisionByZero                     => None,
 
+           RLError::FloatingPointOverflow              => None,
+
            RLError::ParseError(parse_error)            =>
                Some(parse_error),
 
@@ -323,6 +592,9 @@ impl Error for RLError {
 
            RLError::ParseIntError(_parse_int_error)    => None,
 
+           RLError::ProgramError(program_error)        =>
+               Some(program_error),
+
            RLError::ReturnFromError(return_from_error) =>
                Some(return_from_error),
 
@@ -338,6 +610,8 @@ impl Error for RLError {
                Some(unbound_var_error),
 
            RLError::UndefinedFuncError(_undefined_func_error) => None,
+
+           RLError::WithBacktrace(err, _frames)        => err.source(),
        }
    }
 }
@@ -0,0 +1,71 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::fmt;
+
+use pars_symb::span::Span;
+
+// frames shown by render_backtrace, deep recursions are cut off
+const BACKTRACE_LIMIT: usize = 20;
+
+// A function call which was active when an error was signalled
+#[derive(Debug, Clone)]
+pub struct RLFrame {
+    // uppercase, LAMBDA for anonymous functions
+    pub name: String,
+
+    // the printed arguments
+    pub args: Vec<String>,
+
+    // location of the call form, None for calls by FUNCALL, APPLY and
+    // the handlers of conditions
+    pub span: Option<Span>,
+}
+
+impl RLFrame {
+    pub fn new(name: &str, args: Vec<String>, span: Option<Span>) -> Self {
+        Self {
+            name: name.to_uppercase(),
+            args,
+            span,
+        }
+    }
+}
+
+impl fmt::Display for RLFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}", self.name)?;
+
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+// The frames, the innermost first, one on each line:
+//
+// Backtrace:
+//   0: (/ 1 0) at <stdin>:1:23
+//   1: (F 0) at <stdin>:2:1
+pub fn render_backtrace(frames: &[RLFrame]) -> String {
+    let mut out = "Backtrace:".to_string();
+
+    for (number, frame) in frames.iter().take(BACKTRACE_LIMIT).enumerate() {
+        out.push_str(&format!("\n  {}: {}", number, frame));
+
+        if let Some(span) = &frame.span {
+            out.push_str(&format!(" at {}", span));
+        }
+    }
+
+    if frames.len() > BACKTRACE_LIMIT {
+        out.push_str(&format!("\n  ... {} more frames",
+                              frames.len() - BACKTRACE_LIMIT));
+    }
+
+    out
+}
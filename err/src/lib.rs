@@ -4,6 +4,7 @@
 // file that was distributed with this source code.
 
 pub mod err;
+pub mod frame;
 
 
 
@@ -0,0 +1,19 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use expr::condition::RLCondition;
+
+use crate::evaluator::RLEvaluator;
+use crate::unwind::RLUnwind;
+
+// The debugger is entered when no handler takes an error and a
+// debugger is installed, e.g. by the REPL. It runs in the dynamic
+// environment of the error and is left by invoking one of the active
+// restarts, the ABORT restart of the top level is always one of them.
+pub trait RLDebugger {
+    fn debug(&mut self,
+             rl: &mut RLEvaluator,
+             condition: &RLCondition) -> RLUnwind;
+}
@@ -3,41 +3,71 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-use std::cell::RefCell;
+use std::collections::{HashMap, LinkedList};
 use std::rc::Rc;
-use std::collections::{BTreeMap, LinkedList};
-use std::ops::Not;
 
-use crate::named_ds_bind::RLNamedDsBind;
-use crate::zipper::RLZipper;
+use crate::debugger::RLDebugger;
+use crate::named_ds_bind::{RLBinding, RLNamedDsBind};
+use crate::unwind::RLUnwind;
 
+use cl::condition::{condition_to_error,
+                    error_to_condition,
+                    format_control,
+                    not_a_condition_type,
+                    princ_to_string,
+                    report_condition};
 use cl::creator::CLCreator;
+use cl::list::make_list;
 
 use env::block::RLBlock;
+use env::dyn_var::RLDynVar;
 use env::env::RLEnvironment;
 use env::env_trait::{EnvRef, EnvTrait};
 use env::lambda::RLLambda;
 use env::named_lambda::RLNamedLambda;
-use env::result::RLResult;
+use env::ordinary_lambda_list::RLOrdinaryLambdaList;
+use env::result::{RLRestartClause, RLResult};
 use env::symb::RLEnvSymbol;
-use env::var::RLVar;
+use env::var::{RLVar, make_rlvar_hash_map};
 
-use err::err::{RLError,
+use err::err::{ControlError,
+               RLError,
+               ReturnFromError,
+               SimpleError,
+               TypeError,
                UnboundVariableError,
                UndefinedFuncError};
+use err::frame::{RLFrame, render_backtrace};
 
 use expr::atom::RLAtom;
-use expr::expr::{qexpr_to_sexpr, sexpr_to_qexpr};
-use expr::expr::Expr;
+use expr::condition::RLCondition;
+use expr::function::RLFunction;
 use expr::list::RLList;
 use expr::nil::RLNil;
+use expr::restart::RLRestart;
 use expr::sexpr::SExpr;
 use expr::symb::RLSymbol;
 
-use pars::parser::RLParser;
+use hash::hash::{RLHash, clone_hash_map};
+
+use pars::parser::{RLParser, build_dotted_list};
+
+use pars_symb::span::Span;
 
 use pars_symb::symbol::Symbol;
-use pars_symb::token::Token;
+
+use trace::rltrace;
+use trace::trace::{TraceCategory, TraceLevel};
+
+// nested evaluations before the control stack is exhausted
+const EVAL_DEPTH_LIMIT: usize = 10000;
+
+// When less stack than the red zone is left, a form is evaluated on a
+// new segment of the stack allocated on the heap, so the depth limit
+// holds on any thread. A nested evaluation takes about 20 KB of stack
+// in a debug build.
+const STACK_RED_ZONE: usize = 256 * 1024;
+const STACK_SEGMENT_SIZE: usize = 4 * 1024 * 1024;
 
 pub struct RLEvaluator {
     sexpr: SExpr,
@@ -48,13 +78,147 @@ pub struct RLEvaluator {
 
     pub parser: RLParser,
 
-    zipper: RLZipper,
+    error: Option<RLError>,
+
+    // the last form was left by the restart of the top level, it has
+    // no values to print
+    aborted: bool,
 
-    named_ds_bind: RLNamedDsBind,
+    // span of the innermost form which signalled the last error
+    error_span: Option<Span>,
 
-    error: Option<RLError>,
+    // names bound by every frame of push_dyn_env, None for frames
+    // which hide the enclosing bindings (calls of named functions)
+    frame_names: Vec<Option<Vec<String>>>,
+
+    // the bindings of special variables made by every frame of
+    // push_dyn_env, they are undone by pop_dyn_env
+    special_frames: Vec<SpecialFrame>,
+
+    // the local definitions of every FLET, LABELS, MACROLET,
+    // SYMBOL-MACROLET and binding form, None for calls of named
+    // functions which don't see them
+    lexical_scopes: Vec<Option<Vec<(String, LocalDef)>>>,
+
+    // all values of the last evaluated form if it returned multiple
+    // values, None for a single value. Every evaluation starts with
+    // None and forms which use only the primary value reset it.
+    values: Option<LinkedList<SExpr>>,
+
+    // the handlers of every HANDLER-CASE and HANDLER-BIND in effect,
+    // inner ones last
+    handler_clusters: Vec<HandlerCluster>,
+
+    // the restarts in effect, inner ones last
+    restarts: Vec<RLRestart>,
+
+    // number of the HANDLER-CASE and RESTART-CASE forms evaluated so
+    // far, the ids of their handlers and restarts. The restart of the
+    // top level has the id 0.
+    exit_point_count: usize,
+
+    debugger: Option<Box<dyn RLDebugger>>,
 
-    macro_mode: bool,
+    // the function calls in progress, inner ones last
+    call_frames: Vec<CallFrame>,
+
+    // span of the call form whose function is called next
+    call_span: Option<Span>,
+
+    // number of forms being evaluated, inner ones included
+    eval_depth: usize,
+}
+
+// A function call in progress, for the backtrace
+struct CallFrame {
+    name: String,
+
+    args: LinkedList<SExpr>,
+
+    span: Option<Span>,
+}
+
+// How forms evaluated with restarts established were left
+enum Restarted<T> {
+    Returned(T),
+
+    // index of the invoked restart and its arguments
+    Invoked(usize, LinkedList<SExpr>),
+}
+
+// A lexically visible definition of a name. Variables are recorded to
+// shadow the symbol macros of the same name.
+#[derive(Clone)]
+enum LocalDef {
+    // the function value of a FLET or LABELS function
+    Function(RLFunction),
+
+    Macro(Box<RLNamedLambda>),
+
+    SymbolMacro(SExpr),
+
+    Variable,
+}
+
+impl LocalDef {
+    fn is_operator(&self) -> bool {
+        matches!(self, LocalDef::Function(_) | LocalDef::Macro(_))
+    }
+}
+
+// The functions of a LAMBDA, FLET or LABELS form and the local
+// definitions visible where they were made. The functions of LABELS
+// see each other, they are added when one of them is called, which
+// keeps the function values free of reference cycles.
+struct FunctionGroup {
+    // (name, lambda), the name is the one of a local function
+    functions: Vec<(String, RLLambda)>,
+
+    scope: Vec<(String, LocalDef)>,
+
+    recursive: bool,
+}
+
+// The code of a function value, a function of its group
+struct Closure {
+    group: Rc<FunctionGroup>,
+
+    index: usize,
+}
+
+impl Closure {
+    fn get_lambda(&self) -> &RLLambda {
+        &self.group.functions[self.index].1
+    }
+}
+
+// Special variables are bound in the value of their symbol. The
+// previous values are restored when the frame is left, however it
+// is left.
+struct SpecialFrame {
+    // variables declared special by the binding form
+    declared: Vec<String>,
+
+    // (name, previous value) of every binding, None if it was unbound
+    saved: Vec<(String, Option<RLVar>)>,
+}
+
+// The handlers established by one HANDLER-CASE or HANDLER-BIND, the
+// first one whose type matches a signalled condition is tried first.
+#[derive(Clone)]
+struct HandlerCluster {
+    // (type specifier, handler)
+    handlers: Vec<(SExpr, Handler)>,
+}
+
+#[derive(Clone)]
+enum Handler {
+    // function of HANDLER-BIND, it declines by returning
+    Function(SExpr),
+
+    // id of a HANDLER-CASE and index of the clause, the condition is
+    // always handled
+    Case(usize, usize),
 }
 
 impl RLEvaluator {
@@ -69,27 +233,33 @@ impl RLEvaluator {
 
         let parser = RLParser::new();
 
-        let zipper = RLZipper::new(&SExpr::Nil(RLNil::new()),
-            0,
-            0,
-            None,
-            None);
+        let error = None;
 
-        let named_ds_bind = RLNamedDsBind::new();
+        let error_span = None;
 
-        let error = None;
+        let frame_names = Vec::new();
 
-        let macro_mode = false;
+        let lexical_scopes = Vec::new();
 
         RLEvaluator {
             sexpr,
             env,
             cl_create,
             parser,
-            zipper,
-            named_ds_bind,
             error,
-            macro_mode,
+            aborted: false,
+            error_span,
+            frame_names,
+            special_frames: Vec::new(),
+            lexical_scopes,
+            values: None,
+            handler_clusters: Vec::new(),
+            restarts: Vec::new(),
+            exit_point_count: 0,
+            debugger: None,
+            call_frames: Vec::new(),
+            call_span: None,
+            eval_depth: 0,
         }
     }
 
@@ -100,2937 +270,2516 @@ impl RLEvaluator {
         env_binding.init();
 
         self.cl_create.init(env_binding.get_mut_cl_package());
+        self.cl_create.init_rlisp(env_binding.get_mut_rlisp_package());
 
         self.parser.init();
+    }
 
-        // drop(rc_binding);
-        // drop(env_binding);
+    pub fn get_sexpr(&self) -> &SExpr {
+        &self.sexpr
     }
 
-     /*
-     pub fn get_toplevel_sform(&self) -> SExpr {
-     self.toplevel_sform.clone()
-     }
+    pub fn set_sexpr(&mut self, sexpr: SExpr) {
+        self.sexpr = sexpr;
+    }
 
-    pub fn set_toplevel_sform(&mut self, mut vec: Vec<Token>) {
-    let mut result_vec = Vec::<Token>::new();
+    pub fn get_error(&self) -> Option<RLError> {
+        self.error.clone()
+    }
 
-        result_vec.push(Token::Symb(Symbol::new(")")));
+    pub fn is_aborted(&self) -> bool {
+        self.aborted
+    }
 
-        result_vec.append(&mut vec);
+    pub fn reset(&mut self) {
+        self.sexpr = SExpr::Nil(RLNil::new());
+        self.error = None;
+        self.error_span = None;
 
-        result_vec.push(Token::Symb(Symbol::new("eval")));
-        result_vec.push(Token::Symb(Symbol::new("(")));
+        self.parser.reset();
+    }
 
-        println!("result_vec: {:?}", result_vec);
-        println!("");
+    // Evaluates the form set by set_sexpr.
+    pub fn eval(&mut self) -> Result<RLResult, RLError> {
+        let sexpr = self.sexpr.clone();
 
-        self.toplevel_sform = SExpr::SForm(result_vec.clone())
-        }
-      */
+        self.error_span = None;
+        self.aborted = false;
 
-     ///////////////////////////////////////////////////////////
-     /*
-     Configure functions
-      */
-     ///////////////////////////////////////////////////////////
+        self.restarts.push(RLRestart::new("abort", "Return to top level.",
+                                          0, 0, 0));
 
-     fn set_macro_mode (&mut self, mode: bool) {
-         self.macro_mode = mode;
-     }
+        let result = self.eval_sexpr(&sexpr);
 
-     pub fn configure(&mut self) {
-         println!("configure - start");
-         println!("");
+        self.restarts.clear();
 
-         // self.set_toplevel_sform(self.parser.get_tokens());
+        match result {
+            Ok(res) => match self.values.take() {
+                Some(values) => Ok(RLResult::ValuesRes(values)),
 
-         self.sexpr = self.parser.get_sexpr().clone();
-         self.error = self.parser.get_error();
+                None => Ok(RLResult::SExprRes(res)),
+            },
 
-         println!("self.sexpr: {:?}", self.sexpr);
-         println!("");
+            // the evaluation was aborted, e.g. in the debugger
+            Err(RLUnwind::Restart(0, _, _)) => {
+                self.values = None;
+                self.aborted = true;
 
-         println!("self.error: {:?}", self.error);
-         println!("");
+                Ok(RLResult::ValuesRes(LinkedList::new()))
+            }
 
+            Err(unwind) => {
+                let err = unwind_to_error(unwind);
 
+                self.error = Some(err.clone());
 
-        
-         let is_toplevel: bool;
+                Err(err)
+            }
+        }
+    }
 
-         let mut is_macro = false;
+    // Evaluates (load "file").
+    pub fn load(&mut self, file: &str) -> Result<RLResult, RLError> {
+        let mut items = LinkedList::<SExpr>::new();
 
-         let eval_sexpr = self.get_sexpr();
+        items.push_back(SExpr::Symb(RLSymbol::new_with_str("load")));
+        items.push_back(SExpr::Atom(RLAtom::new(&format!("\"{}\"", file))));
 
-         // realize env binding
-         let rc_binding = self.env.clone();
+        self.set_sexpr(make_list(&items));
 
-         let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-             Rc::downgrade(&rc_binding);
+        self.eval()
+    }
 
-         let binding = weak_binding.upgrade().unwrap();
-         drop(rc_binding);
+    // Defines the special variable name, like DEFPARAMETER.
+    pub fn set_global_var(&mut self, name: &str, value: SExpr) {
+        self.with_env(|env| {
+            let curr_pack = env.get_mut_current_package();
 
-         let mut env_binding = binding.borrow_mut();
+            let mut var = RLDynVar::new(None,
+                                        curr_pack.get_name(),
+                                        RLVar::from_sexpr(value));
 
-         is_toplevel = env_binding.is_toplevel();
+            var.set_special(true);
 
-         println!("toplevel is: {:?}", is_toplevel);
-         println!("");
+            let var_name = name.to_uppercase();
 
-         match &self.sexpr {
-             SExpr::Cons(symb, ll) => {
-                 let symb_string = &*symb.name;
+            curr_pack.add_symbol(var_name.clone(),
+                                 RLEnvSymbol::new_var(var_name, var));
+        })
+    }
 
-                 println!("{:?}", symb_string);
-                 println!("");
+    // The message of err with a snippet of the source it refers to,
+    // the plain message if the location is unknown.
+    // The backtrace of err follows.
+    pub fn render_error(&self, err: &RLError) -> String {
+        let span = err.get_span().or(self.error_span.clone());
 
-                 if let Some(symb) = env_binding.get_symbol(
-                     &symb_string.to_string()) {
+        let mut out = match span.as_ref()
+                                .and_then(|span| {
+                                    self.parser.get_source(&span.file)
+                                }) {
+            Some(source) => err.render(span.as_ref().unwrap(), source),
 
-                         is_macro = symb.get_is_macro();
-                     }
+            None => err.to_string(),
+        };
 
-                 if contains_sexpr_cons(ll.clone()) {
-                     println!("contains a cons");
-                     println!("");
-                 }
-             }
+        let frames = err.get_backtrace();
 
-             _ => {}
-         }
+        if !frames.is_empty() {
+            out.push('\n');
+            out.push_str(&render_backtrace(&frames));
+        }
 
-         drop(env_binding);
-         drop(weak_binding);
+        out
+    }
 
-         if is_toplevel &&
-             is_macro {
+    // The function calls in progress, the innermost first.
+    pub fn backtrace(&self) -> Vec<RLFrame> {
+        self.call_frames
+            .iter()
+            .rev()
+            .map(|frame| {
+                RLFrame::new(&frame.name,
+                             frame.args
+                                  .iter()
+                                  .map(|arg| arg.to_string())
+                                  .collect(),
+                             frame.span.clone())
+            })
+            .collect()
+    }
 
-                 let mut linked_list = LinkedList::<SExpr>::new();
+    pub fn set_debugger(&mut self, debugger: Box<dyn RLDebugger>) {
+        self.debugger = Some(debugger);
+    }
 
-                 linked_list.push_front(eval_sexpr.clone());
+    // The restarts in effect, the innermost first.
+    pub fn compute_restarts(&self) -> Vec<RLRestart> {
+        self.restarts.iter().rev().cloned().collect()
+    }
 
-                 self.set_sexpr(SExpr::Cons(Symbol::new("eval"), linked_list));
-             }
+    // The unwind which transfers control to restart, the form which
+    // established it calls the restart with args.
+    pub fn invoke_restart(&self,
+                          restart: &RLRestart,
+                          args: LinkedList<SExpr>) -> RLUnwind {
 
+        rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                 "invoke-restart {} {}", restart, format_items(&args));
 
+        RLUnwind::Restart(restart.get_id(), restart.get_index(), args)
+    }
 
+    // Evaluates sexpr where the evaluation is suspended, e.g. in the
+    // debugger. The values of the suspended evaluation are kept, the
+    // location of an error of sexpr is kept for render_error.
+    pub fn eval_in_frame(&mut self, sexpr: &SExpr) ->
+        Result<RLResult, RLUnwind> {
 
-         self.zipper = RLZipper { sexpr: self.sexpr.clone(),
-             final_sexpr: self.sexpr.clone(),
-             depth_in_parent: 0,
-             index_in_parent: 0,
-             parent: None,
-             children: None {},
-         };
+        let values = self.values.take();
 
-         self.build_zipper(self.sexpr.clone());
+        self.error_span = None;
 
-         self.substitute_var_atoms();
+        let result = self.eval_sexpr(sexpr)
+                         .map(|res| match self.values.take() {
+                             Some(values) => RLResult::ValuesRes(values),
 
-         println!("self.sexpr: {:?}", self.sexpr);
-         println!("");
+                             None => RLResult::SExprRes(res),
+                         });
 
-         println!("self.error: {:?}", self.error);
-         println!("");
+        self.values = values;
 
-         println!("configure - end");
-         println!("");
-     }
+        result
+    }
 
-     pub fn configure_with_sexpr(&mut self, sexpr: SExpr) {
-         self.sexpr = self.build_sexpr_with_dyn_env(sexpr);
+    ///////////////////////////////////////////////////////////
+    /*
+    Evaluation of forms
+    */
+    ///////////////////////////////////////////////////////////
 
-         println!("configure_with_sexpr - start");
-         println!("");
+    fn eval_sexpr(&mut self, sexpr: &SExpr) -> Result<SExpr, RLUnwind> {
+        rltrace!(TraceCategory::Zipper, TraceLevel::Debug, "eval {}", sexpr);
 
-         println!("after bsexpr: {}", self.sexpr);
-         println!("");
+        self.values = None;
 
-         println!("self.zipper: {:?}", self.zipper);
-         println!("");
+        // an endless recursion is an error of the form which would
+        // overflow the stack
+        let result = if self.eval_depth >= EVAL_DEPTH_LIMIT {
+            Err(control_stack_exhausted())
+        } else {
+            self.eval_depth += 1;
 
-         self.zipper =  RLZipper { sexpr: self.sexpr.clone(),
-             // final_sexpr: self.zipper.final_sexpr.clone(),
-             final_sexpr: self.sexpr.clone(),
-             depth_in_parent: self.zipper.depth_in_parent,
-             index_in_parent: self.zipper.index_in_parent,
-             parent: self.zipper.parent.clone(),
-             children: self.zipper.children.clone(),
-         };
+            let result = stacker::maybe_grow(STACK_RED_ZONE,
+                                             STACK_SEGMENT_SIZE,
+                                             || self.eval_form(sexpr));
 
-         self.build_zipper(self.sexpr.clone());
+            self.eval_depth -= 1;
 
-         self.substitute_var_atoms();
+            result
+        };
 
-         println!("configure_with_sexpr - end");
-         println!("");
-     }
+        // an error of the form is signalled where it occurred, before
+        // the frames between the handlers and the form are left
+        let result = match result {
+            Err(RLUnwind::Error(err)) => Err(self.signal_error(err)),
 
-     pub fn configure_with_sexpr_macro(&mut self, sexpr: SExpr) {
-         self.sexpr = sexpr;
+            result => result,
+        };
 
-         println!("configure_with_sexpr_macro - start");
-         println!("");
+        if let Ok(ref value) = result {
+            rltrace!(TraceCategory::Zipper, TraceLevel::Debug,
+                     "{} => {}", sexpr, value);
+        }
+
+        // the innermost form with a known location is reported
+        if let Err(RLUnwind::Unhandled(_)) = result {
+            if self.error_span.is_none() {
+                self.error_span = sexpr.get_span();
+            }
+        }
 
-         println!("self.sexpr: {:?}", self.sexpr);
-         println!("");
+        result
+    }
 
-         println!("self.error: {:?}", self.error);
-         println!("");
+    fn eval_form(&mut self, sexpr: &SExpr) -> Result<SExpr, RLUnwind> {
+        match sexpr {
+            SExpr::Symb(symb) => self.eval_symbol(&symb.get_symbol_name()),
 
-         /*
-         self.zipper =  RLZipper { sexpr: self.sexpr.clone(),
-         final_sexpr: self.sexpr.clone(),
-         depth_in_parent: 0,
-         index_in_parent: 0,
-         parent: None,
-         children: None{},
-         };
-          */
+            SExpr::SList(slist) => self.eval_list(slist.get_items(),
+                                                  sexpr.get_span()),
 
-         self.zipper =  RLZipper { sexpr: self.sexpr.clone(),
-             // final_sexpr: self.zipper.final_sexpr.clone(),
-             final_sexpr: self.sexpr.clone(),
-             depth_in_parent: self.zipper.depth_in_parent,
-             index_in_parent: self.zipper.index_in_parent,
-             parent: self.zipper.parent.clone(),
-             children: self.zipper.children.clone(),
-         };
+            SExpr::SCons(cons) => {
+                let err = TypeError::new(&cons.cdr().to_string(), "LIST");
 
-         self.build_zipper(self.sexpr.clone());
+                Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
 
-         /*
-         self.substitute_var_atoms();
+            // numbers, strings, NIL and function objects evaluate
+            // to themselves
+            _ => Ok(sexpr.clone()),
+        }
+    }
 
-        println!("self.sexpr: {:?}", self.sexpr);
-        println!("");
+    fn eval_symbol(&mut self, name: &str) -> Result<SExpr, RLUnwind> {
+        if let Some(LocalDef::SymbolMacro(expansion)) =
+            self.get_local_def(name, false) {
 
-        println!("self.error: {:?}", self.error);
-        println!("");
-          */
+            rltrace!(TraceCategory::Macroexpand, TraceLevel::Info,
+                     "{} => {}", name.to_uppercase(), expansion);
 
-         println!("Configure__with_sexpr_macro - end");
-         println!("");
-     }
+            return self.eval_sexpr(&expansion)
+        }
 
+        if name.eq("t") || name.starts_with(':') {
+            return Ok(SExpr::Symb(RLSymbol::new_with_str(name)))
+        }
 
+        let env = self.env.clone();
+        let mut env_binding = env.borrow_mut();
 
+        if let Some(mut var) = env_binding.get_curr_eval_dyn_env_var(name) {
+            return Ok(var.var_to_sexpr())
+        }
 
+        let var_name = name.to_uppercase();
 
-     ///////////////////////////////////////////////////////////
-     /*
-     Build sexpr with dyn_env functions
-      */
-     ///////////////////////////////////////////////////////////
+        if let Some(mut var) = env_binding.get_symbol(&var_name)
+                                          .and_then(|symbol|
+                                              symbol.get_dyn_var_value()) {
+            return Ok(var.var_to_sexpr())
+        }
 
-     fn get_var_from_dyn_env(&self, atom: String) -> Result<RLVar, RLError> {
-         // realize env binding to environment
-         let rc_binding = self.env.clone();
+        drop(env_binding);
 
-         let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-             Rc::downgrade(&rc_binding);
+        let err = RLError::UnboundVariableError(
+            UnboundVariableError::new(&var_name));
 
-         let binding = weak_binding.upgrade().unwrap();
-         drop(rc_binding);
+        let condition = error_to_condition(&err);
 
-         let mut env_binding = binding.borrow_mut();
+        let restarts = vec![
+            ("continue".to_string(), format!("Retry using {}.", var_name), 0),
+            ("use-value".to_string(), "Use specified value.".to_string(), 1),
+            ("store-value".to_string(),
+             "Set specified value and use it.".to_string(), 1)];
 
-         if let Some(var) =
-            env_binding.get_curr_eval_dyn_env_var(&atom.to_uppercase()) {
+        // the signalling only returns by a restart
+        let restarted = self.with_restarts(restarts, |rl| {
+            Err::<(), RLUnwind>(rl.signal_error_condition(&condition, err))
+        })?;
 
-                Ok(var)
-            } else {
-                let err = UnboundVariableError::new(&atom.to_uppercase());
-                Err(RLError::UnboundVariableError(err))
+        match restarted {
+            Restarted::Invoked(1, mut args) => {
+                Ok(args.pop_front().unwrap_or(SExpr::Nil(RLNil::new())))
             }
-     }
-
-     fn build_children_sexpr_with_dyn_env(&mut self,
-         mut list: LinkedList<SExpr>) ->
-        LinkedList<SExpr> {
 
-            println!("Begin - bsexpr_children");
-            println!("");
+            Restarted::Invoked(2, mut args) => {
+                let value = args.pop_front()
+                                .unwrap_or(SExpr::Nil(RLNil::new()));
 
-            println!("list: {:?}", list);
-            println!("");
+                self.run_cl_function("setq", LinkedList::from([
+                    SExpr::Symb(RLSymbol::new_with_str(name)),
+                    value.clone()]))?;
 
-            let mut result_ll = LinkedList::<SExpr>::new();
+                Ok(value)
+            }
 
-            if contains_sexpr_cons(list.clone()) {
-                loop {
-                    match list.pop_front() {
-                        Some(SExpr::Cons(sym, ll)) => {
-                            println!("SEXpr::Cons {:?} - {:?}", sym, ll);
-                            println!("");
+            _ => self.eval_symbol(name),
+        }
+    }
 
-                            // linked_list = ll.clone();
-                            result_ll.push_back(
-                                SExpr::Cons(sym,
-                                    self.build_children_sexpr_with_dyn_env(ll)));
-                        }
+    // span is the location of the list, the location of the call in
+    // the backtrace
+    fn eval_list(&mut self,
+                 mut items: LinkedList<SExpr>,
+                 span: Option<Span>) ->
+        Result<SExpr, RLUnwind> {
 
-                        Some(SExpr::Atom(atom)) => {
-                            println!("bsexpr_children: SExpr::Atom {:?}", atom);
-                            println!("");
+        let head = match items.pop_front() {
+            Some(head) => head,
 
-                            if let Ok(mut var) = self.get_var_from_dyn_env(
-                                atom.to_string()) {
+            None => return Ok(SExpr::Nil(RLNil::new())),
+        };
 
-                                    println!("var: {:?}", var);
-                                    println!("");
+        match head {
+            SExpr::Symb(symb) => {
+                let name = symb.get_symbol_name();
 
-                                    println!("{}", var.var_to_sexpr());
-                                    println!("");
+                if let Some(result) = self.eval_special_form(&name, &items) {
+                    return result
+                }
 
-                                    result_ll.push_back(var.var_to_sexpr());
-                                } else {
-                                    result_ll.push_back(SExpr::Atom(atom));
-                                }
-                        }
+                match self.get_local_def(&name, true) {
+                    Some(LocalDef::Function(function)) => {
+                        let args = self.eval_args(&items)?;
 
-                        None => { break; }
+                        self.call_span = span;
 
-                        _ => todo!(),
+                        self.call_lambda(&function, Some(&name), args)
                     }
-                }
-                self.build_children_sexpr_with_dyn_env(result_ll.clone());
-            } else {
-                loop {
-                    match list.pop_front() {
-                        Some(SExpr::Atom(atom)) => {
-                            println!("bsexpr: SExpr::Atom {:?}", atom);
-                            println!("");
 
-                            if let Ok(mut var) = self.get_var_from_dyn_env(
-                                atom.to_string()) {
+                    Some(LocalDef::Macro(named_lambda)) => {
+                        let expansion = self.macroexpand(&named_lambda,
+                                                         &items)?;
 
-                                    println!("var: {:?}", var);
-                                    println!("");
+                        rltrace!(TraceCategory::Macroexpand, TraceLevel::Info,
+                                 "({} {}) => {}",
+                                 name.to_uppercase(),
+                                 format_items(&items),
+                                 expansion);
 
-                                    println!("{}", var.var_to_sexpr());
-                                    println!("");
+                        self.eval_sexpr(&expansion)
+                    }
 
-                                    result_ll.push_back(var.var_to_sexpr());
-                                } else {
-                                    result_ll.push_back(SExpr::Atom(atom));
-                                }
-                        }
+                    _ => self.eval_call(&name, items, span),
+                }
+            }
 
-                        Some(SExpr::Nil(nil)) => {
-                            result_ll.push_back(SExpr::Nil(nil));
-                        }
+            // ((lambda (x) ...) args)
+            SExpr::SList(_) => {
+                let function = self.eval_sexpr(&head)?;
 
-                        None => { break; }
+                if let SExpr::Lambda(_) = function {
+                    let args = self.eval_args(&items)?;
 
-                        _ => todo!(),
-                    }
+                    self.call_span = span;
+
+                    self.funcall(&function, args)
+                } else {
+                    Err(illegal_function_call(&head))
                 }
             }
-            println!("End - bsexpr_children");
-            println!("");
 
-            result_ll
+            _ => Err(illegal_function_call(&head)),
+        }
+    }
+
+    fn eval_args(&mut self, items: &LinkedList<SExpr>) ->
+        Result<LinkedList<SExpr>, RLUnwind> {
+
+        let mut args = LinkedList::<SExpr>::new();
+
+        for item in items {
+            args.push_back(self.eval_sexpr(item)?);
         }
 
-     #[allow(unused_assignments, unused_variables)]
-     fn build_sexpr_with_dyn_env(&mut self, sexpr: SExpr) -> SExpr {
+        Ok(args)
+    }
 
-         let mut result = SExpr::Nil(RLNil::new());
-         let mut result_ll = LinkedList::<SExpr>::new();
+    fn eval_progn(&mut self, forms: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-         let mut count = 0;
+        let mut result = SExpr::Nil(RLNil::new());
 
-         println!("bsexpr - sexpr: {}", sexpr);
-         println!("");
+        self.values = None;
 
-         match sexpr {
-             SExpr::Cons(symb, ll) => {
-                 println!("----------------------------------------------");
-                 println!("Begin - bsexpr: First SExpr::Cons {:?} -- {:?}",
-                     symb, ll);
-                 println!("");
+        for form in forms {
+            result = self.eval_sexpr(form)?;
+        }
 
-                 let mut s_list = ll.clone();
+        Ok(result)
+    }
 
-                 loop {
-                     match s_list.pop_front() {
-                         Some(SExpr::Cons(sym, list)) => {
-                             count = count + 1;
-                             println!("Child - {:?}", count);
+    // Special operators and the macros of the cl package which need
+    // the evaluator. Returns None if name is none of them.
+    fn eval_special_form(&mut self,
+                         name: &str,
+                         args: &LinkedList<SExpr>) ->
+        Option<Result<SExpr, RLUnwind>> {
+
+        let result = match name {
+            "quote" => {
+                if args.len() != 1 {
+                    Err(RLUnwind::Error(RLError::SimpleProgramError))
+                } else {
+                    Ok(args.front().unwrap().clone())
+                }
+            }
 
-                             println!("list: {:?}", list);
-                             println!("");
+            "backquote" => {
+                if args.len() != 1 {
+                    Err(RLUnwind::Error(RLError::SimpleProgramError))
+                } else {
+                    let result = self.backquote(args.front().unwrap(), 1);
 
-                             result_ll.push_back(
-                                 SExpr::Cons(sym,
-                                     self.build_children_sexpr_with_dyn_env(
-                                         list.clone())));
-                         }
+                    self.values = None;
 
-                         Some(SExpr::Atom(atom)) => {
-                             println!("bsexpr: SExpr::Atom {:?}", atom);
-                             println!("");
+                    result
+                }
+            }
 
-                             if let Ok(mut var) = self.get_var_from_dyn_env(
-                                 atom.to_string()) {
+            "comma" | "comma-at" => {
+                let err = SimpleError::new("Comma not inside a backquote.");
 
-                                     println!("var: {:?}", var);
-                                     println!("");
-
-                                     println!("{}", var.var_to_sexpr());
-                                     println!("");
+                Err(RLUnwind::Error(RLError::SimpleError(err)))
+            }
 
-                                     result_ll.push_back(var.var_to_sexpr());
-                                 } else {
-                                     result_ll.push_back(SExpr::Atom(atom))
-                                 };
-                         }
+            // forms handled by the cl package, their arguments
+            // are passed unevaluated
+            "and" | "block" | "cond" | "define-condition" | "defmacro" |
+            "defun" | "defvar" | "destructuring-bind" | "do" | "do*" |
+            "dolist" | "dotimes" | "flet" | "go" | "handler-bind" |
+            "handler-case" | "if" | "ignore-errors" | "labels" | "lambda" |
+            "let" | "let*" | "loop" | "macrolet" | "multiple-value-bind" |
+            "multiple-value-call" | "multiple-value-list" |
+            "multiple-value-prog1" | "nth-value" | "or" | "progn" |
+            "psetq" | "restart-case" | "return" | "return-from" |
+            "symbol-macrolet" | "tagbody" | "unless" | "unwind-protect" |
+            "when" | "with-simple-restart" => {
+                self.eval_cl_form(name, args.clone())
+            }
 
-                         Some(SExpr::SList(slist)) => {
-                             println!("bsexpr: SExpr::SList {:?}", slist);
-                             println!("");
+            // #'name of a local function is its lambda
+            "function" => {
+                let local_def = match args.front() {
+                    Some(SExpr::Symb(symb)) if args.len() == 1 => {
+                        let name = symb.get_symbol_name();
 
-                             let mut slist_ll = slist.get_linked_list();
+                        self.get_local_def(&name, true)
+                            .map(|local_def| (name, local_def))
+                    }
 
-                             let mut new_slist_ll = LinkedList::<SExpr>::new();
+                    _ => None,
+                };
 
-                             new_slist_ll.push_front(
-                                 SExpr::Symb(RLSymbol::new_with_str(")")));
+                match local_def {
+                    Some((_, LocalDef::Function(function))) => {
+                        Ok(SExpr::Lambda(function))
+                    }
 
-                             // remove ")"
-                             slist_ll.pop_back();
+                    Some((name, _)) => Err(undefined_function(&name)),
 
-                             loop {
-                                 if let Some(item) = slist_ll.pop_back() {
-                                     match item {
-                                         SExpr::Atom(atom) => {
-                                             if let Ok(mut var) =
-                                                self.get_var_from_dyn_env(
-                                                    atom.to_string()) {
+                    None => self.eval_cl_form(name, args.clone()),
+                }
+            }
 
-                                                        println!("var: {:?}", var);
-                                                        println!("");
+            "setq" => self.eval_setq(args),
 
-                                                        println!("{}", var.var_to_sexpr());
-                                                        println!("");
+            "#:loop-append" => self.eval_loop_append(args),
 
-                                                        new_slist_ll.push_front(
-                                                            var.var_to_sexpr());
+            "defparameter" => {
+                self.eval_some_args(args, 1, 1)
+                    .and_then(|args| self.eval_cl_form(name, args))
+            }
 
-                                                    } else {
-                                                        new_slist_ll.push_front(
-                                                            SExpr::Atom(atom))
-                                                    };
-                                         }
+            // the tag is evaluated, the forms are left to the block
+            "catch" | "throw" => {
+                self.eval_some_args(args, 0, 1)
+                    .and_then(|args| self.eval_cl_form(name, args))
+            }
 
-                                         SExpr::Symb(symb) => {
-                                             if slist_ll.is_empty() {
-                                                 new_slist_ll.push_front(
-                                                     SExpr::Symb(symb));
-                                                 break;
-                                             } else {
-                                                 new_slist_ll.push_front(
-                                                     SExpr::Symb(symb));
-                                             }
-                                         }
+            "progv" => {
+                self.eval_some_args(args, 0, 2)
+                    .and_then(|args| self.eval_cl_form(name, args))
+            }
 
-                                         _ => {
-                                             new_slist_ll.push_front(item);
-                                         }
-                                     }
-                                 }
-                             }
+            "eval" => {
+                if args.len() != 1 {
+                    Err(RLUnwind::Error(RLError::SimpleProgramError))
+                } else {
+                    self.eval_sexpr(args.front().unwrap())
+                        .and_then(|form| self.eval_sexpr(&form))
+                }
+            }
 
-                             let new_slist =
-                                 SExpr::SList(
-                                     RLList::<SExpr>::new_with_list(
-                                         &new_slist_ll));
-
-                             result_ll.push_back(new_slist);
-                         }
+            "funcall" => {
+                match self.eval_args(args) {
+                    Ok(mut args) => {
+                        match args.pop_front() {
+                            Some(function) => self.funcall(&function, args),
 
-                         Some(SExpr::SForm(form)) => {
-                             result_ll.push_back(SExpr::SForm(form))
-                         }
-
-                         Some(SExpr::SToken(token)) => {
-                             result_ll.push_back(SExpr::SToken(token))
-                         }
+                            None => Err(RLUnwind::Error(
+                                RLError::SimpleProgramError)),
+                        }
+                    }
 
-                         Some(SExpr::Symb(symb)) => {
-                             result_ll.push_back(SExpr::Symb(symb))
-                         }
+                    Err(unwind) => Err(unwind),
+                }
+            }
 
-                         None => {
-                             println!("End - bsexpr: End reached");
-                             println!("-----------------------------------");
-                             println!("");
+            _ => return None,
+        };
 
-                             result = SExpr::Cons(symb.clone(), result_ll);
-                             break;
-                         }
+        Some(result)
+    }
 
-                         t => {
-                             println!("bsexpr: t {:?}", t);
-                             println!("")
-                         }
-                     }
-                 }
-             }
-
-             SExpr::Atom(atom) => {
-                 let atom_string = atom.get_atom_string();
-
-                 if let Ok(mut var) = self.get_var_from_dyn_env(atom_string) {
-
-                     println!("var: {:?}", var);
-                     println!("");
-
-                     result = var.var_to_sexpr();
-                 } else {
-                     result = SExpr::Atom(atom.clone());
-                 }
-             }
-
-             SExpr::Nil(nil) => {
-                 result = SExpr::Nil(nil)
-             }
-
-             SExpr::SList(list) => {
-                 result = SExpr::SList(list)
-             }
-
-             SExpr::Symb(symb) => {
-                 result = SExpr::Symb(symb)
-             }
-
-             SExpr::QList(qlist) => {
-                 result = SExpr::QList(qlist)
-             }
-
-             /*
-             SExpr::Comma(atom, sexpr) => {
-             result = SExpr::Comma(atom, sexpr)
-             }
-              */
-
-             _ => todo!(),
-         }
-
-         result
-     }
-
-
-
-
-
-     ///////////////////////////////////////////////////////////
-     /*
-     Build zipper functions
-      */
-     ///////////////////////////////////////////////////////////
-
-     pub fn build_children(&mut self,
-         depth: usize,
-         mut list: LinkedList<SExpr>) {
-             println!("Begin - b_children {:?}", list);
-             println!("");
-
-             let current_depth = depth + 1;
-             let mut current_index: i32 = -1;
-
-             let mut linked_list = list.clone();
-
-             if contains_sexpr_cons(list.clone()) {
-                 loop {
-                     match list.pop_front() {
-                         Some(SExpr::Cons(sym, ll)) => {
-                             println!("SEXpr::Cons {:?} - {:?}", sym, ll);
-                             println!("");
-
-                             current_index = current_index + 1;
-
-                             println!("SEXpr::Cons current_index: {:?}",
-                                 current_index);
-                             println!("");
-
-                             linked_list = ll.clone();
-
-                             //initialize zipper.children
-                             println!("self.zipper.children == None");
-                             self.zipper.children =
-                            Some(Box::new(RLZipper {
-                                sexpr: SExpr::Cons(sym.clone(), ll.clone()),
-                                final_sexpr: self.zipper.final_sexpr.clone(),
-                                depth_in_parent: current_depth,
-                                index_in_parent: current_index as usize,
-
-                                parent: Some(Box::new(self.zipper.clone())),
-                                children: None }));
-                         }
-
-                         None => { break; }
-
-                         _ => { current_index = current_index + 1;
-                             println!("_ current_index: {:?}", current_index)
-                         }
-                     }
-                 }
-                 self.build_children(current_depth,
-                     linked_list);
-             } else {
-                 println!("End - b_children");
-                 println!("");
-
-                 return;
-             }
-         }
-
-     pub fn build_zipper(&mut self, sexpr: SExpr) {
-         let mut current_index = 0;
-         let mut count = 0;
-
-         match sexpr {
-             SExpr::Cons(symb, ll) => {
-                 println!("");
-                 println!("----------------------------------------------");
-                 println!("Begin - bz: First SExpr::Cons {:?} -- {:?}", symb, ll);
-                 println!("");
-
-                 let mut s_list = ll.clone();
-
-                 loop {
-                     match s_list.pop_front() {
-                         Some(SExpr::Cons(sym, list)) => {
-                             count = count + 1;
-                             println!("Child - {:?}", count);
-
-                             self.zipper = self.zipper.child(
-                                 SExpr::Cons(sym.clone(), list.clone()),
-                                 self.zipper.final_sexpr.clone(),
-                                 0,
-                                 current_index);
-                             // parent);
-
-                             println!("bz: self.children {:?}", self.zipper.children);
-                             println!("");
-
-                             current_index = current_index + 1;
-
-                             println!("bz: sexpr: {:?}", self.zipper.sexpr);
-                             println!("");
-
-                             println!("bz: final_sexpr: {:?}",
-                                 self.zipper.final_sexpr);
-                             println!("");
-
-                             println!("bz: index_in_parent: {:?}",
-                                 self.zipper.index_in_parent);
-                             println!("");
-
-                             // if list contains SExpr::Cons
-                             // go with functions which build up recursively
-                             // all childs
-                             self.build_children(0,
-                                 list.clone());
-                             println!("bz: self.zipper: {:?}", self.zipper);
-                             println!("");
-
-                             println!("bz: self.zipper.children: {:?}", self.zipper.children);
-                             println!("");
-                         }
-
-                         /*
-                         Some(SExpr::Atom(atom)) => {
-                         current_index = current_index + 1;
-                         println!("bz: SExpr::Atom {:?}", atom);
-                         println!("");
-
-                            let var = self.get_var_from_dyn_env(atom.to_string());
-                            println!("var: {:?}", var);
-                            println!("");
-                            }
-                          */
-
-                         None => {
-                             println!("End - bz: End reached");
-                             println!("-----------------------------------");
-                             println!("");
-                             break;
-                         }
-
-                         t => {
-                             current_index = current_index + 1;
-                             println!("bz: t {:?}", t);
-                             println!("")
-                         }
-                     }
-                 }
-             }
-
-             SExpr::Atom(atom) => {
-                 println!("evaluator::build_zipper - atom branch");
-                 println!("");
-
-                 self.zipper =
-                    self.zipper.child(SExpr::Atom(atom.clone()),
-                        self.zipper.final_sexpr.clone(),
-                        0,
-                        current_index);
-                 // parent);
-             }
-
-             SExpr::Nil(nil) => {
-                 println!("evaluator::build_zipper - nil branch");
-                 println!("");
-
-                 self.zipper =
-                    self.zipper.child(SExpr::Nil(nil),
-                        self.zipper.final_sexpr.clone(),
-                        0,
-                        current_index);
-                 // parent);
-             }
-
-             SExpr::Symb(symb) => {
-                 println!("evaluator::build_zipper - symb branch");
-                 println!("");
-
-                 self.zipper =
-                    self.zipper.child(SExpr::Symb(symb),
-                        self.zipper.final_sexpr.clone(),
-                        0,
-                        current_index);
-                 // parent);
-             }
-
-             /*
-             SExpr::QList(qlist) => {
-             println!("evaluator::build_zipper - qlist branch");
-             println!("");
-
-                self.zipper =
-                self.zipper.child(SExpr::QList(qlist),
-                self.zipper.final_sexpr.clone(),
-                0,
-                current_index);
-                // parent);
-                   }
-              */
-
-             SExpr::SList(slist) => {
-                 println!("evaluator::build_zipper - slist branch");
-                 println!("");
-
-                 self.zipper =
-                    self.zipper.child(SExpr::SList(slist),
-                        self.zipper.final_sexpr.clone(),
-                        0,
-                        current_index);
-                 // parent);
-             }
-             _ => unreachable!(),
-         }
-     }
-
-
-
-
-
-     ///////////////////////////////////////////////////////////
-     /*
-     Substitution of var exprs
-      */
-     ///////////////////////////////////////////////////////////
-
-     pub fn is_keyword(&self, keyword: &String) -> bool {
-         // realize env binding to environment
-         let rc_binding = self.env.clone();
-
-         let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-             Rc::downgrade(&rc_binding);
-
-         let binding = weak_binding.upgrade().unwrap();
-         drop(rc_binding);
-
-         let env_binding = binding.borrow_mut();
-
-         if env_binding.is_keyword(keyword.clone()) {
-             drop(env_binding);
-             return true;
-         } else {
-             drop(env_binding);
-             return false;
-         }
-     }
-
-     fn get_var_from_curr_package(&self, atom: String) ->
-        Result<RLResult, RLError> {
+    // (setq a 1 b a) assigns one pair after another, the value of the
+    // last form is returned. A symbol macro is replaced with its
+    // expansion, which must be a symbol.
+    fn eval_setq(&mut self, args: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-            // realize env binding to environment
-            let rc_binding = self.env.clone();
+        if !args.len().is_multiple_of(2) {
+            let err = SimpleError::new(&format!(
+                "Odd number of args to SETQ: {}",
+                format_items(args).to_uppercase()));
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+            return Err(RLUnwind::Error(RLError::SimpleError(err)))
+        }
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+        let mut value = SExpr::Nil(RLNil::new());
 
-            let mut env_binding = binding.borrow_mut();
+        let mut pairs = args.iter();
 
-            let curr_pack = env_binding.get_mut_current_package();
+        while let (Some(var), Some(form)) = (pairs.next(), pairs.next()) {
+            let mut var = var.clone();
 
-            if let Some(var_symb) = curr_pack.get_symbol(&atom.to_uppercase()) {
-                println!("var_symb: {:?}", var_symb);
-                println!("");
+            while let SExpr::Symb(ref symb) = var {
+                match self.get_local_def(&symb.get_symbol_name(), false) {
+                    Some(LocalDef::SymbolMacro(expansion)) => var = expansion,
 
-                let rl_dyn_var = var_symb.dyn_var.clone().unwrap();
+                    _ => break,
+                }
+            }
 
-                drop(env_binding);
-                drop(weak_binding);
+            // there are no generalized places to assign to a symbol
+            // macro which stands for a compound form
+            if let SExpr::SList(_) = var {
+                let err = SimpleError::new(&format!(
+                    "SETQ of symbol-macro place not supported: {}",
+                    var.to_string().to_uppercase()));
 
-                Ok(RLResult::VarRes(rl_dyn_var))
-            } else {
-                let err = UnboundVariableError::new(&atom.to_uppercase());
-                Err(RLError::UnboundVariableError(err))
+                return Err(RLUnwind::Error(RLError::SimpleError(err)))
             }
-        }
 
-     fn get_last_var_from_curr_package(&self, atom: String) ->
-        Result<RLResult, RLError> {
+            value = self.eval_sexpr(form)?;
 
-            let result: RLResult;
+            let mut pair = LinkedList::<SExpr>::new();
 
-            // realize env binding to environment
-            let rc_binding = self.env.clone();
+            pair.push_back(var.clone());
+            pair.push_back(value.clone());
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+            rltrace!(TraceCategory::Env, TraceLevel::Info,
+                     "setq {} = {}", var, value);
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+            self.run_cl_function("setq", pair)?;
+        }
 
-            let mut env_binding = binding.borrow_mut();
+        self.values = None;
 
-            let curr_pack = env_binding.get_mut_current_package();
+        Ok(value)
+    }
 
-            if let Some(var_symb) = curr_pack.get_symbol(&atom.to_uppercase()) {
-                println!("var_symb: {:?}", var_symb);
-                println!("");
+    // (#:loop-append var form) of the LOOP expansion appends the
+    // elements of the value of form to the list in var. The variable
+    // is set to NIL while the list is extended, which leaves the list
+    // to this function alone: it is changed in place instead of copied.
+    fn eval_loop_append(&mut self, args: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                let rl_dyn_var = var_symb.dyn_var.clone().unwrap();
+        let (var, form) = match (args.front(), args.back()) {
+            (Some(var), Some(form)) if args.len() == 2 => (var, form),
 
-                let rl_var = rl_dyn_var.get_var();
+            _ => return Err(RLUnwind::Error(RLError::SimpleProgramError)),
+        };
 
-                match rl_var {
-                    /*
-                    Some(RLVar::SAtomVar(SExpr::Func(atom))) => {
-                    let atom_string = atom.get_atom_string()
-                    .to_lowercase();
+        let mut elements = match self.eval_sexpr(form)? {
+            SExpr::Nil(_) => LinkedList::new(),
 
-                    if let Some(symbol) = curr_pack.get_symbol(&atom_string) {
+            SExpr::SList(slist) => slist.get_items(),
 
-                        let named_lambda = symbol.get_named_lambda()
-                        .expect("REASON");
+            other => {
+                let err = TypeError::new(&other.to_string(), "LIST");
 
-                        result = RLResult::FuncRes(named_lambda.clone());
+                return Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
+        };
 
-                    } else {
-                    let err = UnboundVariableError::new(
-                    &atom_string.to_uppercase());
+        let list = self.eval_sexpr(var)?;
 
-                        return Err(RLError::UnboundVariableError(err))
-                        }
-                        }
-                     */
+        self.run_cl_function("setq", LinkedList::from([
+            var.clone(),
+            SExpr::Nil(RLNil::new())]))?;
 
-                    Some(RLVar::SAtomVar(SExpr::Lambda(atom))) => {
-                        let atom_string = atom.get_atom_string();
+        let list = match list {
+            SExpr::Nil(_) => make_list(&elements),
 
-                        println!("we are here!!");
-                        println!("");
+            SExpr::SList(mut slist) => {
+                while let Some(element) = elements.pop_front() {
+                    slist.push_item(element);
+                }
 
-                        if let Some(lambda) = curr_pack.get_lambda(&atom_string) {
-                            result = RLResult::LambdaRes(lambda.clone());
+                SExpr::SList(slist)
+            }
 
-                        } else {
-                            let err = UnboundVariableError::new(
-                                &atom_string.to_uppercase());
+            other => {
+                let err = TypeError::new(&other.to_string(), "LIST");
 
-                            return Err(RLError::UnboundVariableError(err))
-                        }
-                    }
+                return Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
+        };
 
-                    _ => {
-                        result = RLResult::VarRes(rl_dyn_var);
-                    }
-                }
+        self.run_cl_function("setq", LinkedList::from([var.clone(),
+                                                       list.clone()]))?;
+
+        self.values = None;
+
+        Ok(list)
+    }
 
-                drop(env_binding);
-                drop(weak_binding);
+    // Evaluates count arguments beginning at start, the others are
+    // returned unevaluated.
+    fn eval_some_args(&mut self,
+                      args: &LinkedList<SExpr>,
+                      start: usize,
+                      count: usize) ->
+        Result<LinkedList<SExpr>, RLUnwind> {
 
-                Ok(result)
+        let mut result = LinkedList::<SExpr>::new();
 
+        for (idx, arg) in args.iter().enumerate() {
+            if idx >= start && idx < start + count {
+                result.push_back(self.eval_sexpr(arg)?);
             } else {
-                let err = UnboundVariableError::new(&atom.to_uppercase());
-                Err(RLError::UnboundVariableError(err))
+                result.push_back(arg.clone());
             }
         }
 
-     pub fn substitute_var_atoms(&mut self) {
-         println!("Begin - substitute_var_atoms");
-         println!("");
-
-         match &self.zipper.final_sexpr {
-             SExpr::Cons(symb, ll) => {
-                 let mut linked_list = ll.clone();
-                 let mut result_ll = LinkedList::<SExpr>::new();
-
-                 println!("Begin - substitute_var_atoms");
-                 println!("");
+        Ok(result)
+    }
 
-                 match &*symb.name {
-                     "backquote" |
-                    "defparameter" |
-                    "quote" => {
-                        self.zipper.final_sexpr = SExpr::Cons(symb.clone(),
-                            ll.clone());
-                    }
+    // The forms a function of the cl package hands back are evaluated
+    // in methods of their own, which keeps the frame of this one small:
+    // it is on the stack for every nested call.
+    fn eval_cl_form(&mut self,
+                    name: &str,
+                    args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                     _ => {
-                         for _n in 0..linked_list.len() {
-                             let item = linked_list.pop_front().unwrap();
+        let res = self.run_cl_function(name, args)?;
 
-                             println!("item: {:?}", item);
-                             println!("");
+        match res {
+            RLResult::BlockRes(block) => self.eval_block(&block),
 
-                             if let SExpr::Atom(ref atom) = item {
-                                 let atom_string = atom.get_atom_string();
+            RLResult::ReturnFromRes(block) => {
+                let value = self.eval_progn(block.get_ref_progn_ll())?;
 
-                                 if self.is_keyword(&atom_string) {
-                                     result_ll.push_back(item);
+                Err(RLUnwind::ReturnFrom(block.get_id(), Box::new(value)))
+            }
 
-                                     // number atom
-                                 } else if let Ok(_f64_atom) =
-                            atom_string.parse::<f64>() {
+            RLResult::TagbodyRes(block) => self.eval_tagbody(&block),
 
-                                result_ll.push_back(item);
+            RLResult::GoRes(id, tag) => Err(RLUnwind::Go(id, tag)),
 
-                                // parse atom string
-                            } else {
+            RLResult::UnwindProtectRes(protected_form, cleanup_forms) => {
+                self.eval_unwind_protect(&protected_form, &cleanup_forms)
+            }
 
-                                let string_atom =
-                                    atom_string.parse::<String>().unwrap();
+            RLResult::MultipleValueBindRes(vars, values_form, block) => {
+                self.eval_multiple_value_bind(vars, &values_form, block)
+            }
 
-                                // string atom
-                                if string_atom.starts_with("\"") &&
-                                    string_atom.ends_with("\"") {
+            RLResult::DestructuringBindRes(lambda_list, expression, block) => {
+                self.eval_destructuring_bind(&lambda_list, &expression,
+                                             &block)
+            }
 
-                                        result_ll.push_back(item);
-                                    } else {
-                                        // var atom
-                                        match self.get_var_from_curr_package(
-                                            atom_string) {
+            RLResult::MultipleValueCallRes(function_form, forms) => {
+                self.eval_multiple_value_call(&function_form, &forms)
+            }
 
-                                                Ok(RLResult::VarRes(mut var)) => {
+            RLResult::MultipleValueProg1Res(first_form, forms) => {
+                let values = self.eval_values(&first_form)?;
 
-                                                    println!("var: {:?}", var);
-                                                    println!("");
+                self.eval_progn(&forms)?;
 
-                                                    let sexpr: SExpr = var.var_to_sexpr();
+                Ok(self.return_values(values))
+            }
 
-                                                    result_ll.push_back(sexpr);
-                                                }
+            RLResult::HandlerCaseRes(form, clauses, no_error) => {
+                self.eval_handler_case(&form, &clauses, no_error)
+            }
 
-                                                Err(err) => {
-                                                    self.error = Some(err);
-                                                }
+            RLResult::HandlerBindRes(bindings, forms) => {
+                self.eval_handler_bind(bindings, &forms)
+            }
 
-                                                _ => unreachable!(),
-                                            }
-                                    }
-                            }
-                             } else if let SExpr::Symb(ref symb) = item {
-                                 println!("item is a symb");
-                                 println!("");
+            RLResult::RestartCaseRes(form, clauses) => {
+                self.eval_restart_case(&form, &clauses)
+            }
 
-                                 let symb_string = symb.get_symbol_name();
+            RLResult::IfRes(test_form, then_form, else_form) => {
+                let test = self.eval_sexpr(&test_form)?;
 
-                                 match self.get_var_from_curr_package(
-                                     symb_string) {
+                if let SExpr::Nil(_) = test {
+                    self.eval_sexpr(&else_form)
+                } else {
+                    self.eval_sexpr(&then_form)
+                }
+            }
 
-                                         Ok(RLResult::VarRes(mut var)) => {
+            RLResult::LambdaRes(lambda) => Ok(self.lambda_function(*lambda)),
 
-                                             println!("var: {:?}", var);
-                                             println!("");
+            RLResult::ExpansionRes(expansion) => {
+                rltrace!(TraceCategory::Macroexpand, TraceLevel::Info,
+                         "{} => {}", name.to_uppercase(), expansion);
 
-                                             let sexpr: SExpr = var.var_to_sexpr();
+                self.eval_sexpr(&expansion)
+            }
 
-                                             result_ll.push_back(sexpr);
-                                         }
+            res => self.cl_function_value(res),
+        }
+    }
 
-                                         Err(_err) => {
-                                             result_ll.push_back(item);
-                                         }
+    // A lambda sees the local definitions of the place where it is
+    // created.
+    fn lambda_function(&mut self, lambda: RLLambda) -> SExpr {
+        let group = FunctionGroup {
+            functions: vec![("lambda".to_string(), lambda)],
+            scope: self.visible_definitions(),
+            recursive: false,
+        };
 
-                                         _ => unreachable!(),
-                                     }
-                             } else {
-                                 result_ll.push_back(item);
-                             }
-                         } // for
+        self.values = None;
 
-                         self.zipper.final_sexpr = SExpr::Cons(symb.clone(),
-                             result_ll);
-                     }
-                 }
-             }
+        SExpr::Lambda(function_value(&Rc::new(group), 0))
+    }
 
-             SExpr::Atom(atom) => {
-                 let atom_string = atom.get_atom_string();
+    fn eval_unwind_protect(&mut self,
+                           protected_form: &SExpr,
+                           cleanup_forms: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                 if self.is_keyword(&atom_string) {
-                     // keyword atom
-                     self.sexpr = SExpr::Atom(atom.clone());
+        let result = self.eval_sexpr(protected_form);
 
-                 } else if let Ok(_f64_atom) = atom_string.parse::<f64>() {
-                     // number atom
-                     self.sexpr = SExpr::Atom(atom.clone());
+        rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                 "unwind-protect cleanup {}",
+                 format_items(cleanup_forms));
 
-                 } else {
-                     // string atom
-                     let string_atom = atom_string.parse::<String>().unwrap();
+        // an exit from the cleanup forms replaces the one in
+        // progress
+        let values = self.values.take();
 
-                     // rlstring atom
-                     if string_atom.starts_with("\"") &&
-                         string_atom.ends_with("\"") {
+        self.eval_progn(cleanup_forms)?;
 
-                             self.sexpr = SExpr::Atom(atom.clone());
-                         } else {
-                             match self.get_var_from_curr_package(atom_string) {
+        self.values = values;
 
-                                 Ok(RLResult::VarRes(mut var)) => {
-                                     println!("var: {:?}", var);
-                                     println!("");
+        result
+    }
 
-                                     let sexpr: SExpr = var.var_to_sexpr();
+    fn eval_multiple_value_bind(&mut self,
+                                vars: LinkedList<String>,
+                                values_form: &SExpr,
+                                mut block: RLBlock) ->
+        Result<SExpr, RLUnwind> {
 
-                                     self.sexpr = sexpr;
-                                 }
+        let mut values = self.eval_values(values_form)?;
 
-                                 Err(err) => {
-                                     self.error = Some(err);
-                                 }
-                                 _ => unreachable!(),
-                             }
-                         }
-                 }
-             }
+        for var in vars {
+            let value = values.pop_front()
+                              .unwrap_or(SExpr::Nil(RLNil::new()));
 
-             SExpr::Symb(symb) => {
-                 let symb_name = symb.get_symbol_name();
+            block.add_dyn_env_var(var.to_uppercase(),
+                                  RLVar::from_sexpr(value));
+        }
 
-                 match self.get_var_from_curr_package(symb_name.clone()) {
+        self.eval_block(&block)
+    }
 
-                     Ok(RLResult::VarRes(mut var)) => {
-                         println!("var: {:?}", var);
-                         println!("");
+    fn eval_destructuring_bind(&mut self,
+                               lambda_list: &RLOrdinaryLambdaList,
+                               expression: &SExpr,
+                               block: &RLBlock) ->
+        Result<SExpr, RLUnwind> {
 
-                         let sexpr: SExpr = var.var_to_sexpr();
+        let value = self.eval_sexpr(expression)?;
 
-                         self.sexpr = sexpr;
-                     }
+        let mut named_ds_bind = RLNamedDsBind::new();
 
-                     Err(err) => {
-                         self.error = Some(err);
-                     }
+        named_ds_bind.destructure(lambda_list, &value)?;
 
-                     _ => unreachable!(),
-                 }
-             }
+        let vars = named_ds_bind.get_dyn_env_block();
 
-             SExpr::Nil(_nil) => {}
+        self.push_dyn_env(&vars, true, &block.get_specials());
 
-             _ => todo!(),
-         }
+        let variables = vars.keys()
+                            .map(|name| (name.to_lowercase(),
+                                         LocalDef::Variable))
+                            .collect();
 
-         println!("End - substitute_var_atoms");
-         println!("");
-     }
+        self.lexical_scopes.push(Some(variables));
 
+        let result = self.bind_parameters(&named_ds_bind.get_bindings())
+                         .and_then(|_| self.eval_block(block));
 
+        self.lexical_scopes.pop();
 
+        self.pop_dyn_env();
 
+        result
+    }
 
-     ///////////////////////////////////////////////////////////
-     /*
-     eval functions
-      */
-     ///////////////////////////////////////////////////////////
+    fn eval_multiple_value_call(&mut self,
+                                function_form: &SExpr,
+                                forms: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-     fn eval_children(&mut self) ->
-        Result<RLResult, RLError> {
+        let function = self.eval_sexpr(function_form)?;
 
-            println!("begin - eval_children!");
-
-            match &self.zipper.children.clone().unwrap().sexpr {
-                SExpr::Cons(symb, _ll) => {
-                    println!("cons occurred");
-
-                    let children_sexpr = self.zipper
-                        .children
-                        .clone()
-                        .unwrap()
-                        .sexpr
-                        .clone();
-
-                    println!("eval_children - children_sexpr:");
-                    println!("{:?}", children_sexpr);
-                    println!("");
-
-                    let result = self.eval_rl_symbol(symb.clone(),
-                        children_sexpr);
-
-                    // if let Some(ref _parent) = self.zipper.parent {
-                    println!("parent is there");
-                    println!("");
-
-                    match result {
-                        Ok(RLResult::ExprRes(ref expr)) => {
-                            match expr {
-                                Expr::QExpr(qexpr) =>
-                                self.zipper.sexpr =
-                                    qexpr_to_sexpr(qexpr.clone()),
-
-                                Expr::SExpr(sexpr) =>
-                                self.zipper.sexpr =
-                                    sexpr.clone(),
-                            }
-                        }
+        let mut args = LinkedList::<SExpr>::new();
 
-                        Ok(RLResult::QExprRes(ref qexpr)) => {
-                            self.zipper.sexpr =
-                            qexpr_to_sexpr(qexpr.clone());
-                        }
+        for form in forms {
+            args.append(&mut self.eval_values(form)?);
+        }
 
-                        Ok(RLResult::BlockRes(ref _block)) =>
-                        todo!(),
+        rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                 "multiple-value-call ({} {})",
+                 function, format_items(&args));
 
-                        Ok(RLResult::SExprRes(ref sexpr)) =>
-                        self.zipper.sexpr = sexpr.clone(),
+        self.funcall(&function, args)
+    }
 
-                        Ok(RLResult::NilRes(ref nil)) =>
-                        self.zipper.sexpr = SExpr::Nil(nil.clone()),
+    fn eval_handler_case(&mut self,
+                         form: &SExpr,
+                         clauses: &[(SExpr, Option<String>, RLBlock)],
+                         no_error: Option<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                        Ok(RLResult::BoolRes(_bool)) =>
-                        todo!(),
+        self.exit_point_count += 1;
 
-                        Ok(RLResult::NumRes(f64)) =>
-                        self.zipper.sexpr =
-                            SExpr::Atom(RLAtom::new(&f64.to_string())),
+        let id = self.exit_point_count;
 
-                        Ok(RLResult::StrRes(ref str)) =>
-                        self.zipper.sexpr =
-                            SExpr::Atom(RLAtom::new(str)),
+        let handlers = clauses.iter()
+                              .enumerate()
+                              .map(|(clause, (typespec, _, _))| {
+                                  (typespec.clone(),
+                                   Handler::Case(id, clause))
+                              })
+                              .collect();
 
-                        Ok(RLResult::StringRes(ref rlstring)) =>
-                        self.zipper.sexpr =
-                            SExpr::Atom(RLAtom::new(&rlstring.get())),
+        let depth = self.handler_clusters.len();
 
-                        Ok(RLResult::EnvRes(_env)) =>
-                        todo!(),
+        self.handler_clusters.push(HandlerCluster { handlers });
 
-                        Ok(RLResult::FuncRes(ref func)) =>
-                        self.zipper.sexpr =
-                            SExpr::Atom(RLAtom::new(&func.get_name())),
+        let result = self.eval_values(form);
 
-                        Ok(RLResult::LambdaRes(ref lambda)) =>
-                        self.zipper.sexpr =
-                            SExpr::Lambda(RLAtom::new(&lambda.get_id())),
+        self.handler_clusters.truncate(depth);
 
-                        Ok(RLResult::SymbolRes(_symb)) =>
-                        todo!(),
+        match result {
+            Ok(values) => match no_error {
+                Some(lambda_form) => {
+                    let function = self.eval_sexpr(&lambda_form)?;
 
-                        Ok(RLResult::SymbRes(ref symb)) =>
-                        self.zipper.sexpr =
-                            SExpr::Symb(RLSymbol::new_with_symb(&symb)),
+                    self.funcall(&function, values)
+                }
 
-                        Ok(RLResult::VarRes(_var)) =>
-                        todo!(),
+                None => Ok(self.return_values(values)),
+            },
 
-                        Ok(RLResult::ReturnFromRes(_)) =>
-                        todo!(),
+            Err(RLUnwind::HandlerCase(case_id, clause, condition))
+                if case_id == id => {
 
-                        Ok(RLResult::NamedDsBindRes(_ds)) =>
-                        todo!(),
+                let (typespec, var, mut block) = clauses[clause].clone();
 
-                        Ok(RLResult::MacroRes(_func)) =>
-                        todo!(),
+                rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                         "handler-case {} {}", typespec, condition);
 
-                        Err(err) => { println!("errrrr: {:?}", err);
-                            unreachable!()
-                        }
-                    }
-                    // }
-                    println!("self.zipper.sexpr: {:?}", self.zipper.sexpr);
-                    println!("self.zipper.final_sexpr: {:?}",
-                        self.zipper.final_sexpr);
-                    println!("");
-
-                    self.zipper.parent_of_children();
-
-                    println!("self.zipper.sexpr: {:?}", self.zipper.sexpr);
-                    println!("self.zipper.final_sexpr: {:?}",
-                        self.zipper.final_sexpr);
-                    println!("");
-
-                    return self.eval();
-                    // return result;
-                    // result
+                if let Some(var) = var {
+                    block.add_dyn_env_var(var.to_uppercase(),
+                                          RLVar::from_sexpr(*condition));
                 }
-                _ => unreachable!(),
+
+                self.eval_block(&block)
             }
+
+            Err(unwind) => Err(unwind),
         }
+    }
+
+    fn eval_handler_bind(&mut self,
+                         bindings: Vec<(SExpr, SExpr)>,
+                         forms: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-     pub fn eval(&mut self) -> Result<RLResult, RLError> {
-         println!("");
-         println!("Begin - Eval");
-         println!("");
+        let mut handlers = Vec::<(SExpr, Handler)>::new();
 
-         println!("zipper: {:?}", self.zipper);
-         println!("");
+        for (typespec, handler_form) in bindings {
+            let function = self.eval_sexpr(&handler_form)?;
 
-         println!("zipper.sexpr: {:?}", self.zipper.sexpr);
-         println!("");
+            handlers.push((typespec, Handler::Function(function)));
+        }
 
-         println!("zipper.final_sexpr: {:?}", self.zipper.final_sexpr);
-         println!("");
+        let depth = self.handler_clusters.len();
 
-         // self.substitute_var_atoms();
+        self.handler_clusters.push(HandlerCluster { handlers });
 
-         // avoid deep evaluation of a certain sexpr
-         match &self.zipper.final_sexpr {
-             SExpr::Cons(symb, _ll) => {
-                 match &*symb.name {
-                     "backquote" |
-                    // "block" |
-                    // "progn" |
-                    // "defparameter" |
-                    "quote" => {
-                        return self.eval_rl_symbol(symb.clone(),
-                            self.zipper.final_sexpr.clone());
-                    }
+        let result = self.eval_progn(forms);
 
-                     // "block" |
-                     // "comma" |
-                     // "defparameter" |
-                     /*
-                     "defun" => {
-                     return self.eval_rl_symbol(symb.clone(),
-                     self.zipper.sexpr.clone());
-                     }
-                      */
-
-                     _ => { }
-                 }
-             }
-
-             _ => { }
-         }
-
-         // continue with deep evaluation...
-         if let Some(ref mut pr) = self.zipper.parent {
-
-             pr.final_sexpr = self.zipper.final_sexpr.clone();
-         }
-
-         if let Some(ref mut child) = self.zipper.children {
-             println!("prior - zipper.children.sexpr: {:?}", child.sexpr);
-             println!("");
-             println!("prior - zipper.children.final_sexpr: {:?}",
-                 child.final_sexpr);
-             println!("");
-
-             child.final_sexpr = self.zipper.final_sexpr.clone();
-
-             if let Some(ref mut pr) = child.parent {
-                 pr.sexpr = self.zipper.sexpr.clone();
-                 pr.final_sexpr = self.zipper.final_sexpr.clone();
-             }
-
-             println!("post - zipper.children.sexpr: {:?}", child.sexpr);
-             println!("");
-
-             println!("post - zipper.children.final_sexpr: {:?}",
-                 child.final_sexpr);
-             println!("");
-
-             println!("post - zipper.children.parent.sexpr: {:?}",
-                 child.parent
-                 .clone()
-                 .unwrap()
-                 .sexpr);
-             println!("");
-
-             println!("post - zipper.children.parent.final_sexpr: {:?}",
-                 child.parent
-                 .clone()
-                 .unwrap()
-                 .final_sexpr);
-             println!("");
-
-         } else {
-             println!("no zipper.children!");
-             println!("");
-         }
-
-         if let Some(_child) = &self.zipper.children {
-             // let _ = self.eval_children();
-             let _ = return self.eval_children();
-         }
-
-         if let Some(err) = &self.error {
-             println!("before error......");
-             return Err(err.clone());
-         } else if let None = self.zipper.parent {
-             match &self.zipper.final_sexpr {
-                 SExpr::Cons(symb, _ll) => {
-                     println!("no parent -> before symbol match");
-                     println!("");
-
-                     self.eval_rl_symbol(symb.clone(),
-                         self.zipper.final_sexpr.clone())
-                 }
-                 _ => panic!("no parent - last SExpr-option"),
-             }
-         } else {
-             match &self.zipper.sexpr {
-                 SExpr::Cons(symb, _ll) => {
-                     println!("parent -> before symbol match");
-                     println!("");
-
-                     let result = self.eval_rl_symbol(symb.clone(),
-                         self.zipper.sexpr.clone());
-
-                     if let Some(ref _parent) = self.zipper.parent {
-                         match result {
-                             Ok(RLResult::BlockRes(ref _block)) =>
-                                todo!(),
-
-                             Ok(RLResult::ExprRes(ref expr)) => {
-                                 match expr {
-                                     Expr::QExpr(qexpr) =>
-                                        self.zipper.sexpr =
-                                            qexpr_to_sexpr(qexpr.clone()),
-
-                                     Expr::SExpr(sexpr) =>
-                                        self.zipper.sexpr = sexpr.clone(),
-                                 }
-                             }
-
-                             Ok(RLResult::QExprRes(ref qexpr)) => {
-                                 println!("in QExprRes");
-                                 self.zipper.sexpr =
-                                    qexpr_to_sexpr(qexpr.clone());
-
-                                 println!("self.zipper.sexpr: {:?}",
-                                     self.zipper.sexpr);
-                             }
-
-                             Ok(RLResult::SExprRes(ref sexpr)) =>
-                                self.zipper.sexpr = sexpr.clone(),
-
-                             Ok(RLResult::NilRes(ref nil)) =>
-                                self.zipper.sexpr = SExpr::Nil(nil.clone()),
-
-                             Ok(RLResult::BoolRes(_bool)) =>
-                                todo!(),
-
-                             Ok(RLResult::NumRes(f64)) =>
-                                self.zipper.sexpr =
-                                    SExpr::Atom(RLAtom::new(&f64.to_string())),
-
-                             Ok(RLResult::StrRes(ref str)) =>
-                                self.zipper.sexpr =
-                                    SExpr::Atom(RLAtom::new(str)),
-
-                             Ok(RLResult::StringRes(ref rlstring)) =>
-                                self.zipper.sexpr =
-                                    SExpr::Atom(RLAtom::new(&rlstring.get())),
-
-                             Ok(RLResult::EnvRes(_env)) =>
-                                todo!(),
-
-                             Ok(RLResult::FuncRes(ref func)) => {
-                                 if let Some(lambda) = func.get_lambda() {
-                                     self.zipper.sexpr =
-                                        SExpr::Lambda(
-                                            RLAtom::new(&lambda.get_id()));
-                                 } else {
-                                     self.zipper.sexpr =
-                                        SExpr::Atom(
-                                            RLAtom::new(&func.get_name()));
-                                 }
-                                 /*
-                                 SExpr::Symb(
-                                 RLSymbol::new_with_str(
-                                 &func.get_name()));
-                                  */
-                             }
-
-                             Ok(RLResult::LambdaRes(ref lambda)) => {
-                                 println!("in LambdaRes");
-                                 println!("");
-
-                                 self.zipper.sexpr =
-                                    /*
-                                    SExpr::Atom(
-                                    RLAtom::new(
-                                    &lambda.get_id())),
-                                     */
-
-                                    SExpr::Lambda(
-                                        RLAtom::new(
-                                            &lambda.get_id()))
-                             }
-
-                             Ok(RLResult::SymbolRes(_symb)) =>
-                                todo!(),
-
-                             Ok(RLResult::SymbRes(ref symb)) =>
-                                self.zipper.sexpr =
-                                    SExpr::Symb(RLSymbol::new_with_symb(&symb)),
-
-                             Ok(RLResult::VarRes(_var)) =>
-                                todo!(),
-
-                             Ok(RLResult::ReturnFromRes(_)) =>
-                                todo!(),
-
-                             Ok(RLResult::NamedDsBindRes(_ds)) =>
-                                todo!(),
-
-                             Ok(RLResult::MacroRes(_func)) =>
-                                todo!(),
-
-                             Err(ref err) => {
-                                 println!("err: {:?}", err);
-                                 println!("");
-                             }
-                         }
-                     }
-                     self.zipper.parent();
-                     return self.eval();
-                 }
-
-                 SExpr::Atom(atom) => {
-                     println!("atom-atom");
-                     return Ok(RLResult::SExprRes(SExpr::Atom(atom.clone())))
-                 }
-
-                 SExpr::Nil(nil) => {
-                     println!("nil-nil");
-                     return Ok(RLResult::NilRes(nil.clone()))
-                 }
-
-                 SExpr::Symb(symb) => {
-                     println!("symb-symb");
-                     if self.macro_mode {
-                         Ok(RLResult::SymbRes(symb.get_symbol()))
-                     } else {
-                        
-                         println!("bla");
-                         let item =
-                             self.get_last_var_from_curr_package(
-                                 symb.to_string());
-
-                         println!("item: {:?}", item);
-                         println!("");
-                         item
-                        
-                     }
-                 }
-
-                 /*
-                 SExpr::QList(qlist) => {
-                 println!("qlist-qlist");
-                 return Ok(RLResult::SExprRes(SExpr::QList(qlist.clone())))
-                 }
-                  */
-
-                 SExpr::SList(slist) => {
-                     println!("slist-qlist");
-                     // return Ok(RLResult::SExprRes(
-                     // SExpr::SList(slist.clone())))
-                     return Ok(RLResult::QExprRes(
-                         sexpr_to_qexpr(SExpr::SList(slist.clone()))))
-                 }
-
-                 _ => unreachable!(),
-             }
-         }
-         // }
-     }
-
-     pub fn run_closure(&mut self,
-         sexpr: SExpr,
-         symbol: &mut RLEnvSymbol) ->
-        Result<RLResult, RLError> {
+        self.handler_clusters.truncate(depth);
 
-            let mut result: Result<RLResult, RLError> =
-                Ok(RLResult::NilRes(RLNil::new()));
+        result
+    }
 
-            let mut named_lambda = symbol.named_lambda.clone().unwrap();
+    // the reports are formatted when the restarts are established
+    fn eval_restart_case(&mut self,
+                         form: &SExpr,
+                         clauses: &[RLRestartClause]) ->
+        Result<SExpr, RLUnwind> {
 
-            // second env binding to deliver env as a parameter
-            // of the RLSymbol/RLNamedLambda closure
-            let rc_binding = self.env.clone();
+        let mut restarts = Vec::<(String, String, usize)>::new();
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+        for clause in clauses {
+            let report = self.restart_report(&clause.report)?;
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+            restarts.push((clause.name.clone(), report, clause.arity));
+        }
 
-            let mut env_binding = binding.borrow_mut();
+        match self.with_restarts(restarts, |rl| rl.eval_values(form))? {
+            Restarted::Returned(values) => Ok(self.return_values(values)),
 
-            // env_binding.run_closure(sexpr, closure)
-            // env_binding.run_closure_rl_symbol(sexpr, symbol)
+            Restarted::Invoked(index, args) => {
+                let clause = &clauses[index];
 
-            if let Some(_closure) = named_lambda.closure {
-                println!("in if let sexpr...");
+                rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                         "restart-case {} {}",
+                         clause.name.to_uppercase(),
+                         format_items(&args));
 
-                result = match named_lambda.closure {
-                    Some(ref mut f)  => f(sexpr),
-                    _ => panic!("Closure not found."),
-                };
+                let function = self.eval_sexpr(&clause.function)?;
 
-            } else if let Some(_closure) = named_lambda.closure_env {
-                // result = match named_lambda.closure_env {
-                result = env_binding.run_closure_rl_symbol(sexpr, symbol);
-                /*
-                {
-                Some(ref mut f)  => f(sexpr, env_binding),
-                _ => panic!("Closure_env not found."),
-                };
-                 */
+                self.funcall(&function, args)
             }
+        }
+    }
 
-            println!("before end, run_closure...");
-            println!("");
+    // Runs the closure of a function of the cl package, the args
+    // are passed as they are.
+    fn run_cl_function(&mut self,
+                       name: &str,
+                       args: LinkedList<SExpr>) ->
+        Result<RLResult, RLError> {
 
-            match result {
-                Ok(res)  => {
-                    if let env::result::RLResult::SExprRes(sexpr) = res {
-                        println!("run_closure - SExprRes");
-                        println!("");
+        let env = self.env.clone();
+        let mut env_binding = env.borrow_mut();
 
-                        drop(env_binding);
+        let named_lambda = match env_binding.get_cl_symbol(&name.to_string())
+                                            .and_then(|symbol| {
+                                                symbol.named_lambda.clone()
+                                            }) {
+            Some(named_lambda) => named_lambda,
 
-                        // self.configure_with_sexpr(sexpr.clone());
-                        // self.eval()
+            None => {
+                let err = UndefinedFuncError::new(&name.to_uppercase());
 
-                        match sexpr {
-                            SExpr::SForm(ref _sform) => {
-                                self.eval_sform(sexpr)
-                            }
+                return Err(RLError::UndefinedFuncError(err))
+            }
+        };
 
-                            _ => {
-                                self.configure_with_sexpr(sexpr.clone());
-                                self.eval()
-                            }
-                        }
+        let sexpr = SExpr::Cons(Symbol::new(name), args);
 
-                        /*
-                        } else if let env::result::RLResult::QExprRes(qexpr) = res {
-                        println!("TTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTTT");
-                        println!("");
+        env_binding.run_closure_named_lambda(sexpr, &named_lambda)
+    }
 
-                    drop(env_binding);
+    // Evaluates the forms of block as an implicit progn. The variables
+    // of the block and its LET bindings are added to the current
+    // dynamic environment, outer bindings of the same names are
+    // shadowed until the block is left.
+    fn eval_block(&mut self, block: &RLBlock) -> Result<SExpr, RLUnwind> {
+        let mut block = block.clone();
 
-                    println!("qexpr: {:?}", qexpr);
-                    println!("");
+        let mut block_vars = block.get_dyn_env_block();
 
-                    let sexpr = qexpr_to_sexpr(qexpr);
-                    println!("new_sexpr: {:?}", sexpr );
-                    println!("");
+        let bindings = block.get_let_bindings();
 
-                    self.configure_with_sexpr(sexpr);
+        let sequential = block.has_sequential_bindings();
 
-                    self.eval()
-                         */
+        // LET evaluates all init forms before any variable is bound
+        if !sequential {
+            for (name, init_form) in &bindings {
+                let value = self.eval_sexpr(init_form)?;
 
-                    } else {
-                        return Ok(res)
-                    }
-                }
-                Err(err) => return Err(err),
+                block_vars.insert(name.to_uppercase(),
+                                  RLVar::from_sexpr(value));
             }
         }
 
-     // symbol evaluation without hard-coded-symbols of example-functions
-     pub fn eval_rl_symbol(&mut self,
-         symb: Symbol,
-         sexpr: SExpr) ->
-        Result<RLResult, RLError> {
-
-            println!("Begin eval_rl_symbol");
-            println!("");
-
-            println!("symb: {:?}", symb);
-            println!("");
-
-            println!("sexpr: {:?}", sexpr);
-            println!("");
-
-            // realize env binding to get the appropriate RLSymbol
-            let rc_binding = self.env.clone();
-
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
-
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
-
-            let mut env_binding = binding.borrow_mut();
-
-            /*
-            let next_env_binding = &mut *env_binding;
-            let cl_user_pack = next_env_binding.get_mut_cl_user_package();
-            println!("pack name is: {}", cl_user_pack.get_name());
-             */
-
-            /*
-            println!("strong count: {}",
-            Rc::strong_count(rc_binding));
-            println!("weak count: {}",
-            Rc::weak_count(rc_binding));
-            println!("");
-             */
-
-            // println!("{:?}", env_binding.lex_env);
-            // println!("");
-
-            let mut symbol = env_binding.get_symbol(&symb.name)
-                .unwrap()
-                .clone();
-
-            let home_pack = symbol.get_pack_name();
-            println!("home_pack: {}", home_pack);
-            println!("");
-
-            // let named_lambda = symbol.get_named_lambda();
-
-            /*
-            let closure_binding =
-            <Option<Box<for<'a> fn(expr::sexpr::SExpr,
-            &'a mut RLEnvironment) ->
-            Result<SimpleResult, RLError>>> as Clone>::clone(
-            &env_binding.get_symbol(&symb.name).unwrap()
-            .named_lambda
-            .as_mut()
-            .unwrap()
-            .closure_env);
-            // .unwrap().clone();
-             */
-
-            /*
-            let closure_binding =
-            <Option<for<'a>fn(expr::sexpr::SExpr,
-            &'a mut RLEnvironment) ->
-            Result<SimpleResult, RLError>>>::clone(
-            &env_binding.get_symbol(&symb.name).unwrap()
-            .named_lambda
-            .as_mut()
-            .unwrap()
-            .closure_env);
-
-        let closure = closure_binding.unwrap();
-             */
+        self.push_dyn_env(&block_vars, true, &block.get_specials());
 
-            drop(env_binding);
-            drop(weak_binding);
+        // variables of LET* are added as they are bound
+        let mut variables = block_vars.keys().cloned().collect::<Vec<String>>();
 
-            if let None = symbol.named_lambda {
-                let err = UndefinedFuncError::new(&symb.name);
-                return Err(RLError::UndefinedFuncError(err))
-            } else {
-                // match env_binding.run_closure_rl_symbol(sexpr, symbol) {
-                match self.run_closure(sexpr, &mut symbol) {
-                    Ok(res)  => {
-                        match res {
-                            /*
-                            RLResult::StrRes(ref str) => {
-                            println!("in RLResult::StrRes");
-                            println!("");
-
-                            return Ok(RLResult::StrRes(str.to_string()))
-                            }
-                             */
-
-                            RLResult::BlockRes(ref block) => {
-                                self.eval_block(block.clone())
-                            }
-
-                            RLResult::ReturnFromRes(ref block) => {
-                                // self.push_chain_block(block.clone());
-                                self.eval_return_from(block.clone())
-                            }
-
-                            RLResult::FuncRes(ref func) => {
-                                if symb.name.eq("funcall") {
-                                    self.eval_func(func.clone())
-
-                                } else {
-                                    if home_pack.eq("COMMON-LISP") {
-                                        return Ok(res)
-                                    } else {
-                                        if let None = func.get_block() {
-                                            Ok(RLResult::FuncRes(func.clone()))
-                                        } else {
-                                            self.eval_func(func.clone())
-                                        }
-                                    }
-                                }
-                            }
-
-                            RLResult::LambdaRes(ref lambda) => {
-                                /*
-                                if symb.name.eq("function") |
-                                symb.name.eq("lambda") {
-                                return Ok(RLResult::LambdaRes(lambda.clone()))
-                                } else {
-                                self.eval_lambda(lambda.clone())
-                                }
-                                 */
-
-                                if symb.name.eq("funcall") {
-                                    self.eval_lambda(lambda.clone())
-
-                                } else {
-                                    println!("LambdaRes - Other");
-                                    println!("");
-
-                                    return Ok(RLResult::LambdaRes(lambda.clone()))
-                                }
-                            }
-
-                            RLResult::NamedDsBindRes(ref block) => {
-                                println!("self_macro_mode: {:?}", self.macro_mode);
-                                println!("");
-
-                                if self.macro_mode.not() {
-                                    self.eval_ds(block.clone())
-                                } else {
-                                    Ok(RLResult::NamedDsBindRes(block.clone()))
-                                }
-                            }
-
-                            RLResult::MacroRes(ref func) => {
-                                if home_pack.eq("COMMON-LISP") {
-                                    return Ok(res)
-                                } else {
-                                    println!("func: {:?}", func);
-                                    println!("");
-
-                                    self.eval_macro(func.clone())
-                                }
-                            }
-
-                            _ => {
-                                match &*symb.name {
-                                    "quote" => {
-                                        match res {
-                                            RLResult::ExprRes(Expr::QExpr(qexpr)) =>
-                                        return Ok(RLResult::QExprRes(qexpr)),
-
-                                            RLResult::ExprRes(Expr::SExpr(sexpr)) =>
-                                        return Ok(RLResult::SExprRes(sexpr)),
-
-                                            _ => unreachable!(),
-                                        }
-                                    }
-
-                                    _ => {
-                                        return Ok(res)
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    Err(err) => return Err(err),
-                }
-            }
+        if sequential {
+            variables.retain(|name| {
+                !bindings.iter().any(|(binding, _)| binding.eq_ignore_ascii_case(name))
+            });
         }
 
+        self.push_lexical_scope(&block, &variables);
 
+        self.with_env(|env| env.block_chain_push(&block));
 
+        let result = if sequential {
+            self.bind_sequentially(&bindings)
+                .and_then(|_| self.eval_progn(block.get_ref_progn_ll()))
+        } else {
+            self.eval_progn(block.get_ref_progn_ll())
+        };
 
+        self.with_env(|env| env.block_chain_pop());
 
-     ///////////////////////////////////////////////////////////
-     /*
-     Eval RLResult-object functions
-      */
-     ///////////////////////////////////////////////////////////
+        self.lexical_scopes.pop();
 
-     fn search_for_return_from(&self, mut progn_ll: LinkedList<SExpr>) ->
-        String {
+        self.pop_dyn_env();
 
-            let mut result = "".to_string();
+        catch_return_from(&block, result)
+    }
 
-            for _n in 0..progn_ll.len() {
-                let form = progn_ll.pop_front().unwrap();
+    // Evaluates the statements of a TAGBODY. A GO to one of its tags
+    // continues with the statement following the tag.
+    fn eval_tagbody(&mut self, block: &RLBlock) -> Result<SExpr, RLUnwind> {
+        let statements = block.get_ref_progn_ll()
+                              .iter()
+                              .collect::<Vec<&SExpr>>();
 
-                match form {
-                    SExpr::SForm(mut sform) => {
-                        if sform.is_empty().not() {
-                            println!("sform is not empty");
-                            println!("sform: {:?}", sform);
-                            println!("");
+        self.with_env(|env| env.block_chain_push(block));
 
-                            for _n in 0..sform.len() {
-                                if let Some(Token::Symb(symb)) =
-                                sform.pop() {
+        let mut position = 0;
 
-                                    match &*symb.name {
-                                        "return-from" => {
-                                            println!("in return_from");
-                                            println!("sform: {:?}", sform);
-                                            println!("");
+        let result = loop {
+            let statement = match statements.get(position) {
+                Some(statement) => statement,
 
-                                            if let Some(Token::Atom(atom)) =
-                                            sform.pop() {
+                None => break Ok(SExpr::Nil(RLNil::new())),
+            };
 
-                                                result = atom.clone()
-                                                    .to_uppercase();
-                                            }
-                                        }
+            position += 1;
 
-                                        &_ => {}
-                                    }
-                                }
-                            }
+            match self.eval_sexpr(statement) {
+                Ok(_) => {}
 
-                        } else {}
-                    }
+                Err(RLUnwind::Go(id, tag)) if id.eq(&block.get_id()) => {
+                    rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                             "go {}", tag.to_uppercase());
 
-                    _ => {}
+                    position = block.get_tag_position(&tag).unwrap();
                 }
+
+                Err(unwind) => break Err(unwind),
             }
+        };
 
-            result
-        }
+        self.with_env(|env| env.block_chain_pop());
 
-     fn eval_sform(&mut self, sexpr: SExpr) -> Result<RLResult, RLError> {
-         println!("Begin eval_sform");
-         println!("");
+        self.values = None;
 
-         // let mut result = RLResult::NilRes(RLNil::new());
+        result
+    }
 
-         if let SExpr::SForm(form) = sexpr {
-             self.parser.change_tokens(&form);
+    // LET* binds every variable before the next init form is evaluated.
+    fn bind_sequentially(&mut self,
+                         bindings: &LinkedList<(String, SExpr)>) ->
+        Result<(), RLUnwind> {
 
-             let parse_result = self.parser.parse_to_sexpr();
+        for (name, init_form) in bindings {
+            let var = RLVar::from_sexpr(self.eval_sexpr(init_form)?);
 
-             println!("parse_result: {:?}", parse_result);
-             println!("");
+            self.bind_variable(name, var);
+        }
 
-             println!("self.sexpr: {:?}", self.sexpr);
-             println!("");
+        Ok(())
+    }
 
-             match parse_result {
-                 Ok(ref res)  => {
-                     self.parser.set_sexpr(res.clone());
-                 }
+    // The parameters following the required ones, in order. A pattern
+    // destructures the value of its form.
+    fn bind_parameters(&mut self, bindings: &LinkedList<RLBinding>) ->
+        Result<(), RLUnwind> {
 
-                 Err(ref err) => {
-                     self.parser.set_error(err.clone());
-                 }
-             }
+        for binding in bindings {
+            match binding {
+                RLBinding::Var(name, form) => {
+                    let var = RLVar::from_sexpr(self.eval_sexpr(form)?);
 
-             if self.macro_mode {
-                 println!("Switch to configure_with_sexpr_macro");
-                 println!("");
-                 self.configure_with_sexpr_macro(parse_result?);
-             } else {
-                 self.configure_with_sexpr(parse_result?);
-             }
+                    self.bind_variable(name, var);
+                }
 
-             println!("End eval_sform");
-             println!("");
+                RLBinding::Pattern(pattern, form) => {
+                    let value = self.eval_sexpr(form)?;
 
-             match self.eval() {
-                 Ok(res) => Ok(res),
+                    let mut named_ds_bind = RLNamedDsBind::new();
 
-                 Err(err) => return Err(err),
-             }
+                    named_ds_bind.destructure(pattern, &value)?;
 
-         } else {
-             return Err(RLError::SimpleProgramError);
-         }
-     }
+                    for (name, var) in named_ds_bind.get_dyn_env_block() {
+                        self.bind_variable(&name, var);
+                    }
 
-     // fn eval_block(&mut self, mut block: RLBlock, macro_mode: bool) ->
-     fn eval_block(&mut self, mut block: RLBlock) ->
-        Result<RLResult, RLError> {
+                    self.bind_parameters(&named_ds_bind.get_bindings())?;
+                }
+            }
+        }
 
-            println!("Begin eval_block");
-            println!("");
+        Ok(())
+    }
 
-            println!("macro_mode is: {:?}", self.macro_mode);
-            println!("");
+    // Adds a variable to the current frame, a special variable is
+    // bound dynamically.
+    fn bind_variable(&mut self, name: &str, var: RLVar) {
+        rltrace!(TraceCategory::Env, TraceLevel::Info,
+                 "bind {} = {}", name.to_uppercase(), var);
 
-            println!("BLOCK_YY: {:?}", block);
-            println!("");
+        let declared = self.special_frames
+                           .last()
+                           .map(|frame| frame.declared.clone())
+                           .unwrap_or_default();
 
-            // realize env binding
-            let rc_binding = self.env.clone();
+        if self.is_special(name, &declared) {
+            self.with_env(|env| env.remove_curr_eval_dyn_env_var(name));
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+            self.bind_special(name, var);
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+            return
+        }
 
-            let mut env_binding = binding.borrow_mut();
+        self.with_env(|env| env.add_curr_eval_dyn_env_var(name.to_string(),
+                                                          var));
 
-            env_binding.block_chain_push(&block);
+        if let Some(Some(names)) = self.frame_names.last_mut() {
+            names.push(name.to_uppercase());
+        }
 
-            println!("BLOCK - replace_curr_eval_dyn_env");
-            println!("");
+        if let Some(Some(scope)) = self.lexical_scopes.last_mut() {
+            scope.push((name.to_lowercase(), LocalDef::Variable));
+        }
+    }
 
-            let hash_map = block.get_dyn_env_block();
-            env_binding.replace_curr_eval_dyn_env(&hash_map);
+    ///////////////////////////////////////////////////////////
+    /*
+    Multiple values
+    */
+    ///////////////////////////////////////////////////////////
 
-            let mut progn_ll = block.get_progn_ll();
+    // All values of form, a form which doesn't return multiple values
+    // returns its value.
+    fn eval_values(&mut self, form: &SExpr) ->
+        Result<LinkedList<SExpr>, RLUnwind> {
 
-            println!("block progn_ll: {:?}", progn_ll);
-            println!("");
+        let value = self.eval_sexpr(form)?;
 
-            let block_name_of_return_from =
-                self.search_for_return_from(progn_ll.clone());
+        Ok(self.values
+               .take()
+               .unwrap_or_else(|| LinkedList::from([value])))
+    }
 
-            println!("block_name_of_return_from: {:?}",
-                block_name_of_return_from);
-            println!("");
+    // Makes values the values of the current form and returns the
+    // primary value, NIL for no values.
+    fn return_values(&mut self, values: LinkedList<SExpr>) -> SExpr {
+        let primary = values.front()
+                            .cloned()
+                            .unwrap_or(SExpr::Nil(RLNil::new()));
 
-            let mut block_chain_retained = env_binding.block_chain_retain(
-                &block_name_of_return_from);
+        self.values = Some(values);
 
-            println!("RETAINED_block_chain: {:?}", block_chain_retained);
-            println!("");
+        primary
+    }
 
-            if block_chain_retained.len() == 1 {
-                let mut last_block = env_binding.block_chain_pop().unwrap();
+    // The value of a function of the cl package, VALUES and FLOOR
+    // return multiple values.
+    fn cl_function_value(&mut self, res: RLResult) -> Result<SExpr, RLUnwind> {
+        match res {
+            RLResult::ValuesRes(values) => Ok(self.return_values(values)),
 
-                if last_block.get_name().eq(&block_name_of_return_from) {
-                    last_block.set_return_from(true)
-                }
+            res => {
+                self.values = None;
 
-                env_binding.block_chain_push(&last_block);
+                Ok(RLResult::get_sexpr_from_res(res)?)
             }
+        }
+    }
 
-            if block_chain_retained.len() > 1 {
-                let id_last_block_retained = block_chain_retained.pop()
-                    .unwrap()
-                    .get_id();
+    ///////////////////////////////////////////////////////////
+    /*
+    Function calls and macro expansion
+    */
+    ///////////////////////////////////////////////////////////
 
-                let block_chain = env_binding.get_ref_mut_block_chain();
+    fn eval_call(&mut self,
+                 name: &str,
+                 args: LinkedList<SExpr>,
+                 span: Option<Span>) ->
+        Result<SExpr, RLUnwind> {
 
-                block_chain.retain_mut(
-                    |x| if x.get_id().eq(&id_last_block_retained) &&
-                        x.get_name().eq(&block_name_of_return_from) {
+        let named_lambda = self.get_named_lambda(name)?;
 
-                            x.set_return_from(true);
-                            true
-                        } else {
-                            true
-                        });
+        if named_lambda.get_is_macro() {
+            let expansion = self.macroexpand(&named_lambda, &args)?;
 
-                for _n in 0..block_chain_retained.len() {
-                    let id_block = block_chain_retained.pop()
-                        .unwrap()
-                        .get_id();
+            rltrace!(TraceCategory::Macroexpand, TraceLevel::Info,
+                     "({} {}) => {}",
+                     name.to_uppercase(),
+                     format_items(&args),
+                     expansion);
 
-                    block_chain.retain_mut(
-                        |x| if x.get_id().eq(&id_block) &&
-                            x.get_name().eq(&block_name_of_return_from) {
+            return self.eval_sexpr(&expansion)
+        }
 
-                                x.set_return_from(false);
-                                true
-                            } else {
-                                true
-                            });
-                }
-            }
+        let args = self.eval_args(&args)?;
 
-            drop(env_binding);
-            drop(weak_binding);
+        rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                 "call ({} {})", name.to_uppercase(), format_items(&args));
 
-            let mut result = RLResult::NilRes(RLNil::new());
+        self.call_span = span;
 
-            if progn_ll.len() > 0 {
-                for _n in 0..progn_ll.len() {
+        self.call_function(name, &named_lambda, args)
+    }
 
-                    let sexpr = progn_ll.pop_front().unwrap();
+    fn call_function(&mut self,
+                     name: &str,
+                     named_lambda: &RLNamedLambda,
+                     args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
+
+        if named_lambda.closure.is_none() &&
+           named_lambda.closure_env.is_none() {
+            return self.with_frame(name, args, |rl, args| {
+                rl.apply_named_lambda(named_lambda, args)
+            })
+        }
 
-                    match sexpr {
-                        SExpr::SForm(form) => {
+        self.with_frame(name, args, |rl, args| {
+            let sexpr = SExpr::Cons(Symbol::new(name), args);
 
-                            println!("SFORM");
-                            println!("");
+            let env = rl.env.clone();
+            let mut env_binding = env.borrow_mut();
 
-                            self.parser.change_tokens(&form);
+            let res = env_binding.run_closure_named_lambda(sexpr,
+                                                           named_lambda)?;
 
-                            let parse_result = self.parser.parse_to_sexpr();
+            drop(env_binding);
 
-                            println!("parse_result: {:?}", parse_result);
-                            println!("");
+            match res {
+                RLResult::LoadRes(file, source) => {
+                    rl.load_source(&file, &source)
+                }
 
-                            println!("self.sexpr: {:?}", self.sexpr);
-                            println!("");
+                RLResult::ConditionRes(operator, args) => {
+                    rl.eval_condition_function(&operator, args)
+                }
 
-                            match parse_result {
-                                Ok(ref res)  => {
+                RLResult::BacktraceRes => {
+                    // without the frame of RLISP:BACKTRACE itself
+                    let frames = rl.call_frames
+                                   .iter()
+                                   .rev()
+                                   .skip(1)
+                                   .map(|frame| {
+                                       let mut items = frame.args.clone();
 
-                                    /*
-                                    if contains_function(&res) {
-                                    println!("is function");
-                                    println!("");
+                                       items.push_front(SExpr::Symb(
+                                           RLSymbol::new_with_str(
+                                               &frame.name.to_lowercase())));
 
-                                    self.set_sexpr(&res);
+                                       make_list(&items)
+                                   })
+                                   .collect::<LinkedList<SExpr>>();
 
-                                    match self.eval() {
-                                    // Ok(RLResult::FuncRes(res)) => {
-                                       Ok(RLResult::SExprRes(res)) => {
-                                       self.parser.set_sexpr(
-                                       SExpr::res.clone());
-                                       }
+                    rl.values = None;
 
-                                        Err(err) => return Err(err),
+                    Ok(make_list(&frames))
+                }
 
-                                        _ => { }
-                                        }
-                                        }
-                                     */
+                res => rl.cl_function_value(res),
+            }
+        })
+    }
 
-                                    self.parser.set_sexpr(res.clone());
-                                }
+    // Records the call of the function name with args while f calls
+    // it. The location of the call is taken from call_span. An error
+    // of the call is signalled while its frame is in the backtrace.
+    fn with_frame(&mut self,
+                  name: &str,
+                  args: LinkedList<SExpr>,
+                  f: impl FnOnce(&mut Self, LinkedList<SExpr>) ->
+                      Result<SExpr, RLUnwind>) ->
+        Result<SExpr, RLUnwind> {
 
-                                Err(ref err) => {
-                                    self.parser.set_error(err.clone());
-                                }
-                            }
+        let span = self.call_span.take();
 
-                            if self.macro_mode {
-                                println!("Switch to configure_with_sexpr_macro");
-                                println!("");
-                                self.configure_with_sexpr_macro(parse_result?);
-                            } else {
-                                self.configure_with_sexpr(parse_result?);
-                            }
+        self.call_frames.push(CallFrame {
+            name: name.to_uppercase(),
+            args: args.clone(),
+            span,
+        });
 
-                            match self.eval() {
-                                Ok(res) => {
-                                    println!("res-slistform: {:?}", res);
-                                    println!("");
+        let result = match f(self, args) {
+            Err(RLUnwind::Error(err)) => Err(self.signal_error(err)),
 
-                                    // realize env binding
-                                    let rc_binding = self.env.clone();
+            result => result,
+        };
 
-                                    let weak_binding:
-                                        std::rc::Weak<RefCell<dyn EnvTrait>> =
-                                        Rc::downgrade(&rc_binding);
+        self.call_frames.pop();
 
-                                    let binding =
-                                        weak_binding.upgrade().unwrap();
+        result
+    }
 
-                                    drop(rc_binding);
+    // Reads and evaluates the forms of a file one after another,
+    // the first error aborts the load.
+    fn load_source(&mut self, file: &str, source: &str) ->
+        Result<SExpr, RLUnwind> {
 
-                                    let mut env_binding = binding.borrow_mut();
+        let forms = self.parser.parse_file(file, source)?;
 
-                                    let mut return_from = false;
+        for form in forms {
+            self.eval_sexpr(&form)?;
+        }
 
-                                    if let Some(curr_block) =
-                                    env_binding.get_block_by_id(
-                                        &block.get_id()) {
+        self.values = None;
 
-                                            return_from = curr_block.get_return_from();
-                                        }
+        Ok(SExpr::Symb(RLSymbol::new_with_str("t")))
+    }
 
-                                    drop(env_binding);
-                                    drop(weak_binding);
+    // Calls a function object (#'name, a lambda or a symbol naming
+    // a function) with already evaluated args.
+    fn funcall(&mut self,
+               function: &SExpr,
+               args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                                    if return_from {
-                                        return Ok(res);
-                                    } else {
-                                        result = res;
-                                    }
-                                }
+        match function {
+            SExpr::Func(atom) => {
+                let name = atom.get_atom_string();
 
-                                Err(err) => return Err(err),
-                            }
-                        }
+                let named_lambda = self.get_named_lambda(&name)?;
 
-                        SExpr::SToken(token) => {
-                            self.parser.change_tokens(&vec!(token));
+                self.call_function(&name, &named_lambda, args)
+            }
 
-                            let parse_result = self.parser.parse_to_sexpr();
+            SExpr::Symb(symb) => {
+                let name = symb.get_symbol_name();
 
-                            match parse_result {
-                                Ok(ref res)  => {
-                                    self.parser.set_sexpr(res.clone());
-                                }
+                let named_lambda = self.get_named_lambda(&name)?;
 
-                                Err(ref err) => {
-                                    self.parser.set_error(err.clone());
-                                }
-                            }
+                if named_lambda.get_is_macro() {
+                    return Err(undefined_function(&name))
+                }
 
-                            self.configure_with_sexpr(parse_result?);
+                self.call_function(&name, &named_lambda, args)
+            }
 
-                            match self.eval() {
-                                Ok(res) => {
-                                    println!("res-token: {:?}", res);
-                                    println!("");
+            SExpr::Lambda(function) => self.call_lambda(function, None, args),
 
-                                    // realize env binding
-                                    let rc_binding = self.env.clone();
+            other => {
+                let err = TypeError::new(&other.to_string(), "FUNCTION");
 
-                                    let weak_binding:
-                                        std::rc::Weak<RefCell<dyn EnvTrait>> =
-                                        Rc::downgrade(&rc_binding);
+                Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
+        }
+    }
 
-                                    let binding =
-                                        weak_binding.upgrade().unwrap();
+    // Calls a function value, name is the one of a local function.
+    // Lambdas without a name are LAMBDA in the backtrace.
+    fn call_lambda(&mut self,
+                   function: &RLFunction,
+                   name: Option<&str>,
+                   args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                                    drop(rc_binding);
+        let closure = match function.get_code::<Closure>() {
+            Some(closure) => closure,
 
-                                    let mut env_binding = binding.borrow_mut();
+            None => return Err(RLUnwind::Error(RLError::SimpleProgramError)),
+        };
 
-                                    let mut return_from = false;
+        let name = name.map(|name| name.to_string())
+                       .or(closure.get_lambda().get_named_lambda_name())
+                       .unwrap_or("lambda".to_string());
 
-                                    if let Some(curr_block) =
-                                    env_binding.get_block_by_id(
-                                        &block.get_id()) {
+        self.with_frame(&name, args, |rl, args| rl.apply_lambda(closure, args))
+    }
 
-                                            return_from = curr_block.get_return_from();
-                                        }
+    fn get_named_lambda(&mut self, name: &str) ->
+        Result<Rc<RLNamedLambda>, RLUnwind> {
 
-                                    drop(env_binding);
-                                    drop(weak_binding);
+        let named_lambda = self.with_env(|env| {
+            env.get_symbol(&name.to_string())
+               .and_then(|symbol| symbol.named_lambda.clone())
+        });
 
-                                    if return_from {
-                                        return Ok(res);
-                                    } else {
-                                        result = res;
-                                    }
-                                }
+        match named_lambda {
+            Some(named_lambda) => Ok(named_lambda),
 
-                                Err(err) => return Err(err),
-                            }
-                        }
+            None => Err(undefined_function(name)),
+        }
+    }
 
-                        _ => {
-                            println!("NO SLIST");
-                            println!("");
+    // Functions defined by DEFUN. The parameters are bound in a fresh
+    // environment with the variables the function closes over, the
+    // body is evaluated in the implicit block of the function.
+    fn apply_named_lambda(&mut self,
+                          named_lambda: &RLNamedLambda,
+                          args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                            self.configure_with_sexpr(sexpr);
+        let mut named_ds_bind = RLNamedDsBind::new();
 
-                            match self.eval() {
-                                Ok(res)  => {
-                                    println!("res-other: {:?}", res);
-                                    println!("");
+        // &whole of a macro is the macro form
+        if named_lambda.get_is_macro() {
+            let mut form = args.clone();
 
-                                    result = res
-                                }
+            form.push_front(SExpr::Symb(RLSymbol::new_with_str(
+                &named_lambda.get_name().to_lowercase())));
 
-                                Err(err) => return Err(err),
-                            }
-                        }
-                    }
-                }
+            named_ds_bind.set_whole(make_list(&form));
+        }
 
-                // realize env binding
-                let rc_binding = self.env.clone();
+        named_ds_bind.bind(named_lambda.get_ref_parameters(), &args)?;
 
-                let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                    Rc::downgrade(&rc_binding);
+        let mut block = match named_lambda.get_block() {
+            Some(block) => block,
 
-                let binding = weak_binding.upgrade().unwrap();
-                drop(rc_binding);
+            None => return Err(RLUnwind::Error(RLError::SimpleProgramError)),
+        };
 
-                let mut env_binding = binding.borrow_mut();
+        let mut vars = block.get_dyn_env_block();
 
-                env_binding.block_chain_pop();
+        vars.extend(named_ds_bind.get_dyn_env_block());
 
-                env_binding.release_curr_eval_dyn_env();
+        self.push_dyn_env(&vars, false, &block.get_specials());
 
-                drop(env_binding);
-                drop(weak_binding);
+        self.lexical_scopes.push(None);
 
-                Ok(result)
-            } else { // progn_ll.len = 0 -> NilRes
+        let result = self.bind_parameters(&named_ds_bind.get_bindings())
+                         .and_then(|_| {
+            self.with_env(|env| env.block_chain_push(&block));
 
-                // realize env binding
-                let rc_binding = self.env.clone();
+            let result = self.eval_progn(block.get_ref_progn_ll());
 
-                let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                    Rc::downgrade(&rc_binding);
+            self.with_env(|env| env.block_chain_pop());
 
-                let binding = weak_binding.upgrade().unwrap();
-                drop(rc_binding);
+            result
+        });
 
-                let mut env_binding = binding.borrow_mut();
+        self.lexical_scopes.pop();
 
-                env_binding.block_chain_pop();
+        self.pop_dyn_env();
 
-                env_binding.release_curr_eval_dyn_env();
+        catch_return_from(&block, result)
+    }
 
-                drop(env_binding);
-                drop(weak_binding);
+    // Lambdas and local functions. The body sees the variables the
+    // lambda closes over and its parameters, not the ones of the
+    // caller.
+    fn apply_lambda(&mut self,
+                    closure: &Closure,
+                    args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                Ok(result)
-            }
-        }
+        let lambda = closure.get_lambda();
 
-     fn eval_return_from(&mut self, mut block: RLBlock) ->
-        Result<RLResult, RLError> {
+        let mut named_ds_bind = RLNamedDsBind::new();
 
-            println!("begin eval_return-from");
-            println!("");
+        named_ds_bind.bind(lambda.get_ref_parameters(), &args)?;
 
-            // realize env binding
-            let rc_binding = self.env.clone();
+        let vars = named_ds_bind.get_dyn_env_block();
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+        let mut closure_vars = clone_hash_map(
+            lambda.get_ref_dyn_env_lambda());
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+        closure_vars.extend(vars.clone());
 
-            let mut env_binding = binding.borrow_mut();
+        self.push_dyn_env(&closure_vars, false, &lambda.get_specials());
 
-            env_binding.block_chain_push(&block);
+        let depth = self.lexical_scopes.len();
 
-            println!("RETURN-FROM - replace_curr_eval_dyn_env");
-            println!("");
+        // lambdas see the definitions of their definition
+        self.lexical_scopes.push(None);
+        self.lexical_scopes.push(Some(closure.group.scope.clone()));
 
-            let hash_map = block.get_dyn_env_block();
-            env_binding.replace_curr_eval_dyn_env(&hash_map);
+        if closure.group.recursive {
+            self.lexical_scopes.push(Some(local_functions(&closure.group)));
+        }
 
-            if let Some(block) = env_binding.block_chain_pop() {
+        let parameters = vars.keys()
+                             .map(|name| (name.to_lowercase(), LocalDef::Variable))
+                             .collect();
 
-                drop(env_binding);
-                drop(weak_binding);
+        self.lexical_scopes.push(Some(parameters));
 
-                let mut progn_ll = block.get_progn_ll();
+        let result = self.bind_parameters(&named_ds_bind.get_bindings())
+                         .and_then(|_| self.eval_sexpr(&lambda.get_body()));
 
-                println!("block progn_ll: {:?}", progn_ll);
-                println!("");
+        self.lexical_scopes.truncate(depth);
 
-                let mut result = RLResult::NilRes(RLNil::new());
+        self.pop_dyn_env();
 
-                if progn_ll.len() > 0 {
-                    for _n in 0..progn_ll.len() {
+        result
+    }
 
-                        let sexpr = progn_ll.pop_front().unwrap();
+    // Binds the unevaluated args to the parameters of the macro and
+    // evaluates its body, which returns the expansion.
+    fn macroexpand(&mut self,
+                   named_lambda: &RLNamedLambda,
+                   args: &LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                        match sexpr {
-                            SExpr::SForm(form) => {
+        self.apply_named_lambda(named_lambda, args.clone())
+    }
 
-                                println!("SFORM");
-                                println!("");
+    ///////////////////////////////////////////////////////////
+    /*
+    Conditions
+    */
+    ///////////////////////////////////////////////////////////
 
-                                self.parser.change_tokens(&form);
+    // MAKE-CONDITION, SIGNAL, ERROR, CERROR, WARN and the functions
+    // which find and invoke restarts
+    fn eval_condition_function(&mut self,
+                               operator: &str,
+                               mut args: LinkedList<SExpr>) ->
+        Result<SExpr, RLUnwind> {
 
-                                let parse_result = self.parser.parse_to_sexpr();
+        self.values = None;
 
-                                match parse_result {
-                                    Ok(ref res)  => {
-                                        self.parser.set_sexpr(res.clone());
-                                    }
+        match operator {
+            "make-condition" => {
+                let name = match args.pop_front().unwrap() {
+                    SExpr::Symb(symb) => symb.get_symbol_name(),
 
-                                    Err(ref err) => {
-                                        self.parser.set_error(err.clone());
-                                    }
-                                }
+                    other => {
+                        let err = TypeError::new(&other.to_string(), "SYMBOL");
 
-                                self.configure_with_sexpr(parse_result?);
+                        return Err(RLUnwind::Error(RLError::TypeError(err)))
+                    }
+                };
 
-                                match self.eval() {
-                                    Ok(res) => {
-                                        println!("res-slistform: {:?}", res);
-                                        println!("");
+                Ok(SExpr::Condition(self.make_condition(&name, args)?))
+            }
 
-                                        // realize env binding
-                                        let rc_binding = self.env.clone();
+            "signal" => {
+                let condition = self.coerce_to_condition(args,
+                                                         "simple-condition")?;
 
-                                        let weak_binding:
-                                            std::rc::Weak<RefCell<dyn EnvTrait>> =
-                                            Rc::downgrade(&rc_binding);
+                rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                         "signal {}", condition);
 
-                                        let binding =
-                                            weak_binding.upgrade().unwrap();
+                self.signal(&condition)?;
 
-                                        drop(rc_binding);
+                self.values = None;
 
-                                        let mut env_binding = binding.borrow_mut();
+                Ok(SExpr::Nil(RLNil::new()))
+            }
 
-                                        let mut return_from = false;
+            "error" => {
+                let condition = self.coerce_to_condition(args,
+                                                         "simple-error")?;
 
-                                        if let Some(curr_block) =
-                                    env_binding.get_block_by_id(
-                                        &block.get_id()) {
+                rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                         "error {}", condition);
 
-                                            return_from = curr_block.get_return_from();
-                                        }
+                let err = condition_to_error(&condition);
 
-                                        // env_binding.release_curr_eval_dyn_env();
+                Err(self.signal_error_condition(&condition, err))
+            }
 
-                                        drop(env_binding);
-                                        drop(weak_binding);
+            // the continue format control shares the format arguments
+            // of the error
+            "cerror" => {
+                let control = princ_to_string(&args.pop_front().unwrap());
 
-                                        if return_from {
-                                            return Ok(res);
-                                        } else {
-                                            result = res;
-                                        }
-                                    }
+                let condition = self.coerce_to_condition(args.clone(),
+                                                         "simple-error")?;
 
-                                    Err(err) => return Err(err),
-                                }
-                            }
+                rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                         "cerror {}", condition);
 
-                            SExpr::SToken(token) => {
-                                self.parser.change_tokens(&vec!(token));
+                args.pop_front();
 
-                                let parse_result = self.parser.parse_to_sexpr();
+                let report = format_control(&control, &args)
+                                 .unwrap_or(control);
 
-                                match parse_result {
-                                    Ok(ref res)  => {
-                                        self.parser.set_sexpr(res.clone());
-                                    }
+                let err = condition_to_error(&condition);
 
-                                    Err(ref err) => {
-                                        self.parser.set_error(err.clone());
-                                    }
-                                }
+                // the signalling only returns by a restart
+                self.with_restarts(vec![("continue".to_string(), report, 0)],
+                                   |rl| {
+                    Err::<(), RLUnwind>(rl.signal_error_condition(&condition,
+                                                                  err))
+                })?;
 
-                                self.configure_with_sexpr(parse_result?);
+                self.values = None;
 
-                                match self.eval() {
-                                    Ok(res) => {
-                                        println!("res-token: {:?}", res);
-                                        println!("");
+                Ok(SExpr::Nil(RLNil::new()))
+            }
 
-                                        // realize env binding
-                                        let rc_binding = self.env.clone();
+            "warn" => {
+                let condition = self.coerce_to_condition(args,
+                                                         "simple-warning")?;
 
-                                        let weak_binding:
-                                            std::rc::Weak<RefCell<dyn EnvTrait>> =
-                                            Rc::downgrade(&rc_binding);
+                rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                         "warn {}", condition);
 
-                                        let binding =
-                                            weak_binding.upgrade().unwrap();
+                let restarts = vec![("muffle-warning".to_string(),
+                                     "Skip warning.".to_string(), 0)];
 
-                                        drop(rc_binding);
+                let restarted = self.with_restarts(restarts,
+                                                   |rl| rl.signal(&condition))?;
 
-                                        let mut env_binding = binding.borrow_mut();
+                if let Restarted::Returned(()) = restarted {
+                    eprintln!("WARNING: {}", condition.get_message());
+                }
 
-                                        let mut return_from = false;
+                self.values = None;
 
-                                        if let Some(curr_block) =
-                                    env_binding.get_block_by_id(
-                                        &block.get_id()) {
+                Ok(SExpr::Nil(RLNil::new()))
+            }
 
-                                            return_from = curr_block.get_return_from();
-                                        }
+            "invoke-restart" => {
+                let identifier = args.pop_front().unwrap();
 
-                                        // env_binding.release_curr_eval_dyn_env();
+                match self.find_restart(&identifier)? {
+                    Some(restart) => Err(self.invoke_restart(&restart, args)),
 
-                                        drop(env_binding);
-                                        drop(weak_binding);
+                    None => Err(RLUnwind::Error(no_restart(&identifier))),
+                }
+            }
 
-                                        if return_from {
-                                            return Ok(res);
-                                        } else {
-                                            result = res;
-                                        }
-                                    }
+            "find-restart" => {
+                let identifier = args.pop_front().unwrap();
 
-                                    Err(err) => return Err(err),
-                                }
-                            }
+                Ok(self.find_restart(&identifier)?
+                       .map(SExpr::Restart)
+                       .unwrap_or(SExpr::Nil(RLNil::new())))
+            }
 
-                            _ => {}
+            "compute-restarts" => {
+                let restarts = self.compute_restarts()
+                                   .into_iter()
+                                   .map(SExpr::Restart)
+                                   .collect::<LinkedList<SExpr>>();
 
-                        }
+                Ok(make_list(&restarts))
+            }
+
+            // ABORT and MUFFLE-WARNING require their restart, the
+            // others return NIL without one
+            _ => {
+                let identifier = SExpr::Symb(RLSymbol::new_with_str(operator));
+
+                match self.find_restart(&identifier)? {
+                    Some(restart) => Err(self.invoke_restart(&restart, args)),
+
+                    None if matches!(operator, "abort" | "muffle-warning") => {
+                        Err(RLUnwind::Error(no_restart(&identifier)))
                     }
-                    Ok(result)
-                } else {
-                    Ok(result)
+
+                    None => Ok(SExpr::Nil(RLNil::new())),
                 }
-            } else {
-                return Err(RLError::SimpleProgramError);
             }
         }
+    }
 
-     fn eval_func(&mut self, func: RLNamedLambda) ->
-        Result<RLResult, RLError> {
+    // The condition designated by the datum and arguments of SIGNAL,
+    // ERROR and WARN: a condition, the name of a condition type with
+    // initargs or a format control with format arguments.
+    fn coerce_to_condition(&mut self,
+                           mut args: LinkedList<SExpr>,
+                           default_type: &str) ->
+        Result<RLCondition, RLUnwind> {
 
-            println!("Begin eval_func");
-            println!("");
+        match args.pop_front().unwrap() {
+            SExpr::Condition(condition) => Ok(condition),
 
-            /*
-            // realize env binding
-               let rc_binding = self.env.clone();
+            SExpr::Symb(symb) => self.make_condition(&symb.get_symbol_name(),
+                                                     args),
 
-        let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-        Rc::downgrade(&rc_binding);
+            SExpr::Atom(atom) if atom.is_rlstring_atom() => {
+                let initargs = LinkedList::from([
+                    SExpr::Symb(RLSymbol::new_with_str(":format-control")),
+                    SExpr::Atom(atom),
+                    SExpr::Symb(RLSymbol::new_with_str(":format-arguments")),
+                    make_list(&args)]);
 
-        let binding = weak_binding.upgrade().unwrap();
-        drop(rc_binding);
+                self.make_condition(default_type, initargs)
+            }
 
-        let mut env_binding = binding.borrow_mut();
+            other => {
+                let err = TypeError::new(&other.to_string(),
+                                         "(OR CONDITION SYMBOL STRING)");
 
-        println!("EVAL_FUNC - replace_curr_eval_dyn_env");
-        println!("");
+                Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
+        }
+    }
 
-        // env_binding.replace_curr_eval_dyn_env(func.get_ref_dyn_env());
+    // A condition of the type name. Slots which no initarg initializes
+    // get the value of their initform, slots without one are unbound.
+    fn make_condition(&mut self,
+                      name: &str,
+                      mut initargs: LinkedList<SExpr>) ->
+        Result<RLCondition, RLUnwind> {
 
-        drop(env_binding);
-        drop(weak_binding);
-             */
+        if self.with_env(|env| env.get_condition_type(name)).is_none() {
+            return Err(RLUnwind::Error(not_a_condition_type(name)))
+        }
 
-            // let sexpr = func.get_body();
+        if !initargs.len().is_multiple_of(2) {
+            let err = SimpleError::new(&format!(
+                "Odd-length initialization argument list: {}",
+                format_items(&initargs)));
 
-            if let None = func.get_block() {
-                let lambda = func.get_lambda().expect("REASON");
+            return Err(RLUnwind::Error(RLError::SimpleError(err)))
+        }
 
-                self.eval_lambda(lambda.clone())
-            } else {
-                let block = func.get_block().expect("REASON");
+        let slots = self.with_env(|env| env.get_condition_slots(name));
+
+        let mut values = Vec::<(String, SExpr)>::new();
 
-                self.eval_block(block)
+        while let (Some(key), Some(value)) = (initargs.pop_front(),
+                                              initargs.pop_front()) {
+            let key = key.to_string();
+
+            let initialized = slots.iter()
+                                   .filter(|slot| {
+                                       slot.initarg.as_ref().is_some_and(
+                                           |initarg| initarg.eq(&key))
+                                   })
+                                   .map(|slot| slot.name.clone())
+                                   .collect::<Vec<String>>();
+
+            if initialized.is_empty() {
+                let err = SimpleError::new(&format!(
+                    "Invalid initialization argument {} for the condition \
+                     type {}.",
+                    key, name.to_uppercase()));
+
+                return Err(RLUnwind::Error(RLError::SimpleError(err)))
+            }
+
+            // the leftmost initarg wins
+            for slot_name in initialized {
+                if !values.iter().any(|(name, _)| name.eq(&slot_name)) {
+                    values.push((slot_name, value.clone()));
+                }
             }
         }
 
-     fn eval_lambda(&mut self, mut lambda: RLLambda) ->
-        Result<RLResult, RLError> {
+        for slot in &slots {
+            if values.iter().any(|(name, _)| name.eq(&slot.name)) {
+                continue;
+            }
 
-            println!("Begin eval_lambda");
-            println!("");
+            if let Some(initform) = &slot.initform {
+                let value = self.eval_sexpr(initform)?;
 
-            // realize env binding
-            let rc_binding = self.env.clone();
+                values.push((slot.name.clone(), value));
+            }
+        }
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+        let message = self.with_env(|env| report_condition(env, name, &values))?;
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+        self.values = None;
 
-            let mut env_binding = binding.borrow_mut();
+        Ok(RLCondition::new(name, values, &message))
+    }
 
-            println!("EVAL_LAMBDA - replace_curr_eval_dyn_env");
-            println!("");
+    // Signals the condition of an error of rlisp.
+    fn signal_error(&mut self, err: RLError) -> RLUnwind {
+        let condition = error_to_condition(&err);
 
-            env_binding.replace_curr_eval_dyn_env(lambda.get_ref_dyn_env_lambda());
+        self.signal_error_condition(&condition, err)
+    }
 
-            drop(env_binding);
-            drop(weak_binding);
+    // Signals the condition of an error. If no handler takes the
+    // condition, the debugger is entered, without one err is returned
+    // as unhandled.
+    fn signal_error_condition(&mut self,
+                              condition: &RLCondition,
+                              err: RLError) -> RLUnwind {
 
-            let sexpr = lambda.get_body();
+        let values = self.values.take();
 
-            self.configure_with_sexpr(sexpr);
+        let result = self.signal(condition);
 
-            println!("In eval_lambda - self.sexpr: {}", self.sexpr);
-            println!("");
+        self.values = values;
 
-            // env_binding.release_curr_eval_dyn_env();
+        match result {
+            Ok(()) => self.unhandled(condition, err),
 
-            match self.eval() {
-                Ok(res)  => return Ok(res),
-                Err(err) => return Err(err),
+            Err(unwind) => unwind,
+        }
+    }
+
+    // The debugger is not entered again for errors of the forms
+    // evaluated in it.
+    fn unhandled(&mut self, condition: &RLCondition, err: RLError) ->
+        RLUnwind {
+
+        match self.debugger.take() {
+            Some(mut debugger) => {
+                let unwind = debugger.debug(self, condition);
+
+                self.debugger = Some(debugger);
+
+                unwind
             }
+
+            None => RLUnwind::Unhandled(err.with_backtrace(self.backtrace())),
         }
+    }
 
-     fn eval_ds(&mut self, block: RLBlock) ->
-        Result<RLResult, RLError> {
+    // Calls the handlers for the condition from the innermost cluster
+    // outwards. A HANDLER-CASE takes the condition by unwinding to its
+    // clause, a handler of HANDLER-BIND declines by returning. Handlers
+    // run with the clusters outside of their own in effect.
+    fn signal(&mut self, condition: &RLCondition) -> Result<(), RLUnwind> {
+        let mut index = self.handler_clusters.len();
 
-            println!("Begin eval_ds");
-            println!("");
+        while index > 0 {
+            index -= 1;
 
-            println!("block: {:?}", block);
-            println!("");
+            let cluster = self.handler_clusters[index].clone();
 
-            self.set_macro_mode(true);
+            for (typespec, handler) in cluster.handlers {
+                if !self.condition_typep(condition, &typespec) {
+                    continue;
+                }
+
+                match handler {
+                    Handler::Case(id, clause) => {
+                        return Err(RLUnwind::HandlerCase(
+                            id, clause,
+                            Box::new(SExpr::Condition(condition.clone()))))
+                    }
+
+                    Handler::Function(function) => {
+                        rltrace!(TraceCategory::Zipper, TraceLevel::Info,
+                                 "handler-bind {} {}", typespec, condition);
 
-            let progn_ll = block.get_progn_ll();
+                        let inner_clusters =
+                            self.handler_clusters.split_off(index);
 
-            self.named_ds_bind.set_expr_ll(&progn_ll);
+                        let result = self.funcall(&function, LinkedList::from([
+                            SExpr::Condition(condition.clone())]));
 
-            self.named_ds_bind.set_block(&block);
+                        self.handler_clusters.extend(inner_clusters);
 
-            self.named_ds_bind.build_macro_sexpr();
+                        result?;
+                    }
+                }
+            }
+        }
 
-            // realize env binding
-            let rc_binding = self.env.clone();
+        Ok(())
+    }
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                Rc::downgrade(&rc_binding);
+    // typespec is a condition type, T, or an OR, AND or NOT of them
+    fn condition_typep(&mut self,
+                       condition: &RLCondition,
+                       typespec: &SExpr) -> bool {
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+        match typespec {
+            SExpr::Symb(symb) if symb.get_symbol_name().eq("t") => true,
 
-            let env_binding = binding.borrow_mut();
+            SExpr::Symb(symb) => {
+                let type_name = condition.get_type_name();
 
-            let mut sexpr = self.named_ds_bind.do_macro_replacements(env_binding);
+                self.with_env(|env| {
+                    env.condition_subtypep(&type_name, &symb.get_symbol_name())
+                })
+            }
 
-            println!("sexpr: {:?}", sexpr);
-            println!("");
+            SExpr::SList(slist) => {
+                let mut items = slist.get_items();
 
-            if let Ok(SExpr::SBTreeMap(ref mut sbtm)) = sexpr {
-                let mut new_sbtm = BTreeMap::<String, SExpr>::new();
+                let combinator = match items.pop_front() {
+                    Some(SExpr::Symb(symb)) => symb.get_symbol_name(),
 
-                for _n in 0..sbtm.len() {
-                    if let Some((key, sexpr)) = sbtm.pop_first() {
-                        println!("key: {:?}", key);
-                        println!("sexpr: {:?}", sexpr);
-                        println!("");
+                    _ => return false,
+                };
 
-                        match self.eval_sform(sexpr) {
-                            Ok(res)  => {
-                                if let Ok(sexpr) =
-                                RLResult::get_sexpr_from_res(res) {
+                match &*combinator {
+                    "or" => items.iter()
+                                 .any(|item| self.condition_typep(condition,
+                                                                  item)),
 
-                                    new_sbtm.insert(key, sexpr);
-                                }
-                            }
+                    "and" => items.iter()
+                                  .all(|item| self.condition_typep(condition,
+                                                                   item)),
 
-                            Err(err) => return Err(err),
-                        }
+                    "not" if items.len() == 1 => {
+                        !self.condition_typep(condition, items.front().unwrap())
                     }
+
+                    _ => false,
                 }
+            }
 
-                println!("new_sbtm: {:?}", new_sbtm);
-                println!("");
+            _ => false,
+        }
+    }
 
-                sexpr = Ok(self.named_ds_bind.replace_scons(new_sbtm.clone()));
-            }
+    ///////////////////////////////////////////////////////////
+    /*
+    Restarts
+    */
+    ///////////////////////////////////////////////////////////
 
-            // drop(env_binding);
-            drop(weak_binding);
+    // Evaluates f with restarts established, each one a name, a report
+    // and the number of its required arguments. The first restart is
+    // found first. An invoked restart returns its index.
+    fn with_restarts<T>(&mut self,
+                        restarts: Vec<(String, String, usize)>,
+                        f: impl FnOnce(&mut Self) -> Result<T, RLUnwind>) ->
+        Result<Restarted<T>, RLUnwind> {
 
-            match sexpr {
-                Ok(sexpr) => {
+        self.exit_point_count += 1;
 
-                    // self.configure_with_sexpr_macro(sexpr);
+        let id = self.exit_point_count;
 
-                    self.set_macro_mode(false);
+        let depth = self.restarts.len();
 
-                    return Ok(RLResult::SExprRes(sexpr));
-                }
+        for (index, (name, report, arity)) in restarts.iter()
+                                                      .enumerate()
+                                                      .rev() {
+            self.restarts.push(RLRestart::new(name, report, id, index,
+                                              *arity));
+        }
 
-                Err(err) => {
-                    return Err(err);
-                }
-            }
+        let result = f(self);
 
-            /*
-            match self.eval() {
-            Ok(res)  => {
-            self.set_macro_mode(false);
+        self.restarts.truncate(depth);
 
-                return Ok(res);
-                }
+        match result {
+            Ok(value) => Ok(Restarted::Returned(value)),
 
-            Err(err) => return Err(err),
+            Err(RLUnwind::Restart(restart_id, index, args))
+                if restart_id == id => {
+
+                Ok(Restarted::Invoked(index, args))
             }
-             */
+
+            Err(unwind) => Err(unwind),
         }
+    }
 
-     fn eval_macro(&mut self, rlmacro: RLNamedLambda) ->
-        Result<RLResult, RLError> {
+    // The report of a restart from a format control and the forms of
+    // its arguments, "" for no report.
+    fn restart_report(&mut self, report: &LinkedList<SExpr>) ->
+        Result<String, RLUnwind> {
 
-            println!("Begin eval_macro");
-            println!("");
+        let mut forms = report.iter();
 
-            self.set_macro_mode(true);
+        let control = match forms.next() {
+            Some(form) => princ_to_string(&self.eval_sexpr(form)?),
 
-            let block = rlmacro.get_block().expect("REASON");
+            None => return Ok(String::new()),
+        };
 
-            /*
-            let mut toplevel_sform = SExpr::SForm(Vec::<Token>::new());
+        let mut arguments = LinkedList::<SExpr>::new();
 
-        if let Some(SExpr::SForm(vec)) = block.get_progn_ll().pop_front() {
-        toplevel_sform = SExpr::SForm(vec);
+        for form in forms {
+            arguments.push_back(self.eval_sexpr(form)?);
         }
-             */
 
-            let old_block = block.clone();
+        self.values = None;
 
-            println!("rlmacro - block: {:?}", block);
-            println!("");
+        Ok(format_control(&control, &arguments)?)
+    }
 
-            if let Ok(RLResult::NamedDsBindRes(ds_block)) =
-            self.eval_block(block) {
+    // The innermost active restart of a name, or the restart itself if
+    // it is still active.
+    fn find_restart(&self, identifier: &SExpr) ->
+        Result<Option<RLRestart>, RLUnwind> {
+
+        match identifier {
+            SExpr::Restart(restart) => {
+                Ok(self.restarts
+                       .iter()
+                       .find(|active| {
+                           active.get_id() == restart.get_id() &&
+                           active.get_index() == restart.get_index()
+                       })
+                       .cloned())
+            }
 
-                println!("ds_block: {:?}", ds_block);
-                println!("");
+            SExpr::Symb(symb) => {
+                let name = symb.get_symbol_name().to_uppercase();
 
-                self.named_ds_bind.set_expr_ll(&ds_block.get_progn_ll());
+                Ok(self.restarts
+                       .iter()
+                       .rev()
+                       .find(|active| active.get_name().eq(&name))
+                       .cloned())
+            }
 
-                self.named_ds_bind.set_block(&old_block);
+            // anonymous restarts are not found by name
+            SExpr::Nil(_) => Ok(None),
 
-                // self.named_ds_bind.set_toplevel_sform(&toplevel_sform);
+            other => {
+                let err = TypeError::new(&other.to_string(),
+                                         "(OR RESTART SYMBOL)");
 
-                self.named_ds_bind.build_macro_sexpr();
+                Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
+        }
+    }
 
-                // realize env binding
-                let rc_binding = self.env.clone();
+    ///////////////////////////////////////////////////////////
+    /*
+    Backquote
+    */
+    ///////////////////////////////////////////////////////////
 
-                let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-                    Rc::downgrade(&rc_binding);
+    // depth counts the nesting of backquotes, only commas on
+    // depth 1 are evaluated.
+    fn backquote(&mut self, form: &SExpr, depth: usize) ->
+        Result<SExpr, RLUnwind> {
 
-                let binding = weak_binding.upgrade().unwrap();
-                drop(rc_binding);
+        let items = match form {
+            SExpr::SList(slist) => slist.get_items(),
 
-                let env_binding = binding.borrow_mut();
+            // (a ,@x . b)
+            SExpr::SCons(cons) => {
+                let items = self.backquote_element(&cons.car(), depth)?;
+                let tail = self.backquote(&cons.cdr(), depth)?;
 
-                let mut sexpr =
-                    self.named_ds_bind.do_macro_replacements(env_binding);
+                return Ok(build_dotted_list(items, tail))
+            }
 
-                println!("after do_macro_replacements ----------------");
-                println!("sexpr: {:?}", sexpr);
-                println!("");
-                println!("--------------------------------------------");
-                println!("");
+            _ => return Ok(form.clone()),
+        };
 
-                if let Ok(SExpr::SBTreeMap(ref mut sbtm)) = sexpr {
+        if let Some((name, inner)) = reader_macro_form(&items) {
+            match &*name {
+                "comma" if depth == 1 => {
+                    return self.eval_sexpr(&inner)
+                }
+
+                "comma" | "comma-at" => {
+                    let inner = self.backquote(&inner, depth - 1)?;
 
-                    let mut new_sbtm = BTreeMap::<String, SExpr>::new();
+                    return Ok(two_element_list(&name, inner))
+                }
 
-                    for _n in 0..sbtm.len() {
-                        if let Some((key, sexpr)) = sbtm.pop_first() {
-                            println!("key: {:?}", key);
-                            println!("sexpr: {:?}", sexpr);
-                            println!("");
+                "backquote" => {
+                    let inner = self.backquote(&inner, depth + 1)?;
 
-                            self.configure_with_sexpr_macro(sexpr);
+                    return Ok(two_element_list(&name, inner))
+                }
 
-                            match self.eval() {
-                                Ok(res)  => {
-                                    if let Ok(sexpr) =
-                                    RLResult::get_sexpr_from_res(res) {
+                _ => {}
+            }
+        }
 
-                                        new_sbtm.insert(key, sexpr);
-                                    }
-                                }
+        let mut result = LinkedList::<SExpr>::new();
 
-                                Err(err) => return Err(err),
-                            }
-                        }
-                    }
-                    println!("new_sbtm: {:?}", new_sbtm);
-                    println!("");
+        let mut items = items.into_iter();
 
-                    sexpr = Ok(self.named_ds_bind.replace_scons(
-                        new_sbtm.clone()));
-                }
+        let mut first = true;
 
-                // drop(env_binding);
-                drop(weak_binding);
+        while let Some(item) = items.next() {
+            // the reader makes (a . ,x) the list (a comma x)
+            if !first && items.len() == 1 {
+                if let SExpr::Symb(ref symb) = item {
+                    let name = symb.get_symbol_name();
 
-                self.set_macro_mode(false);
+                    if name.eq("comma") || name.eq("comma-at") {
+                        let tail = two_element_list(&name,
+                                                    items.next().unwrap());
 
-                println!("End eval_macro");
-                println!("");
+                        let tail = self.backquote(&tail, depth)?;
 
-                Ok(RLResult::SExprRes(sexpr?))
-            } else {
-                return Err(RLError::SimpleProgramError);
+                        return Ok(build_dotted_list(result, tail))
+                    }
+                }
             }
-        }
 
-     /*
-     fn eval_macro(&mut self, rlmacro: RLNamedLambda) ->
-     Result<RLResult, RLError> {
+            result.append(&mut self.backquote_element(&item, depth)?);
 
-        println!("Begin eval_macro");
-        println!("");
+            first = false;
+        }
 
-        self.set_macro_mode(true);
+        Ok(make_list(&result))
+    }
 
-        /*
-        // realize env binding
-           let rc_binding = self.env.clone();
+    // The elements item stands for in the list around it: ,@x on depth
+    // 1 is replaced by the elements of the value of x, and ,,@x on
+    // depth 2 by a comma form for each of them.
+    fn backquote_element(&mut self, item: &SExpr, depth: usize) ->
+        Result<LinkedList<SExpr>, RLUnwind> {
 
-        let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-        Rc::downgrade(&rc_binding);
+        if let Some((name, inner)) = reader_macro_item(item) {
+            if name.eq("comma-at") && depth == 1 {
+                return self.splice(&inner)
+            }
 
-        let binding = weak_binding.upgrade().unwrap();
-        drop(rc_binding);
+            if (name.eq("comma") || name.eq("comma-at")) && depth == 2 {
+                if let Some((inner_name, form)) = reader_macro_item(&inner) {
+                    if inner_name.eq("comma-at") {
+                        return Ok(self.splice(&form)?
+                                      .into_iter()
+                                      .map(|value| {
+                                          two_element_list(&name, value)
+                                      })
+                                      .collect())
+                    }
+                }
+            }
+        }
 
-        let env_binding = binding.borrow_mut();
+        let mut elements = LinkedList::<SExpr>::new();
 
-        println!("EVAL_MACRO - replace dyn_env");
-        println!("");
+        elements.push_back(self.backquote(item, depth)?);
 
-        // env_binding.replace_curr_eval_dyn_env(rlmacro.get_ref_dyn_env());
+        Ok(elements)
+    }
 
-        drop(env_binding);
-        drop(weak_binding);
-      */
+    // The elements of the list form evaluates to
+    fn splice(&mut self, form: &SExpr) ->
+        Result<LinkedList<SExpr>, RLUnwind> {
 
-        let block = rlmacro.get_block().expect("REASON");
+        match self.eval_sexpr(form)? {
+            SExpr::Nil(_) => Ok(LinkedList::new()),
 
-        /*
-        let mut toplevel_sform = SExpr::SForm(Vec::<Token>::new());
+            SExpr::SList(spliced) => Ok(spliced.get_items()),
 
-        if let Some(SExpr::SForm(vec)) = block.get_progn_ll().pop_front() {
-        toplevel_sform = SExpr::SForm(vec);
+            other => {
+                let err = TypeError::new(&other.to_string(), "LIST");
+
+                Err(RLUnwind::Error(RLError::TypeError(err)))
+            }
         }
-      */
+    }
 
-        let old_block = block.clone();
+    ///////////////////////////////////////////////////////////
+    /*
+    Environment helpers
+    */
+    ///////////////////////////////////////////////////////////
 
-        println!("rlmacro - block: {:?}", block);
-        println!("");
+    fn with_env<R>(&mut self, f: impl FnOnce(&mut dyn EnvTrait) -> R) -> R {
+        let env = self.env.clone();
+        let mut env_binding = env.borrow_mut();
 
-        if let Ok(RLResult::NamedDsBindRes(ds_block)) =
-        self.eval_block(block) {
+        f(&mut *env_binding)
+    }
 
-            println!("ds_block: {:?}", ds_block);
-            println!("");
+    // Makes vars the current dynamic environment. With inherit the
+    // variables of the current environment stay visible. Variables
+    // which are special or declared special by the binding form are
+    // bound in the values of their symbols instead.
+    fn push_dyn_env(&mut self,
+                    vars: &HashMap<String, RLVar, RLHash>,
+                    inherit: bool,
+                    specials: &[String]) {
 
-            self.named_ds_bind.set_expr_ll(&ds_block.get_progn_ll());
+        let mut special_vars = Vec::<(String, RLVar)>::new();
 
-            self.named_ds_bind.set_block(&old_block);
+        let mut lexical_vars = make_rlvar_hash_map();
 
-            // self.named_ds_bind.set_toplevel_sform(&toplevel_sform);
+        for (key, value) in vars {
+            if self.is_special(key, specials) {
+                special_vars.push((key.clone(), value.clone()));
+            } else {
+                lexical_vars.insert(key.clone(), value.clone());
+            }
+        }
 
-            self.named_ds_bind.build_macro_sexpr();
+        self.with_env(|env| {
+            let mut map = match env.get_ref_curr_eval_dyn_env() {
+                Some(curr_map) if inherit => clone_hash_map(curr_map),
 
-            // realize env binding
-               let rc_binding = self.env.clone();
+                _ => make_rlvar_hash_map(),
+            };
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-            Rc::downgrade(&rc_binding);
+            // outer lexical bindings are shadowed
+            for (key, _) in &special_vars {
+                map.remove(key);
+            }
+
+            for (key, value) in &lexical_vars {
+                rltrace!(TraceCategory::Env, TraceLevel::Info,
+                         "bind {} = {}", key, value);
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+                map.insert(key.clone(), value.clone());
+            }
 
-            let env_binding = binding.borrow_mut();
+            env.replace_curr_eval_dyn_env(&map);
+        });
 
-            let mut sexpr =
-            self.named_ds_bind.do_macro_replacements(env_binding);
+        self.special_frames.push(SpecialFrame {
+            declared: specials.to_vec(),
+            saved: Vec::new(),
+        });
 
-            println!("after do_macro_replacements ----------------");
-            println!("sexpr: {:?}", sexpr);
-            println!("");
-            println!("--------------------------------------------");
-            println!("");
+        for (key, value) in special_vars {
+            self.bind_special(&key, value);
+        }
 
-            if let Ok(SExpr::SBTreeMap(ref mut sbtm)) = sexpr {
+        let names = lexical_vars.keys().cloned().collect::<Vec<String>>();
 
-                let mut new_sbtm = BTreeMap::<String, SExpr>::new();
+        self.frame_names.push(if inherit { Some(names) } else { None });
+    }
 
-                for _n in 0..sbtm.len() {
-                if let Some((key, sexpr)) = sbtm.pop_first() {
-                println!("key: {:?}", key);
-                println!("sexpr: {:?}", sexpr);
-                println!("");
+    // Makes the local definitions of a block and its variables
+    // visible. Each local function gets the definitions visible at its
+    // definition, the ones of LABELS also see themselves.
+    fn push_lexical_scope(&mut self, block: &RLBlock, variables: &[String]) {
+        let mut scope = variables.iter()
+                                 .map(|name| (name.to_lowercase(),
+                                              LocalDef::Variable))
+                                 .collect::<Vec<(String, LocalDef)>>();
+
+        for (name, expansion) in block.get_symbol_macros() {
+            scope.push((name, LocalDef::SymbolMacro(expansion)));
+        }
 
-                        self.configure_with_sexpr_macro(sexpr);
+        for (name, named_lambda) in block.get_macros() {
+            scope.push((name, LocalDef::Macro(Box::new(named_lambda))));
+        }
 
-                        match self.eval() {
-                        Ok(res)  => {
-                        if let Ok(sexpr) =
-                        RLResult::get_sexpr_from_res(res) {
+        let functions = block.get_functions();
 
-                                    new_sbtm.insert(key, sexpr);
-                                    }
-                                    }
+        if !functions.is_empty() {
+            let group = FunctionGroup {
+                functions,
+                scope: self.visible_definitions(),
+                recursive: block.has_recursive_functions(),
+            };
 
-                            Err(err) => return Err(err),
-                            }
-                            }
-                            }
-                            println!("new_sbtm: {:?}", new_sbtm);
-                            println!("");
+            scope.extend(local_functions(&Rc::new(group)));
+        }
 
-                sexpr = Ok(self.named_ds_bind.replace_scons(
-                new_sbtm.clone()));
-                }
+        self.lexical_scopes.push(Some(scope));
+    }
 
-            // drop(env_binding);
-               drop(weak_binding);
+    // The local definitions up to the innermost call of a named
+    // function, inner ones last.
+    fn visible_definitions(&self) -> Vec<(String, LocalDef)> {
+        let mut definitions = Vec::new();
 
-              // realize env binding
-                 let rc_binding = self.env.clone();
+        for scope in self.lexical_scopes.iter().rev() {
+            match scope {
+                Some(scope) => {
+                    definitions.splice(0..0, scope.iter().cloned());
+                }
 
-            let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-            Rc::downgrade(&rc_binding);
+                None => break,
+            }
+        }
 
-            let binding = weak_binding.upgrade().unwrap();
-            drop(rc_binding);
+        definitions
+    }
 
-            let env_binding = binding.borrow_mut();
+    // The innermost local definition of name in the function namespace
+    // (operator) or in the variable namespace.
+    fn get_local_def(&self, name: &str, operator: bool) -> Option<LocalDef> {
+        for scope in self.lexical_scopes.iter().rev() {
+            let scope = scope.as_ref()?;
+
+            let local_def = scope.iter()
+                                 .rev()
+                                 .find(|(def_name, local_def)| {
+                                     def_name.eq(name) &&
+                                     local_def.is_operator() == operator
+                                 });
+
+            if let Some((_, local_def)) = local_def {
+                return Some(local_def.clone())
+            }
+        }
 
-            // env_binding.release_curr_eval_dyn_env();
-               let is_toplevel = env_binding.is_toplevel();
+        None
+    }
 
-            println!("toplevel is: {:?}", is_toplevel);
-            println!("");
+    // Binds the special variable name in the current frame.
+    fn bind_special(&mut self, name: &str, var: RLVar) {
+        rltrace!(TraceCategory::Env, TraceLevel::Info,
+                 "bind special {} = {}", name.to_uppercase(), var);
 
-            drop(env_binding);
-            drop(weak_binding);
-
-            match sexpr {
-            Ok(mut sexpr) => {
-            println!("in eval_ds - sexpr: {:?}", sexpr);
-            println!("");
-
-                    if is_toplevel {
-                    match sexpr {
-                    SExpr::Atom(atom) => {
-                    self.configure_with_sexpr_macro(
-                    SExpr::Atom(atom))
-                    }
+        let old_var = self.with_env(|env| env.bind_special_var(name, var));
 
-                            SExpr::SList(ref mut slist) => {
+        if let Some(frame) = self.special_frames.last_mut() {
+            frame.saved.push((name.to_uppercase(), old_var));
+        }
+    }
 
-                                let cons = slist.slist_to_cons();
+    // Special variables are proclaimed by DEFVAR and DEFPARAMETER,
+    // declared lists the ones of (declare (special ...)).
+    fn is_special(&mut self, name: &str, declared: &[String]) -> bool {
+        declared.iter().any(|special| special.eq_ignore_ascii_case(name)) ||
+            self.with_env(|env| env.is_special_var(name))
+    }
 
-                                println!("cons: {:?}", cons);
-                                println!("");
+    // Restores the environment of the enclosing frame and the values
+    // of the special variables bound by the frame. Assignments to
+    // variables the frame did not bind itself are kept, they belong
+    // to the bindings of the enclosing frame.
+    fn pop_dyn_env(&mut self) {
+        if let Some(frame) = self.special_frames.pop() {
+            for (name, old_var) in frame.saved.into_iter().rev() {
+                rltrace!(TraceCategory::Env, TraceLevel::Info,
+                         "unbind special {}", name);
+
+                self.with_env(|env| env.restore_special_var(&name, old_var));
+            }
+        }
 
-                                let sform = slist.slist_to_sform();
-                                println!("sform: {:?}", sform);
-                                println!("");
+        let names = self.frame_names.pop().flatten();
 
-                                self.configure_with_sexpr_macro(cons);
+        self.with_env(|env| {
+            let inner_map = env.get_ref_curr_eval_dyn_env()
+                               .as_ref()
+                               .map(clone_hash_map);
 
-                            }
-                            _ => unreachable!(),
-                            }
-                            } else {
-                            self.configure_with_sexpr_macro(sexpr);
-                            }
-                            }
+            env.release_curr_eval_dyn_env();
 
-                Err(err) => {
-                self.error = Some(err);
+            if let (Some(names), Some(inner_map)) = (names, inner_map) {
+                for (key, value) in inner_map {
+                    if !names.contains(&key) &&
+                       env.get_curr_eval_dyn_env_var(&key).is_some() {
+                        env.add_curr_eval_dyn_env_var(key, value);
+                    }
                 }
+            }
+        })
+    }
+}
+
+// (comma x), (comma-at x), (backquote x) => (name, x)
+fn reader_macro_form(items: &LinkedList<SExpr>) -> Option<(String, SExpr)> {
+    if items.len() != 2 {
+        return None
+    }
+
+    match (items.front(), items.back()) {
+        (Some(SExpr::Symb(symb)), Some(inner)) => {
+            let name = symb.get_symbol_name();
+
+            match &*name {
+                "comma" | "comma-at" | "backquote" => {
+                    Some((name, inner.clone()))
                 }
 
-            match self.eval() {
-            Ok(res)  => {
-            self.set_macro_mode(false);
+                _ => None,
+            }
+        }
+
+        _ => None,
+    }
+}
+
+fn reader_macro_item(item: &SExpr) -> Option<(String, SExpr)> {
+    match item {
+        SExpr::SList(slist) => reader_macro_form(&slist.get_items()),
+
+        _ => None,
+    }
+}
 
-                   return Ok(res);
-                   }
+// (a b c) => "A B C"
+fn format_items(items: &LinkedList<SExpr>) -> String {
+    items.iter()
+         .map(|item| item.to_string())
+         .collect::<Vec<String>>()
+         .join(" ")
+}
 
-               Err(err) => {
-               if let Some(self_err) = &self.error {
+fn two_element_list(name: &str, sexpr: SExpr) -> SExpr {
+    let mut items = LinkedList::<SExpr>::new();
 
-                       return Err(self_err.clone());
-                       } else {
-                       return Err(err);
-                       }
-                       }
-                       }
-                       } else {
-                       return Err(RLError::SimpleProgramError);
-                       }
-    
-      */
+    items.push_back(SExpr::Symb(RLSymbol::new_with_str(name)));
+    items.push_back(sexpr);
 
+    SExpr::SList(RLList::<SExpr>::new_with_items(&items))
+}
 
+fn catch_return_from(block: &RLBlock,
+                     result: Result<SExpr, RLUnwind>) ->
+    Result<SExpr, RLUnwind> {
 
-     ///////////////////////////////////////////////////////////
-     /*
-     Utility functions
-      */
-     ///////////////////////////////////////////////////////////
+    match result {
+        Err(RLUnwind::ReturnFrom(id, value)) if id.eq(&block.get_id()) => {
+            Ok(*value)
+        }
 
-     pub fn get_sexpr(&self) -> &SExpr {
-        &self.sexpr
+        result => result,
     }
+}
 
-    pub fn set_sexpr(&mut self, sexpr: SExpr) {
-        self.sexpr = sexpr.clone();
-    }
+fn unwind_to_error(unwind: RLUnwind) -> RLError {
+    match unwind {
+        RLUnwind::Error(err) | RLUnwind::Unhandled(err) => err,
 
-    pub fn reset(&mut self) {
-        self.sexpr = SExpr::Nil(RLNil::new());
+        RLUnwind::ReturnFrom(id, _value) => {
+            let err = ReturnFromError::new(&id);
 
-        self.parser.reset();
+            RLError::ReturnFromError(err)
+        }
 
-        // realize env binding
-        let rc_binding = self.env.clone();
+        RLUnwind::Go(_id, tag) => {
+            let err = ControlError::new(&format!(
+                "Attempt to GO to the tag {} outside of its TAGBODY.",
+                tag.to_uppercase()));
 
-        let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-            Rc::downgrade(&rc_binding);
+            RLError::ControlError(err)
+        }
 
-        let binding = weak_binding.upgrade().unwrap();
-        drop(rc_binding);
+        RLUnwind::HandlerCase(_id, _clause, condition) => {
+            let err = ControlError::new(&format!(
+                "The HANDLER-CASE for {} has been left.", condition));
 
-        let mut env_binding = binding.borrow_mut();
+            RLError::ControlError(err)
+        }
 
-        env_binding.block_chain_clear();
+        RLUnwind::Restart(_id, _index, _args) => {
+            let err = ControlError::new(
+                "The form which established the restart has been left.");
 
-        drop(env_binding);
-        drop(weak_binding);
+            RLError::ControlError(err)
+        }
     }
+}
 
-    pub fn update_symbols(&mut self) {
-        println!("begin update_symbols");
-        println!("");
+fn no_restart(identifier: &SExpr) -> RLError {
+    let err = ControlError::new(&format!("No restart {} is active.",
+                                         identifier));
 
-        // realize env binding to environment
-        let rc_binding = self.env.clone();
+    RLError::ControlError(err)
+}
 
-        let weak_binding: std::rc::Weak<RefCell<dyn EnvTrait>> =
-            Rc::downgrade(&rc_binding);
+fn undefined_function(name: &str) -> RLUnwind {
+    let err_description = format!("The function COMMON-LISP-USER::{} is \
+                                   undefined.",
+                                  name.to_uppercase());
 
-        let binding = weak_binding.upgrade().unwrap();
-        drop(rc_binding);
+    let err = UndefinedFuncError::new(&err_description);
 
-        let mut env_binding = binding.borrow_mut();
+    RLUnwind::Error(RLError::UndefinedFuncError(err))
+}
 
-        let curr_pack = env_binding.get_mut_current_package();
+// The value of the index-th function of group
+fn function_value(group: &Rc<FunctionGroup>, index: usize) -> RLFunction {
+    let closure = Closure {
+        group: group.clone(),
+        index,
+    };
 
-        let curr_hash_map = curr_pack.get_symbols_hash_map();
+    RLFunction::new(&closure.get_lambda().get_id(), Rc::new(closure))
+}
 
-        for key in curr_hash_map.keys() {
-            self.parser.lexer.add_symbol(&key.to_lowercase());
-        }
+// The functions of a FLET or LABELS form as local definitions
+fn local_functions(group: &Rc<FunctionGroup>) -> Vec<(String, LocalDef)> {
+    group.functions
+         .iter()
+         .enumerate()
+         .map(|(index, (name, _lambda))| {
+             (name.clone(), LocalDef::Function(function_value(group, index)))
+         })
+         .collect()
+}
 
-        // self.parser.lexer.show_all_symbols();
+fn control_stack_exhausted() -> RLUnwind {
+    let err = SimpleError::new(&format!(
+        "Control stack exhausted: more than {} nested evaluations.",
+        EVAL_DEPTH_LIMIT));
 
-        println!("end update_symbols");
-        println!("");
-    }
+    RLUnwind::Error(RLError::SimpleError(err))
 }
 
-pub fn contains_sexpr_cons(mut list: LinkedList<SExpr>) -> bool {
-    let mut result: bool = false;
+fn illegal_function_call(head: &SExpr) -> RLUnwind {
+    let err_description = format!("Illegal function call: {}", head);
 
-    while list.is_empty().not() {
-        let item = list.pop_front();
-        match item {
-            Some(SExpr::Cons(_symb, _ll)) => {
-                result = true;
-                return result;
-            }
-            _ => { result = false; }
-        }
-     }
-     result
+    let err = SimpleError::new(&err_description);
+
+    RLUnwind::Error(RLError::SimpleError(err))
 }
@@ -3,9 +3,10 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+pub mod debugger;
 pub mod evaluator;
 pub mod named_ds_bind;
-pub mod zipper;
+pub mod unwind;
 
 
 
@@ -3,70 +3,61 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-use std::cell::RefMut;
-use std::collections::{BTreeMap, HashMap, LinkedList};
-use std::ops::Not;
+use std::collections::{HashMap, LinkedList};
+
+use cl::list::make_list;
 
 use env::block::RLBlock;
-use env::env_trait::EnvTrait;
+use env::ordinary_lambda_list::{RLOrdinaryLambdaList, RLParam};
 use env::var::RLVar;
 
-use err::err::{RLError,
-               SimpleError,
-               UnboundVariableError};
+use err::err::{ProgramError, RLError, TypeError};
 
-use expr::atom::RLAtom;
-use expr::comma::RLComma;
+use expr::cons::RLCons;
 use expr::list::RLList;
 use expr::nil::RLNil;
 use expr::sexpr::SExpr;
+use expr::symb::RLSymbol;
 
 use hash::hash::RLHash;
 
-use rand::Rng;
-
-const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
-                         0123456789";
+// A binding the evaluator makes after the required parameters.
+#[derive(Debug, Clone)]
+pub enum RLBinding {
+    // variable and the form of its value
+    Var(String, SExpr),
 
-const ID_LEN: usize = 8;
+    // nested lambda list and the form of the list it destructures
+    Pattern(Box<RLOrdinaryLambdaList>, SExpr),
+}
 
+// Binds the parameters of a lambda list to a list of arguments. The
+// arguments are evaluated for functions and unevaluated for macros.
+// The required parameters are bound to their arguments, all other
+// parameters become bindings which the evaluator makes one after
+// another like LET*, so init forms see the parameters to their left.
+// Supplied arguments are quoted.
 #[derive(Debug)]
 pub struct RLNamedDsBind {
-    expr_ll: LinkedList<SExpr>,
-
-    sexpr_ll: LinkedList<SExpr>,
-
-    sexpr_with_scons: SExpr,
-
-    scons: BTreeMap<String, SExpr>,
+    block: RLBlock,
 
-    // the two parameters from the Lisp spec
-    // expr:
-    // env:
+    bindings: LinkedList<RLBinding>,
 
-    block: RLBlock,
+    // the value of &whole if it is not the list of arguments, like the
+    // whole form of a macro call
+    whole: Option<SExpr>,
 }
 
 impl RLNamedDsBind {
 
     pub fn new() -> RLNamedDsBind {
 
-        let expr_ll = LinkedList::<SExpr>::new();
-
-        let sexpr_ll = LinkedList::<SExpr>::new();
-
-        let sexpr_with_scons = SExpr::Nil(RLNil::new());
-
-        let scons = BTreeMap::<String, SExpr>::new();
-
-        let block = RLBlock::new("Dummy".to_string());
+        let block = RLBlock::new_with_id_as_name();
 
         Self {
-            expr_ll,
-            sexpr_ll,
-            sexpr_with_scons,
-            scons,
             block,
+            bindings: LinkedList::new(),
+            whole: None,
         }
     }
 
@@ -74,1439 +65,338 @@ impl RLNamedDsBind {
         self.block = block.clone();
     }
 
-    pub fn set_expr_ll(&mut self, ll: &LinkedList<SExpr>) {
-        self.expr_ll = ll.clone()
+    pub fn set_whole(&mut self, whole: SExpr) {
+        self.whole = Some(whole);
     }
 
-    pub fn contains_sexpr_slist(&self, ll: &mut LinkedList<SExpr>) -> bool {
-        loop {
-            match ll.pop_front() {
-                Some(SExpr::SList(_slist)) => {
-                    return true;
-                }
+    // Binds the arguments of a call.
+    pub fn bind(&mut self,
+                parameters: &RLOrdinaryLambdaList,
+                args: &LinkedList<SExpr>) -> Result<(), RLError> {
 
-                None => {
-                    return false;
-                }
-
-                _ => {}
-            }
-        }
+        self.bind_list(parameters, args, None)
     }
 
-    pub fn bq_comma(&self, ll: &mut LinkedList<SExpr>) -> RLComma {
-        println!("Begin bq_comma");
-        println!("");
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        let mut stored_item: SExpr;
-
-        loop {
-            let item = ll.pop_front().unwrap();
-
-            stored_item = item.clone();
+    // Binds the elements of value, which must be a list matching the
+    // shape of the destructuring lambda list.
+    pub fn destructure(&mut self,
+                       parameters: &RLOrdinaryLambdaList,
+                       value: &SExpr) -> Result<(), RLError> {
 
-            println!("item: {:?}", item);
-            println!("");
+        let (items, tail) = match value {
+            SExpr::SList(slist) => (slist.get_items(), None),
 
-            match item {
-                SExpr::Symb(symb) => {
-                    let symb_name = symb.get_symbol_name();
+            SExpr::Nil(_) => (LinkedList::<SExpr>::new(), None),
 
-                    match &*symb_name {
-                        "`" => {
-                            result_ll.push_back(SExpr::Symb(symb));
-                        }
-
-                        "," => {
-                            result_ll.push_back(SExpr::Symb(symb));
-                        }
-
-                        "'" => {
-                            result_ll.push_back(SExpr::Symb(symb));
-                        }
-
-                        ",@" => {
-                            result_ll.push_back(SExpr::Symb(symb));
-                        }
-
-                        &_ => {
-                            println!("stored_item: {:?}", stored_item);
-                            println!("");
-
-                            result_ll.push_back(stored_item.clone());
-
-                            ll.push_front(stored_item);
-                            break;
-                        }
-                    }
-                }
+            SExpr::SCons(_) => {
+                let mut items = LinkedList::<SExpr>::new();
 
-                _ => {
-                    println!("stored_item: {:?}", stored_item);
-                    println!("");
+                let mut rest = value.clone();
 
-                    result_ll.push_back(stored_item.clone());
+                while let SExpr::SCons(cons) = rest {
+                    items.push_back(cons.car());
 
-                    ll.push_front(stored_item);
-                    break;
+                    rest = cons.cdr();
                 }
-            }
-        }
-
-        println!("ll: {:?}", ll);
-        println!("");
-
-        println!("result_ll: {:?}", result_ll);
-        println!("");
-
-        let mut bq_comma_expr = RLComma::new();
-        bq_comma_expr.init_members(&result_ll);
-
-        println!("bq_comma_expr: {:?}", bq_comma_expr);
-        println!("");
-
-        println!("End bq_comma");
-        println!("");
-
-        bq_comma_expr
-    }
-
-
-
-
-
-    // `(+ `,(+ 1 2) 3)
-    // `(+ ,(+ 1 `,(+ 2 3) 4) 5)
-    // `(+ ,(+ 1 `,(+ `,x `,y) `,z) 3)
-    // `(+ 1 ,a)
-
-    pub fn create_comma_exprs(&mut self,
-                              ll: &LinkedList<SExpr>,
-                              mut dangling_bq: i64) ->
-        LinkedList<SExpr> {
-
-        println!("Begin - create_comma_exprs");
-        println!("");
-
-        println!("dangling_bq: {:?}", dangling_bq);
-        println!("");
-
-        let mut linked_list = ll.clone();
-
-        println!("linked_list: {:?}", linked_list);
-        println!("");
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        // let stored_ll = linked_list.clone();
-
-        // let comma_expr_ll = LinkedList::<SExpr>::new();
-        let mut slist_ll = LinkedList::<SExpr>::new();
-
-        let mut work_on_slist = false;
-
-        let mut comma_expr = RLComma::new();
-
-        let mut list_comma_expr = RLComma::new();
-
-        let mut comma_expr_created = false;
-        let mut list_comma_expr_created = false;
-
-        let mut paren_count = 0;
-
-        loop {
-            match linked_list.pop_front() {
-                Some(SExpr::Symb(mut symb)) => {
-                    let symb_name = symb.get_symbol_name();
 
-                    match &*symb_name {
-                        "`" => {
-                            if work_on_slist {
-                                slist_ll.push_back(SExpr::Symb(symb));
-                            } else {
-                                linked_list.push_front(SExpr::Symb(symb));
+                match rest {
+                    SExpr::SList(slist) => {
+                        items.append(&mut slist.get_items());
 
-                                comma_expr =
-                                    self.bq_comma(&mut linked_list);
-
-                                comma_expr_created = true;
-                            }
-                        }
-
-                        "," => {
-                            if work_on_slist {
-                                slist_ll.push_back(SExpr::Symb(symb));
-                            } else {
-                                linked_list.push_front(SExpr::Symb(symb));
-
-                                comma_expr = self.bq_comma(&mut linked_list);
-                                comma_expr_created = true;
-                            }
-                        }
-
-                        ",@" => {
-                            if work_on_slist {
-                                slist_ll.push_back(SExpr::Symb(symb));
-                            } else {
-                                linked_list.push_front(SExpr::Symb(symb));
-
-                                comma_expr =
-                                    self.bq_comma(&mut linked_list);
-
-                                comma_expr_created = true;
-                            }
-                        }
-
-                        "(" => {
-                            if work_on_slist &&
-                               comma_expr_created.not() {
-
-                                paren_count = paren_count + 1;
-                                slist_ll.push_back(SExpr::Symb(symb));
-
-                            } else if work_on_slist &&
-                                      comma_expr_created {
-
-                                // comma_expr_created = false;
-
-                                paren_count = paren_count + 1;
-                                slist_ll.push_back(SExpr::Symb(symb))
-
-                            } else if work_on_slist.not() &&
-                                      comma_expr_created {
-
-                                work_on_slist = true;
-
-                                comma_expr_created = false;
-
-                                list_comma_expr = comma_expr.clone();
-
-                                println!("list_comma_expr: {:?}",
-                                     list_comma_expr);
-                                println!("");
-
-                                let list_bq_count =
-                                    list_comma_expr.get_list_bq_count();
-
-                                let elem_bq_count =
-                                    list_comma_expr.get_elem_bq_count();
-
-                                let comma_count =
-                                    list_comma_expr.get_comma_count();
-
-                                let bq_comma_diff =
-                                    list_bq_count +
-                                    elem_bq_count -
-                                    comma_count;
-
-                                if bq_comma_diff > 0 {
-                                    dangling_bq = dangling_bq +
-                                                  bq_comma_diff;
-                                }
-
-                                list_comma_expr.set_list_bq_count(
-                                    dangling_bq);
-
-                                println!("list_comma_expr -after setting danglin_bq: {:?}", list_comma_expr);
-                                println!("");
-
-                                list_comma_expr.set_is_list(true);
-
-                                list_comma_expr_created = true;
-
-                                paren_count = paren_count + 1;
-                                slist_ll.push_back(SExpr::Symb(symb));
-
-                            } else if work_on_slist.not() &&
-                                      comma_expr_created.not() {
-
-                               result_ll.push_back(SExpr::Symb(symb));
-                            }
-                        }
-
-                        ")" => {
-                            if work_on_slist {
-                                paren_count = paren_count - 1;
-                                slist_ll.push_back(SExpr::Symb(symb));
-
-                                if paren_count == 0 {
-                                    work_on_slist = false;
-
-                                    let mut new_rllist =
-                                        RLList::<SExpr>::new_with_list(
-                                            &slist_ll);
-
-                                    if list_comma_expr_created {
-                                        list_comma_expr_created = false;
-                                        new_rllist.set_comma(&list_comma_expr);
-                                        println!("list_comma_expr: {:?}",
-                                            list_comma_expr);
-                                        println!("");
-                                    }
-
-                                    let new_ll =
-                                        self.create_comma_exprs(&slist_ll,
-                                                                dangling_bq);
-
-                                    new_rllist.set_linked_list(&new_ll);
-
-
-                                    result_ll.push_back(
-                                        SExpr::SList(new_rllist));
-
-                                    slist_ll.clear();
-                                }
-                            } else {
-                                result_ll.push_back(SExpr::Symb(symb));
-                            }
-                        }
-
-                        &_ => {
-                            if work_on_slist &&
-                               comma_expr_created {
-
-                               symb.set_comma(&comma_expr);
-
-                               comma_expr_created = false;
-
-                               slist_ll.push_back(SExpr::Symb(symb));
-
-                            } else if work_on_slist &&
-                                      comma_expr_created.not() {
-
-                                slist_ll.push_back(SExpr::Symb(symb));
-
-                            } else if work_on_slist.not() &&
-                                      comma_expr_created {
-
-                                comma_expr.set_list_bq_count(dangling_bq);
-
-                                symb.set_comma(&comma_expr);
-
-                                comma_expr_created = false;
-
-                                result_ll.push_back(SExpr::Symb(symb));
-                            } else {
-
-                                result_ll.push_back(SExpr::Symb(symb));
-                            }
-                        }
+                        (items, None)
                     }
-                }
 
-                Some(SExpr::Atom(mut atom)) => {
-                    if work_on_slist &&
-                       comma_expr_created {
+                    SExpr::Nil(_) => (items, None),
 
-                        atom.set_comma(&comma_expr);
-
-                        comma_expr_created = false;
-                        // slist_ll.push_back(SExpr::Atom(atom));
-                        slist_ll.push_back(SExpr::Atom(atom));
-
-                    } else if work_on_slist &&
-                              comma_expr_created.not() {
-
-                        slist_ll.push_back(SExpr::Atom(atom));
-                    } else if work_on_slist.not() &&
-                              comma_expr_created {
-
-                        atom.set_comma(&comma_expr);
-
-                        comma_expr_created = false;
-
-                        result_ll.push_back(SExpr::Atom(atom));
-                    } else {
-                        result_ll.push_back(SExpr::Atom(atom));
-                    }
+                    tail => (items, Some(tail)),
                 }
-
-                Some(SExpr::Nil(nil)) => {
-                    if work_on_slist &&
-                       comma_expr_created {
-
-                        comma_expr_created = false;
-
-                        slist_ll.push_back(SExpr::Nil(nil));
-
-                    } else if work_on_slist &&
-                              comma_expr_created.not() {
-
-                        slist_ll.push_back(SExpr::Nil(nil));
-
-                    } else if work_on_slist.not() &&
-                              comma_expr_created {
-
-                        comma_expr_created = false;
-
-                        result_ll.push_back(SExpr::Nil(nil));
-
-                    } else {
-                        result_ll.push_back(SExpr::Nil(nil));
-                    }
-                }
-
-                None => {
-                    break;
-                }
-
-                _ => unreachable!(),
             }
-        }
-
-        println!("final result_ll: {:?}", result_ll);
-        println!("");
-
-        result_ll
-    }
-
-    pub fn build_macro_sexpr(&mut self) {
-        println!("Begin build_macro_sexpr");
-        println!("");
-
-        let mut linked_list = self.expr_ll.clone();
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        let first_bq_comma = self.bq_comma(&mut linked_list);
-
-        let list_bq_count = first_bq_comma.get_list_bq_count();
-        let elem_bq_count = first_bq_comma.get_elem_bq_count();
-        let comma_count   = first_bq_comma.get_comma_count();
-
-        let bq_comma_diff = list_bq_count + elem_bq_count - comma_count;
-
-        let mut dangling_bq: i64 = 0;
-
-        if bq_comma_diff > 0 {
-            dangling_bq = bq_comma_diff;
-        }
-
-        println!("linked_list: {:?}", linked_list);
-        println!("");
 
-        let sexpr_item = linked_list.pop_front().expect("REASON");
+            other => {
+                let err = TypeError::new(&other.to_string(), "LIST");
 
-        match sexpr_item {
-            SExpr::Symb(mut symb)   => {
-                symb.set_comma(&first_bq_comma);
-
-                result_ll.push_back(SExpr::Symb(symb));
-            }
-
-            SExpr::Atom(mut atom)   => {
-                atom.set_comma(&first_bq_comma);
-
-                result_ll.push_back(SExpr::Atom(atom));
-            }
-
-            SExpr::SList(mut slist) => {
-                slist.set_comma(&first_bq_comma);
-
-                let mut slist_ll = slist.get_linked_list();
-
-                slist_ll = self.create_comma_exprs(&slist_ll,
-                                                   dangling_bq);
-
-                slist.set_linked_list(&slist_ll);
-
-                result_ll.push_back(SExpr::SList(slist));
+                return Err(RLError::TypeError(err))
             }
+        };
 
-            SExpr::Nil(mut nil)     => {
-                nil.set_comma(&first_bq_comma);
-
-                result_ll.push_back(SExpr::Nil(nil));
-            }
-
-            _ => unreachable!(),
-        }
-
-        println!("result_ll: {:?}", result_ll);
-        println!("");
-
-        self.sexpr_ll = result_ll.clone();
-
-        println!("End build_macro_sexpr");
-        println!("");
-    }
-
-
-
+        if tail.is_some() && parameters.rest.is_none() {
+            let err = ProgramError::new(&format!(
+                "dotted list {} given to lambda list {}",
+                value, parameters.lambda_list));
 
-
-    pub fn get_ref_dyn_env_block(&mut self) ->
-        &HashMap<String, RLVar, RLHash> {
-
-        self.block.get_ref_dyn_env_block()
-    }
-
-    pub fn get_dyn_env_var(&mut self, key: &str) -> Option<RLVar> {
-        if let Some(item) = self.block.get_dyn_env_var(&key.to_string()
-                                                           .to_uppercase()) {
-            return Some(item);
-        } else {
-            return None;
+            return Err(RLError::ProgramError(err))
         }
-    }
 
-    pub fn add_dyn_env_var(&mut self, name: String, var: RLVar) {
-        self.block.add_dyn_env_var(name.to_uppercase(), var.clone());
+        self.bind_list(parameters, &items, tail)
     }
 
-    pub fn get_replace_expr(&mut self,
-                            comma_var: String,
-                            env_ref: &mut RefMut<'_, dyn EnvTrait>) ->
-        Result<SExpr, RLError> {
+    // args and the tail of a dotted list, which only a &rest parameter
+    // can take
+    fn bind_list(&mut self,
+                 parameters: &RLOrdinaryLambdaList,
+                 args: &LinkedList<SExpr>,
+                 tail: Option<SExpr>) -> Result<(), RLError> {
 
-        let mut result_expr = SExpr::Nil(RLNil::new());
+        let required_count = parameters.required.len();
 
-        /*
-        if let Some(mut var) = env_ref.get_curr_eval_dyn_env_var(&comma_var) {
+        let max_args = required_count + parameters.optional_params.len();
 
-            result_expr = var.var_to_sexpr();
-        } else {
-        */
+        let unlimited = parameters.rest.is_some() || parameters.has_key;
 
-        let comma_var_upp = &comma_var.clone().to_uppercase();
-
-        if let Some(mut var) = self.get_dyn_env_var(&comma_var_upp) {
-            result_expr = var.var_to_sexpr();
-        } else {
-            println!("symbol_current_package: {:?}",
-                env_ref.symbol_in_current_package(&comma_var_upp));
-            println!("");
-
-            if let Some(symb) = env_ref.get_symbol(&comma_var_upp) {
-
-                println!("symb:  {:?}", symb);
-                println!("");
-
-                if let Some(mut var) = symb.get_dyn_var() {
-                    println!("var: {:?}", var);
-                    println!("");
-
-                    result_expr = var.var_to_sexpr();
-                }
-            } else {
-                let err = UnboundVariableError::new(&comma_var.to_uppercase());
-                return Err(RLError::UnboundVariableError(err));
+        if args.len() < required_count || (!unlimited && args.len() > max_args) {
+            if !parameters.destructuring {
+                return Err(RLError::SimpleProgramError)
             }
-        }
-        Ok(result_expr)
-    }
 
-    fn replace_scon(&mut self, ll: &LinkedList<SExpr>,
-                    key: String, sexpr: SExpr) -> LinkedList<SExpr> {
-
-        println!("Begin - replace_scon");
-        println!("");
-
-        let mut linked_list = ll.clone();
-
-        println!("linked_list: {:?}", linked_list);
-        println!("");
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        loop {
-            match linked_list.pop_front() {
-                Some(SExpr::Atom(atom)) => {
-                    let atom_string = atom.get_atom_string();
-
-                    if atom_string.eq(&key) {
-                        result_ll.push_back(sexpr.clone());
-                    } else {
-                        result_ll.push_back(SExpr::Atom(atom));
-                    }
-                }
-
-                Some(SExpr::SList(slist)) => {
-                    let list = slist.get_linked_list();
-
-                    result_ll.push_back(
-                        SExpr::SList(
-                            RLList::<SExpr>::new_with_list(
-                                &self.replace_scon(&list,
-                                                  key.clone(),
-                                                  sexpr.clone()))));
-                }
-
-                Some(t) => {
-                    result_ll.push_back(t);
-                }
-
-                None => {
-                    break;
-                }
-            }
+            return Err(shape_error(parameters,
+                                   &make_dotted_list(args, tail),
+                                   args.len()))
         }
 
-        println!("result_ll: {:?}", result_ll);
-        println!("");
-
-        // self.sexpr_with_scons = result_ll;
-
-        println!("End - replace_scon");
-        println!("");
-
-        result_ll
-    }
+        if let Some(name) = &parameters.whole {
+            let whole = self.whole
+                            .take()
+                            .unwrap_or(make_dotted_list(args, tail.clone()));
 
-    pub fn replace_scons(&mut self, mut btm: BTreeMap<String, SExpr>) -> SExpr {
-        println!("Begin - replace_scons");
-        println!("");
-
-        println!("self.sexpr_with_scons: {:?}", self.sexpr_with_scons);
-        println!("");
-
-        let sexpr = self.sexpr_with_scons.clone();
-
-        let mut result_ll: LinkedList::<SExpr>;
-
-        match sexpr {
-            SExpr::SList(slist) => {
-                result_ll = slist.get_linked_list();
-
-                for _n in 0..btm.len() {
-                    if let Some((key, sexpr)) = btm.pop_first() {
-                        println!("key: {:?}", key);
-                        println!("sexpr: {:?}", sexpr);
-                        println!("");
-
-                        result_ll =
-                            self.replace_scon(&result_ll,
-                                              key.clone(),
-                                              sexpr.clone());
-                    }
-                }
-            }
-            _ => unreachable!(),
+            self.add_dyn_env_var(name.clone(), RLVar::from_sexpr(whole));
         }
 
-        println!("result_ll: {:?}", result_ll);
-        println!("");
-
-        println!("End - replace_scons");
-        println!("");
-
-        SExpr::SList(RLList::<SExpr>::new_with_list(&result_ll))
-    }
-
-
-    fn eval_list_comma_expr(&mut self,
-                            sexpr: SExpr) ->
-        Result<LinkedList<SExpr>, RLError> {
-
-        println!("Begin eval_list_comma_expr");
-        println!("");
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        let slist_ll: LinkedList<SExpr>;
-
-        let mut rllist: RLList<SExpr>;
-
-        let mut result: Result<LinkedList<SExpr>, RLError> =
-            Ok(result_ll.clone());
-
-        let mut comma: RLComma;
-
-        match sexpr {
-            SExpr::SList(ref slist) => {
-
-                slist_ll = slist.get_linked_list();
-
-                rllist = slist.clone();
-
-                println!("slist_ll: {:?}", slist_ll);
-                println!("");
-
-                if let Some(slist_comma) = slist.get_comma() {
-                    println!("slist_comma: {:?}", slist_comma);
-                    println!("");
-
-                    comma = slist_comma;
-                } else {
-                    return Ok(slist_ll);
-                }
-
-                 println!("comma: {:?}", comma);
-                 println!("");
-            }
-            _ => unreachable!(),
+        if let Some(name) = &parameters.environment {
+            self.add_dyn_env_var(name.clone(),
+                                 RLVar::from_sexpr(SExpr::Nil(RLNil::new())));
         }
 
-        let list_bq_count = comma.get_list_bq_count();
-        let elem_bq_count = comma.get_elem_bq_count();
-        let comma_count = comma.get_comma_count();
-
-        // let is_comma_at = comma.get_is_comma_at();
-        let mut eval_expr = comma.get_eval_expr();
-        // let is_list = comma.get_is_list();
-
-        let mut comma_expr = comma.get_comma_expr();
-
-        println!("comma_expr: {:?}", comma_expr);
-        println!("");
-
-        let str_expr = comma.get_str_expr();
-
-        // define certain eval conditions
-        let bq_comma_diff = list_bq_count + elem_bq_count - comma_count;
-
-        if list_bq_count + elem_bq_count == comma_count &&
-           bq_comma_diff == 0 {
-            comma.set_eval_expr(true);
-            eval_expr = true;
-        }
+        let mut args_ll = args.clone();
 
-        if comma_count > list_bq_count + elem_bq_count &&
-           bq_comma_diff < 0 {
-            let err = SimpleError::new("Comma not inside a backquote.");
+        for param in &parameters.required {
+            let arg = args_ll.pop_front().unwrap();
 
-            result = Err(RLError::SimpleError(err));
-        }
-
-        if eval_expr &&
-           str_expr.eq(",") {
-
-            /*
-            for _n in 0..comma_expr.len() {
-                result_ll.push_back(comma_expr.pop_front().unwrap())
-            }
-
-            for _n in 0..slist_ll.len() {
-                if let Some(expr) = slist_ll.pop_front() {
-                    result_ll.push_back(expr);
+            match &param.pattern {
+                Some(pattern) => {
+                    self.bindings.push_back(
+                        RLBinding::Pattern(pattern.clone(), quote(arg)));
                 }
-            }
-
-            result = Ok(result_ll.clone());
-            */
-
-            // let scon = rllist.slist_to_cons();
-            let scon = rllist.slist_to_sform();
-
-            let mut rng = rand::thread_rng();
-
-            let mut scon_id = "scon".to_string();
-
-            scon_id.push('_');
-
-            let id: String = (0..ID_LEN).map(|_| {
-                let idx = rng.gen_range(0..CHARSET.len());
-                CHARSET[idx] as char
-            }).collect();
-
-            scon_id.push_str(id.as_str());
-
-            println!("scon_id: {:?}", scon_id);
-            println!("");
-
-            self.scons.insert(scon_id.clone(), scon.clone());
-
-            println!("scons: {:?}", self.scons);
-            println!("");
-
-            result_ll.push_back(SExpr::Atom(RLAtom::new(&scon_id)));
-
-            println!("result_ll after slist_to_cons: {:?}", result_ll);
-            println!("");
-
-            result = Ok(result_ll.clone());
-        }
-
-        if eval_expr.not() {
-            if comma_expr.len() == 1 {
-                result_ll.push_back(sexpr);
 
-            } else if comma_expr.len() > 1 {
-
-                for _n in 0..comma_expr.len() {
-                    result_ll.push_back(comma_expr.pop_front().unwrap())
+                None => {
+                    self.add_dyn_env_var(param.name.clone(),
+                                         RLVar::from_sexpr(arg));
                 }
-
-                result_ll.push_back(sexpr);
-
-                result = Ok(result_ll.clone());
             }
         }
 
-        println!("End eval_list_comma_expr");
-        println!("");
-
-        result
-    }
-
-    fn eval_member_comma_expr(&mut self,
-                              sexpr: SExpr,
-                              env_ref: &mut RefMut<'_, dyn EnvTrait>)
-        -> Result<LinkedList<SExpr>, RLError> {
-
-        println!("Begin eval_member_comma_expr");
-        println!("");
-
-        let name: String;
-
-        let mut comma = RLComma::new();
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        let mut result: Result<LinkedList<SExpr>, RLError> =
-            Ok(result_ll.clone());
-
-        match sexpr {
-            SExpr::Atom(ref atom) => {
-                name = atom.get_atom_string();
-
-                if let Some(atom_comma) = atom.get_comma() {
-                    println!("atom_comma: {:?}", atom_comma);
-                    println!("");
+        for param in &parameters.optional_params {
+            let supplied = match args_ll.pop_front() {
+                Some(arg) => {
+                    self.add_binding(param, quote(arg));
 
-                    comma = atom_comma;
+                    true
                 }
-            }
-
-            SExpr::Symb(ref symb) => {
-                name = symb.get_symbol_name();
 
-                if let Some(symb_comma) = symb.get_comma() {
-                    comma = symb_comma;
-                }
-            }
-
-            SExpr::Nil(ref nil)   => {
-                name = nil.get_nil_name();
+                None => {
+                    self.add_binding(param, param.init_form.clone());
 
-                if let Some(nil_comma) = nil.get_comma() {
-                    comma = nil_comma;
+                    false
                 }
-            }
-
-            _ => unreachable!(),
-        }
-
-        println!("eval_member_comma_expr - comma: {:?}", comma);
-        println!("");
-
-        let list_bq_count = comma.get_list_bq_count();
-        let elem_bq_count = comma.get_elem_bq_count();
-        let comma_count = comma.get_comma_count();
-
-        // let is_comma_at = comma.get_is_comma_at();
-        let mut eval_expr = comma.get_eval_expr();
-        // let is_list = false;
-
-        let mut comma_expr = comma.get_comma_expr();
-        let str_expr = comma.get_str_expr();
-
-        // define certain eval conditions
-        let bq_comma_diff = list_bq_count + elem_bq_count - comma_count;
+            };
 
-        if list_bq_count + elem_bq_count == comma_count &&
-           bq_comma_diff == 0 {
-            comma.set_eval_expr(true);
-            eval_expr = true;
-        }
-
-        if comma_count > list_bq_count + elem_bq_count &&
-           bq_comma_diff < 0 {
-            let err = SimpleError::new("Comma not inside a backquote.");
-
-            result = Err(RLError::SimpleError(err));
-        }
-
-        if eval_expr &&
-           str_expr.eq(",") {
-
-            if name.eq("NIL") {
-                result_ll.push_back(SExpr::Nil(RLNil::new()));
-            } else {
-                if let Ok(expr) = self.get_replace_expr(name.clone(),
-                                                        env_ref) {
-                    result_ll.push_back(expr);
-                }
+            if let Some(supplied_p) = &param.supplied_p {
+                self.bindings.push_back(
+                    RLBinding::Var(supplied_p.clone(), boolean(supplied)));
             }
-
-            result = Ok(result_ll.clone());
         }
 
-        if eval_expr.not() {
-            if comma_expr.len() == 1 {
-                result_ll.push_back(sexpr);
-
-            } else if comma_expr.len() > 1 {
-
-                for _n in 0..comma_expr.len() {
-                    result_ll.push_back(comma_expr.pop_front().unwrap())
-                }
-
-                result_ll.push_back(sexpr);
-
-                result = Ok(result_ll.clone());
-            }
+        if let Some(param) = &parameters.rest {
+            self.add_binding(param, quote(make_dotted_list(&args_ll, tail)));
         }
 
-        if eval_expr &&
-           str_expr.eq(",@") {
-
-            let expr = self.get_replace_expr(name.clone(), env_ref);
+        if parameters.has_key {
+            let keyword_args = keyword_args(parameters, &args_ll)?;
 
-            match expr {
-                Ok(mut res) => {
-                    if let SExpr::SList(ref mut slist) = res {
-                        slist.pop_front();
-                        slist.pop_back();
+            for param in &parameters.key_params {
+                let arg = keyword_args.iter()
+                                      .find(|(keyword, _)| {
+                                          keyword.eq(&param.keyword)
+                                      });
 
-                        for _n in 0..slist.len() {
-                            if let Some(item) = slist.pop_front() {
-
-                                println!("item: {:?}", item);
-                                println!("");
+                match arg {
+                    Some((_, arg)) => {
+                        self.add_binding(param, quote(arg.clone()));
+                    }
 
-                                result_ll.push_back(item);
-                            }
-                        }
+                    None => {
+                        self.add_binding(param, param.init_form.clone());
                     }
-                    result = Ok(result_ll);
                 }
 
-                Err(err) => {
-                    result = Err(err);
+                if let Some(supplied_p) = &param.supplied_p {
+                    self.bindings.push_back(
+                        RLBinding::Var(supplied_p.clone(),
+                                       boolean(arg.is_some())));
                 }
             }
         }
 
-        println!("result: {:?}", result);
-        println!("");
-
-        println!("End eval_member_comma_expr");
-        println!("");
+        for param in &parameters.aux_params {
+            self.add_binding(param, param.init_form.clone());
+        }
 
-        result
+        Ok(())
     }
 
-    fn eval_simple_comma_expr(&mut self,
-                              sexpr: SExpr,
-                              env_ref: &mut RefMut<'_, dyn EnvTrait>) ->
-        Result<SExpr, RLError> {
-
-        println!("Begin eval_simple_comma_expr");
-        println!("");
-
-        let mut result: Result<SExpr, RLError> =
-            Ok(SExpr::Nil(RLNil::new()));
-
-        let name: String;
-
-        let mut comma = RLComma::new();
-
-        let mut sexpr_ll = LinkedList::<SExpr>::new();
-
-        match sexpr {
-            SExpr::Atom(ref atom) => {
-                name = atom.get_atom_string();
-
-                if let Some(atom_comma) = atom.get_comma() {
-                    println!("atom_comma: {:?}", atom_comma);
-                    println!("");
+    // the bindings of the parameters following the required ones
+    pub fn get_bindings(&self) -> LinkedList<RLBinding> {
+        self.bindings.clone()
+    }
 
-                    comma = atom_comma;
-                }
-            }
+    fn add_binding(&mut self, param: &RLParam, form: SExpr) {
+        let binding = match &param.pattern {
+            Some(pattern) => RLBinding::Pattern(pattern.clone(), form),
 
-            SExpr::Symb(ref symb) => {
-                name = symb.get_symbol_name();
+            None => RLBinding::Var(param.name.clone(), form),
+        };
 
-                if let Some(symb_comma) = symb.get_comma() {
-                    comma = symb_comma;
-                }
-            }
+        self.bindings.push_back(binding);
+    }
 
-            SExpr::Nil(ref nil)   => {
-                name = nil.get_nil_name();
+    pub fn get_dyn_env_block(&mut self) -> HashMap<String, RLVar, RLHash> {
+        self.block.get_dyn_env_block()
+    }
 
-                if let Some(nil_comma) = nil.get_comma() {
-                    comma = nil_comma;
-                }
-            }
+    pub fn get_dyn_env_var(&mut self, key: &str) -> Option<RLVar> {
+        self.block.get_dyn_env_var(key)
+    }
 
-            _ => unreachable!(),
-        }
+    pub fn add_dyn_env_var(&mut self, name: String, var: RLVar) {
+        self.block.add_dyn_env_var(name, var)
+    }
+}
 
-        println!("comma: {:?}", comma);
-        println!("");
+// The (keyword, value) pairs of the arguments following the optional
+// ones. Unknown keywords are an error unless the lambda list has
+// &allow-other-keys or the arguments contain :allow-other-keys with a
+// true value.
+fn keyword_args(parameters: &RLOrdinaryLambdaList,
+                args: &LinkedList<SExpr>) ->
+    Result<Vec<(String, SExpr)>, RLError> {
 
-        // let list_bq_count = comma.get_list_bq_count();
-        let elem_bq_count = comma.get_elem_bq_count();
-        let comma_count = comma.get_comma_count();
+    if !args.len().is_multiple_of(2) {
+        let err = ProgramError::new("odd number of &KEY arguments");
 
-        let is_comma_at = comma.get_is_comma_at();
-        let mut eval_expr = comma.get_eval_expr();
-        // let is_list = false;
+        return Err(RLError::ProgramError(err))
+    }
 
-        println!("is_comma_at: {:?}", is_comma_at);
-        println!("");
+    let mut pairs = Vec::<(SExpr, SExpr)>::new();
 
-        let mut comma_expr = comma.get_comma_expr();
-        let str_expr = comma.get_str_expr();
+    let mut items = args.iter();
 
-        // define certain eval conditions
-        let bq_comma_diff = elem_bq_count - comma_count;
+    while let (Some(keyword), Some(value)) = (items.next(), items.next()) {
+        pairs.push((keyword.clone(), value.clone()));
+    }
 
-        if elem_bq_count == comma_count &&
-           bq_comma_diff == 0 {
-            comma.set_eval_expr(true);
-            eval_expr = true;
-        }
+    let mut allow_other_keys = parameters.allow_other_keys;
 
-        if comma_count > elem_bq_count &&
-           bq_comma_diff < 0 {
-            let err = SimpleError::new("Comma not inside a backquote.");
+    let first_allow = pairs.iter().find(|(keyword, _)| {
+        keyword_name(keyword).as_deref() == Some(":allow-other-keys")
+    });
 
-            result = Err(RLError::SimpleError(err));
-        }
+    if let Some((_, value)) = first_allow {
+        allow_other_keys |= !matches!(value, SExpr::Nil(_));
+    }
 
-        if is_comma_at {
-            let err = SimpleError::new(
-                &format!("{} is not a well-formed backquote expression.",
-                    str_expr));
+    let mut keyword_args = Vec::<(String, SExpr)>::new();
 
-            result =  Err(RLError::SimpleError(err));
-        }
+    for (keyword, value) in pairs {
+        let name = keyword_name(&keyword);
 
-        /*
-                X      ,X      ,@X
-             ,',X     ,,X     ,@,X
-            ,@',X    ,,@X    ,@,@X
-        */
+        let known = name.as_ref().is_some_and(|name| {
+            name.eq(":allow-other-keys") ||
+            parameters.key_params
+                      .iter()
+                      .any(|param| param.keyword.eq(name))
+        });
 
-        if eval_expr &&
-           str_expr.eq("`,") {
+        if !known && !allow_other_keys {
+            let err = ProgramError::new(&format!(
+                "Unknown &KEY argument: {}",
+                keyword.to_string().to_uppercase()));
 
-            if name.eq("NIL") {
-                result = Ok(SExpr::Nil(RLNil::new()));
-            } else {
-                result = self.get_replace_expr(name.clone(), env_ref);
-            }
+            return Err(RLError::ProgramError(err))
         }
 
-        if eval_expr.not() {
-            if comma_expr.len() == 1 {
-                result = Ok(sexpr);
-
-            } else if comma_expr.len() > 1 {
-                comma_expr.pop_front();
-
-                for _n in 0..comma_expr.len() {
-                    sexpr_ll.push_back(comma_expr.pop_front().unwrap())
-                }
-
-                sexpr_ll.push_back(sexpr);
-
-                result = Ok(SExpr::SList(
-                             RLList::<SExpr>::new_with_list(&sexpr_ll)));
-            }
+        if let Some(name) = name {
+            keyword_args.push((name, value));
         }
-
-        println!("result: {:?}", result);
-        println!("");
-
-        println!("End eval_simple_comma_expr");
-        println!("");
-
-        result
     }
 
+    Ok(keyword_args)
+}
 
+fn keyword_name(sexpr: &SExpr) -> Option<String> {
+    match sexpr {
+        SExpr::Symb(symb) => Some(symb.get_symbol_name()),
 
-
-
-    pub fn replace_comma_exprs(&mut self,
-                               ll: &LinkedList<SExpr>,
-                               list_bq_count: i64,
-                               env_ref: &mut RefMut<'_, dyn EnvTrait>)
-        -> LinkedList<SExpr> {
-
-        println!("Begin - replace_list_comma_exprs");
-        println!("");
-
-        let mut linked_list = ll.clone();
-
-        println!("linked_list: {:?}", linked_list);
-        println!("");
-
-        let mut result_ll = LinkedList::<SExpr>::new();
-
-        loop {
-            match linked_list.pop_front() {
-                Some(SExpr::Atom(mut atom)) => {
-                    if atom.has_comma() {
-
-                        let mut comma = atom.get_comma().unwrap();
-                        comma.set_list_bq_count(list_bq_count);
-                        atom.set_comma(&comma);
-
-                        if let Ok(mut sexpr_ll) =
-                            self.eval_member_comma_expr(SExpr::Atom(atom),
-                                                        env_ref) {
-
-                            for _n in 0..sexpr_ll.len() {
-                                if let Some(item) = sexpr_ll.pop_front() {
-                                    result_ll.push_back(item);
-                                }
-                            }
-                        }
-                    } else {
-                        result_ll.push_back(SExpr::Atom(atom));
-                    }
-                }
-
-                Some(SExpr::Symb(mut symb)) => {
-                    /*
-                    let name = symb.get_symbol_name();
-                    if let Some(symb_comma) = symb.get_comma() {
-                        println!("symb_comma: {:?}", symb_comma);
-                        println!("");
-                    }
-                    */
-
-                    if symb.has_comma() {
-                        let mut comma = symb.get_comma().unwrap();
-                        comma.set_list_bq_count(list_bq_count);
-                        symb.set_comma(&comma);
-
-                        if let Ok(mut sexpr_ll) =
-                            self.eval_member_comma_expr(SExpr::Symb(symb),
-                                                        env_ref) {
-
-                            for _n in 0..sexpr_ll.len() {
-                                if let Some(item) = sexpr_ll.pop_front() {
-                                    result_ll.push_back(item);
-                                }
-                            }
-                        }
-                    } else {
-                        result_ll.push_back(SExpr::Symb(symb));
-                    }
-                }
-
-                Some(SExpr::SList(slist)) => {
-                    println!("in SExpr::SList");
-                    println!("");
-                    println!("slist: {:?}", slist);
-                    println!("");
-
-                    let slist_ll = slist.get_linked_list();
-
-                    let mut next_list_comma = RLComma::new();
-
-
-                    if slist.has_comma() {
-                        println!("slist comma: {:?}", slist.get_comma());
-                        println!("");
-
-                        if let Some(comma) = slist.get_comma() {
-                            next_list_comma = comma;
-                        }
-                    }
-
-                    let list_bq_count_next_list =
-                        list_bq_count + next_list_comma.get_list_bq_count();
-
-                    if self.contains_sexpr_slist(&mut slist_ll.clone()) {
-                        let mut new_rllist =
-                            RLList::<SExpr>::new_with_list(&slist_ll);
-
-                        /*
-                        if list_comma_expr_created {
-                            list_comma_expr_created = false;
-                            new_rllist.set_comma(&list_comma_expr);
-                        }
-                        */
-
-                        /*
-                        if let Some(comma) = slist.get_comma() {
-                            new_list.set_comma(&comma);
-                        }
-                        */
-
-                        let new_ll =
-                            self.replace_comma_exprs(&slist_ll,
-                                                     list_bq_count_next_list,
-                                                     env_ref);
-
-                        println!("new_ll: {:?}", new_ll);
-                        println!("");
-
-                        new_rllist.set_linked_list(&new_ll);
-
-
-                        result_ll.push_back(SExpr::SList(new_rllist));
-                    } else {
-                        /*
-                        result_ll.push_back(SExpr::SList(
-                                        RLList::<SExpr>::new_with_list(
-                                            &self.replace_comma_exprs(
-                                                &slist_ll, env_ref))));
-                        */
-
-                        let mut new_list =
-                            RLList::<SExpr>::new_with_list(
-                                &self.replace_comma_exprs(&slist_ll,
-                                                          list_bq_count,
-                                                          env_ref));
-
-                        if let Some(comma) = slist.get_comma() {
-                            new_list.set_comma(&comma);
-                        }
-
-                        println!("new_list: {:?}", new_list);
-                        println!("");
-
-                        if let Ok(mut sexpr_ll) =
-                            self.eval_list_comma_expr(
-                                SExpr::SList(new_list)) {
-
-                            println!("sexpr_ll: {:?}", sexpr_ll);
-                            println!("");
-
-                            for _n in 0..sexpr_ll.len() {
-                                if let Some(item) = sexpr_ll.pop_front() {
-                                    result_ll.push_back(item);
-                                }
-                            }
-                        } else {
-                            println!("NOT OK!");
-                            println!("");
-                        }
-                    }
-                }
-
-                Some(SExpr::Nil(mut nil))     => {
-                    if nil.has_comma() {
-
-                        let mut comma = nil.get_comma().unwrap();
-                        comma.set_list_bq_count(list_bq_count);
-                        nil.set_comma(&comma);
-
-                        if let Ok(mut sexpr_ll) =
-                            self.eval_member_comma_expr(SExpr::Nil(nil),
-                                                        env_ref) {
-
-                        for _n in 0..sexpr_ll.len() {
-                            if let Some(item) = sexpr_ll.pop_front() {
-                                result_ll.push_back(item);
-                                }
-                            }
-                        }
-                    } else {
-                        result_ll.push_back(SExpr::Nil(nil));
-                    }
-                }
-
-                None => {
-                    break;
-                }
-
-                _ => unreachable!(),
-            }
-        }
-
-        println!("result_ll: {:?}", result_ll);
-        println!("");
-
-        println!("End - replace_list_comma_exprs");
-        println!("");
-
-        result_ll
+        _ => None,
     }
+}
 
-    fn start_eval_list(&mut self,
-                       sexpr: SExpr,
-                       env_ref: &mut RefMut<'_, dyn EnvTrait>) ->
-        Result<SExpr, RLError> {
-
-        println!("start_eval_list");
-        println!("");
-
-        let mut result: Result<SExpr, RLError> =
-            Ok(SExpr::Nil(RLNil::new()));
-
-        let mut comma = RLComma::new();
-
-        match sexpr {
-            SExpr::SList(mut slist) => {
-
-                if let Some(slist_comma) = slist.get_comma() {
-                    comma = slist_comma;
-                }
-
-                let mut slist_ll = slist.get_linked_list();
-
-                println!("slist_ll: {:?}", slist_ll);
-                println!("");
-
-                /*
-                let list_bq_count = comma.get_list_bq_count();
-
-                println!("start_eval_list - list_bq_count: {:?}",
-                    list_bq_count);
-                println!("");
-                */
-
-                let list_bq_count = comma.get_elem_bq_count();
-
-                /*
-                println!("start_eval_list - elem_bq_count: {:?}",
-                    elem_bq_count);
-                println!("");
-                */
-
-                slist_ll = self.replace_comma_exprs(&slist_ll,
-                                                    list_bq_count,
-                                                    env_ref);
-
-                println!("slist_ll - after replace_list_comma_exprs: {:?}",
-                    slist_ll);
-                println!("");
-
-
-                if slist_ll.len() > 1 {
-                    slist.set_linked_list(&slist_ll);
-
-                    if // slist_ll.contains_cons() &&
-                       self.scons.is_empty().not() {
-
-                       println!("---------------------------------");
-                       println!("");
-
-                       println!("self.scons: {:?}", self.scons);
-                       println!("");
-
-                       println!("---------------------------------");
-                       println!("");
-
-                       self.sexpr_with_scons = SExpr::SList(slist);
-
-                       return Ok(SExpr::SBTreeMap(self.scons.clone()))
-                    }
-
-                    result = Ok(SExpr::SList(slist));
-                }
-            }
+// too few elements in (1) to satisfy lambda list (a b): exactly 2
+// expected, but got 1
+fn shape_error(parameters: &RLOrdinaryLambdaList,
+               value: &SExpr,
+               count: usize) -> RLError {
+
+    let required_count = parameters.required.len();
+
+    let expected = if parameters.rest.is_some() || parameters.has_key {
+        format!("at least {}", required_count)
+    } else if parameters.optional_params.is_empty() {
+        format!("exactly {}", required_count)
+    } else {
+        format!("between {} and {}",
+                required_count,
+                required_count + parameters.optional_params.len())
+    };
+
+    let err = ProgramError::new(&format!(
+        "too {} elements in {} to satisfy lambda list {}: \
+         {} expected, but got {}",
+        if count < required_count { "few" } else { "many" },
+        value,
+        parameters.lambda_list,
+        expected,
+        count));
+
+    RLError::ProgramError(err)
+}
 
-            _ => unreachable!(),
+// the items followed by tail, a proper list without a tail
+fn make_dotted_list(items: &LinkedList<SExpr>, tail: Option<SExpr>) -> SExpr {
+    match tail {
+        Some(tail) => {
+            items.iter()
+                 .rev()
+                 .fold(tail, |cdr, car| {
+                     SExpr::SCons(RLCons::<SExpr>::new_cons(car.clone(), cdr))
+                 })
         }
 
-        // result = self.eval_list_comma_expr(result, env_ref);
-
-        println!("result: {:?}", result);
-        println!("");
-
-        println!("End - start_eval_list");
-        println!("");
-
-        result
+        None => make_list(items),
     }
+}
 
-    pub fn do_macro_replacements(&mut self,
-                                 mut env_ref: RefMut<'_, dyn EnvTrait>) ->
-        Result<SExpr, RLError> {
-
-        println!("Begin do_macro_replacements");
-        println!("");
-
-        let mut result_sexpr = SExpr::Nil(RLNil::new());
-
-        self.scons.clear();
-
-        println!("self.expr_ll: {:?}", self.expr_ll);
-        println!("");
-
-        println!("self.sexpr_ll: {:?}", self.sexpr_ll);
-        println!("");
-
-        let mut linked_list = self.sexpr_ll.clone();
-
-        match linked_list.pop_front() {
-            Some(SExpr::Atom(atom))   => {
-                match self.eval_simple_comma_expr(SExpr::Atom(atom),
-                                                  &mut env_ref) {
-
-                    Ok(res) => {
-                        result_sexpr = res;
-                    }
-
-                    Err(err) => {
-                        return Err(err);
-                    }
-                }
-
-                drop(env_ref);
-            }
-
-            Some(SExpr::SList(slist)) => {
-                match self.start_eval_list(SExpr::SList(slist),
-                                           &mut env_ref) {
-
-                    Ok(res) => {
-                        result_sexpr = res;
-                    }
-
-                    Err(err) => {
-                        return Err(err);
-                    }
-                }
-
-                drop(env_ref);
-            }
-
-            Some(SExpr::Symb(symb))   => {
-                match self.eval_simple_comma_expr(SExpr::Symb(symb),
-                                                  &mut env_ref) {
-
-                    Ok(res) => {
-                        result_sexpr = res;
-                    }
-
-                    Err(err) => {
-                        return Err(err);
-                    }
-                }
-
-                drop(env_ref);
-            }
-
-            Some(SExpr::Nil(nil))     => {
-                match self.eval_simple_comma_expr(SExpr::Nil(nil),
-                                                  &mut env_ref) {
-
-                    Ok(res) => {
-                        result_sexpr = res;
-                    }
-
-                    Err(err) => {
-                        return Err(err);
-                    }
-                }
-
-                drop(env_ref);
-            }
-
-            None                      => {
-                drop(env_ref);
-            }
-
-                                    _ => unreachable!(),
-        }
+// (quote sexpr), a supplied argument is not evaluated again
+fn quote(sexpr: SExpr) -> SExpr {
+    let items = LinkedList::from([
+        SExpr::Symb(RLSymbol::new_with_str("quote")),
+        sexpr]);
 
-        println!("End do_macro_replacements");
-        println!("");
+    SExpr::SList(RLList::<SExpr>::new_with_items(&items))
+}
 
-        Ok(result_sexpr)
+fn boolean(value: bool) -> SExpr {
+    if value {
+        SExpr::Symb(RLSymbol::new_with_str("t"))
+    } else {
+        SExpr::Nil(RLNil::new())
     }
 }
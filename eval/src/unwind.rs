@@ -0,0 +1,41 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::collections::LinkedList;
+
+use err::err::RLError;
+
+use expr::sexpr::SExpr;
+
+// Non-local exits travel up the evaluator as the error part of a Result,
+// until the form which established the exit point catches them. The
+// forms are boxed to keep the Results small, every evaluation returns
+// one.
+#[derive(Debug, Clone)]
+pub enum RLUnwind {
+    Error(RLError),
+
+    // id of the target block, value
+    ReturnFrom(String, Box<SExpr>),
+
+    // id of the target tagbody, tag
+    Go(String, String),
+
+    // id of the target HANDLER-CASE, index of the clause, condition
+    HandlerCase(usize, usize, Box<SExpr>),
+
+    // id of the form which established the restart, index of the
+    // restart, arguments of INVOKE-RESTART
+    Restart(usize, usize, LinkedList<SExpr>),
+
+    // an error no handler took, it aborts the evaluation
+    Unhandled(RLError),
+}
+
+impl From<RLError> for RLUnwind {
+    fn from(err: RLError) -> Self {
+        RLUnwind::Error(err)
+    }
+}
@@ -0,0 +1,53 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+#[test]
+fn unquote() {
+    assert_eq!(eval("(let ((x 1)) `(a ,x ,(+ x 1)))").unwrap(), "(A 1 2)");
+    assert_eq!(eval("(let ((x (list 1 2))) `(a ,@x c))").unwrap(),
+               "(A 1 2 C)");
+    assert_eq!(eval("`(a ,@nil c)").unwrap(), "(A C)");
+}
+
+#[test]
+fn dotted_unquote() {
+    assert_eq!(eval("(let ((x (list 1 2))) `(a . ,x))").unwrap(),
+               "(A 1 2)");
+    assert_eq!(eval("(let ((x 5)) `(a . ,x))").unwrap(), "(A . 5)");
+    assert_eq!(eval("(let ((x nil) (y 3)) `(,@x . ,y))").unwrap(), "3");
+    assert_eq!(eval("(defmacro my-list (&rest xs) `(list . ,xs))
+                     (my-list 1 2 3)").unwrap(),
+               "(1 2 3)");
+}
+
+#[test]
+fn splice_before_dotted_tail() {
+    assert_eq!(eval("(let ((x (list 1 2))) `(a ,@x . b))").unwrap(),
+               "(A 1 2 . B)");
+    assert_eq!(eval_error("(let ((x 5)) `(a ,@x . b))"),
+               "TYPE-ERROR expected-type: LIST datum: 5");
+}
+
+#[test]
+fn nested_backquote() {
+    assert_eq!(eval("(let ((x 1)) `(a `(b ,(c ,x))))").unwrap(),
+               "(A `(B ,(C 1)))");
+    assert_eq!(eval("(defmacro m (&rest xs) ``(,,@xs))
+                     (let ((a 1) (b 2)) (m a b))").unwrap(),
+               "(1 2)");
+    assert_eq!(eval("(defmacro m (&rest xs) ``(x ,@,@xs))
+                     (let ((a (list 1)) (b (list 2))) (m a b))").unwrap(),
+               "(X 1 2)");
+}
+
+#[test]
+fn comma_outside_backquote() {
+    assert_eq!(eval_error("(let ((x 1)) ,x)"),
+               "SIMPLE-ERROR Comma not inside a backquote.");
+}
@@ -0,0 +1,67 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+const MAKE_COUNTER: &str = "(defun make-counter ()
+                              (let ((n 0)) (lambda () (setq n (+ n 1))))) ";
+
+#[test]
+fn independent_counters() {
+    let source = "(let ((a (make-counter)) (b (make-counter)))
+                    (funcall a)
+                    (funcall a)
+                    (list (funcall a) (funcall b) (funcall b)))";
+
+    assert_eq!(eval(&format!("{}{}", MAKE_COUNTER, source)).unwrap(),
+               "(3 1 2)");
+}
+
+#[test]
+fn shared_binding() {
+    assert_eq!(eval("(let* ((n 0)
+                            (inc (lambda () (setq n (+ n 1))))
+                            (get (lambda () n)))
+                       (funcall inc)
+                       (funcall inc)
+                       (funcall get))").unwrap(),
+               "2");
+    assert_eq!(eval("(let ((n 0))
+                       (flet ((inc () (setq n (+ n 1)))) (inc) (inc))
+                       n)").unwrap(),
+               "2");
+}
+
+#[test]
+fn binding_per_iteration() {
+    assert_eq!(eval("(let ((fs nil))
+                       (dolist (i '(1 2 3))
+                         (let ((j i)) (setq fs (cons (lambda () j) fs))))
+                       (list (funcall (car fs)) (funcall (car (cdr fs)))))")
+                   .unwrap(),
+               "(3 2)");
+}
+
+#[test]
+fn lexical_not_dynamic() {
+    assert_eq!(eval("(let ((x 1))
+                       (let ((f (lambda () x))) (let ((x 2)) (funcall f))))")
+                   .unwrap(),
+               "1");
+    assert_eq!(eval("(defun adder (n) (lambda (x) (+ x n)))
+                     (funcall (adder 3) 4)").unwrap(),
+               "7");
+    assert_eq!(eval("(let ((f (let ((secret 42)) (lambda () secret))))
+                       (funcall f))").unwrap(),
+               "42");
+}
+
+#[test]
+fn funcall_non_function() {
+    assert_eq!(eval_error("(funcall 5)"),
+               "TYPE-ERROR expected-type: FUNCTION datum: 5");
+}
@@ -0,0 +1,35 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use err::err::RLError;
+
+use eval::evaluator::RLEvaluator;
+
+// Evaluates the forms of source in a new evaluator and returns the
+// printed value of the last one, multiple values are separated by " ; ".
+pub fn eval(source: &str) -> Result<String, RLError> {
+    let mut rl = RLEvaluator::new();
+
+    rl.init();
+
+    let mut value = String::new();
+
+    for sexpr in rl.parser.parse_all(source)? {
+        rl.set_sexpr(sexpr);
+
+        value = rl.eval()?.to_string();
+    }
+
+    Ok(value)
+}
+
+// The first line of the error the forms of source signal
+pub fn eval_error(source: &str) -> String {
+    match eval(source) {
+        Ok(value) => panic!("{} returns {}", source, value),
+
+        Err(err) => err.to_string().lines().next().unwrap().to_string(),
+    }
+}
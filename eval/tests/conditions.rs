@@ -0,0 +1,121 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+#[test]
+fn handler_case_clause_order() {
+    assert_eq!(eval("(handler-case (error \"boom ~a\" 1)
+                       (simple-error () :simple)
+                       (error () :error))").unwrap(),
+               ":SIMPLE");
+    assert_eq!(eval("(handler-case (error \"boom\")
+                       (type-error () :type)
+                       (error () :error))").unwrap(),
+               ":ERROR");
+    assert_eq!(eval("(handler-case
+                         (handler-case (error \"x\") (type-error () :inner))
+                       (error () :outer))").unwrap(),
+               ":OUTER");
+}
+
+#[test]
+fn handler_case_values() {
+    assert_eq!(eval("(handler-case (+ 1 2) (error () :error))").unwrap(), "3");
+    assert_eq!(eval("(handler-case (+ 1 2)
+                       (error () :error)
+                       (:no-error (v) (list :ok v)))").unwrap(),
+               "(:OK 3)");
+    assert_eq!(eval("(multiple-value-list
+                       (handler-case (error \"x\") (error () (values 1 2))))")
+                   .unwrap(),
+               "(1 2)");
+    assert_eq!(eval("(handler-case (error \"x\")
+                       (error (c) (simple-condition-format-control c)))")
+                   .unwrap(),
+               "\"x\"");
+    assert_eq!(eval("(handler-case (car 1)
+                       (type-error (c)
+                         (list (type-error-datum c)
+                               (type-error-expected-type c))))").unwrap(),
+               "(1 LIST)");
+}
+
+#[test]
+fn handler_bind_runs_innermost_first() {
+    assert_eq!(eval("(let ((log nil))
+                       (handler-case
+                           (handler-bind
+                               ((error (lambda (c)
+                                         (setq log (cons :outer log)))))
+                             (handler-bind
+                                 ((error (lambda (c)
+                                           (setq log (cons :inner log)))))
+                               (error \"x\")))
+                         (error () log)))").unwrap(),
+               "(:OUTER :INNER)");
+    assert_eq!(eval("(let ((log nil))
+                       (handler-case
+                           (handler-bind
+                               ((error (lambda (c)
+                                         (setq log (cons :first log))))
+                                (error (lambda (c)
+                                         (setq log (cons :second log)))))
+                             (error \"x\"))
+                         (error () log)))").unwrap(),
+               "(:SECOND :FIRST)");
+}
+
+#[test]
+fn handler_bind_declines_by_returning() {
+    assert_eq!(eval("(handler-bind ((warning (lambda (c) :ignored)))
+                       (signal 'warning)
+                       :continued)").unwrap(),
+               ":CONTINUED");
+    assert_eq!(eval_error("(handler-bind ((error (lambda (c) :declined)))
+                             (error \"x\"))"),
+               "SIMPLE-ERROR x");
+}
+
+#[test]
+fn handler_bind_transfers_control() {
+    assert_eq!(eval("(block b
+                       (handler-bind
+                           ((error (lambda (c) (return-from b :returned))))
+                         (error \"x\")))").unwrap(),
+               ":RETURNED");
+    assert_eq!(eval("(catch 'x
+                       (handler-bind ((error (lambda (c) (throw 'x :thrown))))
+                         (error \"x\")))").unwrap(),
+               ":THROWN");
+}
+
+#[test]
+fn signal() {
+    assert_eq!(eval("(signal \"nothing\")").unwrap(), "NIL");
+    assert_eq!(eval("(handler-case (signal 'warning) (warning () :warned))")
+                   .unwrap(),
+               ":WARNED");
+}
+
+#[test]
+fn define_condition() {
+    assert_eq!(eval("(define-condition my-error (error)
+                       ((code :initarg :code :reader my-code)))
+                     (handler-case (error 'my-error :code 42)
+                       (my-error (c) (my-code c)))").unwrap(),
+               "42");
+    assert_eq!(eval("(define-condition my-error (error) ())
+                     (handler-case (error 'my-error) (error () :parent))")
+                   .unwrap(),
+               ":PARENT");
+}
+
+#[test]
+fn unhandled_error() {
+    assert_eq!(eval_error("(error \"boom ~a\" 7)"), "SIMPLE-ERROR boom 7");
+}
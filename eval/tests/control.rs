@@ -0,0 +1,112 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+#[test]
+fn catch_returns() {
+    assert_eq!(eval("(catch 'a 1 2)").unwrap(), "2");
+    assert_eq!(eval("(catch 'a (throw 'a 1) 2)").unwrap(), "1");
+    assert_eq!(eval("(catch 'a (catch 'b (throw 'a 1) 2) 3)").unwrap(), "1");
+    assert_eq!(eval("(catch 'a (list (catch 'a (throw 'a 1)) 2))").unwrap(),
+               "(1 2)");
+    assert_eq!(eval("(multiple-value-list
+                       (catch 'a (throw 'a (values 1 2))))").unwrap(),
+               "(1 2)");
+}
+
+#[test]
+fn throw_from_called_function() {
+    assert_eq!(eval("(defun f (tag) (throw tag 5))
+                     (let ((tag (list 'x))) (catch tag (f tag)))").unwrap(),
+               "5");
+}
+
+#[test]
+fn tags_compared_by_identity() {
+    assert_eq!(eval("(let ((tag (list 1))) (catch tag (throw tag 2)))")
+                   .unwrap(),
+               "2");
+    assert_eq!(eval("(let ((tag \"a\")) (catch tag (throw tag 2)))").unwrap(),
+               "2");
+    assert_eq!(eval("(catch #\\a (throw #\\a 2))").unwrap(), "2");
+    assert_eq!(eval("(catch 1 (throw 1 2))").unwrap(), "2");
+    assert_eq!(eval("(catch :a (throw :a 2))").unwrap(), "2");
+    assert_eq!(eval_error("(catch (list 1) (throw (list 1) 2))"),
+               "CONTROL-ERROR Attempt to THROW to a tag that does not exist: \
+                (1)");
+    assert_eq!(eval_error("(catch \"a\" (throw \"a\" 2))"),
+               "CONTROL-ERROR Attempt to THROW to a tag that does not exist: \
+                \"a\"");
+    assert_eq!(eval_error("(catch 1.0 (throw 1 2))"),
+               "CONTROL-ERROR Attempt to THROW to a tag that does not exist: \
+                1");
+}
+
+#[test]
+fn throw_without_catch() {
+    assert_eq!(eval_error("(throw 'nowhere 1)"),
+               "CONTROL-ERROR Attempt to THROW to a tag that does not exist: \
+                NOWHERE");
+    assert_eq!(eval("(handler-case (throw 'nowhere 1)
+                       (control-error () :caught))").unwrap(),
+               ":CAUGHT");
+}
+
+#[test]
+fn unwind_protect_on_normal_exit() {
+    assert_eq!(eval("(let ((log nil))
+                       (list (unwind-protect 1 (setq log 2)) log))").unwrap(),
+               "(1 2)");
+}
+
+#[test]
+fn unwind_protect_on_throw() {
+    assert_eq!(eval("(let ((log nil))
+                       (list (catch 'done
+                               (unwind-protect (throw 'done 1)
+                                 (setq log (cons :cleanup log))))
+                             log))").unwrap(),
+               "(1 (:CLEANUP))");
+    assert_eq!(eval("(let ((log nil))
+                       (list (catch 'a
+                               (unwind-protect
+                                   (unwind-protect (throw 'a 1)
+                                     (setq log (cons 1 log)))
+                                 (setq log (cons 2 log))))
+                             log))").unwrap(),
+               "(1 (2 1))");
+}
+
+#[test]
+fn unwind_protect_on_go_and_return_from() {
+    assert_eq!(eval("(let ((log nil))
+                       (tagbody
+                          (unwind-protect (go end)
+                            (setq log (cons :cleanup log)))
+                          (setq log (cons :skipped log))
+                        end)
+                       log)").unwrap(),
+               "(:CLEANUP)");
+    assert_eq!(eval("(let ((log nil))
+                       (list (block b
+                               (unwind-protect (return-from b 1)
+                                 (setq log (cons :cleanup log))))
+                             log))").unwrap(),
+               "(1 (:CLEANUP))");
+}
+
+#[test]
+fn unwind_protect_on_error() {
+    assert_eq!(eval("(let ((log nil))
+                       (list (handler-case
+                                 (unwind-protect (error \"boom\")
+                                   (setq log (cons :cleanup log)))
+                               (error () :handled))
+                             log))").unwrap(),
+               "(:HANDLED (:CLEANUP))");
+}
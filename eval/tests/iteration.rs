@@ -0,0 +1,144 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+#[test]
+fn do_dotimes_dolist() {
+    assert_eq!(eval("(do ((i 0 (+ i 1)) (acc nil (cons i acc)))
+                         ((= i 3) acc))").unwrap(),
+               "(2 1 0)");
+    assert_eq!(eval("(let ((s 0)) (dotimes (i 4 s) (setq s (+ s i))))")
+                   .unwrap(),
+               "6");
+    assert_eq!(eval("(let ((r nil))
+                       (dolist (x '(1 2 3) r) (setq r (cons x r))))").unwrap(),
+               "(3 2 1)");
+}
+
+#[test]
+fn simple_loop() {
+    assert_eq!(eval("(let ((n 0))
+                       (loop (setq n (+ n 1)) (when (> n 3) (return n))))")
+                   .unwrap(),
+               "4");
+}
+
+#[test]
+fn for_arithmetic() {
+    assert_eq!(eval("(loop for i from 1 to 3 collect i)").unwrap(),
+               "(1 2 3)");
+    assert_eq!(eval("(loop for i from 0 below 7 by 2 collect i)").unwrap(),
+               "(0 2 4 6)");
+    assert_eq!(eval("(loop for i from 10 downto 1 by 3 collect i)").unwrap(),
+               "(10 7 4 1)");
+}
+
+#[test]
+fn for_by_checks_step() {
+    assert_eq!(eval_error("(loop for i below 10 by 0 collect i)"),
+               "TYPE-ERROR The value 0 is not of type (REAL (0))");
+    assert_eq!(eval_error("(let ((s -1))
+                             (loop for i below 10 by s collect i))"),
+               "TYPE-ERROR The value -1 is not of type (REAL (0))");
+}
+
+#[test]
+fn for_in_on_across() {
+    assert_eq!(eval("(loop for x on '(1 2 3) collect x)").unwrap(),
+               "((1 2 3) (2 3) (3))");
+    assert_eq!(eval("(loop for c across \"abc\" collect c)").unwrap(),
+               "(#\\a #\\b #\\c)");
+    assert_eq!(eval("(loop for x in '(a b c) for i from 0 collect (cons i x))")
+                   .unwrap(),
+               "((0 . A) (1 . B) (2 . C))");
+}
+
+#[test]
+fn for_equals_then() {
+    assert_eq!(eval("(loop for i in '(1 2 3) for j = (* i 10)
+                       collect (list i j))").unwrap(),
+               "((1 10) (2 20) (3 30))");
+    assert_eq!(eval("(loop with a = 1 for b = 2 then (+ b a) until (> b 4)
+                       finally (return b))").unwrap(),
+               "5");
+}
+
+#[test]
+fn repeat() {
+    assert_eq!(eval("(let ((n 0)) (loop repeat 3 do (setq n (+ n 1))) n)")
+                   .unwrap(),
+               "3");
+    assert_eq!(eval("(loop repeat 0 collect 1)").unwrap(), "NIL");
+}
+
+#[test]
+fn named_while_return() {
+    assert_eq!(eval("(let ((l '(1 2 3)))
+                       (loop named outer while l
+                             do (when (= (car l) 2) (return-from outer :two))
+                                (setq l (cdr l))))").unwrap(),
+               ":TWO");
+}
+
+#[test]
+fn accumulation() {
+    assert_eq!(eval("(loop for i below 3 append (list i i))").unwrap(),
+               "(0 0 1 1 2 2)");
+    assert_eq!(eval("(loop for i from 1 to 4 sum i)").unwrap(), "10");
+    assert_eq!(eval("(loop for i in '(1 2 3 4) count (evenp i))").unwrap(),
+               "2");
+    assert_eq!(eval("(loop for i in '(3 1 4 1 5) maximize i)").unwrap(),
+               "5");
+    assert_eq!(eval("(loop for i in '(3 1 4 1 5) minimize i)").unwrap(),
+               "1");
+    assert_eq!(eval("(loop for x in '(1 2 3) collect x into r
+                       finally (return r))").unwrap(),
+               "(1 2 3)");
+}
+
+#[test]
+fn conditionals() {
+    assert_eq!(eval("(loop for i from 1 to 4
+                       when (oddp i) collect i else collect (- i))").unwrap(),
+               "(1 -2 3 -4)");
+    assert_eq!(eval("(loop for i from 1 to 3 unless (= i 2) collect i)")
+                   .unwrap(),
+               "(1 3)");
+}
+
+#[test]
+fn errors() {
+    assert_eq!(eval_error("(loop (f) do (g))"),
+               "SIMPLE-ERROR (F) is not a LOOP keyword.");
+    assert_eq!(eval_error("(loop foo)"),
+               "SIMPLE-ERROR FOO is not a LOOP keyword.");
+    assert_eq!(eval_error("(loop for x)"),
+               "SIMPLE-ERROR Malformed LOOP FOR clause for X.");
+    assert_eq!(eval_error("(loop for x in)"),
+               "SIMPLE-ERROR LOOP FOR needs a form.");
+    assert_eq!(eval_error("(loop for i from 1 by)"),
+               "SIMPLE-ERROR LOOP BY needs a form.");
+    assert_eq!(eval_error("(loop do)"), "SIMPLE-ERROR LOOP DO needs a form.");
+    assert_eq!(eval_error("(loop when (p))"),
+               "SIMPLE-ERROR A LOOP conditional needs a clause.");
+    assert_eq!(eval_error("(loop collect)"),
+               "SIMPLE-ERROR LOOP COLLECT needs a form.");
+    assert_eq!(eval_error("(loop collect x into 5)"),
+               "TYPE-ERROR expected-type: SYMBOL datum: 5");
+}
+
+#[test]
+fn long_accumulation() {
+    assert_eq!(eval("(length (loop for i below 20000 collect i))").unwrap(),
+               "20000");
+    assert_eq!(eval("(let ((seen nil))
+                       (loop for i below 3 collect i into r
+                             do (setq seen (cons r seen))
+                             finally (return (list r seen))))").unwrap(),
+               "((0 1 2) ((0 1 2) (0 1) (0)))");
+}
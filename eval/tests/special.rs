@@ -0,0 +1,78 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+const GET_X: &str = "(defvar *x* 1) (defun get-x () *x*) ";
+
+#[test]
+fn defvar_and_defparameter() {
+    assert_eq!(eval("(defvar *x* 1) (defvar *x* 2) *x*").unwrap(), "1");
+    assert_eq!(eval("(defparameter *y* 1) (defparameter *y* 2) *y*").unwrap(),
+               "2");
+    assert_eq!(eval("(defvar *u*)
+                     (handler-case *u* (unbound-variable () :unbound))")
+                   .unwrap(),
+               ":UNBOUND");
+}
+
+#[test]
+fn dynamic_binding() {
+    let source = "(list (let ((*x* 2)) (get-x)) (get-x))";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "(2 1)");
+
+    let source = "(list (let* ((*x* 2) (y (get-x))) y) (get-x))";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "(2 1)");
+
+    let source = "(let ((*x* 2)) (setq *x* 3)) *x*";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "1");
+
+    assert_eq!(eval_error("(defvar *u*) (let ((*u* 1)) *u*) *u*"),
+               "UNBOUND-VARIABLE *U*");
+}
+
+#[test]
+fn restored_after_throw() {
+    let source = "(list (catch 'out (let ((*x* 2)) (throw 'out (get-x))))
+                        (get-x))";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "(2 1)");
+}
+
+#[test]
+fn restored_after_return_from_and_go() {
+    let source = "(list (block b (let ((*x* 2)) (return-from b (get-x))))
+                        (get-x))";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "(2 1)");
+
+    let source = "(tagbody (let ((*x* 2)) (go out)) out) (get-x)";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "1");
+}
+
+#[test]
+fn restored_after_error() {
+    let source = "(list (handler-case (let ((*x* 2)) (error \"x\"))
+                          (error () (get-x)))
+                        (get-x))";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "(1 1)");
+}
+
+#[test]
+fn handler_bind_sees_signalling_bindings() {
+    let source = "(block nil
+                    (let ((*x* 5))
+                      (handler-bind ((error (lambda (c) (return (get-x)))))
+                        (let ((*x* 6)) (error \"x\")))))";
+
+    assert_eq!(eval(&format!("{}{}", GET_X, source)).unwrap(), "6");
+}
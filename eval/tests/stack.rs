@@ -0,0 +1,33 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+// The tests run on threads with a small stack, the evaluator grows it
+// on the heap.
+
+#[test]
+fn deep_recursion() {
+    assert_eq!(eval("(defun f (n) (if (= n 0) 0 (+ 1 (f (- n 1)))))
+                     (f 2000)").unwrap(),
+               "2000");
+}
+
+#[test]
+fn endless_recursion() {
+    assert_eq!(eval_error("(defun f (n) (+ 1 (f n))) (f 0)"),
+               "SIMPLE-ERROR Control stack exhausted: \
+                more than 10000 nested evaluations.");
+}
+
+#[test]
+fn endless_recursion_is_catchable() {
+    assert_eq!(eval("(defun f (n) (+ 1 (f n)))
+                     (handler-case (f 0)
+                       (error () :exhausted))").unwrap(),
+               ":EXHAUSTED");
+}
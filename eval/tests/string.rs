@@ -0,0 +1,65 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+mod common;
+
+use common::{eval, eval_error};
+
+#[test]
+fn unicode_characters() {
+    assert_eq!(eval("(length \"héllo日本\")").unwrap(), "7");
+    assert_eq!(eval("(char \"héllo日本\" 5)").unwrap(), "#\\日");
+    assert_eq!(eval("(subseq \"héllo日本\" 1 6)").unwrap(), "\"éllo日\"");
+    assert_eq!(eval("(search \"日本\" \"héllo日本\")").unwrap(), "5");
+    assert_eq!(eval("(position #\\日 \"héllo日本\")").unwrap(), "5");
+    assert_eq!(eval("(make-string 3 :initial-element #\\é)").unwrap(),
+               "\"ééé\"");
+}
+
+#[test]
+fn unicode_case_and_trim() {
+    assert_eq!(eval("(string-upcase \"éa éa\" :start 1)").unwrap(),
+               "\"éA ÉA\"");
+    assert_eq!(eval("(string-capitalize \"élan vital\")").unwrap(),
+               "\"Élan Vital\"");
+    assert_eq!(eval("(string-trim \"é \" \" éaé \")").unwrap(), "\"a\"");
+}
+
+#[test]
+fn unicode_comparison() {
+    assert_eq!(eval("(string< \"é\" \"éa\")").unwrap(), "1");
+    assert_eq!(eval("(string= \"aé\" \"bé\" :start1 1 :start2 1)").unwrap(),
+               "T");
+    assert_eq!(eval("(string= \"aé\" \"bé\")").unwrap(), "NIL");
+}
+
+#[test]
+fn index_errors() {
+    assert_eq!(eval_error("(char \"héllo日本\" 7)"),
+               "SIMPLE-ERROR Invalid index 7 for a string of length 7.");
+    assert_eq!(eval_error("(char 5 0)"),
+               "SIMPLE-TYPE-ERROR expected-type: STRING datum: 5");
+    assert_eq!(eval_error("(subseq \"héllo\" -1)"),
+               "SIMPLE-TYPE-ERROR expected-type: UNSIGNED-BYTE datum: -1");
+}
+
+#[test]
+fn bounding_index_errors() {
+    assert_eq!(eval_error("(subseq \"héllo\" 3 2)"),
+               "SIMPLE-ERROR The bounding indices 3 and 2 are bad \
+                for a sequence of length 5.");
+    assert_eq!(eval_error("(subseq \"héllo\" 1 9)"),
+               "SIMPLE-ERROR The bounding indices 1 and 9 are bad \
+                for a sequence of length 5.");
+    assert_eq!(eval_error("(string-upcase \"éa\" :end 3)"),
+               "SIMPLE-ERROR The bounding indices 0 and 3 are bad \
+                for a sequence of length 2.");
+    assert_eq!(eval_error("(position #\\l \"héllo\" :start 9)"),
+               "SIMPLE-ERROR The bounding indices 9 and 5 are bad \
+                for a sequence of length 5.");
+    assert_eq!(eval_error("(string= \"aé\" \"bé\" :end1 5)"),
+               "SIMPLE-ERROR The bounding indices 0 and 5 are bad \
+                for a sequence of length 2.");
+}
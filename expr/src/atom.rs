@@ -4,31 +4,47 @@
 // file that was distributed with this source code.
 
 use std::fmt;
+use std::rc::Rc;
 
 use crate::comma::RLComma;
 use crate::string::RLString;
 
+use pars_symb::span::Span;
+
 #[derive(Debug, Clone)]
 pub struct RLAtom {
-    name: String,
+    // shared by the copies of the atom, a string read or made once is
+    // the same string wherever it is passed
+    name: Rc<String>,
 
     comma: Option<RLComma>,
+
+    span: Option<Span>,
 }
 
 impl RLAtom {
     pub fn new(name: &str) -> RLAtom {
-        let name = name.to_string();
+        let name = Rc::new(name.to_string());
 
         let comma = None;
 
         RLAtom {
             name,
             comma,
+            span: None,
         }
     }
 
     pub fn get_atom_string(&self) -> String {
-        self.name.clone()
+        (*self.name).clone()
+    }
+
+    pub fn set_span(&mut self, span: &Span) {
+        self.span = Some(span.clone());
+    }
+
+    pub fn get_span(&self) -> Option<Span> {
+        self.span.clone()
     }
 
     pub fn set_comma(&mut self, comma: &RLComma) {
@@ -57,14 +73,17 @@ impl RLAtom {
         }
     }
 
-    pub fn get_atom_rlstring(&self) -> RLString {
-        let string = self.name.clone();
+    // the same atom, not only an atom of the same text
+    pub fn is_same(&self, other: &RLAtom) -> bool {
+        Rc::ptr_eq(&self.name, &other.name)
+    }
 
-        RLString::new(&string)
+    pub fn get_atom_rlstring(&self) -> RLString {
+        RLString::new(&self.name)
     }
 
     pub fn set_name_to_uppercase(&mut self) {
-        self.name = self.name.to_uppercase();
+        self.name = Rc::new(self.name.to_uppercase());
     }
 }
 
@@ -73,6 +92,10 @@ impl fmt::Display for RLAtom {
         // println!("in fmt for RLAtom");
         // println!("self: {:?}", self);
 
+        if self.is_rlstring_atom() {
+            return write!(f, "{}", self.get_atom_rlstring())
+        }
+
         match self {
             RLAtom { name: atom, .. } => write!(f, "{}", atom),
         }
@@ -0,0 +1,102 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::fmt;
+
+// The names of the characters which are read and printed by name,
+// Space and Newline are standard, the others semi-standard
+const CHAR_NAMES: [(&str, char); 10] = [
+    ("Space", ' '),
+    ("Newline", '\n'),
+    ("Tab", '\t'),
+    ("Page", '\u{0c}'),
+    ("Rubout", '\u{7f}'),
+    ("Linefeed", '\n'),
+    ("Return", '\r'),
+    ("Backspace", '\u{08}'),
+    ("Nul", '\u{00}'),
+    ("Null", '\u{00}'),
+];
+
+// A character object, read as #\a or #\Space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RLChar {
+    ch: char,
+}
+
+impl RLChar {
+    pub fn new(ch: char) -> Self {
+        Self {
+            ch,
+        }
+    }
+
+    // the character of the text after #\, a single character or a
+    // name like Space, compared case-insensitively
+    pub fn read(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return Some(Self::new(ch))
+        }
+
+        // U+0007, the name of unnamed control characters
+        if let Some(code) = name.strip_prefix("U+")
+                                .or_else(|| name.strip_prefix("u+")) {
+            return u32::from_str_radix(code, 16).ok()
+                                                .and_then(char::from_u32)
+                                                .map(Self::new)
+        }
+
+        CHAR_NAMES.iter()
+                  .find(|(char_name, _)| char_name.eq_ignore_ascii_case(name))
+                  .map(|(_, ch)| Self::new(*ch))
+    }
+
+    pub fn get(&self) -> char {
+        self.ch
+    }
+
+    // characters whose other case is more than one character, like
+    // the upper case of #\ß, are left alone
+    pub fn upcase(&self) -> Self {
+        Self::new(single_char(self.ch.to_uppercase()).unwrap_or(self.ch))
+    }
+
+    pub fn downcase(&self) -> Self {
+        Self::new(single_char(self.ch.to_lowercase()).unwrap_or(self.ch))
+    }
+
+    // the name the character is printed by, None for graphic characters
+    pub fn get_name(&self) -> Option<String> {
+        if !self.ch.is_whitespace() && !self.ch.is_control() {
+            return None
+        }
+
+        match CHAR_NAMES.iter().find(|(_, ch)| *ch == self.ch) {
+            Some((name, _)) => Some(name.to_string()),
+
+            None => Some(format!("U+{:04X}", self.ch as u32)),
+        }
+    }
+}
+
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ch),
+
+        _ => None,
+    }
+}
+
+impl fmt::Display for RLChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get_name() {
+            Some(name) => write!(f, "#\\{}", name),
+
+            None => write!(f, "#\\{}", self.ch),
+        }
+    }
+}
@@ -115,6 +115,14 @@ impl RLComma {
                 self.is_list = false;
             }
 
+            Some(SExpr::Char(_ch)) => {
+                self.is_list = false;
+            }
+
+            Some(SExpr::Num(_num)) => {
+                self.is_list = false;
+            }
+
             Some(SExpr::Nil(_nil)) => {
                 self.is_list = false;
             }
@@ -192,9 +200,6 @@ impl RLComma {
             return Err(RLError::SimpleError(err));
         }
 
-        println!("self.comma_expr: {:?}", self.comma_expr);
-        println!("");
-
         if self.elem_bq_count == 1 &&
            self.str_expr.eq("`") &&
            self.is_list {}
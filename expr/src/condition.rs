@@ -0,0 +1,86 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::fmt;
+
+use crate::nil::RLNil;
+use crate::sexpr::SExpr;
+
+use err::err::RLError;
+
+// An instance of a condition type. The slots of the type and of its
+// supertypes get their values when the condition is made, the report
+// is formatted at the same time.
+#[derive(Debug, Clone)]
+pub struct RLCondition {
+    // name of the condition type, e.g. SIMPLE-ERROR
+    type_name: String,
+
+    // (slot name, value) pairs, the names are uppercase
+    slots: Vec<(String, SExpr)>,
+
+    message: String,
+
+    // the error the condition was made for if it was signalled by
+    // a function of rlisp
+    error: Option<RLError>,
+}
+
+impl RLCondition {
+    pub fn new(type_name: &str,
+               slots: Vec<(String, SExpr)>,
+               message: &str) -> Self {
+
+        Self {
+            type_name: type_name.to_uppercase(),
+            slots,
+            message: message.to_string(),
+            error: None,
+        }
+    }
+
+    pub fn new_from_error(type_name: &str,
+                          slots: Vec<(String, SExpr)>,
+                          message: &str,
+                          error: RLError) -> Self {
+
+        let mut condition = Self::new(type_name, slots, message);
+
+        condition.error = Some(error);
+
+        condition
+    }
+
+    pub fn get_type_name(&self) -> String {
+        self.type_name.clone()
+    }
+
+    pub fn has_slot(&self, name: &str) -> bool {
+        self.slots.iter().any(|(slot, _)| slot.eq_ignore_ascii_case(name))
+    }
+
+    // the value of the slot name, NIL if the condition doesn't have it
+    pub fn get_slot_value(&self, name: &str) -> SExpr {
+        self.slots
+            .iter()
+            .find(|(slot, _)| slot.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+            .unwrap_or(SExpr::Nil(RLNil::new()))
+    }
+
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+
+    pub fn get_error(&self) -> Option<RLError> {
+        self.error.clone()
+    }
+}
+
+impl fmt::Display for RLCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#<{} \"{}\">", self.type_name, self.message)
+    }
+}
@@ -3,6 +3,8 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::rc::Rc;
+
 use crate::comma::RLComma;
 // use crate::list::RLList;
 // use crate::nil::RLNil;
@@ -11,18 +13,27 @@ use crate::sexpr::SExpr;
 
 #[derive(Debug, Clone)]
 pub struct RLCons<T> {
-    first: Box<T>,
-    second: Box<T>,
+    // shared by the copies of the cons, which are the same object
+    first: Rc<T>,
+    second: Rc<T>,
 
     comma: Option<RLComma>,
 }
 
+impl<T> RLCons<T> {
+    // the same cons, not only a cons of the same car and cdr
+    pub fn is_same(&self, other: &RLCons<T>) -> bool {
+        Rc::ptr_eq(&self.first, &other.first) &&
+        Rc::ptr_eq(&self.second, &other.second)
+    }
+}
+
 impl RLCons<SExpr> {
     pub fn new_cons(first: SExpr,
                     second: SExpr) -> RLCons<SExpr> {
-        let first = Box::new(first);
+        let first = Rc::new(first);
 
-        let second = Box::new(second);
+        let second = Rc::new(second);
 
         let comma = None;
 
@@ -36,9 +47,9 @@ impl RLCons<SExpr> {
     pub fn new_with_comma(first: SExpr,
                           second: SExpr,
                           comma: Option<RLComma>) -> RLCons<SExpr> {
-        let first = Box::new(first);
+        let first = Rc::new(first);
 
-        let second = Box::new(second);
+        let second = Rc::new(second);
 
         let comma = comma;
 
@@ -50,19 +61,19 @@ impl RLCons<SExpr> {
     }
 
     pub fn set_first(&mut self, sexpr: SExpr) {
-        self.first = Box::new(sexpr);
+        self.first = Rc::new(sexpr);
     }
 
     pub fn set_second(&mut self, sexpr: SExpr) {
-        self.second = Box::new(sexpr);
+        self.second = Rc::new(sexpr);
     }
 
     pub fn car(&self) -> SExpr {
-        *self.first.clone()
+        (*self.first).clone()
     }
 
     pub fn cdr(&self) -> SExpr {
-        *self.second.clone()
+        (*self.second).clone()
     }
 
     pub fn set_comma(&mut self, comma: &RLComma) {
@@ -85,9 +96,9 @@ impl RLCons<SExpr> {
 impl RLCons<QExpr> {
     pub fn new_cons(first: QExpr,
                     second: QExpr) -> RLCons<QExpr> {
-        let first = Box::new(first);
+        let first = Rc::new(first);
 
-        let second = Box::new(second);
+        let second = Rc::new(second);
 
         let comma = None;
 
@@ -101,9 +112,9 @@ impl RLCons<QExpr> {
     pub fn new_with_comma(first: QExpr,
                           second: QExpr,
                           comma: Option<RLComma>) -> RLCons<QExpr> {
-        let first = Box::new(first);
+        let first = Rc::new(first);
 
-        let second = Box::new(second);
+        let second = Rc::new(second);
 
         let comma = comma;
 
@@ -115,19 +126,19 @@ impl RLCons<QExpr> {
     }
 
     pub fn set_first(&mut self, qexpr: QExpr) {
-        self.first = Box::new(qexpr);
+        self.first = Rc::new(qexpr);
     }
 
     pub fn set_second(&mut self, qexpr: QExpr) {
-        self.second = Box::new(qexpr);
+        self.second = Rc::new(qexpr);
     }
 
     pub fn car(&self) -> QExpr {
-        *self.first.clone()
+        (*self.first).clone()
     }
 
     pub fn cdr(&self) -> QExpr {
-        *self.second.clone()
+        (*self.second).clone()
     }
 
     pub fn set_comma(&mut self, comma: &RLComma) {
@@ -74,9 +74,6 @@ pub fn slist_to_scons(sexpr: SExpr) -> Result<SExpr, RLError> {
 
                 result = SExpr::Cons(symb, cons_ll);
 
-                println!("{}", result);
-                println!("");
-
             } else { // throw an RLError...
             }
         }
@@ -95,6 +92,10 @@ pub fn qexpr_to_sexpr(qexpr: QExpr) -> SExpr {
             SExpr::Atom(RLAtom::new(&atom_string))
         }
 
+        QExpr::Char(ch) => SExpr::Char(ch),
+
+        QExpr::Num(num) => SExpr::Num(num),
+
         QExpr::QCons(cons) => {
             let first = qexpr_to_sexpr(cons.car());
 
@@ -107,16 +108,16 @@ pub fn qexpr_to_sexpr(qexpr: QExpr) -> SExpr {
                                                          comma))
         }
 
-        QExpr::QList2(mut list) => {
-            let mut s_list = LinkedList::<SExpr>::new();
+        QExpr::QList2(list) => {
+            let s_list = qexprll_to_sexprll(list.get_linked_list());
 
-            while list.is_empty().not() {
-                let q_elem = list.pop_back().unwrap();
-                s_list.push_front(qexpr_to_sexpr(q_elem));
-            }
             SExpr::SList(RLList::<SExpr>::new_with_list(&s_list))
         }
 
+        QExpr::Func(atom) => SExpr::Func(atom),
+
+        QExpr::Lambda(function) => SExpr::Lambda(function),
+
         QExpr::Nil(nil) => SExpr::Nil(nil),
 
         QExpr::Symb(sym) => {
@@ -134,6 +135,10 @@ pub fn sexpr_to_qexpr(sexpr: SExpr) -> QExpr {
             QExpr::Atom(RLAtom::new(&string))
         }
 
+        SExpr::Char(ch) => QExpr::Char(ch),
+
+        SExpr::Num(num) => QExpr::Num(num),
+
         SExpr::SCons(cons) => {
             let first = sexpr_to_qexpr(cons.car());
 
@@ -149,31 +154,27 @@ pub fn sexpr_to_qexpr(sexpr: SExpr) -> QExpr {
         SExpr::Nil(nil) => QExpr::Nil(nil),
 
         SExpr::SList(slist) => {
-            let mut q_ll = LinkedList::<QExpr>::new();
-
-            let mut list = slist.get_linked_list();
-
-            while list.is_empty().not() {
-                let s_elem = list.pop_back().unwrap();
-                q_ll.push_front(sexpr_to_qexpr(s_elem));
-            }
+            let q_ll = sexprll_to_qexprll(slist.get_linked_list());
 
             let qlist = RLList::<QExpr>::new_with_list(&q_ll);
 
             QExpr::QList2(qlist)
         }
 
+        SExpr::QList(qlist) => QExpr::QList2(qlist),
+
+        SExpr::Func(atom) => QExpr::Func(atom),
+
+        SExpr::Lambda(function) => QExpr::Lambda(function),
+
         SExpr::Symb(sym) => {
             let sym_name = sym.get_symbol_name();
 
             QExpr::Symb(RLSymbol::new_with_str(&sym_name))
         }
 
-        t => {
-            // Dummy or Cons
-            println!("OTHER: {:?}", t);
-            unreachable!()
-        }
+        // Dummy, Cons and the forms used during evaluation
+        _ => unreachable!(),
     }
 }
 
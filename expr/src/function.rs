@@ -0,0 +1,54 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::any::Any;
+use std::fmt;
+use std::rc::Rc;
+
+// A function made by LAMBDA, FLET or LABELS. The code with the
+// variables it closes over is of a type of the crate which calls it,
+// the value owns it: it is freed with the last copy of the value.
+#[derive(Clone)]
+pub struct RLFunction {
+    // printed in #<FUNCTION (LAMBDA) {id}>
+    id: String,
+
+    code: Rc<dyn Any>,
+}
+
+impl RLFunction {
+    pub fn new(id: &str, code: Rc<dyn Any>) -> Self {
+        Self {
+            id: id.to_string(),
+            code,
+        }
+    }
+
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    // the same function, not only a copy of the same code
+    pub fn is_same(&self, other: &RLFunction) -> bool {
+        Rc::ptr_eq(&self.code, &other.code)
+    }
+
+    // None if the code is of another type
+    pub fn get_code<T: 'static>(&self) -> Option<&T> {
+        self.code.downcast_ref::<T>()
+    }
+}
+
+impl fmt::Debug for RLFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RLFunction({})", self.id)
+    }
+}
+
+impl fmt::Display for RLFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id)
+    }
+}
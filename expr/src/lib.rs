@@ -9,12 +9,17 @@ use err::err::RLError;
 
 pub mod atom;
 pub mod bool;
+pub mod char;
 pub mod comma;
+pub mod condition;
 pub mod cons;
 pub mod expr;
+pub mod function;
 pub mod list;
 pub mod nil;
+pub mod num;
 pub mod qexpr;
+pub mod restart;
 pub mod sexpr;
 pub mod symb;
 pub mod string;
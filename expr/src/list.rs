@@ -5,6 +5,7 @@
 
 use std::collections::LinkedList;
 use std::ops::Not;
+use std::rc::Rc;
 
 use crate::atom::RLAtom;
 use crate::comma::RLComma;
@@ -16,14 +17,34 @@ use crate::symb::RLSymbol;
 
 use err::err::RLError;
 
+use pars_symb::span::Span;
 use pars_symb::symbol::Symbol;
 use pars_symb::token::Token;
 
 #[derive(Debug, Clone)]
 pub struct RLList<T> {
-    list: LinkedList<T>,
+    // shared by the copies of the list until one of them changes it,
+    // reading a variable holding a list does not copy its items
+    list: Rc<LinkedList<T>>,
 
     comma: Option<RLComma>,
+
+    span: Option<Span>,
+}
+
+impl<T> RLList<T> {
+    pub fn set_span(&mut self, span: &Span) {
+        self.span = Some(span.clone());
+    }
+
+    pub fn get_span(&self) -> Option<Span> {
+        self.span.clone()
+    }
+
+    // the same list, not only a list of the same items
+    pub fn is_same(&self, other: &RLList<T>) -> bool {
+        Rc::ptr_eq(&self.list, &other.list)
+    }
 }
 
 impl RLList<SExpr> {
@@ -36,8 +57,9 @@ impl RLList<SExpr> {
         let comma = None;
 
         RLList {
-            list,
+            list: Rc::new(list),
             comma,
+            span: None,
         }
     }
 
@@ -47,9 +69,45 @@ impl RLList<SExpr> {
         let comma = None;
 
         RLList {
-            list,
+            list: Rc::new(list),
+            comma,
+            span: None,
+        }
+    }
+
+    // wraps items into "(" and ")"
+    pub fn new_with_items(items: &LinkedList<SExpr>) -> RLList<SExpr> {
+        let mut list = items.clone();
+
+        list.push_front(SExpr::Symb(RLSymbol::new_with_str("(")));
+        list.push_back(SExpr::Symb(RLSymbol::new_with_str(")")));
+
+        let comma = None;
+
+        RLList {
+            list: Rc::new(list),
             comma,
+            span: None,
+        }
+    }
+
+    // items of the list without the surrounding "(" and ")"
+    pub fn get_items(&self) -> LinkedList<SExpr> {
+        let mut items = (*self.list).clone();
+
+        if let Some(SExpr::Symb(symb)) = items.front() {
+            if symb.get_symbol_name().eq("(") {
+                items.pop_front();
+            }
+        }
+
+        if let Some(SExpr::Symb(symb)) = items.back() {
+            if symb.get_symbol_name().eq(")") {
+                items.pop_back();
+            }
         }
+
+        items
     }
 
     pub fn set_comma(&mut self, comma: &RLComma) {
@@ -69,7 +127,7 @@ impl RLList<SExpr> {
     }
 
     pub fn set_linked_list(&mut self, linked_list: &LinkedList<SExpr>) {
-        self.list = linked_list.clone();
+        self.list = Rc::new(linked_list.clone());
     }
 
     pub fn len(&self) -> usize {
@@ -77,35 +135,54 @@ impl RLList<SExpr> {
     }
 
     pub fn get_linked_list(&self) -> LinkedList<SExpr> {
-        self.list.clone()
+        (*self.list).clone()
     }
 
     pub fn pop_back(&mut self) -> Option<SExpr> {
-        self.list.pop_back()
+        Rc::make_mut(&mut self.list).pop_back()
     }
 
     pub fn pop_front(&mut self) -> Option<SExpr> {
-        self.list.pop_front()
+        Rc::make_mut(&mut self.list).pop_front()
     }
 
     pub fn push_back(&mut self, sexpr: SExpr) {
-        self.list.push_back(sexpr);
+        Rc::make_mut(&mut self.list).push_back(sexpr);
     }
 
     pub fn push_front(&mut self, sexpr: SExpr) {
-        self.list.push_front(sexpr);
+        Rc::make_mut(&mut self.list).push_front(sexpr);
     }
 
     pub fn is_empty(&self) -> bool {
         self.list.is_empty()
     }
 
+    // adds sexpr as the last item, before the closing ")"
+    pub fn push_item(&mut self, sexpr: SExpr) {
+        let list = Rc::make_mut(&mut self.list);
+
+        let close = match list.back() {
+            Some(SExpr::Symb(symb)) if symb.get_symbol_name().eq(")") => {
+                list.pop_back()
+            }
+
+            _ => None,
+        };
+
+        list.push_back(sexpr);
+
+        if let Some(close) = close {
+            list.push_back(close);
+        }
+    }
+
     pub fn get_rest_lambda_list(&mut self) -> SExpr {
         let rest_keyword = SExpr::Atom(RLAtom::new("&rest"));
 
-        self.list.push_front(rest_keyword);
+        Rc::make_mut(&mut self.list).push_front(rest_keyword);
 
-        SExpr::SList(RLList::<SExpr>::new_with_list(&self.list.clone()))
+        SExpr::SList(RLList::<SExpr>::new_with_list(&self.list))
     }
 
     pub fn slist_to_cons(&mut self) -> SExpr {
@@ -115,9 +192,6 @@ impl RLList<SExpr> {
 
         let mut ll = LinkedList::<SExpr>::new();
 
-        println!("slist_to_cons - linked_list: {:?}", linked_list);
-        println!("");
-
         // remove parenthesis
         linked_list.pop_front();
         linked_list.pop_back();
@@ -144,9 +218,6 @@ impl RLList<SExpr> {
 
         let mut linked_list = self.get_linked_list();
 
-        println!("slist_to_form - linked_list: {:?}", linked_list);
-        println!("");
-
         // remove parenthesis
         // self.list.pop_front();
         // self.list.pop_back();
@@ -167,6 +238,14 @@ impl RLList<SExpr> {
                     vec.push(Token::Atom(atom_string));
                 }
 
+                SExpr::Char(ch) => {
+                    vec.push(Token::Char(ch.get()));
+                }
+
+                SExpr::Num(num) => {
+                    vec.push(Token::Atom(num.to_string()));
+                }
+
                 SExpr::Symb(symb) => {
                     let symb_string = symb.get_symbol_name();
 
@@ -205,6 +284,14 @@ impl RLList<SExpr> {
                     vec.push(Token::Atom(atom_string));
                 }
 
+                SExpr::Char(ch) => {
+                    vec.push(Token::Char(ch.get()));
+                }
+
+                SExpr::Num(num) => {
+                    vec.push(Token::Atom(num.to_string()));
+                }
+
                 SExpr::Symb(symb) => {
                     let symb_string = symb.get_symbol_name();
 
@@ -239,8 +326,9 @@ impl RLList<QExpr> {
         let comma = None;
 
         RLList {
-            list,
+            list: Rc::new(list),
             comma,
+            span: None,
         }
     }
 
@@ -262,8 +350,9 @@ impl RLList<QExpr> {
         let comma = None;
 
         RLList {
-            list,
+            list: Rc::new(list),
             comma,
+            span: None,
         }
     }
 
@@ -273,11 +362,31 @@ impl RLList<QExpr> {
         let comma = None;
 
         RLList {
-            list,
+            list: Rc::new(list),
             comma,
+            span: None,
         }
     }
 
+    // items of the list without the surrounding "(" and ")"
+    pub fn get_items(&self) -> LinkedList<QExpr> {
+        let mut items = (*self.list).clone();
+
+        if let Some(QExpr::Symb(symb)) = items.front() {
+            if symb.get_symbol_name().eq("(") {
+                items.pop_front();
+            }
+        }
+
+        if let Some(QExpr::Symb(symb)) = items.back() {
+            if symb.get_symbol_name().eq(")") {
+                items.pop_back();
+            }
+        }
+
+        items
+    }
+
     pub fn set_comma(&mut self, comma: &RLComma) {
         self.comma = Some(comma.clone());
     }
@@ -295,10 +404,12 @@ impl RLList<QExpr> {
     }
 
     pub fn set_linked_list(&mut self, linked_list: &LinkedList<QExpr>) {
-        self.list = linked_list.clone();
+        let mut list = linked_list.clone();
+
+        list.push_back(QExpr::Symb(RLSymbol::new_with_str(")")));
+        list.push_front(QExpr::Symb(RLSymbol::new_with_str("(")));
 
-        self.list.push_back(QExpr::Symb(RLSymbol::new_with_str(")")));
-        self.list.push_front(QExpr::Symb(RLSymbol::new_with_str("(")));
+        self.list = Rc::new(list);
     }
 
     pub fn len(&self) -> usize {
@@ -306,13 +417,15 @@ impl RLList<QExpr> {
     }
 
     pub fn get_linked_list(&self) -> LinkedList<QExpr> {
-        self.list.clone()
+        (*self.list).clone()
     }
 
     pub fn pop_back(&mut self) -> Option<QExpr> {
-        self.list.pop_back();
+        let list = Rc::make_mut(&mut self.list);
 
-        let option_item = self.list.pop_back();
+        list.pop_back();
+
+        let option_item = list.pop_back();
 
         self.push_back(QExpr::Symb(RLSymbol::new_with_str(")")));
 
@@ -320,9 +433,11 @@ impl RLList<QExpr> {
     }
 
     pub fn pop_front(&mut self) -> Option<QExpr> {
-        self.list.pop_front();
+        let list = Rc::make_mut(&mut self.list);
+
+        list.pop_front();
 
-        let option_item = self.list.pop_front();
+        let option_item = list.pop_front();
 
         self.push_front(QExpr::Symb(RLSymbol::new_with_str("(")));
 
@@ -330,19 +445,23 @@ impl RLList<QExpr> {
     }
 
     pub fn push_back(&mut self, qexpr: QExpr) {
-        self.list.pop_back();
+        let list = Rc::make_mut(&mut self.list);
 
-        self.list.push_back(qexpr);
+        list.pop_back();
 
-        self.list.push_back(QExpr::Symb(RLSymbol::new_with_str(")")));
+        list.push_back(qexpr);
+
+        list.push_back(QExpr::Symb(RLSymbol::new_with_str(")")));
     }
 
     pub fn push_front(&mut self, qexpr: QExpr) {
-        self.list.pop_front();
+        let list = Rc::make_mut(&mut self.list);
+
+        list.pop_front();
 
-        self.list.push_front(qexpr);
+        list.push_front(qexpr);
 
-        self.list.push_front(QExpr::Symb(RLSymbol::new_with_str("(")));
+        list.push_front(QExpr::Symb(RLSymbol::new_with_str("(")));
     }
 
     pub fn is_empty(&self) -> bool {
@@ -371,9 +490,6 @@ impl RLList<QExpr> {
     pub fn cdr(&mut self) -> Result<QExpr, RLError> {
         let mut linked_list = self.get_linked_list();
 
-        println!("linked_list: {:?}", linked_list);
-        println!("");
-
         if linked_list.len() == 2 {
             return Ok(QExpr::Nil(RLNil::new()));
         } else if linked_list.len() >= 3 {
@@ -429,9 +545,6 @@ impl RLList<QExpr> {
         while first_ll.is_empty().not() {
             if let Some(elem) = first_ll.pop_back() {
 
-                println!("elem: {:?}", elem);
-                println!("");
-
                 result_list.push_front(elem);
             }
         }
@@ -440,6 +553,7 @@ impl RLList<QExpr> {
 
         self.set_linked_list(&result_list);
 
-        Self { list: self.list.clone(), comma: self.comma.clone() }
+        Self { list: self.list.clone(), comma: self.comma.clone(),
+               span: self.span.clone() }
     }
 }
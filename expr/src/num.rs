@@ -0,0 +1,637 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
+
+use err::err::{RLError, SimpleError, SimpleTypeError};
+
+// How FLOOR, CEILING, TRUNCATE and ROUND turn a quotient into an
+// integer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rounding {
+    Floor,
+    Ceiling,
+    Truncate,
+    // to the nearest integer, to the even one if it is halfway
+    Round,
+}
+
+// Numbers are kept in the narrowest representation, integers which
+// fit in 64 bits are fixnums and ratios with denominator 1 are
+// integers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RLNum {
+    Fixnum(i64),
+    Bignum(BigInt),
+    Ratio(BigRational),
+    SingleFloat(f32),
+    DoubleFloat(f64),
+}
+
+impl RLNum {
+    pub fn from_bigint(int: BigInt) -> RLNum {
+        match int.to_i64() {
+            Some(fixnum) => RLNum::Fixnum(fixnum),
+            None => RLNum::Bignum(int),
+        }
+    }
+
+    pub fn from_ratio(ratio: BigRational) -> RLNum {
+        if ratio.denom().is_one() {
+            RLNum::from_bigint(ratio.numer().clone())
+        } else {
+            RLNum::Ratio(ratio)
+        }
+    }
+
+    // Reads the decimal syntax of integers (1, -2, 3.), ratios (1/3)
+    // and floats (2.5, .5, 1e10, 2.5d0). Floats without exponent
+    // marker and with e, f or s are single floats, d and l make
+    // double floats. None if token is not a number, an error if it
+    // is one which can't be represented, like 1/0 or 1e999.
+    pub fn read(token: &str) -> Result<Option<RLNum>, RLError> {
+        let (sign, body) = split_sign(token);
+
+        if body.is_empty() {
+            return Ok(None)
+        }
+
+        // integer with optional trailing decimal point
+        let int_body = body.strip_suffix('.').unwrap_or(body);
+
+        if is_digits(int_body, 10) {
+            return Ok(read_integer(sign, int_body, 10))
+        }
+
+        if let Some((numer, denom)) = body.split_once('/') {
+            if is_digits(numer, 10) &&
+               is_digits(denom, 10) &&
+               denom.chars().all(|c| c == '0') {
+                return Err(RLError::DivisionByZero)
+            }
+
+            return Ok(RLNum::read_radix(token, 10))
+        }
+
+        let (mantissa, marker, exponent) =
+            match body.find(['e', 'E', 'd', 'D', 'f', 'F', 's', 'S',
+                             'l', 'L']) {
+                Some(pos) => (&body[..pos],
+                              body[pos..].chars().next(),
+                              Some(&body[pos + 1..])),
+
+                None => (body, None, None),
+            };
+
+        let mantissa_ok = match mantissa.split_once('.') {
+            Some((int, frac)) => {
+                (int.is_empty() || is_digits(int, 10)) &&
+                (frac.is_empty() || is_digits(frac, 10)) &&
+                !(int.is_empty() && frac.is_empty()) &&
+                (exponent.is_some() || !frac.is_empty())
+            }
+
+            None => is_digits(mantissa, 10) && exponent.is_some(),
+        };
+
+        let exponent_ok = match exponent {
+            Some(exp) => is_digits(split_sign(exp).1, 10),
+            None => true,
+        };
+
+        if !mantissa_ok || !exponent_ok {
+            return Ok(None)
+        }
+
+        let text = format!("{}{}e{}", sign, mantissa, exponent.unwrap_or("0"));
+
+        let float = match marker.map(|marker| marker.to_ascii_lowercase()) {
+            Some('d') | Some('l') => text.parse::<f64>()
+                                         .ok()
+                                         .map(RLNum::DoubleFloat),
+
+            _ => text.parse::<f32>().ok().map(RLNum::SingleFloat),
+        };
+
+        float.map(RLNum::check_overflow).transpose()
+    }
+
+    // Integers and ratios in radix, as read by #x, #o, #b and #nR.
+    pub fn read_radix(token: &str, radix: u32) -> Option<RLNum> {
+        let (sign, body) = split_sign(token);
+
+        match body.split_once('/') {
+            Some((numer, denom)) => {
+                if !is_digits(numer, radix) || !is_digits(denom, radix) {
+                    return None
+                }
+
+                let numer = BigInt::parse_bytes(
+                    format!("{}{}", sign, numer).as_bytes(), radix)?;
+
+                let denom = BigInt::parse_bytes(denom.as_bytes(), radix)?;
+
+                if denom.is_zero() {
+                    return None
+                }
+
+                Some(RLNum::from_ratio(BigRational::new(numer, denom)))
+            }
+
+            None if is_digits(body, radix) => read_integer(sign, body, radix),
+
+            None => None,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            RLNum::Fixnum(fixnum) => *fixnum == 0,
+            RLNum::Bignum(int) => int.is_zero(),
+            RLNum::Ratio(ratio) => ratio.is_zero(),
+            RLNum::SingleFloat(float) => *float == 0.0,
+            RLNum::DoubleFloat(float) => *float == 0.0,
+        }
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, RLNum::SingleFloat(_) | RLNum::DoubleFloat(_))
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        match self {
+            RLNum::Fixnum(fixnum) => *fixnum as f64,
+            RLNum::Bignum(int) => int.to_f64().unwrap_or(f64::NAN),
+            RLNum::Ratio(ratio) => ratio.to_f64().unwrap_or(f64::NAN),
+            RLNum::SingleFloat(float) => *float as f64,
+            RLNum::DoubleFloat(float) => *float,
+        }
+    }
+
+    // Exact value of a rational, None for floats.
+    pub fn to_ratio(&self) -> Option<BigRational> {
+        match self {
+            RLNum::Fixnum(fixnum) => {
+                Some(BigRational::from_integer(BigInt::from(*fixnum)))
+            }
+
+            RLNum::Bignum(int) => Some(BigRational::from_integer(int.clone())),
+
+            RLNum::Ratio(ratio) => Some(ratio.clone()),
+
+            _ => None,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(self, RLNum::Fixnum(_) | RLNum::Bignum(_))
+    }
+
+    // Single floats unless a double float is involved.
+    pub fn make_float(value: f64, double: bool) -> RLNum {
+        if double {
+            RLNum::DoubleFloat(value)
+        } else {
+            RLNum::SingleFloat(value as f32)
+        }
+    }
+
+    pub fn is_double_float(&self) -> bool {
+        matches!(self, RLNum::DoubleFloat(_))
+    }
+
+    // Floats which became infinite or NaN signal
+    // FLOATING-POINT-OVERFLOW instead of being returned.
+    pub fn check_overflow(self) -> Result<RLNum, RLError> {
+        let finite = match self {
+            RLNum::SingleFloat(float) => float.is_finite(),
+            RLNum::DoubleFloat(float) => float.is_finite(),
+            _ => true,
+        };
+
+        if finite {
+            Ok(self)
+        } else {
+            Err(RLError::FloatingPointOverflow)
+        }
+    }
+
+    // Rationals are compared exactly, None if a float is NaN.
+    pub fn compare(&self, other: &RLNum) -> Option<Ordering> {
+        match (self.to_ratio(), other.to_ratio()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+
+            _ => self.to_f64().partial_cmp(&other.to_f64()),
+        }
+    }
+
+    pub fn signum(&self) -> Ordering {
+        self.compare(&RLNum::Fixnum(0)).unwrap_or(Ordering::Equal)
+    }
+
+    pub fn abs(&self) -> RLNum {
+        if self.signum() == Ordering::Less {
+            self.negate()
+        } else {
+            self.clone()
+        }
+    }
+
+    pub fn negate(&self) -> RLNum {
+        match self {
+            RLNum::Fixnum(fixnum) => match fixnum.checked_neg() {
+                Some(negated) => RLNum::Fixnum(negated),
+                None => RLNum::Bignum(-BigInt::from(*fixnum)),
+            },
+
+            RLNum::Bignum(int) => RLNum::from_bigint(-int),
+            RLNum::Ratio(ratio) => RLNum::Ratio(-ratio),
+            RLNum::SingleFloat(float) => RLNum::SingleFloat(-float),
+            RLNum::DoubleFloat(float) => RLNum::DoubleFloat(-float),
+        }
+    }
+
+    pub fn add(&self, other: &RLNum) -> RLNum {
+        if let (RLNum::Fixnum(a), RLNum::Fixnum(b)) = (self, other) {
+            if let Some(sum) = a.checked_add(*b) {
+                return RLNum::Fixnum(sum)
+            }
+        }
+
+        self.contagion(other, |a, b| a + b, |a, b| a + b)
+    }
+
+    pub fn sub(&self, other: &RLNum) -> RLNum {
+        if let (RLNum::Fixnum(a), RLNum::Fixnum(b)) = (self, other) {
+            if let Some(difference) = a.checked_sub(*b) {
+                return RLNum::Fixnum(difference)
+            }
+        }
+
+        self.contagion(other, |a, b| a - b, |a, b| a - b)
+    }
+
+    pub fn mul(&self, other: &RLNum) -> RLNum {
+        if let (RLNum::Fixnum(a), RLNum::Fixnum(b)) = (self, other) {
+            if let Some(product) = a.checked_mul(*b) {
+                return RLNum::Fixnum(product)
+            }
+        }
+
+        self.contagion(other, |a, b| a * b, |a, b| a * b)
+    }
+
+    // Division of rationals is exact, (/ 1 3) is the ratio 1/3.
+    pub fn div(&self, other: &RLNum) -> Result<RLNum, RLError> {
+        if other.is_zero() {
+            return Err(RLError::DivisionByZero)
+        }
+
+        Ok(self.contagion(other, |a, b| a / b, |a, b| a / b))
+    }
+
+    // Integer quotient and remainder of self and divisor, the
+    // remainder has the sign required by rounding.
+    pub fn divide(&self, divisor: &RLNum, rounding: Rounding) ->
+        Result<(RLNum, RLNum), RLError> {
+
+        if divisor.is_zero() {
+            return Err(RLError::DivisionByZero)
+        }
+
+        let quotient = match (self.to_ratio(), divisor.to_ratio()) {
+            (Some(a), Some(b)) => {
+                let exact = a / b;
+
+                match rounding {
+                    Rounding::Floor => exact.floor(),
+                    Rounding::Ceiling => exact.ceil(),
+                    Rounding::Truncate => exact.trunc(),
+
+                    Rounding::Round => {
+                        let floor = exact.floor();
+                        let half = BigRational::new(BigInt::one(),
+                                                    BigInt::from(2));
+
+                        match (&exact - &floor).cmp(&half) {
+                            Ordering::Less => floor,
+                            Ordering::Greater => floor + BigInt::one(),
+
+                            Ordering::Equal if floor.numer().is_even() => {
+                                floor
+                            }
+
+                            Ordering::Equal => floor + BigInt::one(),
+                        }
+                    }
+                }.to_integer()
+            }
+
+            _ => {
+                let exact = self.to_f64() / divisor.to_f64();
+
+                let rounded = match rounding {
+                    Rounding::Floor => exact.floor(),
+                    Rounding::Ceiling => exact.ceil(),
+                    Rounding::Truncate => exact.trunc(),
+                    Rounding::Round => exact.round_ties_even(),
+                };
+
+                match BigInt::from_f64(rounded) {
+                    Some(quotient) => quotient,
+
+                    None => {
+                        let err = SimpleTypeError::new(&self.to_string(),
+                                                       "INTEGER");
+
+                        return Err(RLError::SimpleTypeError(err))
+                    }
+                }
+            }
+        };
+
+        let quotient = RLNum::from_bigint(quotient);
+
+        let remainder = self.sub(&quotient.mul(divisor));
+
+        Ok((quotient, remainder))
+    }
+
+    // Rational powers of integer exponents are exact, everything else
+    // is computed in floating point.
+    pub fn expt(&self, power: &RLNum) -> Result<RLNum, RLError> {
+        if let (Some(base), true) = (self.to_ratio(), power.is_integer()) {
+            let exponent = match power.to_ratio()
+                                      .and_then(|power| power.to_integer()
+                                                             .to_i32()) {
+                Some(exponent) => exponent,
+
+                None => {
+                    let err = SimpleError::new(&format!(
+                        "The exponent {} is too large.", power));
+
+                    return Err(RLError::SimpleError(err))
+                }
+            };
+
+            if exponent < 0 && base.is_zero() {
+                return Err(RLError::DivisionByZero)
+            }
+
+            let magnitude = exponent.unsigned_abs();
+
+            let result = BigRational::new(base.numer().pow(magnitude),
+                                          base.denom().pow(magnitude));
+
+            return Ok(RLNum::from_ratio(if exponent < 0 {
+                result.recip()
+            } else {
+                result
+            }))
+        }
+
+        let double = self.is_double_float() || power.is_double_float();
+
+        if self.signum() == Ordering::Less && !power.is_integer() {
+            let err = SimpleError::new(&format!(
+                "Complex results are not supported: (EXPT {} {})",
+                self, power));
+
+            return Err(RLError::SimpleError(err))
+        }
+
+        Ok(RLNum::make_float(self.to_f64().powf(power.to_f64()), double))
+    }
+
+    // Greatest common divisor of two integers, always non-negative.
+    pub fn gcd(&self, other: &RLNum) -> Option<RLNum> {
+        let a = self.to_ratio().filter(|_| self.is_integer())?;
+        let b = other.to_ratio().filter(|_| other.is_integer())?;
+
+        Some(RLNum::from_bigint(a.to_integer().gcd(&b.to_integer())))
+    }
+
+    // Least common multiple of two integers, always non-negative.
+    pub fn lcm(&self, other: &RLNum) -> Option<RLNum> {
+        let a = self.to_ratio().filter(|_| self.is_integer())?;
+        let b = other.to_ratio().filter(|_| other.is_integer())?;
+
+        Some(RLNum::from_bigint(a.to_integer().lcm(&b.to_integer()).abs()))
+    }
+
+    // Rationals are combined exactly, a float makes the result a
+    // float of the larger format of both arguments.
+    fn contagion(&self,
+                 other: &RLNum,
+                 rational_op: fn(BigRational, BigRational) -> BigRational,
+                 float_op: fn(f64, f64) -> f64) -> RLNum {
+
+        match (self.to_ratio(), other.to_ratio()) {
+            (Some(a), Some(b)) => RLNum::from_ratio(rational_op(a, b)),
+
+            _ => {
+                let result = float_op(self.to_f64(), other.to_f64());
+
+                if matches!(self, RLNum::DoubleFloat(_)) ||
+                   matches!(other, RLNum::DoubleFloat(_)) {
+                    RLNum::DoubleFloat(result)
+                } else {
+                    RLNum::SingleFloat(result as f32)
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for RLNum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RLNum::Fixnum(fixnum) => write!(f, "{}", fixnum),
+            RLNum::Bignum(int) => write!(f, "{}", int),
+            RLNum::Ratio(ratio) => {
+                write!(f, "{}/{}", ratio.numer(), ratio.denom())
+            }
+            RLNum::SingleFloat(float) => {
+                write!(f, "{}", format_float(&float.to_string(),
+                                             &format!("{:e}", float),
+                                             float.abs() as f64,
+                                             None))
+            }
+            RLNum::DoubleFloat(float) => {
+                write!(f, "{}", format_float(&float.to_string(),
+                                             &format!("{:e}", float),
+                                             float.abs(),
+                                             Some('d')))
+            }
+        }
+    }
+}
+
+// 2.5, 1.0e10 and 2.5d0. Floats between 10^-3 and 10^7 are printed
+// without exponent, double floats always carry the d marker.
+fn format_float(plain: &str,
+                scientific: &str,
+                magnitude: f64,
+                marker: Option<char>) -> String {
+
+    if !magnitude.is_finite() {
+        return format!("#<FLOAT {}>", plain)
+    }
+
+    let with_point = |mantissa: &str| {
+        if mantissa.contains('.') {
+            mantissa.to_string()
+        } else {
+            format!("{}.0", mantissa)
+        }
+    };
+
+    if magnitude == 0.0 || (1e-3..1e7).contains(&magnitude) {
+        match marker {
+            Some(marker) => format!("{}{}0", with_point(plain), marker),
+            None => with_point(plain),
+        }
+    } else {
+        let (mantissa, exponent) = scientific.split_once('e')
+                                             .unwrap_or((scientific, "0"));
+
+        format!("{}{}{}",
+                with_point(mantissa),
+                marker.unwrap_or('e'),
+                exponent)
+    }
+}
+
+fn split_sign(token: &str) -> (&str, &str) {
+    match token.strip_prefix('-') {
+        Some(body) => ("-", body),
+        None => ("", token.strip_prefix('+').unwrap_or(token)),
+    }
+}
+
+fn is_digits(digits: &str, radix: u32) -> bool {
+    !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix))
+}
+
+fn read_integer(sign: &str, digits: &str, radix: u32) -> Option<RLNum> {
+    let int = BigInt::parse_bytes(format!("{}{}", sign, digits).as_bytes(),
+                                  radix)?;
+
+    Some(RLNum::from_bigint(int))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(token: &str) -> RLNum {
+        RLNum::read(token).unwrap().unwrap()
+    }
+
+    // the printed representation of the number read from token, it
+    // reads back as the same number
+    fn printed(token: &str) -> String {
+        let num = read(token);
+        let text = num.to_string();
+
+        assert_eq!(read(&text), num, "{} printed as {}", token, text);
+
+        text
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(printed("42"), "42");
+        assert_eq!(printed("-7"), "-7");
+        assert_eq!(printed("+5"), "5");
+        assert_eq!(printed("3."), "3");
+        assert_eq!(printed("007"), "7");
+
+        assert_eq!(read("9223372036854775807"), RLNum::Fixnum(i64::MAX));
+        assert_eq!(read("-9223372036854775808"), RLNum::Fixnum(i64::MIN));
+
+        assert!(matches!(read("9223372036854775808"), RLNum::Bignum(_)));
+        assert_eq!(printed("-123456789012345678901234567890"),
+                   "-123456789012345678901234567890");
+    }
+
+    #[test]
+    fn ratios() {
+        assert_eq!(printed("1/3"), "1/3");
+        assert_eq!(printed("2/4"), "1/2");
+        assert_eq!(printed("-6/4"), "-3/2");
+        assert_eq!(read("-6/3"), RLNum::Fixnum(-2));
+        assert_eq!(printed("1/123456789012345678901"),
+                   "1/123456789012345678901");
+
+        assert!(matches!(RLNum::read("1/0"), Err(RLError::DivisionByZero)));
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(printed("2.5"), "2.5");
+        assert_eq!(printed(".5"), "0.5");
+        assert_eq!(printed("-0.25"), "-0.25");
+        assert_eq!(printed("0.0"), "0.0");
+        assert_eq!(printed("1e10"), "1.0e10");
+        assert_eq!(printed("1.5e7"), "1.5e7");
+        assert_eq!(printed("1.0e-5"), "1.0e-5");
+        assert_eq!(printed("123.456"), "123.456");
+
+        assert!(matches!(read("2.5"), RLNum::SingleFloat(_)));
+        assert!(matches!(read("2.5f0"), RLNum::SingleFloat(_)));
+        assert!(matches!(read("2.5d0"), RLNum::DoubleFloat(_)));
+        assert!(matches!(read("2.5l0"), RLNum::DoubleFloat(_)));
+    }
+
+    #[test]
+    fn double_floats() {
+        assert_eq!(printed("2.5d0"), "2.5d0");
+        assert_eq!(printed("1d0"), "1.0d0");
+        assert_eq!(printed("-1.5D3"), "-1500.0d0");
+        assert_eq!(printed("1d300"), "1.0d300");
+        assert_eq!(printed("0.1d0"), "0.1d0");
+        assert_eq!(printed("1.0d-10"), "1.0d-10");
+    }
+
+    #[test]
+    fn overflow() {
+        assert!(matches!(RLNum::read("1e39"),
+                         Err(RLError::FloatingPointOverflow)));
+        assert!(matches!(RLNum::read("-1e39"),
+                         Err(RLError::FloatingPointOverflow)));
+        assert!(matches!(RLNum::read("1d309"),
+                         Err(RLError::FloatingPointOverflow)));
+
+        assert_eq!(printed("3e38"), "3.0e38");
+    }
+
+    #[test]
+    fn not_numbers() {
+        for token in ["abc", "-", "+", ".", "1.2.3", "1e", "e5", "1/2/3",
+                      "1/", "/2", "1+", "--1", "1.5/2", "#x10"] {
+            assert!(matches!(RLNum::read(token), Ok(None)), "{}", token);
+        }
+    }
+
+    #[test]
+    fn radix() {
+        assert_eq!(RLNum::read_radix("FF", 16), Some(RLNum::Fixnum(255)));
+        assert_eq!(RLNum::read_radix("-101", 2), Some(RLNum::Fixnum(-5)));
+        assert_eq!(RLNum::read_radix("zz", 36), Some(RLNum::Fixnum(1295)));
+        assert_eq!(RLNum::read_radix("10/4", 8), Some(read("2")));
+        assert_eq!(RLNum::read_radix("1/11", 2), Some(read("1/3")));
+
+        assert_eq!(RLNum::read_radix("12", 2), None);
+        assert_eq!(RLNum::read_radix("1/0", 10), None);
+        assert_eq!(RLNum::read_radix("", 16), None);
+    }
+}
@@ -7,9 +7,12 @@ use std::fmt;
 // use std::ops::Not;
 
 use crate::atom::RLAtom;
+use crate::char::RLChar;
 use crate::cons::RLCons;
+use crate::num::RLNum;
 use crate::QuoteTrait;
-use crate::expr::{sexpr_to_qexpr};
+use crate::expr::{qexpr_to_sexpr, sexpr_to_qexpr};
+use crate::function::RLFunction;
 use crate::list::RLList;
 use crate::sexpr::SExpr;
 use crate::symb::RLSymbol;
@@ -21,6 +24,13 @@ use err::err::{RLError, TypeError};
 pub enum QExpr {
     Atom(RLAtom),
 
+    Char(RLChar),
+
+    Num(RLNum),
+
+    Func(RLAtom),
+    Lambda(RLFunction),
+
     Nil(RLNil),
 
     Symb(RLSymbol),
@@ -47,7 +57,7 @@ impl QExpr {
                 Ok(QExpr::Nil(nil.clone()))
             }
 
-            QExpr::Atom(atom) => {
+            QExpr::Atom(atom) | QExpr::Func(atom) => {
                 let atom_string = atom.get_atom_string();
 
                 let err = TypeError::new(&atom_string, "LIST");
@@ -55,6 +65,12 @@ impl QExpr {
                 return Err(RLError::TypeError(err));
             }
 
+            QExpr::Lambda(function) => {
+                let err = TypeError::new(&function.get_id(), "LIST");
+
+                Err(RLError::TypeError(err))
+            }
+
             QExpr::Symb(symb) => {
                 let symb_name = symb.get_symbol_name();
 
@@ -63,6 +79,18 @@ impl QExpr {
                 return Err(RLError::TypeError(err));
             }
 
+            QExpr::Char(ch) => {
+                let err = TypeError::new(&ch.to_string(), "LIST");
+
+                return Err(RLError::TypeError(err));
+            }
+
+            QExpr::Num(num) => {
+                let err = TypeError::new(&num.to_string(), "LIST");
+
+                Err(RLError::TypeError(err))
+            }
+
             QExpr::QList2(list) => {
                 match list.car() {
                     Ok(res) => Ok(res),
@@ -83,7 +111,7 @@ impl QExpr {
                 Ok(QExpr::Nil(nil.clone()))
             }
 
-            QExpr::Atom(atom) => {
+            QExpr::Atom(atom) | QExpr::Func(atom) => {
                 let atom_string = atom.get_atom_string();
 
                 let err = TypeError::new(&atom_string, "LIST");
@@ -91,6 +119,12 @@ impl QExpr {
                 return Err(RLError::TypeError(err));
             }
 
+            QExpr::Lambda(function) => {
+                let err = TypeError::new(&function.get_id(), "LIST");
+
+                Err(RLError::TypeError(err))
+            }
+
             QExpr::Symb(symb) => {
                 let symb_name = symb.get_symbol_name();
 
@@ -99,6 +133,18 @@ impl QExpr {
                 return Err(RLError::TypeError(err));
             }
 
+            QExpr::Char(ch) => {
+                let err = TypeError::new(&ch.to_string(), "LIST");
+
+                return Err(RLError::TypeError(err));
+            }
+
+            QExpr::Num(num) => {
+                let err = TypeError::new(&num.to_string(), "LIST");
+
+                Err(RLError::TypeError(err))
+            }
+
             QExpr::QList2(list) => {
                 match list.cdr() {
                     Ok(res) => Ok(res),
@@ -146,11 +192,7 @@ impl QExpr {
                 qlist.pop_back();
 
                 /*
-                println!("qlist: {:?}", qlist);
-                println!("");
 
-                println!("qlist len(): {:?}", qlist.len());
-                println!("");
                 */
 
                 // pop the first elem of q_list
@@ -269,9 +311,6 @@ impl QExpr {
                                     paren_count = paren_count - 1;
 
                                     if paren_count == 0 {
-                                        println!("paren_count == 0");
-                                        println!("");
-
                                         result_list.push_back(
                                             QExpr::Symb(
                                                 RLSymbol::new_with_str(")")));
@@ -312,7 +351,7 @@ impl QExpr {
                 result_car = *first.clone();
             }
 
-            QExpr::Atom(atom) => {
+            QExpr::Atom(atom) | QExpr::Func(atom) => {
                 let atom_string = atom.get_atom_string();
 
                 let err = TypeError::new(&atom_string, "LIST");
@@ -320,6 +359,12 @@ impl QExpr {
                 return Err(RLError::TypeError(err));
             }
 
+            QExpr::Lambda(function) => {
+                let err = TypeError::new(&function.get_id(), "LIST");
+
+                return Err(RLError::TypeError(err));
+            }
+
             QExpr::Symb(symb) => {
                 let symb_name = symb.get_symbol_name();
 
@@ -365,11 +410,7 @@ impl QExpr {
                 qlist.pop_back();
 
                 /*
-                println!("qlist: {:?}", qlist);
-                println!("");
 
-                println!("qlist len(): {:?}", qlist.len());
-                println!("");
                 */
 
                 // pop the first elem of q_list
@@ -411,9 +452,6 @@ impl QExpr {
                                     paren_count = paren_count - 1;
 
                                     if paren_count == 0 {
-                                        println!("paren_count == 0");
-                                        println!("");
-
                                         break;
                                     }
                                 }
@@ -440,7 +478,7 @@ impl QExpr {
                 result_cdr = *second.clone();
             }
 
-            QExpr::Atom(atom) => {
+            QExpr::Atom(atom) | QExpr::Func(atom) => {
                 let atom_string = atom.get_atom_string();
 
                 let err = TypeError::new(&atom_string, "LIST");
@@ -448,6 +486,12 @@ impl QExpr {
                 return Err(RLError::TypeError(err));
             }
 
+            QExpr::Lambda(function) => {
+                let err = TypeError::new(&function.get_id(), "LIST");
+
+                return Err(RLError::TypeError(err));
+            }
+
             QExpr::Symb(symb) => {
                 let symb_name = symb.get_symbol_name();
 
@@ -558,8 +602,6 @@ impl QExpr {
 impl QuoteTrait for QExpr {
 
     fn quote(sexpr: SExpr) -> Result<QExpr, RLError> {
-        println!("in QuoteTrait qexpr - quote");
-
         let result_qexpr: QExpr;
 
         match sexpr {
@@ -577,95 +619,33 @@ impl QuoteTrait for QExpr {
     }
 }
 
-#[allow(unused_assignments)]
 impl fmt::Display for QExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // println!("in fmt for QExpr");
-        // println!("self: {:?}", self);
         match self {
-            QExpr::Atom(s) => { // println!("atom");
-                                write!(f, "{}", s) }
+            QExpr::Atom(s) => write!(f, "{}", s),
 
-            QExpr::Symb(s)  => write!(f, "{}", s),
+            QExpr::Char(ch) => write!(f, "{}", ch),
 
-            QExpr::Nil(nil) => write!(f, "{}", nil),
-
-            QExpr::QList2(qlist) => {
-                let list = qlist.get_linked_list();
-
-                let mut iter = list.iter().peekable();
-
-                for qexpr in list.clone() {
-                    iter.next();
-
-                    let _ = match qexpr {
-                        QExpr::Symb(s) => {
-                            let s_name = s.get_symbol_name();
-
-                            match &*s_name {
-                                "(" => {
-                                    write!(f, "{}", s_name)
-                                }
-
-                                ")" => {
-                                    write!(f, "{}", s_name)
-                                }
-
-                                "`" => {
-                                    write!(f, "{}", s_name)
-                                }
-
-                                "," => {
-                                    write!(f, "{}", s_name)
-                                }
-
-                                _ => { write!(f, "{} ", s_name.to_uppercase())
-                                }
-                            }
-                        }
-
-                        QExpr::QList2(q_list) => {
-                            if let Some(QExpr::Atom(_atom)) = iter.peek() {
-                                write!(f, "{} ", QExpr::QList2(q_list.clone()))
-                            } else { write!(f, "{}", QExpr::QList2(q_list.clone())) }
-                        }
+            QExpr::Num(num) => write!(f, "{}", num),
 
-                        QExpr::Atom(a) => {
-                            if let Some(QExpr::Symb(s)) = iter.peek() {
-                                let s_name = s.get_symbol_name();
+            QExpr::Func(func) => write!(f, "#<FUNCTION {}>",
+                                        func.get_atom_string()
+                                            .to_uppercase()),
 
-                                match &*s_name {
-                                    "(" => { write!(f, "{} ", a)? }
+            QExpr::Lambda(lambda) => write!(f, "#<FUNCTION (LAMBDA) {{{}}}>",
+                                            lambda),
 
-                                    ")" => { write!(f, "{}", a)? }
+            QExpr::Symb(s)  =>
+                write!(f, "{}", s.get_symbol_name().to_uppercase()),
 
-                                      _ => write!(f, "{} ", a)?,
-                                }
-                            }
-
-                            if let Some(QExpr::Atom(_)) = iter.peek() {
-                                write!(f, "{} ", a)?
-                            }
-
-                            if let Some(QExpr::QList2(_list)) = iter.peek() {
-                                write!(f, "{} ", a)
-                            } else { Ok(()) }
-                        }
-
-                        QExpr::Nil(nil) => write!(f, "{}", nil),
+            QExpr::Nil(nil) => write!(f, "{}", nil),
 
-                        _ => write!(f, ""),
-                    };
-                }
-                Ok(())
+            QExpr::QList2(qlist) => {
+                write!(f, "{}", qexpr_to_sexpr(QExpr::QList2(qlist.clone())))
             }
 
             QExpr::QCons(cons) => {
-                let first = cons.car();
-
-                let second = cons.cdr();
-
-                write!(f, "({} . {})", first, second)
+                write!(f, "{}", qexpr_to_sexpr(QExpr::QCons(cons.clone())))
             }
         }
     }
@@ -0,0 +1,71 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::fmt;
+
+// A restart established by RESTART-CASE or by the evaluator, e.g. the
+// CONTINUE restart of CERROR. It is invoked by unwinding to the form
+// which established it.
+#[derive(Debug, Clone)]
+pub struct RLRestart {
+    // uppercase, NIL for anonymous restarts
+    name: String,
+
+    report: String,
+
+    // id of the establishing form and index of the restart in it
+    id: usize,
+    index: usize,
+
+    // number of required arguments, the debugger asks for them
+    arity: usize,
+}
+
+impl RLRestart {
+    pub fn new(name: &str,
+               report: &str,
+               id: usize,
+               index: usize,
+               arity: usize) -> Self {
+
+        Self {
+            name: name.to_uppercase(),
+            report: report.to_string(),
+            id,
+            index,
+            arity,
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_report(&self) -> String {
+        self.report.clone()
+    }
+
+    pub fn get_id(&self) -> usize {
+        self.id
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_arity(&self) -> usize {
+        self.arity
+    }
+}
+
+impl fmt::Display for RLRestart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.report.is_empty() {
+            write!(f, "#<RESTART {}>", self.name)
+        } else {
+            write!(f, "#<RESTART {} \"{}\">", self.name, self.report)
+        }
+    }
+}
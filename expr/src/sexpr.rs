@@ -7,15 +7,21 @@ use std::fmt;
 use std::collections::{BTreeMap, LinkedList};
 
 use crate::atom::RLAtom;
+use crate::char::RLChar;
+use crate::condition::RLCondition;
 use crate::cons::RLCons;
+use crate::function::RLFunction;
 use crate::list::RLList;
 use crate::nil::RLNil;
+use crate::num::RLNum;
 use crate::qexpr::QExpr;
+use crate::restart::RLRestart;
 use crate::symb::RLSymbol;
 use crate::QuoteTrait;
 
 use err::err::RLError;
 
+use pars_symb::span::Span;
 use pars_symb::symbol::Symbol;
 use pars_symb::token::Token;
 
@@ -24,8 +30,21 @@ use pars_symb::token::Token;
 pub enum SExpr {
     Atom(RLAtom),
 
-    // Func(RLAtom),
-    Lambda(RLAtom),
+    Char(RLChar),
+
+    // a number, kept parsed so arithmetic doesn't re-read it
+    Num(RLNum),
+
+    Func(RLAtom),
+
+    // a function made by LAMBDA, FLET or LABELS
+    Lambda(RLFunction),
+
+    // an instance of a condition type
+    Condition(RLCondition),
+
+    // an active restart
+    Restart(RLRestart),
 
     Nil(RLNil),
 
@@ -50,13 +69,58 @@ pub enum SExpr {
     SBTreeMap(BTreeMap<String, SExpr>),
 }
 
-impl SExpr { }
+impl SExpr {
+    // where the form was read, None for forms built at run time
+    pub fn get_span(&self) -> Option<Span> {
+        match self {
+            SExpr::Atom(atom)   => atom.get_span(),
+            SExpr::Symb(symb)   => symb.get_span(),
+            SExpr::SList(slist) => slist.get_span(),
+            SExpr::SCons(cons)  => cons.car().get_span(),
+            _                   => None,
+        }
+    }
+
+    // EQL: characters and numbers of the same type are the same if they
+    // have the same value, symbols if they have the same name as they
+    // are all interned. Strings, lists, conses and functions are the
+    // same only if they are one object, copies of a value share it.
+    pub fn is_eql(&self, other: &SExpr) -> bool {
+        match (self, other) {
+            (SExpr::Char(ch1), SExpr::Char(ch2)) => ch1 == ch2,
+
+            (SExpr::Num(num1), SExpr::Num(num2)) => num1 == num2,
+
+            (SExpr::Symb(symb1), SExpr::Symb(symb2)) => {
+                symb1.get_symbol_name().eq(&symb2.get_symbol_name())
+            }
+
+            (SExpr::Nil(_), SExpr::Nil(_)) => true,
+
+            (SExpr::Atom(atom1), SExpr::Atom(atom2)) => {
+                atom1.is_same(atom2)
+            }
+
+            (SExpr::SList(slist1), SExpr::SList(slist2)) => {
+                slist1.is_same(slist2)
+            }
+
+            (SExpr::SCons(cons1), SExpr::SCons(cons2)) => {
+                cons1.is_same(cons2)
+            }
+
+            (SExpr::Lambda(function1), SExpr::Lambda(function2)) => {
+                function1.is_same(function2)
+            }
+
+            _ => false,
+        }
+    }
+}
 
 impl QuoteTrait for SExpr {
 
     fn quote(sexpr: SExpr) -> Result<SExpr, RLError> {
-        println!("in QuoteTrait sexpr - quote");
-
         match sexpr {
             SExpr::Cons(symb, ll) => {
                 Ok(SExpr::Cons(symb, ll))
@@ -70,6 +134,14 @@ impl QuoteTrait for SExpr {
                 Ok(SExpr::Atom(atom))
             }
 
+            SExpr::Char(ch) => {
+                Ok(SExpr::Char(ch))
+            }
+
+            SExpr::Num(num) => {
+                Ok(SExpr::Num(num))
+            }
+
             SExpr::Nil(nil) => {
                 Ok(SExpr::Nil(nil))
             }
@@ -85,264 +157,119 @@ impl QuoteTrait for SExpr {
 
 impl fmt::Display for SExpr {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // println!("in fmt for SExpr");
-        // println!("self: {:?}", self);
-
         match self {
-            SExpr::Atom(a) => write!(f, "{}", a.get_atom_string()),
+            SExpr::Atom(a) => write!(f, "{}", a),
+
+            SExpr::Char(ch) => write!(f, "{}", ch),
+
+            SExpr::Num(num) => write!(f, "{}", num),
 
-            // SExpr::Func(func) => write!(f, "{}", func),
+            SExpr::Func(func) => write!(f, "#<FUNCTION {}>",
+                                        func.get_atom_string()
+                                            .to_uppercase()),
 
-            SExpr::Lambda(lambda) => write!(f, "{}", lambda),
+            SExpr::Lambda(lambda) => write!(f, "#<FUNCTION (LAMBDA) {{{}}}>",
+                                            lambda),
 
+            SExpr::Condition(condition) => write!(f, "{}", condition),
+            SExpr::Restart(restart) => write!(f, "{}", restart),
             SExpr::Cons(head, rest) => {
-                write!(f, "({}", head)?;
+                write!(f, "({}", head.name.to_uppercase())?;
                 for s in rest.iter() {
                     write!(f, " {}", s)?
                 }
                 write!(f, ")")
             }
 
-            SExpr::QList(qlist) => {
-                // println!("Formatter for SExpr::QList: {:?}", qlist);
-                // println!("");
-
-                let list = qlist.get_linked_list();
-
-                let mut iter = list.iter().peekable();
-
-                for sexpr in list.clone() {
-                    iter.next();
-
-                    let _ = match sexpr {
-                        QExpr::Symb(s) => {
-                            let s_name = s.get_symbol_name();
-
-                            match &*s_name {
-                                "(" => {
-                                    write!(f, "{}", s_name)
-                                }
-
-                                ")" => {
-                                    Ok(if let None = iter.peek() {
-                                        write!(f, "{}", s_name)?
-                                    } else {
-                                        write!(f, "{}", s_name)?
-                                    })
-                                }
-
-                                _ => { write!(f, "{} ",
-                                    s_name.to_uppercase()) }
-                            }
-                        }
-
-                        QExpr::QList2(s_list) => {
-                            if let Some(QExpr::Atom(_atom)) = iter.peek() {
-                                write!(f, "{} ",
-                                    QExpr::QList2(s_list.clone()))
-                            } else {
-                                write!(f, "{}",
-                                    QExpr::QList2(s_list.clone())) }
-                        }
-
-                        QExpr::Atom(a) => {
-                            if let Some(QExpr::Symb(s)) = iter.peek() {
-                                let s_name = s.get_symbol_name();
-
-                                match &*s_name {
-                                    "(" => { write!(f, "{} ", a)? }
-
-                                    ")" => { write!(f, "{}", a)? }
-
-                                      _ => write!(f, "{} ", a)?,
-                                }
-                            }
-
-                            if let Some(QExpr::Atom(_)) = iter.peek() {
-                                write!(f, "{} ", a)?
-                            }
-
-                            if let Some(QExpr::QList2(_list)) = iter.peek() {
-                                write!(f, "{} ", a)
-                            } else { Ok(()) }
-                        }
-
-                        QExpr::Nil(nil) => write!(f, "{}", nil),
-
-                        _ => write!(f, ""),
-                    };
-                }
-                Ok(())
-            }
+            SExpr::QList(qlist) => write!(f, "{}", QExpr::QList2(qlist.clone())),
 
             SExpr::Nil(nil) => write!(f, "{}", nil),
 
             SExpr::Dummy => write!(f, ""),
 
-            SExpr::SList(slist) => {
-                let list = slist.get_linked_list();
-
-                // list.push_back(SExpr::Symb(RLSymbol::new_with_str(")")));
-                // list.push_front(SExpr::Symb(RLSymbol::new_with_str("(")));
+            SExpr::SList(slist) => fmt_items(f, &slist.get_items()),
 
-                // println!("list: {:?}", list);
-                // println!("");
-
-                let mut iter = list.iter().peekable();
-
-                for sexpr in list.clone() {
-                    iter.next();
-
-                    let _ = match sexpr {
-                        SExpr::Symb(symb) => {
-                            let symb_name = symb.get_symbol_name();
+            SExpr::SForm(form) =>
+                write!(f, "{:?}", form),
 
-                            match &*symb_name {
-                                "(" => {
-                                    write!(f, "{}", symb_name)
-                                }
+            SExpr::SToken(token) =>
+                write!(f, "{:?}", token),
 
-                                ")" => {
-                                    Ok(if let None = iter.peek() {
-                                        write!(f, "{}", symb_name)?
-                                    } else {
-                                        if let Some(SExpr::Symb(s)) =
-                                            iter.peek() {
+            SExpr::SBTreeMap(btm) =>
+                write!(f, "{:?}", btm),
 
-                                            let s_name = s.get_symbol_name();
+            SExpr::SCons(cons) => {
+                write!(f, "({}", cons.car())?;
 
-                                            match &*s_name {
-                                                "(" => {
+                let mut rest = cons.cdr();
 
-                                                write!(f, "{} ", symb)? }
+                loop {
+                    match rest {
+                        SExpr::SCons(next) => {
+                            write!(f, " {}", next.car())?;
 
-                                                ")" => { write!(f, "{}", symb)? }
+                            rest = next.cdr();
+                        }
 
-                                                _ => write!(f, "{} ", symb)?,
-                                            }
-                                        } else {
-                                            write!(f, "{} ", symb_name)?
-}
-                                    })
-                                }
-
-                                "'" => {
-                                    write!(f, "{}", symb_name)
-                                }
-
-                                "`" => {
-                                    write!(f, "{}", symb_name)
-                                }
-
-                                "," => {
-                                    write!(f, "{}", symb_name)
-                                }
-
-                                _ => {
-                                      Ok(if let None = iter.peek() {
-                                        write!(f, "{}", symb_name
-                                                           .to_uppercase())?
-                                    } else {
-                                        if let Some(SExpr::Symb(s)) =
-                                            iter.peek() {
-
-                                            let s_name = s.get_symbol_name();
-
-                                            match &*s_name {
-
-                                                "(" => {
-                                                    write!(f, "{} ",
-                                                        symb_name
-                                                           .to_uppercase())?
-                                                }
-
-                                                ")" => {
-                                                    write!(f, "{}",
-                                                        symb_name
-                                                           .to_uppercase())?
-                                                }
-
-                                                _ => {
-                                                    write!(f, "{} ",
-                                                        symb_name
-                                                           .to_uppercase())?
-                                                }
-                                            }
-                                        } else {
-                                            write!(f, "{} ",
-                                                symb_name.to_uppercase())?
-                                        }
-                                    })
-                                    /*
-                                    write!(f, "{} ", symb_name
-                                                         .to_uppercase())
-                                   */
-                                }
+                        SExpr::SList(slist) => {
+                            for item in slist.get_items() {
+                                write!(f, " {}", item)?;
                             }
-                        }
 
-                        SExpr::SList(s_list) => {
-                            if let Some(SExpr::Atom(_atom)) = iter.peek() {
-                                write!(f, "{} ", SExpr::SList(s_list.clone()))
-                            } else { write!(f, "{}", SExpr::SList(s_list.clone())) }
+                            break;
                         }
 
-                        SExpr::Atom(a) => {
-                            let atom_string = a.get_atom_string();
-
-                            if let Some(SExpr::Symb(s)) = iter.peek() {
-                                let s_name = s.get_symbol_name();
-
-                                match &*s_name {
-                                    "(" => { write!(f, "{} ", atom_string)? }
-
-                                    ")" => {
-                                        write!(f, "{}", atom_string)?
-                                    }
-
-                                    _ => {
-                                        write!(f, "{} ", atom_string)?
-                                    }
-                                }
-                            }
+                        SExpr::Nil(_) => break,
 
-                            if let Some(SExpr::Atom(_)) = iter.peek() {
-                                write!(f, "{} ", atom_string)?
-                            }
+                        other => {
+                            write!(f, " . {}", other)?;
 
-                            if let Some(SExpr::SList(_list)) = iter.peek() {
-                                write!(f, "{} ", atom_string)
-                            } else { Ok(()) }
+                            break;
                         }
-
-                        SExpr::Nil(nil) => write!(f, "{}", nil),
-
-                        _ => write!(f, ""),
-                    };
+                    }
                 }
-                Ok(())
-            }
-
-            SExpr::SForm(form) =>
-                write!(f, "{:?}", form),
-
-            SExpr::SToken(token) =>
-                write!(f, "{:?}", token),
-
-            SExpr::SBTreeMap(btm) =>
-                write!(f, "{:?}", btm),
 
-            SExpr::SCons(cons) => {
-                let first = cons.car();
+                write!(f, ")")
+            }
 
-                let second = cons.cdr();
+            SExpr::Symb(symb) =>
+                write!(f, "{}", symb.get_symbol_name().to_uppercase()),
+        }
+    }
+}
 
-                write!(f, "({} . {})", first, second)
+// prints the items of a list, (quote x) and friends are printed
+// with their reader macro characters
+fn fmt_items(f: &mut fmt::Formatter<'_>,
+             items: &LinkedList<SExpr>) -> fmt::Result {
+    if items.len() == 2 {
+        if let (Some(SExpr::Symb(symb)), Some(form)) =
+            (items.front(), items.back()) {
+
+            let prefix = match &*symb.get_symbol_name() {
+                "quote"     => Some("'"),
+                "function"  => Some("#'"),
+                "backquote" => Some("`"),
+                "comma"     => Some(","),
+                "comma-at"  => Some(",@"),
+                _ => None,
+            };
+
+            if let Some(prefix) = prefix {
+                return write!(f, "{}{}", prefix, form)
             }
+        }
+    }
+
+    write!(f, "(")?;
 
-            SExpr::Symb(symb) => write!(f, "{}", symb),
+    for (idx, item) in items.iter().enumerate() {
+        if idx > 0 {
+            write!(f, " ")?;
         }
+
+        write!(f, "{}", item)?;
     }
-}
 
-/////////////////////////////////////////////////////////////
+    write!(f, ")")
+}
@@ -5,6 +5,8 @@
 
 use std::fmt;
 
+use crate::char::RLChar;
+
 // use crate::bool::RLBool;
 
 // use err::err::RLError;
@@ -78,9 +80,6 @@ impl RLString {
 
                            if let SExpr::Atom(atom) = expr {
 
-                               println!("atom: {:?}", atom);
-                               println!("");
-
                                result_string.concatenate(
                                    &SExpr::Atom(atom).get_atom_rlstring());
                            } else {
@@ -92,16 +91,81 @@ impl RLString {
         */
     }
 
+    pub fn from_chars(chars: &[char]) -> RLString {
+        RLString { string: chars.iter().collect() }
+    }
+
+    // strings are indexed by characters, not by bytes
+    pub fn chars(&self) -> Vec<char> {
+        self.string.chars().collect()
+    }
+
+    pub fn length(&self) -> usize {
+        self.string.chars().count()
+    }
+
+    // the text of the atom holding the string, the contents between
+    // double quotes without escapes
+    pub fn to_atom_string(&self) -> String {
+        format!("\"{}\"", self.string)
+    }
+
     // rust style wrapper for capitalization
     pub fn to_uppercase(&mut self) -> RLString {
         self.string = self.string.to_uppercase();
         RLString { string: self.string.clone() }
     }
 
-    // lisp style wrapper for capitalization
-    pub fn string_capitalize(&mut self) -> RLString {
-        self.string = self.string.to_uppercase();
-        RLString { string: self.string.clone() }
+    // lisp style wrappers for the case conversions, character by
+    // character like CHAR-UPCASE and CHAR-DOWNCASE
+    pub fn string_upcase(&self, start: usize, end: usize) -> RLString {
+        self.map_chars(start, end, |_, ch| ch.upcase())
+    }
+
+    pub fn string_downcase(&self, start: usize, end: usize) -> RLString {
+        self.map_chars(start, end, |_, ch| ch.downcase())
+    }
+
+    // the first character of each word in upper case, the others in
+    // lower case, words are runs of alphanumeric characters
+    pub fn string_capitalize(&self, start: usize, end: usize) -> RLString {
+        self.map_chars(start, end, |previous, ch| {
+            if previous.is_some_and(char::is_alphanumeric) {
+                ch.downcase()
+            } else {
+                ch.upcase()
+            }
+        })
+    }
+
+    // convert the characters from start to end, the function also
+    // gets the character before
+    fn map_chars(&self,
+                 start: usize,
+                 end: usize,
+                 convert: impl Fn(Option<char>, RLChar) -> RLChar) ->
+        RLString {
+
+        let chars = self.chars();
+
+        let converted = chars.iter()
+                             .enumerate()
+                             .map(|(idx, ch)| {
+                                 if idx < start || idx >= end {
+                                     return *ch
+                                 }
+
+                                 let previous = match idx {
+                                     0 => None,
+
+                                     _ => Some(chars[idx - 1]),
+                                 };
+
+                                 convert(previous, RLChar::new(*ch)).get()
+                             })
+                             .collect::<Vec<char>>();
+
+        RLString::from_chars(&converted)
     }
 
     /*
@@ -117,10 +181,20 @@ impl RLString {
     */
 }
 
+// printed readably, a double quote or backslash in the string is
+// preceded by a backslash
 impl fmt::Display for RLString {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // println!("in fmt for String");
-        // println!("self: {:?}", self);
-      write!(f, "\"{}\"", &self.string)
+        write!(f, "\"")?;
+
+        for ch in self.string.chars() {
+            if ch == '"' || ch == '\\' {
+                write!(f, "\\")?;
+            }
+
+            write!(f, "{}", ch)?;
+        }
+
+        write!(f, "\"")
     }
 }
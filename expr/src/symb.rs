@@ -7,6 +7,7 @@ use std::fmt;
 
 use crate::comma::RLComma;
 
+use pars_symb::span::Span;
 use pars_symb::symbol::Symbol;
 
 #[derive(Debug, Clone)]
@@ -14,6 +15,8 @@ pub struct RLSymbol {
     symbol: Symbol,
 
     comma: Option<RLComma>,
+
+    span: Option<Span>,
 }
 
 impl RLSymbol {
@@ -25,6 +28,7 @@ impl RLSymbol {
         RLSymbol {
             symbol,
             comma,
+            span: None,
         }
     }
 
@@ -36,6 +40,7 @@ impl RLSymbol {
         RLSymbol {
             symbol,
             comma,
+            span: None,
         }
     }
 
@@ -47,6 +52,14 @@ impl RLSymbol {
         self.symbol.clone()
     }
 
+    pub fn set_span(&mut self, span: &Span) {
+        self.span = Some(span.clone());
+    }
+
+    pub fn get_span(&self) -> Option<Span> {
+        self.span.clone()
+    }
+
     pub fn set_comma(&mut self, comma: &RLComma) {
         self.comma = Some(comma.clone());
     }
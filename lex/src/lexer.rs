@@ -3,17 +3,23 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-use std::borrow::Cow;
 use std::collections::HashMap;
 
 use crate::symbol_creator::SymbolCreator;
 
-use hash::hash::{RLHash, clone_hash_map};
+use err::err::{RLError, ParseError};
 
+use expr::char::RLChar;
+use expr::num::RLNum;
+
+use hash::hash::RLHash;
+
+use pars_symb::span::Span;
 use pars_symb::symbol::Symbol;
 use pars_symb::token::{Token, make_token_hash_map};
 
-use regex::Regex;
+use trace::rltrace;
+use trace::trace::{TraceCategory, TraceLevel, is_enabled};
 
 // #[derive(Debug)]
 pub struct RLLexer {
@@ -24,6 +30,18 @@ pub struct RLLexer {
     symbol_creator: SymbolCreator,
 
     pub tokens: Vec<Token>,
+
+    // span of every token in tokens, same (reversed) order
+    spans: Vec<Span>,
+
+    restored_span: Span,
+
+    eof_span: Span,
+
+    // file, first line and first byte offset of the next input
+    file: String,
+    line_base: usize,
+    byte_base: usize,
 }
 
 impl RLLexer {
@@ -36,20 +54,29 @@ impl RLLexer {
 
         let tokens = Vec::<Token>::new();
 
+        let spans = Vec::<Span>::new();
+
+        let restored_span = Span::default();
+
+        let eof_span = Span::default();
+
+        let file = String::new();
+
         RLLexer { restored_token,
                   symbols,
                   symbol_creator,
                   tokens,
+                  spans,
+                  restored_span,
+                  eof_span,
+                  file,
+                  line_base: 1,
+                  byte_base: 0,
         }
     }
 
     pub fn init(&mut self) {
         self.symbol_creator.init(&mut self.symbols);
-
-        /*
-        self.symbols.insert("print".to_string(),
-                            Token::Symb(Symbol::new("print")));
-        */
     }
 
     pub fn get_tokens(&self) -> Vec<Token> {
@@ -65,485 +92,535 @@ impl RLLexer {
         self.symbols.remove(key);
     }
 
-    pub fn show_all_symbols(&self) {
-        for key in self.symbols.keys() {
-            println!("{key}");
-        }
+    pub fn is_known_symbol(&self, key: &String) -> bool {
+        self.symbols.contains_key(key)
     }
 
-    pub fn parse(&mut self, input: &str) {
-        // let restored_token = None;
-
-        let mut strings: Vec<&str> = vec![];
-
-        let mod_input: Cow<'_, str> = Cow::from(input);
+    // The spans of the next input start at line and byte of file.
+    pub fn set_origin(&mut self, file: &str, line: usize, byte: usize) {
+        self.file = file.to_string();
+        self.line_base = line;
+        self.byte_base = byte;
+    }
 
-        let quote1_re = Regex::new(r#"".+?""#).unwrap();
+    // Character-level tokenizer. Reader macro characters (quote,
+    // backquote, comma, comma-at and #') are emitted as separate
+    // Symb tokens, the parser builds the corresponding forms.
+    pub fn parse(&mut self, input: &str) -> Result<(), RLError> {
+        let chars: Vec<char> = input.chars().collect();
+
+        // byte offset of every char, and of the end of input
+        let mut offsets: Vec<usize> = input.char_indices()
+                                           .map(|(offset, _)| offset)
+                                           .collect();
+        offsets.push(input.len());
+
+        let mut tokens = Vec::<Token>::new();
+        let mut spans = Vec::<Span>::new();
+
+        let mut idx = 0;
+        let mut line = self.line_base;
+        let mut column = 1;
+
+        while idx < chars.len() {
+            let c = chars[idx];
+            let start = (line, column, idx);
+
+            match c {
+                '\n' => {
+                    idx += 1;
+                    line += 1;
+                    column = 1;
+                }
+
+                _ if c.is_whitespace() => {
+                    idx += 1;
+                    column += 1;
+                }
+
+                ';' => {
+                    while idx < chars.len() && chars[idx] != '\n' {
+                        idx += 1;
+                        column += 1;
+                    }
+                }
 
-        for caps in quote1_re.captures_iter(&mod_input) {
-            let s_val = caps.get(0).unwrap().as_str();
-            strings.push(s_val);
-        }
+                '(' | ')' | '\'' | '`' => {
+                    tokens.push(Token::Symb(Symbol::new(&c.to_string())));
 
-        strings.reverse();
-
-        println!("lexer - strings: {:?}", strings);
-        println!("");
-
-        //
-        let mod_input = quote1_re.replace_all(&mod_input, "\"\" dummy");
-        println!("quote1_re: {}", mod_input);
-        println!("");
-
-        // #' function designator I
-        let sharp_quote_func = Regex::new(r#"#'(?P<sqf>[^\(\s]+)"#).unwrap();
-        let mod_input =
-            sharp_quote_func.replace_all(&mod_input, " (function $sqf) ");
-        println!("sharp_quote_func #'nvknfkef: {}", mod_input);
-        println!("");
-
-        // #' function designator II
-        let sharp_quote_lambda =
-            Regex::new(r#"#'(?P<sql>.?\(([^()]*|\(([^()]*|\(([^()]*|\(([^()]*|\(([^()]*|\([^()]*\))*\))*\))*\))*\))*\))"#).unwrap();
-        let mod_input =
-            sharp_quote_lambda.replace_all(&mod_input, " (function $sql) ");
-        println!("sharp_quote_lambda #'(lambda () (+ )): {}", mod_input);
-        println!("");
-
-        // `(+ 1 2)
-        let backquote_paren =
-            Regex::new(r#"`(?P<bq_paren>.?\(([^()]*|\(([^()]*|\(([^()]*|\(([^()]*|\(([^()]*|\([^()]*\))*\))*\))*\))*\))*\))"#).unwrap();
-
-        let mut matches_bq_paren: Vec<_> =
-            backquote_paren.find_iter(&mod_input)
-                           .map(|m| m.as_str())
-                           .collect();
-
-        let mod_input =
-            backquote_paren.replace_all(&mod_input, "backquote_paren_pushed");
-
-        /*
-        for _n in 0..matches_bq_paren.len() {
-            if let Some(item) = matches_bq_atom.pop() {
-                println!("item: {:?}", item);
-                println!("");
+                    idx += 1;
+                    column += 1;
+
+                    spans.push(self.make_span(start, idx, &offsets));
+                }
+
+                ',' => {
+                    if idx + 1 < chars.len() && chars[idx + 1] == '@' {
+                        tokens.push(Token::Symb(Symbol::new(",@")));
+
+                        idx += 2;
+                        column += 2;
+                    } else {
+                        tokens.push(Token::Symb(Symbol::new(",")));
+
+                        idx += 1;
+                        column += 1;
+                    }
+
+                    spans.push(self.make_span(start, idx, &offsets));
+                }
+
+                '"' => {
+                    let mut string = "\"".to_string();
+                    let mut terminated = false;
+
+                    idx += 1;
+                    column += 1;
+
+                    while idx < chars.len() {
+                        let sc = chars[idx];
+
+                        idx += 1;
+
+                        if sc == '\n' {
+                            line += 1;
+                            column = 1;
+                        } else {
+                            column += 1;
+                        }
+
+                        match sc {
+                            '"' => {
+                                terminated = true;
+                                break;
+                            }
+
+                            '\\' => {
+                                if idx < chars.len() {
+                                    string.push(chars[idx]);
+
+                                    if chars[idx] == '\n' {
+                                        line += 1;
+                                        column = 1;
+                                    } else {
+                                        column += 1;
+                                    }
+
+                                    idx += 1;
+                                }
+                            }
+
+                            _ => string.push(sc),
+                        }
+                    }
+
+                    if !terminated {
+                        let err = ParseError::new_end_of_input(
+                            "Unterminated string",
+                            Token::Eof,
+                            self.make_span(start, idx, &offsets));
+
+                        return Err(RLError::ParseError(err))
+                    }
+
+                    string.push('"');
+
+                    tokens.push(Token::Atom(string));
+                    spans.push(self.make_span(start, idx, &offsets));
+                }
+
+                '#' => {
+                    let next = chars.get(idx + 1).copied();
+
+                    match next {
+                        Some('\'') => {
+                            tokens.push(Token::Symb(Symbol::new("#'")));
+
+                            idx += 2;
+                            column += 2;
+
+                            spans.push(self.make_span(start, idx, &offsets));
+                        }
+
+                        Some('|') => {
+                            let mut depth = 1;
+
+                            idx += 2;
+                            column += 2;
+
+                            while idx < chars.len() && depth > 0 {
+                                if chars[idx] == '|' &&
+                                   chars.get(idx + 1) == Some(&'#') {
+                                    depth -= 1;
+                                    idx += 2;
+                                    column += 2;
+                                } else if chars[idx] == '#' &&
+                                          chars.get(idx + 1) == Some(&'|') {
+                                    depth += 1;
+                                    idx += 2;
+                                    column += 2;
+                                } else if chars[idx] == '\n' {
+                                    idx += 1;
+                                    line += 1;
+                                    column = 1;
+                                } else {
+                                    idx += 1;
+                                    column += 1;
+                                }
+                            }
+
+                            if depth > 0 {
+                                let err = ParseError::new_end_of_input(
+                                    "Unterminated comment",
+                                    Token::Eof,
+                                    self.make_span(start, idx, &offsets));
+
+                                return Err(RLError::ParseError(err))
+                            }
+                        }
+
+                        // #\a, #\( and #\Space, the first character is
+                        // read even if it terminates tokens
+                        Some('\\') if idx + 2 < chars.len() => {
+                            let mut name = chars[idx + 2].to_string();
+
+                            idx += 3;
+
+                            if name == "\n" {
+                                line += 1;
+                                column = 1;
+                            } else {
+                                column += 3;
+                            }
+
+                            while idx < chars.len() &&
+                                  !is_terminating(chars[idx]) {
+                                name.push(chars[idx]);
+
+                                idx += 1;
+                                column += 1;
+                            }
+
+                            match RLChar::read(&name) {
+                                Some(ch) => {
+                                    tokens.push(Token::Char(ch.get()));
+
+                                    spans.push(self.make_span(start,
+                                                              idx,
+                                                              &offsets));
+                                }
+
+                                None => {
+                                    let err = ParseError::new_with_span(
+                                        "Unrecognized character name",
+                                        Token::Atom(format!("#\\{}", name)),
+                                        self.make_span(start, idx, &offsets));
+
+                                    return Err(RLError::ParseError(err))
+                                }
+                            }
+                        }
+
+                        Some('x' | 'X' | 'o' | 'O' | 'b' | 'B') |
+                        Some('0'..='9') => {
+                            let mut name = String::new();
+
+                            idx += 1;
+                            column += 1;
+
+                            while idx < chars.len() &&
+                                  !is_terminating(chars[idx]) {
+                                name.push(chars[idx]);
+
+                                idx += 1;
+                                column += 1;
+                            }
+
+                            match read_radix_number(&name) {
+                                Some(num) => {
+                                    tokens.push(Token::Atom(num.to_string()));
+
+                                    spans.push(self.make_span(start,
+                                                              idx,
+                                                              &offsets));
+                                }
+
+                                None => {
+                                    let err = ParseError::new_with_span(
+                                        "Bad number syntax",
+                                        Token::Atom(format!("#{}", name)),
+                                        self.make_span(start, idx, &offsets));
+
+                                    return Err(RLError::ParseError(err))
+                                }
+                            }
+                        }
+
+                        _ => {
+                            let mut dispatch = "#".to_string();
+
+                            if let Some(n) = next {
+                                dispatch.push(n);
+                            }
+
+                            let err = ParseError::new_with_span(
+                                "Unsupported dispatch macro",
+                                Token::Atom(dispatch),
+                                self.make_span(start, idx, &offsets));
+
+                            return Err(RLError::ParseError(err))
+                        }
+                    }
+                }
+
+                _ => {
+                    let mut name = String::new();
+
+                    while idx < chars.len() &&
+                          !is_terminating(chars[idx]) {
+                        name.push(chars[idx]);
+
+                        idx += 1;
+                        column += 1;
+                    }
+
+                    let number = match RLNum::read(&name) {
+                        Ok(number) => number,
+
+                        Err(err) => {
+                            let err = ParseError::new_with_span(
+                                &format!("{} while reading number", err),
+                                Token::Atom(name),
+                                self.make_span(start, idx, &offsets));
+
+                            return Err(RLError::ParseError(err))
+                        }
+                    };
+
+                    // numbers are interned by their printed
+                    // representation, 2/4 is read as 1/2
+                    if let Some(num) = number {
+                        tokens.push(Token::Atom(num.to_string()));
+                    } else {
+                        // the reader is case-insensitive, symbols are
+                        // interned in lower case and printed in upper case
+                        tokens.push(Token::Symb(
+                            Symbol::new(&name.to_lowercase())));
+                    }
+
+                    spans.push(self.make_span(start, idx, &offsets));
+                }
             }
         }
-        */
 
-        // `nvknfkef
-        let backquote_atom = Regex::new(r#"`(?P<bq_atom>[^\(\s]+)"#).unwrap();
+        rltrace!(TraceCategory::Lexer, TraceLevel::Info,
+                 "{} tokens from {}:{}",
+                 tokens.len(), self.file, self.line_base);
 
-        let mut matches_bq_atom: Vec<_> =
-            backquote_atom.find_iter(&mod_input)
-                          .map(|m| m.as_str())
-                          .collect();
-
-        /*
-        for _n in 0..matches_bq_atom.len() {
-            if let Some(item) = matches_bq_atom.pop() {
-                println!("item: {:?}", item);
-                println!("");
+        if is_enabled(TraceCategory::Lexer, TraceLevel::Debug) {
+            for (token, span) in tokens.iter().zip(spans.iter()) {
+                rltrace!(TraceCategory::Lexer, TraceLevel::Debug,
+                         "{} at {}", token, span);
             }
         }
-        */
-
-        let mod_input =
-            backquote_atom.replace_all(&mod_input, "backquote_atom_pushed");
-
-        /*
-        let mod_input =
-            backquote_atom.replace_all(&mod_input, " (backquote $q) ");
-
-        println!("backquote_atom `nvknfkef: {}", mod_input);
-        println!("");
-        */
-
-        let quote_paren =
-            Regex::new(r#"'(?P<q_paren>.?\(([^()]*|\(([^()]*|\(([^()]*|\(([^()]*|\(([^()]*|\([^()]*\))*\))*\))*\))*\))*\))"#).unwrap();
-
-        // let is_quote_paren = quote_paren.is_match(&mod_input);
-
-        let mut mod_input =
-            quote_paren.replace_all(&mod_input, " (quote $q_paren) ");
-        println!("quote_paren '(()): {}", mod_input);
-        println!("");
-
-        // 'nvknfkef
-        // let quote_atom = Regex::new(r#"'(?P<x>[^\(]+)"#).unwrap();
-
-        let quote_atom = Regex::new(r#"'(?P<x>[^\(\s]+)"#).unwrap();
-
-        let mod_input_quote_atom = mod_input.clone();
-
-        /*
-        if is_quote_paren {
-            mod_input = quote_atom.replace_all(
-                &mod_input_quote_atom, " ' $x ");
-        } else {
-        */
-            mod_input = quote_atom.replace_all(
-                &mod_input_quote_atom, " (quote $x) ");
-        // }
 
-        println!("quote_atom 'nvknfkef: {}", mod_input);
-        println!("");
+        tokens.reverse();
+        spans.reverse();
 
+        self.tokens = tokens;
+        self.spans = spans;
+        self.eof_span = self.make_span((line, column, idx), idx, &offsets);
 
-
-        let backquote_paren_pushed =
-            Regex::new(r#"backquote_paren_pushed"#).unwrap();
-
-        matches_bq_paren.reverse();
-
-        let mod_input_for = mod_input.clone();
-
-        let backquote_for = Regex::new(r#"`(?P<bq_for>.+?)"#).unwrap();
-        let quote_for = Regex::new(r#"'(?P<q_for>.+?)"#).unwrap();
-        let comma_for = Regex::new(r#",(?P<c_for>.+?)"#).unwrap();
-
-        for _n in 0..matches_bq_paren.len() {
-            if let Some(bq) = matches_bq_paren.pop() {
-                println!("bq: {:?}", bq);
-                println!("");
-
-                let zero = backquote_paren.replace_all(&bq, "(backquote $bq_paren)");
-                let first = backquote_for.replace_all(&zero, "` $bq_for");
-                let second = quote_for.replace_all(&first, "' $q_for");
-                let third = comma_for.replace_all(&second, ", $c_for");
-
-                mod_input = backquote_paren_pushed.replace(
-                    &mod_input_for, third);
-
-                println!("mod_input in for: {:?}", mod_input);
-                println!("");
-            }
-        }
-
-        println!("backquote_paren_pushed: {}", mod_input);
-        println!("");
-
-
-
-        let backquote_atom_pushed =
-            Regex::new(r#"backquote_atom_pushed"#).unwrap();
-
-        matches_bq_atom.reverse();
-
-        let mod_input_for2 = mod_input.clone();
-
-        let backquote_for2 = Regex::new(r#"`(?P<bq_for2>.+?)"#).unwrap();
-        let quote_for2 = Regex::new(r#"'(?P<q_for2>.+?)"#).unwrap();
-        let comma_for2 = Regex::new(r#",(?P<c_for2>.+?)"#).unwrap();
-
-        for _n in 0..matches_bq_atom.len() {
-            if let Some(bq) = matches_bq_atom.pop() {
-                println!("bq: {:?}", bq);
-                println!("");
-
-                let zero = backquote_atom.replace_all(&bq, "(backquote $bq_atom)");
-                let first = backquote_for2.replace_all(&zero, "` $bq_for2");
-                let second = quote_for2.replace_all(&first, "' $q_for2");
-                let third = comma_for2.replace_all(&second, ", $c_for2");
-
-                mod_input = backquote_atom_pushed.replace(
-                    &mod_input_for2, third);
-            }
-        }
-
-        println!("backquote_atom_pushed: {}", mod_input);
-        println!("");
-
-
-
-        let whitespace_paren_right = Regex::new(r#"(?P<y>.+?)\)"#).unwrap();
-        let mod_input =
-            whitespace_paren_right.replace_all(&mod_input, "$y ) ");
-        println!("insert whitespace paren right: {:?}", mod_input);
-        println!("");
-
-        let whitespace_paren_left = Regex::new(r#"\((?P<z>.+?)"#).unwrap();
-        let mod_input =
-            whitespace_paren_left.replace_all(&mod_input, " ( $z");
-        println!("insert whitespace paren left: {:?}", mod_input);
-        println!("");
-
-        let whitespace_paren_left2 = Regex::new(r#"\((?P<a>.+?)"#).unwrap();
-        let mod_input =
-            whitespace_paren_left2.replace_all(&mod_input, " ( $a");
-        println!("insert whitespace paren left2: {:?}", mod_input);
-        println!("");
-
-        /*
-        let nil_re    = Regex::new(r#"\((?P<n> +?)\)"#).unwrap();
-        let mod_input = nil_re.replace_all(&mod_input, "()");
-        println!("reduce whitespaces in nil: {}", mod_input);
-        println!("");
-        */
-
-        let comma_at = Regex::new(r#", +@"#).unwrap();
-        let mod_input =
-            comma_at.replace_all(&mod_input, " ,@ ");
-        println!("comma_at: {}", mod_input);
-        println!("");
-
-        println!("mod_input after regex: {:?}", mod_input);
-        println!("");
-
-        let mut hidden_token: Option<&str> = None;
-
-        let symbols = clone_hash_map(&self.symbols);
-
-        // let mut tokens = mod_input
-        self.tokens = mod_input
-            .split_ascii_whitespace()
-            .map(move |c| if let Some(x) = hidden_token
-                         { println!("lexer - hidden_token {:?}", x);
-                           println!("");
-
-                           hidden_token = None;
-                           match x {
-                           _ => Token::Atom(String::from(x)) }
-                         } else if let Some(token) = symbols.get(c) {
-                             token.clone()
-                         } else {
-                             match c {
-
-                                 _ if c.starts_with("\"") => {
-                                     hidden_token = strings.pop();
-                                     Token::Symb(Symbol::new("nil"))
-                                 }
-
-                                 // _ if c.contains("dummy") =>
-                                 // Token::Sym(Symbol::Nil),
-
-                                 _ => {
-                                     // println!("lexer - c: {:?}", c);
-                                     // println!("");
-                                 Token::Atom(String::from(c))
-                                 }
-                             }
-                         }).collect::<Vec<_>>();
-
-            self.tokens.reverse();
-            // tokens.retain(|x| *x != Token::Symb(Symbol::Nil));
-            self.tokens.retain(|x| *x !=
-                Token::Symb(Symbol::new("nil")));
-
-            println!("tokens: {:?}", self.tokens);
-            println!("");
-
-            // self.quote_tokens = self.tokens.clone();
+        Ok(())
     }
 
-    /*
-    fn reset(&mut self) -> Token {
+    pub fn next(&mut self) -> Token {
         let token = self.tokens
-                    .last()
-                    .cloned()
-                    .unwrap_or(Token::Eof);
+                        .pop()
+                        .unwrap_or(Token::Eof);
 
-        self.tokens.pop();
+        self.restored_span = self.spans
+                                 .pop()
+                                 .unwrap_or(self.eof_span.clone());
 
         self.restored_token = Some(token);
-
         self.restored_token.clone().unwrap()
     }
 
-    fn reset2(&mut self) -> Token {
-        self.restored_token = None;
-
+    pub fn peek(&mut self) -> Token {
         self.tokens
             .last()
             .cloned()
             .unwrap_or(Token::Eof)
     }
-    */
-
-    pub fn next(&mut self) -> Token {
-        let token = self.tokens
-                        .pop()
-                        .unwrap_or(Token::Eof);
-
-        self.restored_token = Some(token);
 
-        self.restored_token.clone().unwrap()
+    // span of the token returned by the last call of next
+    pub fn span(&self) -> Span {
+        self.restored_span.clone()
     }
 
-    pub fn peek(&mut self) -> Token {
-        self.tokens
+    // span of the token returned by the next call of next
+    pub fn peek_span(&self) -> Span {
+        self.spans
             .last()
             .cloned()
-            // .copied()
-            .unwrap_or(Token::Eof)
+            .unwrap_or(self.eof_span.clone())
+    }
+
+    // start (line, column, char index) and end char index of a token
+    fn make_span(&self,
+                 start: (usize, usize, usize),
+                 end: usize,
+                 offsets: &[usize]) -> Span {
+        let (line, column, idx) = start;
+
+        Span::new(&self.file,
+                  line,
+                  column,
+                  self.byte_base + offsets[idx],
+                  self.byte_base + offsets[end])
     }
 
     pub fn restore_token(&mut self) {
-        self.tokens.push(self.restored_token.clone().unwrap())
+        if let Some(token) = self.restored_token.clone() {
+            self.tokens.push(token);
+            self.spans.push(self.restored_span.clone());
+        }
     }
 
     pub fn push_token(&mut self, token: Token) {
-        self.tokens.push(token)
+        self.tokens.push(token);
+        self.spans.push(self.eof_span.clone());
     }
 
-    /*
-    pub fn get_tokens(&self) -> Vec<Token> {
-        self.tokens.clone()
+    pub fn change_tokens(&mut self, tokens: &Vec<Token>) {
+        self.tokens = tokens.clone();
+        self.spans = vec![self.eof_span.clone(); tokens.len()];
     }
-    */
 
-    pub fn change_tokens(&mut self, tokens: &Vec<Token>) {
-        self.tokens = tokens.clone()
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
     }
 }
 
+fn is_terminating(c: char) -> bool {
+    c.is_whitespace() ||
+    matches!(c, '(' | ')' | '\'' | '`' | ',' | '"' | ';')
+}
 
+// x1F, o17, b101 and 36rZZ, the text after the # of a rational
+// in another radix
+fn read_radix_number(name: &str) -> Option<RLNum> {
+    let mut chars = name.chars();
 
+    let (radix, digits) = match chars.next()?.to_ascii_lowercase() {
+        'x' => (16, chars.as_str()),
+        'o' => (8, chars.as_str()),
+        'b' => (2, chars.as_str()),
 
+        _ => {
+            let (radix, digits) = name.split_once(['r', 'R'])?;
 
+            let radix = radix.parse::<u32>().ok()?;
 
+            if !(2..=36).contains(&radix) {
+                return None
+            }
 
+            (radix, digits)
+        }
+    };
 
+    RLNum::read_radix(digits, radix)
+}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
 
+    // the tokens of input in reading order
+    fn read_tokens(input: &str) -> Vec<Token> {
+        let mut lexer = RLLexer::new();
 
+        lexer.parse(input).unwrap();
 
+        let mut tokens = Vec::new();
 
+        while !lexer.is_empty() {
+            tokens.push(lexer.next());
+        }
 
+        tokens
+    }
 
+    fn is_parse_error(input: &str) -> bool {
+        matches!(RLLexer::new().parse(input), Err(RLError::ParseError(_)))
+    }
 
+    fn atom(text: &str) -> Token {
+        Token::Atom(text.to_string())
+    }
 
+    fn symb(name: &str) -> Token {
+        Token::Symb(Symbol::new(name))
+    }
 
+    #[test]
+    fn string_escapes() {
+        assert_eq!(read_tokens(r#""a\"b""#), vec![atom("\"a\"b\"")]);
+        assert_eq!(read_tokens(r#""a\\b""#), vec![atom("\"a\\b\"")]);
+        assert_eq!(read_tokens(r#""\a""#), vec![atom("\"a\"")]);
+        assert_eq!(read_tokens("\"a;b\" c"), vec![atom("\"a;b\""), symb("c")]);
 
+        assert!(is_parse_error("\"abc"));
+        assert!(is_parse_error(r#""abc\""#));
+    }
 
+    #[test]
+    fn characters() {
+        assert_eq!(read_tokens(r"#\a #\A"),
+                   vec![Token::Char('a'), Token::Char('A')]);
+        assert_eq!(read_tokens(r"#\Space #\newline"),
+                   vec![Token::Char(' '), Token::Char('\n')]);
+        assert_eq!(read_tokens(r"(#\( #\))"),
+                   vec![symb("("), Token::Char('('), Token::Char(')'),
+                        symb(")")]);
+        assert_eq!(read_tokens(r"#\λ"), vec![Token::Char('λ')]);
+
+        assert!(is_parse_error(r"#\NoSuchName"));
+    }
 
-        /*
-        // backquote related
-        // `,(+ 2 3)
-        let backquote_comma_paren =
-            Regex::new(r#"`,(?P<p>\(.+?\))"#).unwrap();
-        let mod_input =
-            backquote_comma_paren.replace_all(&mod_input, " (backquote , $p) ");
-        println!("backquote_comma_paren `(+ 2 3): {}", mod_input);
-        println!("");
-
-
-        // `(+ 2 3)
-        let backquote_paren =
-            Regex::new(r#"`(?P<p>\(.+?\))"#).unwrap();
-
-        let mod_input = backquote_paren.replace_all(
-            &mod_input, " (backquote $p) ");
-
-        // self.backquotes.push(mod_input.to_string());
-
-        println!("backquote_paren `(+ 2 3): {}", mod_input);
-        println!("");
-
-
-        // `()
-        let backquote_nil =
-            Regex::new(r#"`(\(\))"#).unwrap();
-        let mod_input =
-            backquote_nil.replace_all(&mod_input, " (backquote ()) ");
-
-        println!("backquote nil: `(): {}", mod_input);
-        println!("");
-        */
-
-
-
-
-        /*
-        // quote related
-        // '(+ 2 (- 4 3))
-        let qexpr2_re =
-            Regex::new(r#"'(?P<q>\(.+?([^\)]+).+?\))"#).unwrap();
-        let mod_input =
-            qexpr2_re.replace_all(&mod_input, " (quote $q) ");
-
-        // '(+ 2 (- 4 3) 5)
-        let qexpr3_re =
-            Regex::new(r#"'(?P<r>\(.+?([^\)]+).+?\).+\))"#).unwrap();
-        let mod_input =
-            qexpr3_re.replace_all(&mod_input, " (quote $r) ");
-        */
-
-
-
-
+    #[test]
+    fn radix_numbers() {
+        assert_eq!(read_tokens("#x1F #XFF"), vec![atom("31"), atom("255")]);
+        assert_eq!(read_tokens("#b101 #o17"), vec![atom("5"), atom("15")]);
+        assert_eq!(read_tokens("#3r12 #36rZZ"),
+                   vec![atom("5"), atom("1295")]);
+        assert_eq!(read_tokens("#x-10 #b1/10"),
+                   vec![atom("-16"), atom("1/2")]);
+
+        assert!(is_parse_error("#xFG"));
+        assert!(is_parse_error("#b102"));
+        assert!(is_parse_error("#37r1"));
+        assert!(is_parse_error("#x"));
+    }
 
-        /*
-        // Comma related
-        // ,',nvknfkef
-        let comma_quote_comma = Regex::new(r#",(?P<s>[^\(\s]+)"#).unwrap();
-        let mod_input =
-            comma_quote_comma.replace_all(
-                &mod_input, " (comma /COMMA/QUOTE/COMMA/ $s) ");
-        println!("comma_quote_comma ,',nvknfkef: {}", mod_input);
-        println!("");
-        */
+    #[test]
+    fn block_comments() {
+        assert_eq!(read_tokens("a #| b |# c"), vec![symb("a"), symb("c")]);
+        assert_eq!(read_tokens("a #| b #| c |# d |# e"),
+                   vec![symb("a"), symb("e")]);
+        assert_eq!(read_tokens("#|\n(\n|#x ; y\nz"),
+                   vec![symb("x"), symb("z")]);
 
-        /*
-        // ,@nvknfkef
-        // let comma_at = Regex::new(r#",@(?P<s>[^\(\s]+)"#).unwrap();
-        let comma_at = Regex::new(r#",@(?P<s>[^\(\s]+)"#).unwrap();
-        let mod_input =
-            comma_at.replace_all(&mod_input, " ,@ $s) ");
-        println!("comma_at 'nvknfkef: {}", mod_input);
-        println!("");
-        */
+        assert!(is_parse_error("a #| b"));
+        assert!(is_parse_error("#| #| |#"));
+    }
 
-        /*
-        // ,(+ 2 3)
-        let comma_paren =
-            Regex::new(r#",(?P<r>\(.+?\))"#).unwrap();
-        let mod_input =
-            comma_paren.replace_all(&mod_input, " (comma ,$r) ");
-        println!("comma_paren ,(+ 2 3): {}", mod_input);
-        println!("");
-        */
+    #[test]
+    fn dispatch_macros() {
+        assert_eq!(read_tokens("#'car"), vec![symb("#'"), symb("car")]);
 
-        /*
-        // ,nvknfkef
-        let comma = Regex::new(r#",(?P<t>[^\(\s]+)"#).unwrap();
-        // let comma = Regex::new(r#",,(?P<t>[^\(\s]+)"#).unwrap();
-        let mod_input =
-            comma.replace_all(&mod_input, " , $t ");
-        println!("comma ,nvknfkef: {}", mod_input);
-        println!("");
-        */
-
-
-             /*
-        // '()
-        let quote_nil =
-            Regex::new(r#"'(\(\))"#).unwrap();
-        let mod_input =
-            quote_nil.replace_all(&mod_input, " (quote ()) ");
-        println!("quote_nil '(): {}", mod_input);
-        println!("");
-
-        // '((list 1 2) (list 3 4))
-        let quote_paren1 =
-            Regex::new(r#"(?<start_quote>'\()(?<middle>\(([^()]|(R))*\))(?<last>.+\))"#).unwrap();
-
-        let mod_input =
-            quote_paren1.replace(&mod_input, "(quote ($middle $last)");
-        println!("quote_paren1 '((list 1 2) (list 3 4)): {:?}", mod_input);
-        println!("");
-
-        // '(+ 2 3)
-        // let quote_paren2 =
-        //     Regex::new(r#"'(?P<v>\(.+?\))"#).unwrap();
-
-        let quote_paren2 =
-            Regex::new(r#"'(?<var>\(.+?\))|(R)"#).unwrap();
-
-        // let mod_input =
-        //     quote_paren2.replace(&mod_input, "(quote $v)");
-        let mod_input =
-            quote_paren2.replace_all(&mod_input, "(quote $var)");
-        println!("quote_paren2 '(+ 2 3): {:?}", mod_input);
-        println!("");
-        */
+        assert!(is_parse_error("#<x>"));
+    }
+}
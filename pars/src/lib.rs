@@ -4,12 +4,6 @@
 // file that was distributed with this source code.
 
 pub mod parser;
-pub mod param_creator;
-pub mod param_generator;
-pub mod data_control_params;
-pub mod eval_comp_params;
-pub mod list_params;
-pub mod string_params;
 
 
 
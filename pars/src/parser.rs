@@ -3,594 +3,361 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-use std::collections::HashMap;
-use std::collections::LinkedList;
-
-use crate::param_creator::RLParamCreator;
-use crate::param_generator::{RLParamGenerator,
-                             make_param_gens_hash_map};
+use std::collections::{HashMap, LinkedList};
 
 use err::err::{RLError, ParseError};
 
 use expr::atom::RLAtom;
+use expr::char::RLChar;
+use expr::cons::RLCons;
 use expr::list::RLList;
 use expr::nil::RLNil;
+use expr::num::RLNum;
 use expr::sexpr::SExpr;
 use expr::symb::RLSymbol;
 
-use hash::hash::RLHash;
-
 use lex::lexer::RLLexer;
 
-use pars_symb::symbol::Symbol;
+use pars_symb::span::Span;
 use pars_symb::token::Token;
 
+use trace::rltrace;
+use trace::trace::{TraceCategory, TraceLevel};
+
+// name of the source of forms typed at the REPL
+pub const STDIN_SOURCE: &str = "<stdin>";
+
 pub struct RLParser {
     sexpr: SExpr,
 
-    tokens: Vec<Token>,
-
     pub lexer: RLLexer,
 
-    param_creator: RLParamCreator,
-
-    param_gens: HashMap<String, RLParamGenerator, RLHash>,
-
     error: Option<RLError>,
+
+    // text of every source read so far, for error snippets
+    sources: HashMap<String, String>,
 }
 
 impl RLParser {
     pub fn new() -> Self {
         let sexpr = SExpr::Nil(RLNil::new());
 
-        let tokens = Vec::<Token>::new();
-
         let lexer = RLLexer::new();
 
-        let param_creator = RLParamCreator::new();
-
-        let param_gens = make_param_gens_hash_map();
-
         let error = None;
 
+        let sources = HashMap::<String, String>::new();
+
         RLParser { sexpr,
-                   tokens,
                    lexer,
-                   param_creator,
-                   param_gens,
                    error,
+                   sources,
         }
     }
 
     pub fn init(&mut self) {
         self.lexer.init();
-        self.param_creator.init(&mut self.param_gens);
     }
 
     pub fn get_tokens(&self) -> Vec<Token> {
-        self.tokens.clone()
+        self.lexer.get_tokens()
     }
 
-    #[allow(unused_assignments)]
+    // Reads the next complete form from the token stream of the lexer.
     pub fn parse_to_sexpr(&mut self) -> Result<SExpr, RLError> {
+        let token = self.lexer.next();
+        let span = self.lexer.span();
 
-        println!("parse_to_sexpr - start");
-        println!("");
-
-        self.error = None;
-
-        /*
-        println!("begin - lexer:");
-        self.lexer.show_all_symbols();
-        println!("");
-        */
+        match token {
+            Token::Atom(atom) => {
+                // the lexer has checked the number can be represented
+                if let Ok(Some(num)) = RLNum::read(&atom) {
+                    return Ok(SExpr::Num(num))
+                }
 
-        self.tokens = self.lexer.get_tokens();
+                let mut atom = RLAtom::new(&atom);
 
-        println!("self.lexer.tokens: {:?}", self.lexer.tokens);
-        println!("");
+                atom.set_span(&span);
 
-        let mut lhs = match self.lexer.next() {
-            Token::Atom(atom) => {
-                match &*atom {
-                    "()"  | "nil" |
-                    "NIL" | "Nil" |
-                    "NIl" | "NiL" |
-                    "niL" | "nIL" |
-                    "nIl" => SExpr::Nil(RLNil::new()),
-
-                    _ => SExpr::Atom(RLAtom::new(&atom)),
-                }
+                Ok(SExpr::Atom(atom))
             }
 
+            Token::Char(ch) => Ok(SExpr::Char(RLChar::new(ch))),
+
             Token::Symb(symb) => {
                 match &*symb.name {
-                    "(" => SExpr::Dummy,
+                    "(" => self.parse_list(span),
 
                     ")" => {
-                        let err = ParseError::new(
-                            "First", pars_symb::token::Token::Symb(symb));
-                          return Err(RLError::ParseError(err))
-                    }
+                        let err = ParseError::new_with_span(
+                            "Unmatched close parenthesis",
+                            Token::Symb(symb),
+                            span);
 
-                    _ => {
-                        SExpr::Symb(RLSymbol::new_with_symb(&symb))
+                        Err(RLError::ParseError(err))
                     }
-                }
-            }
 
-            Token::Eof => {
-                let err = ParseError::new("First", pars_symb::token::Token::Eof);
-                return Err(RLError::ParseError(err))
-            }
-        };
+                    "'"  => self.parse_reader_macro("quote", span),
+                    "#'" => self.parse_reader_macro("function", span),
+                    "`"  => self.parse_reader_macro("backquote", span),
+                    ","  => self.parse_reader_macro("comma", span),
+                    ",@" => self.parse_reader_macro("comma-at", span),
 
-        loop {
-            let sym = match self.lexer.peek() {
-                Token::Eof => break,
-                Token::Symb(sym) => sym,
-                // Token::Atom(atom) => break,
-                t => {
-                    let err = ParseError::new("Symbol", t);
-                    return Err(RLError::ParseError(err))
-                }
-            };
+                    "." => {
+                        let err = ParseError::new_with_span(
+                            "Dot context error",
+                            Token::Symb(symb),
+                            span);
 
-            let mut list: LinkedList<SExpr> = LinkedList::new();
-
-            // lexer.next(); // upon symbol
+                        Err(RLError::ParseError(err))
+                    }
 
-            match self.lexer.next() {  // upon symbol
-                Token::Symb(symb) => {
-                    match &*symb.name {
-                        "block" => {
-                            println!("parser: in block");
+                    "nil" => Ok(SExpr::Nil(RLNil::new())),
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("block")
-                                                    .unwrap()
-                                                    .clone();
+                    _ => {
+                        let mut symb = RLSymbol::new_with_symb(&symb);
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
+                        symb.set_span(&span);
 
-                                    println!("block-list: {:?}", list);
-                                    println!("");
-                                }
+                        Ok(SExpr::Symb(symb))
+                    }
+                }
+            }
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+            Token::Eof => {
+                let err = ParseError::new_end_of_input("End of file",
+                                                       Token::Eof,
+                                                       span);
 
-                        "backquote" => {
-                            println!("parser: in backquote");
+                Err(RLError::ParseError(err))
+            }
+        }
+    }
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("backquote")
-                                                    .unwrap()
-                                                    .clone();
+    // 'x => (quote x), #'x => (function x), `x => (backquote x),
+    // ,x => (comma x), ,@x => (comma-at x)
+    fn parse_reader_macro(&mut self,
+                          name: &str,
+                          span: Span) -> Result<SExpr, RLError> {
+        if let Token::Eof = self.lexer.peek() {
+            let err = ParseError::new_end_of_input(
+                "End of file after reader macro",
+                Token::Eof,
+                span);
+
+            return Err(RLError::ParseError(err))
+        }
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+        let form = self.parse_to_sexpr()?;
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+        let form_span = span.to(&self.lexer.span());
 
-                        "concatenate" => {
-                            println!("parser: in concatenate");
-
-                            /*
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("concatenate")
-                                                    .unwrap()
-                                                    .clone();
-
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(_linked_list) => {
-                                    // list = linked_list;
-                                    list = param_gen.build_param_list();
-                                }
-
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                            */
-                        }
+        let mut symb = RLSymbol::new_with_str(name);
 
-                        "defun" => {
-                            println!("parser: in defun");
+        symb.set_span(&span);
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("defun")
-                                                    .unwrap()
-                                                    .clone();
+        let mut ll = LinkedList::<SExpr>::new();
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+        ll.push_back(SExpr::Symb(symb));
+        ll.push_back(form);
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+        let mut list = RLList::<SExpr>::new_with_items(&ll);
 
-                        "defmacro" => {
-                            println!("parser: in defmacro");
+        list.set_span(&form_span);
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("defmacro")
-                                                    .unwrap()
-                                                    .clone();
+        Ok(SExpr::SList(list))
+    }
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+    // Called after "(" has been consumed. span belongs to the opening
+    // parenthesis and is reported if the list is unbalanced.
+    fn parse_list(&mut self, span: Span) -> Result<SExpr, RLError> {
+        let mut items = LinkedList::<SExpr>::new();
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+        loop {
+            match self.lexer.peek() {
+                Token::Eof => {
+                    let err = ParseError::new_end_of_input(
+                        "Unbalanced parenthesis",
+                        Token::Symb(pars_symb::symbol::Symbol::new("(")),
+                        span);
 
-                        "defparameter" => {
-                            println!("parser: in defparameter");
-                        }
+                    return Err(RLError::ParseError(err))
+                }
 
-                        "defvar" => {
-                            println!("parser: in defvar");
-                        }
+                Token::Symb(symb) if symb.name == ")" => {
+                    self.lexer.next();
 
-                        "eval" => {
-                            println!("parser: in eval");
-
-                            /*
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("eval")
-                                                    .unwrap()
-                                                    .clone();
-
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
-
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                            */
-                        }
+                    break;
+                }
 
-                        "function" => {
-                            println!("parser: in function");
-
-                            /*
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("function")
-                                                    .unwrap()
-                                                    .clone();
-
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
-
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                            */
-                        }
+                Token::Symb(symb) if symb.name == "." => {
+                    self.lexer.next();
 
-                        "lambda" => {
-                            println!("parser: in lambda");
+                    if items.is_empty() {
+                        let err = ParseError::new_with_span(
+                            "Nothing appears before . in list",
+                            Token::Symb(symb),
+                            self.lexer.span());
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("lambda")
-                                                    .unwrap()
-                                                    .clone();
+                        return Err(RLError::ParseError(err))
+                    }
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+                    let tail = self.parse_to_sexpr()?;
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+                    match self.lexer.next() {
+                        Token::Symb(close) if close.name == ")" => {}
 
-                        "progv" => {
-                            println!("parser: in progv");
+                        Token::Eof => {
+                            let err = ParseError::new_end_of_input(
+                                "Unbalanced parenthesis",
+                                Token::Symb(
+                                    pars_symb::symbol::Symbol::new("(")),
+                                span);
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("progv")
-                                                    .unwrap()
-                                                    .clone();
+                            return Err(RLError::ParseError(err))
+                        }
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+                        token => {
+                            let err = ParseError::new_with_span(
+                                "More than one object follows . in list",
+                                token,
+                                self.lexer.span());
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
+                            return Err(RLError::ParseError(err))
                         }
+                    }
 
-                        "quote" => {
-                            println!("parser: in quote");
+                    return Ok(build_dotted_list(items, tail));
+                }
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("quote")
-                                                    .unwrap()
-                                                    .clone();
+                _ => {
+                    let item = self.parse_to_sexpr()?;
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+                    items.push_back(item);
+                }
+            }
+        }
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+        if items.is_empty() {
+            Ok(SExpr::Nil(RLNil::new()))
+        } else {
+            let mut list = RLList::<SExpr>::new_with_items(&items);
 
-                        "return-from" => {
-                            println!("parser: in return-from");
+            list.set_span(&span.to(&self.lexer.span()));
 
-                            let param_gen: &mut RLParamGenerator =
-                                &mut self.param_gens.get("return-from")
-                                                    .unwrap()
-                                                    .clone();
+            Ok(SExpr::SList(list))
+        }
+    }
 
-                            match param_gen.run_closure(&mut self.lexer) {
-                                Ok(ll) => {
-                                    list = param_gen.build_param_list(ll);
-                                }
+    // Reads the next top-level form.
+    fn read_form(&mut self) -> Result<SExpr, RLError> {
+        let span = self.lexer.peek_span();
 
-                                Err(err) => {
-                                    return Err(err);
-                                }
-                            }
-                        }
+        let form = self.parse_to_sexpr()?;
 
-                        _ => {}
-                   } // match &*symb.symbol {
-                } // Token::Symb(symb)
+        rltrace!(TraceCategory::Parser, TraceLevel::Info,
+                 "read {} at {}", form, span);
 
-                _ => { }
-            }; // match self.lexer.next()
+        rltrace!(TraceCategory::Parser, TraceLevel::Debug,
+                 "{:?}", form);
 
-            println!("after qlist loop");
-            println!("");
+        Ok(form)
+    }
 
-/////////////////////////////////////////////////////////////////////
-/////////////////////////////////////////////////////////////////////
+    // Parses the first form of input.
+    pub fn parse(&mut self, input: &str) -> Result<SExpr, RLError> {
+        self.add_source(STDIN_SOURCE, input);
 
-            loop {
-                println!("in inner loop...");
+        self.lexer.parse(input)?;
 
-                let mut paren_count = 1;
+        self.read_form()
+    }
 
-                match self.lexer.next() {
-                    Token::Atom(atom) => {
-                        println!("loop after qlist, option Atom...");
-                        match &*atom {
-                            "()"  | "nil" |
-                            "NIL" | "Nil" |
-                            "NIl" | "NiL" |
-                            "niL" | "nIL" |
-                            "nIl" => {
-                                list.push_back(SExpr::Nil(RLNil::new()));
-                            }
+    // Parses all top-level forms of input.
+    pub fn parse_all(&mut self, input: &str) -> Result<Vec<SExpr>, RLError> {
+        self.add_source(STDIN_SOURCE, input);
 
-                            _ => {
-                                list.push_back(
-                                    SExpr::Atom(RLAtom::new(&atom)));
-                            }
-                        }
-                    }
+        self.lexer.parse(input)?;
 
-                    Token::Symb(symb) => {
-                        match &*symb.name {
-                            "(" => {
-                                println!("loop after qlist, option (");
-                                println!("");
-
-                                paren_count = paren_count + 1;
-
-                                self.lexer.restore_token();
-
-                                let inner_sexpr = self.parse_to_sexpr();
-
-                                match inner_sexpr {
-                                    Ok(_) => {
-                                        println!("INNER_SEXPR: {:?}",
-                                            inner_sexpr);
-                                        println!("");
-
-                                        list.push_back(inner_sexpr.unwrap())
-                                    }
-
-                                    Err(err) => {
-                                        return Err(err);
-                                    }
-                                }
-                            }
-
-                            ")" => {
-                               println!("loop after qlist, token::Symb, matched )...");
-                               self.lexer.restore_token();
-                               break;
-                            }
-
-                            _ => {
-                                println!("loop after qlist, option ( _ =>");
-                                println!("{}", &*symb.name);
-                                println!("");
-
-                                list.push_back(
-                                    SExpr::Symb(RLSymbol::new_with_symb(
-                                        &Symbol {name: symb.name.clone() })));
-
-                                /*
-                                let err = ParseError::new("Inner",
-                                    pars_symb::token::Token::Symb(symb));
-                                return Err(RLError::ParseError(err))
-                                */
-                            }
-                        }
-                    }
+        let mut forms = Vec::<SExpr>::new();
 
-                    Token::Eof => {
-                        println!("in inner loop, option t...");
-
-                        self.lexer.restore_token();
-                        match self.lexer.peek() {
-                            /*
-                            Token::Symb(Symbol::ParenRight) => { break }
-                             _ => {
-                                 let err = ParseError::new("Inner", t);
-                                 return Err(RLispError::ParseError(err))
-                             }
-                             */
-
-                             Token::Symb(symb) => {
-                                 match &*symb.name {
-                                     ")" =>  {
-                                        println!("loop after qlist, option t, matched )...");
-                                        println!("");
-
-                                        break;
-                                     }
-
-                                     _ => {
-                                         let err = ParseError::new("Inner",
-                                         pars_symb::token::Token::Symb(symb));
-                                         return Err(RLError::ParseError(err))
-                                     }
-                                 }
-                             }
-
-                             Token::Eof => {
-                                 println!("EOF-EOF");
-                                 println!("");
-
-                                 /*
-                                 let err = ParseError::new("Inner",
-                                     pars_symb::token::Token::Eof);
-                                 return Err(RLError::ParseError(err))
-                                 */
-
-                                 break;
-                             }
-
-                             t => {
-                                 println!("inner loop, option t _ =>");
-
-                                 let err = ParseError::new("Inner", t);
-                                 return Err(RLError::ParseError(err))
-                             }
-                        };
-                    }
-                };
-            } // inner loop
+        while !self.lexer.is_empty() {
+            forms.push(self.read_form()?);
+        }
 
-            println!("left inner loop...");
-            println!("");
+        Ok(forms)
+    }
 
-            println!("sym: {:?}", sym);
-            println!("");
-            println!("list: {:?}", list);
-            println!("");
+    pub fn parse_silent(&mut self, input: &str) {
+        let parse_result = self.parse(input);
 
-            if list.is_empty() {
-                let mut lhs_list = LinkedList::<SExpr>::new();
+        match parse_result {
+            Ok(res)  => self.sexpr = res,
+            Err(err) => self.error = Some(err),
+        }
+    }
 
-                lhs_list.push_front(
-                    SExpr::Symb(RLSymbol::new_with_str(")")));
+    // Parses all top-level forms of the source text of file. A file
+    // which is read again replaces its previous source.
+    pub fn parse_file(&mut self,
+                      file: &str,
+                      input: &str) -> Result<Vec<SExpr>, RLError> {
+        self.sources.remove(file);
 
-                lhs_list.push_front(
-                    SExpr::Symb(RLSymbol::new_with_symb(&sym)));
+        self.add_source(file, input);
 
-                lhs_list.push_front(
-                    SExpr::Symb(RLSymbol::new_with_str("(")));
+        self.lexer.parse(input)?;
 
-                lhs = SExpr::SList(RLList::<SExpr>::new_with_list(&lhs_list));
-            } else {
-                lhs = SExpr::Cons(sym, list.clone());
-            }
+        let mut forms = Vec::<SExpr>::new();
 
-            // lhs = SExpr::Cons(sym, list.clone());
+        while !self.lexer.is_empty() {
+            forms.push(self.read_form()?);
+        }
 
-            println!("lhs is: {}", lhs);
-            println!("");
+        Ok(forms)
+    }
 
-            match self.lexer.next() {
-                Token::Eof => break,
+    // false if input ends inside a form, a string or a comment. The
+    // source of input is not recorded, it is read again once complete.
+    pub fn is_complete(&mut self, input: &str) -> bool {
+        self.lexer.set_origin(STDIN_SOURCE, 1, 0);
 
-                Token::Symb(symb) => {
-                    match &*symb.name {
-                        ")" => break,
-                          _ => {
-                              let err = ParseError::new("Last",
-                                  pars_symb::token::Token::Symb(symb));
-                              return Err(RLError::ParseError(err))
-                        }
-                    }
-                }
+        let mut result = self.lexer.parse(input);
 
-                Token::Atom(atom) => {
-                    println!("last - option t");
-                    let err = ParseError::new("Last",
-                        pars_symb::token::Token::Atom(atom));
-                    return Err(RLError::ParseError(err))
-                }
-            };
-        } // outer loop
-
-        println!("second lhs is: {:?}", lhs);
-        println!("");
+        while result.is_ok() && !self.lexer.is_empty() {
+            result = self.parse_to_sexpr().map(|_| ());
+        }
 
-        println!("parse_to_sexpr - end");
-        println!("");
+        match result {
+            Err(err) => !err.is_end_of_input(),
 
-        Ok(lhs)
+            Ok(()) => true,
+        }
     }
 
-    pub fn parse(&mut self, input: &str) -> Result<SExpr, RLError> {
-        self.lexer.parse(input);
+    // Appends input to the source text of file, the spans of the
+    // next tokens continue where the previous input of file ended.
+    pub fn add_source(&mut self, file: &str, input: &str) {
+        let source = self.sources
+                         .entry(file.to_string())
+                         .or_default();
 
-        self.parse_to_sexpr()
-    }
+        if !source.is_empty() && !source.ends_with('\n') {
+            source.push('\n');
+        }
 
-    pub fn parse_silent(&mut self, input: &str) {
-        self.lexer.parse(input);
+        let line = source.lines().count() + 1;
+        let byte = source.len();
 
-        let parse_result = self.parse_to_sexpr();
+        source.push_str(input);
 
-        println!("parse_silent - self.error: {:?}", self.error);
+        self.lexer.set_origin(file, line, byte);
+    }
 
-        match parse_result {
-            Ok(res)  => self.sexpr = res,
-            Err(err) => self.error = Some(err),
-        }
+    pub fn get_source(&self, file: &str) -> Option<&str> {
+        self.sources
+            .get(file)
+            .map(|source| source.as_str())
     }
 
     pub fn set_sexpr(&mut self, sexpr: SExpr) {
@@ -611,6 +378,7 @@ impl RLParser {
 
     pub fn reset(&mut self) {
         self.sexpr = SExpr::Nil(RLNil::new());
+        self.error = None;
     }
 
     pub fn change_tokens(&mut self, tokens: &Vec<Token>) {
@@ -618,4 +386,27 @@ impl RLParser {
     }
 }
 
-////////////////////////////////////////////////////////////
+// (a b . c) => (a . (b . c)), (a . (b c)) => (a b c)
+pub fn build_dotted_list(mut items: LinkedList<SExpr>, tail: SExpr) -> SExpr {
+    match tail {
+        SExpr::Nil(_) => {
+            return SExpr::SList(RLList::<SExpr>::new_with_items(&items));
+        }
+
+        SExpr::SList(ref list) => {
+            items.append(&mut list.get_items());
+
+            return SExpr::SList(RLList::<SExpr>::new_with_items(&items));
+        }
+
+        _ => {}
+    }
+
+    let mut result = tail;
+
+    while let Some(item) = items.pop_back() {
+        result = SExpr::SCons(RLCons::<SExpr>::new_cons(item, result));
+    }
+
+    result
+}
@@ -0,0 +1,48 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::fmt;
+use std::rc::Rc;
+
+// Location of a token or form in its source. line and column start
+// at 1, start and end are the byte range in the whole source of file.
+// Spans are copied with the forms, the file name is shared.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub file: Rc<str>,
+
+    pub line: usize,
+    pub column: usize,
+
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(file: &str,
+               line: usize,
+               column: usize,
+               start: usize,
+               end: usize) -> Span {
+        let file = Rc::from(file);
+
+        Span { file, line, column, start, end }
+    }
+
+    // span from the beginning of self to the end of other
+    pub fn to(&self, other: &Span) -> Span {
+        let mut span = self.clone();
+
+        span.end = other.end;
+
+        span
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
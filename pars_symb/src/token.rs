@@ -14,6 +14,9 @@ use hash::hash::RLHash;
 pub enum Token {
     Atom(String),
     Symb(Symbol),
+
+    // the character of #\a or #\Space
+    Char(char),
     Eof,
 }
 
@@ -22,6 +25,7 @@ impl fmt::Display for Token {
         match self {
             Token::Atom(s)   => write!(f, "{}", s),
             Token::Symb(s)    => write!(f, "{}", s.name),
+            Token::Char(c)   => write!(f, "#\\{}", c),
             Token::Eof       => write!(f, "Eof"),
         }
     }
@@ -3,74 +3,454 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
-use std::io::{BufRead, stdin, stdout, Write};
+use std::collections::LinkedList;
+use std::io::{stdin, stdout, Write};
+use std::process::exit;
+
+use env::result::RLResult;
+
+use err::frame::render_backtrace;
 
 // use eval::evaluator::{RLEvaluator, downcast_result};
+use eval::debugger::RLDebugger;
 use eval::evaluator::RLEvaluator;
+use eval::unwind::RLUnwind;
+
+use expr::atom::RLAtom;
+use expr::condition::RLCondition;
+use expr::list::RLList;
+use expr::nil::RLNil;
+use expr::restart::RLRestart;
+use expr::sexpr::SExpr;
+
+use trace::trace::{configure, configure_from_env};
+
+const USAGE: &str = "\
+Usage: rlisp [options] [script.lisp [args...]]
+
+Without a script rlisp starts the REPL. The arguments following the
+script are bound to *ARGS* as a list of strings.
+
+Options:
+  -e, --eval FORM   evaluate FORM, print its value and exit
+  --load FILE       load FILE before the REPL or the script starts
+  --trace SPEC      trace categories, e.g. \"zipper,env=debug\" or \"all\"
+  -h, --help        show this help
+
+Trace categories are lexer, parser, zipper, macroexpand and env, the
+levels are info and debug. RLISP_TRACE takes the same SPEC as --trace,
+(rlisp:set-trace :zipper t) changes a category at run time.";
 
 fn show_repl_intro() {
-    print!("RLisp> ");
+    show_prompt("RLisp> ");
+}
+
+fn show_prompt(prompt: &str) {
+    print!("{}", prompt);
     stdout()
         .flush()
         .expect("Failed to flush");
 }
 
+// The next line of stdin without the line break, None at the end of
+// the input. Stdin is not kept locked, the debugger reads from it
+// while the REPL evaluates a form.
+fn read_line() -> Option<String> {
+    let mut line = String::new();
+
+    match stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+
+        Ok(_) => Some(line.trim_end_matches(['\n', '\r']).to_string()),
+    }
+}
+
+// The lines of the next complete form after prompt, None at the end
+// of the input.
+fn read_form(rl: &mut RLEvaluator, prompt: &str) -> Option<String> {
+    let mut input = String::new();
+
+    show_prompt(prompt);
+
+    loop {
+        let line = read_line()?;
+
+        if input.is_empty() && line.trim().is_empty() {
+            show_prompt(prompt);
+
+            continue;
+        }
+
+        input.push_str(&line);
+        input.push('\n');
+
+        if rl.parser.is_complete(&input) {
+            return Some(input)
+        }
+
+        show_repl_continuation();
+    }
+}
+
+// shown while a form spans several lines
+fn show_repl_continuation() {
+    print!("  ...> ");
+    stdout()
+        .flush()
+        .expect("Failed to flush");
+}
+
+// Evaluates every top-level form of input in order and prints each
+// result. The remaining forms are skipped after an error, which is
+// reported by returning false.
+fn eval_input(rl: &mut RLEvaluator, input: &str) -> bool {
+    rl.reset();
+
+    let forms = match rl.parser.parse_all(input) {
+        Ok(forms) => forms,
+
+        Err(err) => {
+            eprintln!("{}", rl.render_error(&err));
+
+            return false
+        }
+    };
+
+    for sexpr in forms {
+        rl.set_sexpr(sexpr);
+
+        match rl.eval() {
+            // back at the top level from the debugger, nothing to print
+            Ok(_) if rl.is_aborted() => {}
+
+            Ok(RLResult::ValuesRes(values)) => print_values(&values),
+
+            Ok(res) => println!("{}", res),
+
+            Err(err) => {
+                eprintln!("{}", rl.render_error(&err));
+
+                return false
+            }
+        };
+    }
+
+    true
+}
+
+// The debugger of the REPL. It shows the error and the active restarts
+// and reads commands until a restart is invoked: the number of a
+// restart invokes it, any other form is evaluated in the failing frame.
+struct ReplDebugger;
+
+impl RLDebugger for ReplDebugger {
+    fn debug(&mut self,
+             rl: &mut RLEvaluator,
+             condition: &RLCondition) -> RLUnwind {
+
+        eprintln!("debugger invoked on a {}: {}",
+                  condition.get_type_name(),
+                  condition.get_message());
+
+        let restarts = rl.compute_restarts();
+
+        eprintln!("\nrestarts (invokable by number):");
+
+        // a restart without a report is described by its name
+        for (number, restart) in restarts.iter().enumerate() {
+            let report = match restart.get_report() {
+                report if report.is_empty() => restart.get_name(),
+
+                report => report,
+            };
+
+            eprintln!("  {}: [{}] {}", number, restart.get_name(), report);
+        }
+
+        let frames = rl.backtrace();
+
+        if !frames.is_empty() {
+            eprintln!("\n{}", render_backtrace(&frames));
+        }
+
+        eprintln!("\nEnter a restart number or a form to evaluate it in \
+                   the failing frame.");
+
+        // the outermost restart returns to the top level
+        let abort = restarts.last().unwrap();
+
+        loop {
+            let input = match read_form(rl, "Debug> ") {
+                Some(input) => input,
+
+                None => return rl.invoke_restart(abort, LinkedList::new()),
+            };
+
+            if let Ok(number) = input.trim().parse::<usize>() {
+                match restarts.get(number) {
+                    Some(restart) => {
+                        if let Some(args) = read_restart_args(rl, restart) {
+                            return rl.invoke_restart(restart, args)
+                        }
+                    }
+
+                    None => eprintln!("There is no restart {}.", number),
+                }
+
+                continue;
+            }
+
+            let forms = match rl.parser.parse_all(&input) {
+                Ok(forms) => forms,
+
+                Err(err) => {
+                    eprintln!("{}", rl.render_error(&err));
+
+                    continue;
+                }
+            };
+
+            for sexpr in forms {
+                match rl.eval_in_frame(&sexpr) {
+                    Ok(RLResult::ValuesRes(values)) => print_values(&values),
+
+                    Ok(res) => println!("{}", res),
+
+                    Err(RLUnwind::Error(err)) |
+                    Err(RLUnwind::Unhandled(err)) => {
+                        eprintln!("{}", rl.render_error(&err));
+
+                        break;
+                    }
+
+                    // e.g. (invoke-restart 'use-value 1)
+                    Err(unwind) => return unwind,
+                }
+            }
+        }
+    }
+}
+
+// The values of the required arguments of restart, each one the
+// primary value of a form read from stdin. None if a form fails.
+fn read_restart_args(rl: &mut RLEvaluator, restart: &RLRestart) ->
+    Option<LinkedList<SExpr>> {
+
+    let mut args = LinkedList::<SExpr>::new();
+
+    while args.len() < restart.get_arity() {
+        let input = read_form(rl, "Enter a form to be evaluated: ")?;
+
+        let sexpr = match rl.parser.parse_all(&input) {
+            Ok(forms) if forms.len() == 1 => forms[0].clone(),
+
+            Ok(_) => {
+                eprintln!("Enter exactly one form.");
+
+                continue;
+            }
+
+            Err(err) => {
+                eprintln!("{}", rl.render_error(&err));
+
+                return None
+            }
+        };
+
+        match rl.eval_in_frame(&sexpr) {
+            Ok(RLResult::ValuesRes(values)) => {
+                args.push_back(values.front()
+                                     .cloned()
+                                     .unwrap_or(SExpr::Nil(RLNil::new())));
+            }
+
+            Ok(res) => match RLResult::get_sexpr_from_res(res) {
+                Ok(value) => args.push_back(value),
+
+                Err(err) => {
+                    eprintln!("{}", rl.render_error(&err));
+
+                    return None
+                }
+            },
+
+            Err(RLUnwind::Error(err)) | Err(RLUnwind::Unhandled(err)) => {
+                eprintln!("{}", rl.render_error(&err));
+
+                return None
+            }
+
+            Err(_) => return None,
+        }
+    }
+
+    Some(args)
+}
+
+// every value on a line of its own, like (values 1 2) => 1 and 2
+fn print_values(values: &LinkedList<SExpr>) {
+    if values.is_empty() {
+        println!("; No values");
+    }
+
+    for value in values {
+        println!("{}", value);
+    }
+}
+
+fn load_file(rl: &mut RLEvaluator, file: &str) -> bool {
+    rl.reset();
+
+    match rl.load(file) {
+        Ok(_res) => true,
+
+        Err(err) => {
+            eprintln!("{}", rl.render_error(&err));
+
+            false
+        }
+    }
+}
+
+// *ARGS* => ("arg1" "arg2" ...)
+fn make_args_list(args: &[String]) -> SExpr {
+    if args.is_empty() {
+        return SExpr::Nil(RLNil::new())
+    }
+
+    let items = args.iter()
+                    .map(|arg| SExpr::Atom(RLAtom::new(
+                        &format!("\"{}\"", arg))))
+                    .collect::<LinkedList<SExpr>>();
+
+    SExpr::SList(RLList::<SExpr>::new_with_items(&items))
+}
+
 fn main() {
-    // let mut parser = RLParser::new();
-    // let mut rl = RLEvaluator::new_with_parser(&parser);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let mut evals = Vec::<String>::new();
+    let mut loads = Vec::<String>::new();
+    let mut script: Option<String> = None;
+    let mut script_args: &[String] = &[];
+
+    if let Err(msg) = configure_from_env() {
+        eprintln!("rlisp: RLISP_TRACE: {}", msg);
+
+        exit(2);
+    }
+
+    let mut idx = 0;
+
+    while idx < args.len() {
+        match args[idx].as_str() {
+            "-e" | "--eval" | "--load" | "--trace"
+                if idx + 1 >= args.len() => {
+                eprintln!("rlisp: missing argument for {}\n\n{}",
+                          args[idx],
+                          USAGE);
+
+                exit(2);
+            }
+
+            "-e" | "--eval" => {
+                evals.push(args[idx + 1].clone());
+
+                idx += 2;
+            }
+
+            "--load" => {
+                loads.push(args[idx + 1].clone());
+
+                idx += 2;
+            }
+
+            "--trace" => {
+                if let Err(msg) = configure(&args[idx + 1]) {
+                    eprintln!("rlisp: --trace: {}", msg);
+
+                    exit(2);
+                }
+
+                idx += 2;
+            }
+
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+
+                exit(0);
+            }
+
+            arg if arg.starts_with('-') && arg.len() > 1 => {
+                eprintln!("rlisp: unknown option {}\n\n{}", arg, USAGE);
+
+                exit(2);
+            }
+
+            _ => {
+                script = Some(args[idx].clone());
+                script_args = &args[idx + 1..];
+
+                break;
+            }
+        }
+    }
+
     let mut rl = RLEvaluator::new();
     rl.init();
 
+    rl.set_global_var("*args*", make_args_list(script_args));
+
+    for file in &loads {
+        if !load_file(&mut rl, file) {
+            exit(1);
+        }
+    }
+
+    if let Some(file) = script {
+        let success = load_file(&mut rl, &file) &&
+                      evals.iter().all(|form| eval_input(&mut rl, form));
+
+        exit(if success { 0 } else { 1 });
+    }
+
+    if !evals.is_empty() {
+        let success = evals.iter().all(|form| eval_input(&mut rl, form));
+
+        exit(if success { 0 } else { 1 });
+    }
+
+    rl.set_debugger(Box::new(ReplDebugger));
+
     println!("RLisp Version 0.0.1");
     println!("Press Crtl+c to exit");
     println!("");
 
     show_repl_intro();
 
-    for line in stdin().lock().lines() {
-        let line = line.unwrap();
+    // lines of a form which is not complete yet
+    let mut input = String::new();
 
-        if line.is_empty() {
+    while let Some(line) = read_line() {
+        if input.is_empty() && line.trim().is_empty() {
             show_repl_intro();
-        } else {
-            // let parser = RLParser::new();
-            // &parser.parse_silent(&line);
-            rl.update_symbols();
-            rl.reset();
-            rl.parser.parse_silent(&line);
-            rl.configure();
-
-            // let mut rl = RLEvaluator::new_with_parser(&parser);
-            // rl.init();
-
-            /*
-            let mut sexpr: SExpr = SExpr::Nil;
-            let mut parse_err: Option<RLError> = None;
-
-            match parser.parse(&line) {
-                Ok(expr) => sexpr = expr,
-                Err(err) => parse_err = Some(err),
-            };
 
-            // println!("{}", sexpr);
+            continue;
+        }
 
-            if let Some(ref err) = parse_err {
-                eprintln!("{}", err);
-                } else {
-                    let mut rl = RLEvaluator::new_with_sexpr(&sexpr);
-                    // println!("{:?}", rl.get_sexpr());
-            */
+        input.push_str(&line);
+        input.push('\n');
 
-            // continue with rl.eval()
-            println!("main -> sexpr is: {:?}", rl.get_sexpr());
+        if !rl.parser.is_complete(&input) {
+            show_repl_continuation();
 
-            match rl.eval() {
-                Ok(res) => println!("{}", res),
-                Err(err) => eprintln!("{}", err),
-            };
+            continue;
+        }
 
-            // }
-            show_repl_intro();
-        } // else
-    } // for
+        eval_input(&mut rl, &input);
+
+        input.clear();
+
+        show_repl_intro();
+    } // while
 } // main
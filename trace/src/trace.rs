@@ -0,0 +1,149 @@
+// This file is part of the rlisp package.
+//
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::cell::Cell;
+use std::fmt;
+
+// environment variable read at startup, same syntax as --trace
+pub const TRACE_ENV_VAR: &str = "RLISP_TRACE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceCategory {
+    Lexer,
+    Parser,
+    // evaluation of forms
+    Zipper,
+    Macroexpand,
+    Env,
+}
+
+impl TraceCategory {
+    pub const ALL: [TraceCategory; 5] = [TraceCategory::Lexer,
+                                         TraceCategory::Parser,
+                                         TraceCategory::Zipper,
+                                         TraceCategory::Macroexpand,
+                                         TraceCategory::Env];
+
+    // lexer, :lexer and LEXER name the same category
+    pub fn from_name(name: &str) -> Option<TraceCategory> {
+        let name = name.strip_prefix(':').unwrap_or(name);
+
+        TraceCategory::ALL
+            .into_iter()
+            .find(|category| category.to_string()
+                                     .eq_ignore_ascii_case(name))
+    }
+}
+
+impl fmt::Display for TraceCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceCategory::Lexer       => write!(f, "lexer"),
+            TraceCategory::Parser      => write!(f, "parser"),
+            TraceCategory::Zipper      => write!(f, "zipper"),
+            TraceCategory::Macroexpand => write!(f, "macroexpand"),
+            TraceCategory::Env         => write!(f, "env"),
+        }
+    }
+}
+
+// Info shows what happens, Debug adds the data it happens to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TraceLevel {
+    Off,
+    Info,
+    Debug,
+}
+
+impl TraceLevel {
+    // nil and t are accepted for (rlisp:set-trace :env t)
+    pub fn from_name(name: &str) -> Option<TraceLevel> {
+        let name = name.strip_prefix(':').unwrap_or(name);
+
+        match &*name.to_lowercase() {
+            "off" | "nil" | "0"  => Some(TraceLevel::Off),
+            "info" | "t" | "1"   => Some(TraceLevel::Info),
+            "debug" | "2"        => Some(TraceLevel::Debug),
+            _                    => None,
+        }
+    }
+}
+
+thread_local! {
+    static LEVELS: Cell<[TraceLevel; 5]> =
+        const { Cell::new([TraceLevel::Off; 5]) };
+}
+
+pub fn set_trace(category: TraceCategory, level: TraceLevel) {
+    LEVELS.with(|levels| {
+        let mut curr_levels = levels.get();
+
+        curr_levels[category as usize] = level;
+
+        levels.set(curr_levels);
+    })
+}
+
+pub fn get_trace(category: TraceCategory) -> TraceLevel {
+    LEVELS.with(|levels| levels.get()[category as usize])
+}
+
+pub fn is_enabled(category: TraceCategory, level: TraceLevel) -> bool {
+    level != TraceLevel::Off && get_trace(category) >= level
+}
+
+// Enables the categories of a comma separated list like
+// "lexer,zipper=debug" or "all". Without a level Info is used.
+pub fn configure(spec: &str) -> Result<(), String> {
+    for item in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let (name, level_name) = match item.split_once('=') {
+            Some((name, level_name)) => (name, Some(level_name)),
+            None => (item, None),
+        };
+
+        let level = match level_name {
+            Some(level_name) => TraceLevel::from_name(level_name)
+                .ok_or(format!("unknown trace level {}", level_name))?,
+
+            None => TraceLevel::Info,
+        };
+
+        if name.eq_ignore_ascii_case("all") {
+            TraceCategory::ALL
+                .into_iter()
+                .for_each(|category| set_trace(category, level));
+        } else {
+            let category = TraceCategory::from_name(name)
+                .ok_or(format!("unknown trace category {}", name))?;
+
+            set_trace(category, level);
+        }
+    }
+
+    Ok(())
+}
+
+pub fn configure_from_env() -> Result<(), String> {
+    match std::env::var(TRACE_ENV_VAR) {
+        Ok(spec) => configure(&spec),
+        Err(_) => Ok(()),
+    }
+}
+
+// Traces go to stderr, stdout is left to the program.
+pub fn write_trace(category: TraceCategory, args: fmt::Arguments) {
+    eprintln!("; {}: {}", category, args);
+}
+
+// rltrace!(TraceCategory::Env, TraceLevel::Info, "bind {}", name);
+// The arguments are only formatted if the category is enabled.
+#[macro_export]
+macro_rules! rltrace {
+    ($category:expr, $level:expr, $($arg:tt)*) => {
+        if $crate::trace::is_enabled($category, $level) {
+            $crate::trace::write_trace($category, format_args!($($arg)*));
+        }
+    };
+}